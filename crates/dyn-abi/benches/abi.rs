@@ -98,6 +98,21 @@ fn sol_types_encode(c: &mut Criterion) {
         b.iter(|| black_box(&input).abi_encode());
     });
 
+    // compares single-pass encoding with encoding through the token tree
+    g.bench_function("dynamic_struct", |b| {
+        let input = encode_dynamic_struct_input();
+        b.iter(|| black_box(&input).abi_encode());
+    });
+
+    g.bench_function("dynamic_struct/tokens", |b| {
+        let input = encode_dynamic_struct_input();
+        let tokens = <DynamicInput as SolType>::tokenize(&input);
+        assert_eq!(alloy_sol_types::abi::encode(&tokens), input.abi_encode());
+        b.iter(|| {
+            alloy_sol_types::abi::encode(&<DynamicInput as SolType>::tokenize(black_box(&input)))
+        });
+    });
+
     g.finish();
 }
 
@@ -130,6 +145,12 @@ sol! {
         uint256 amountOutMinimum;
         uint160 sqrtPriceLimitX96;
     }
+
+    struct DynamicInput {
+        Input[] inputs;
+        bytes[] data;
+        string name;
+    }
 }
 
 fn encode_single_input() -> String {
@@ -149,6 +170,14 @@ fn encode_struct_input() -> Input {
     }
 }
 
+fn encode_dynamic_struct_input() -> DynamicInput {
+    DynamicInput {
+        inputs: vec![encode_struct_input(); 64],
+        data: (0..64).map(|i| vec![i; i as usize]).collect(),
+        name: encode_single_input(),
+    }
+}
+
 fn encode_struct_input_tokens() -> [ethabi::Token; 8] {
     let input = encode_struct_input();
     [
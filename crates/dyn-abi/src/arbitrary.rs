@@ -672,6 +672,15 @@ mod tests {
             prop_assert_eq!(parsed.as_ref(), Ok(&ty), "types don't match {:?}", s);
         }
 
+        prop_assert_eq!(
+            value.abi_encode().len(),
+            value.abi_encoded_size() + value.is_dynamic() as usize * 32
+        );
+        prop_assert_eq!(
+            value.abi_encode_packed().len(),
+            value.abi_packed_encoded_size()
+        );
+
         let data = value.abi_encode_params();
         match ty.abi_decode_params(&data) {
             // skip the check if the type contains a CustomStruct, since
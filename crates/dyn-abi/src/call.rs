@@ -0,0 +1,292 @@
+use crate::{DynSolType, DynSolValue, Error, Result};
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::{keccak256, Selector, B256};
+use alloy_sol_types::abi::Decoder;
+
+/// Returns the canonical signature of a function, error, or event with the
+/// given name and parameters, such as `transfer(address,uint256)`.
+///
+/// This is the preimage of selectors and event topics, and it matches the
+/// `SIGNATURE`s generated by [`sol!`](alloy_sol_types::sol!): structs are
+/// formatted as tuples, and tuples with a single element have no trailing
+/// comma, unlike in [`DynSolType::sol_type_name`].
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{signature, DynSolType};
+///
+/// let params = [
+///     DynSolType::parse("uint")?,
+///     DynSolType::parse("(address)[]")?,
+/// ];
+/// assert_eq!(signature("foo", &params), "foo(uint256,(address)[])");
+/// # Ok::<_, alloy_dyn_abi::Error>(())
+/// ```
+pub fn signature(name: &str, params: &[DynSolType]) -> String {
+    let mut out = String::with_capacity(
+        name.len()
+            + 2
+            + params
+                .iter()
+                .map(DynSolType::sol_type_name_capacity)
+                .sum::<usize>(),
+    );
+    out.push_str(name);
+    out.push('(');
+    for (i, ty) in params.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        ty.signature_raw(&mut out);
+    }
+    out.push(')');
+    out
+}
+
+/// Returns the selector of a function or error with the given name and
+/// parameters: the first 4 bytes of the hash of its [`signature`].
+pub fn selector(name: &str, params: &[DynSolType]) -> Selector {
+    Selector::from_slice(&keccak256(signature(name, params))[..4])
+}
+
+/// Returns the first topic of a non-anonymous event with the given
+/// signature, such as `Transfer(address,address,uint256)`: the hash of the
+/// signature.
+///
+/// The signature must be canonical, without parameter names or type aliases
+/// such as `uint`. See [`signature`] to format one from types, and
+/// [`DynSolValue::encode_topic`] to encode the values of indexed parameters.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::event_topic;
+/// use alloy_primitives::b256;
+///
+/// assert_eq!(
+///     event_topic("Transfer(address,address,uint256)"),
+///     b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"),
+/// );
+/// ```
+pub fn event_topic(signature: &str) -> B256 {
+    keccak256(signature)
+}
+
+/// A dynamic ABI function call.
+///
+/// This is a representation of a Solidity function, which can be used to
+/// encode calls to it and decode their inputs and return values, like
+/// [`SolCall`](alloy_sol_types::SolCall) for functions known at compile time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynSolCall {
+    selector: Selector,
+    name: String,
+    parameters: Vec<DynSolType>,
+    returns: Vec<DynSolType>,
+}
+
+impl DynSolCall {
+    /// Creates a new function, computing its selector.
+    pub fn new(
+        name: impl Into<String>,
+        parameters: Vec<DynSolType>,
+        returns: Vec<DynSolType>,
+    ) -> Self {
+        let name = name.into();
+        Self {
+            selector: selector(&name, &parameters),
+            name,
+            parameters,
+            returns,
+        }
+    }
+
+    /// Get the selector of this function.
+    #[inline]
+    pub const fn selector(&self) -> Selector {
+        self.selector
+    }
+
+    /// Get the name of this function.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the parameter types of this function.
+    #[inline]
+    pub fn parameters(&self) -> &[DynSolType] {
+        &self.parameters
+    }
+
+    /// Get the return types of this function.
+    #[inline]
+    pub fn returns(&self) -> &[DynSolType] {
+        &self.returns
+    }
+
+    /// Returns the canonical signature of this function, such as
+    /// `transfer(address,uint256)`. See [`signature`] for more details.
+    #[inline]
+    pub fn signature(&self) -> String {
+        signature(&self.name, &self.parameters)
+    }
+
+    /// ABI-encodes the given values as a call to this function, **with** its
+    /// selector.
+    pub fn abi_encode_input(&self, values: &[DynSolValue]) -> Result<Vec<u8>> {
+        encode_typeck(&self.parameters, values).map(|encoded| {
+            let mut data = Vec::with_capacity(4 + encoded.len());
+            data.extend_from_slice(self.selector.as_slice());
+            data.extend_from_slice(&encoded);
+            data
+        })
+    }
+
+    /// ABI-decodes the arguments of a call to this function, **with** its
+    /// selector.
+    pub fn abi_decode_input(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
+        let actual = data
+            .get(..4)
+            .ok_or(alloy_sol_types::Error::Overrun { offset: 0 })?;
+        let actual = Selector::from_slice(actual);
+        if actual != self.selector {
+            return Err(Error::SelectorMismatch {
+                expected: self.selector,
+                actual,
+            })
+        }
+        abi_decode(&self.parameters, &data[4..], validate)
+    }
+
+    /// ABI-encodes the given values as the return values of this function.
+    pub fn abi_encode_output(&self, values: &[DynSolValue]) -> Result<Vec<u8>> {
+        encode_typeck(&self.returns, values)
+    }
+
+    /// ABI-decodes the return values of this function.
+    pub fn abi_decode_output(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
+        abi_decode(&self.returns, data, validate)
+    }
+}
+
+pub(crate) fn encode_typeck(types: &[DynSolType], values: &[DynSolValue]) -> Result<Vec<u8>> {
+    if values.len() != types.len() {
+        return Err(Error::EncodeLengthMismatch {
+            expected: types.len(),
+            actual: values.len(),
+        })
+    }
+    for (ty, value) in core::iter::zip(types, values) {
+        if !ty.matches(value) {
+            return Err(Error::TypeMismatch {
+                expected: ty.sol_type_name().into_owned(),
+                actual: value
+                    .sol_type_name()
+                    .unwrap_or_else(|| "<none>".into())
+                    .into_owned(),
+            })
+        }
+    }
+    Ok(DynSolValue::encode_seq(values))
+}
+
+fn abi_decode(types: &[DynSolType], data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
+    let mut decoder = Decoder::new(data, validate);
+    let values = types
+        .iter()
+        .map(|ty| ty.abi_decode_inner(&mut decoder, crate::DynToken::decode_single_populate))
+        .collect::<Result<Vec<_>>>()?;
+    if validate && DynSolValue::encode_seq(&values) != data {
+        return Err(alloy_sol_types::Error::ReserMismatch.into())
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+    use alloy_primitives::{Address, U256};
+    use alloy_sol_types::{sol, SolCall, SolEvent};
+
+    sol! {
+        struct Single {
+            uint a;
+        }
+
+        function f(uint a, Single s, Single[] ss, (bool, int8)[2] t) returns (bool);
+
+        event E(uint indexed a, Single s);
+    }
+
+    fn params() -> Vec<DynSolType> {
+        let single = DynSolType::Tuple(vec![DynSolType::Uint(256)]);
+        vec![
+            DynSolType::parse("uint").unwrap(),
+            single.clone(),
+            DynSolType::Array(Box::new(single)),
+            DynSolType::parse("(bool,int8)[2]").unwrap(),
+        ]
+    }
+
+    #[test]
+    fn canonical_signatures() {
+        let params = params();
+        assert_eq!(
+            signature("f", &params),
+            "f(uint256,(uint256),(uint256)[],(bool,int8)[2])"
+        );
+        assert_eq!(signature("f", &params), fCall::SIGNATURE);
+        assert_eq!(selector("f", &params), fCall::SELECTOR);
+        assert_eq!(signature("g", &[]), "g()");
+
+        let event_params = [params[0].clone(), params[1].clone()];
+        assert_eq!(signature("E", &event_params), E::SIGNATURE);
+        assert_eq!(
+            event_topic(&signature("E", &event_params)),
+            E::SIGNATURE_HASH
+        );
+    }
+
+    #[test]
+    fn call() {
+        let call = DynSolCall::new(
+            "transfer",
+            vec![DynSolType::Address, DynSolType::Uint(256)],
+            vec![DynSolType::Bool],
+        );
+        assert_eq!(call.signature(), "transfer(address,uint256)");
+        assert_eq!(call.selector(), Selector::new([0xa9, 0x05, 0x9c, 0xbb]));
+
+        let args = [Address::repeat_byte(0x11).into(), U256::from(100).into()];
+        let data = call.abi_encode_input(&args).unwrap();
+        assert_eq!(data[..4], call.selector()[..]);
+        assert_eq!(call.abi_decode_input(&data, true).unwrap(), args);
+        assert_eq!(
+            call.abi_decode_input(&data[4..], true),
+            Err(Error::SelectorMismatch {
+                expected: call.selector(),
+                actual: Selector::from_slice(&data[4..8]),
+            })
+        );
+        assert!(matches!(
+            call.abi_encode_input(&args[..1]),
+            Err(Error::EncodeLengthMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+        assert!(matches!(
+            call.abi_encode_input(&[args[1].clone(), args[0].clone()]),
+            Err(Error::TypeMismatch { .. })
+        ));
+
+        let output = call.abi_encode_output(&[true.into()]).unwrap();
+        assert_eq!(
+            call.abi_decode_output(&output, true).unwrap(),
+            [true.into()]
+        );
+    }
+}
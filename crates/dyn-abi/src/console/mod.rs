@@ -0,0 +1,239 @@
+//! Decoding of Hardhat `console.log` calls.
+//!
+//! Hardhat's and Foundry's `console.sol` libraries log values by calling
+//! [`HARDHAT_CONSOLE_ADDRESS`] with one of the overloaded `log` functions.
+//! These calls never succeed on-chain, but tracing tools can use
+//! [`decode_console_log`] to render them the same way Hardhat does.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_dyn_abi::{console::decode_console_log, DynSolValue};
+//! use alloy_primitives::{hex, U256};
+//!
+//! // log(string,uint256)
+//! let mut data = hex!("b60e72cc").to_vec();
+//! data.extend(
+//!     DynSolValue::Tuple(vec![
+//!         "balance: %s wei".to_string().into(),
+//!         U256::from(100).into(),
+//!     ])
+//!     .abi_encode_params(),
+//! );
+//! assert_eq!(
+//!     decode_console_log(&data).as_deref(),
+//!     Some("balance: 100 wei")
+//! );
+//! ```
+
+use crate::{DynSolType, DynSolValue};
+use alloc::string::{String, ToString};
+use alloy_primitives::{address, hex, Address, Selector};
+use core::fmt::Write;
+
+mod signatures;
+use signatures::SIGNATURES;
+
+/// The address of the Hardhat console:
+/// `0x000000000000000000636F6e736F6c652e6c6f67`, which is `console.log` in
+/// ASCII.
+pub const HARDHAT_CONSOLE_ADDRESS: Address = address!("000000000000000000636F6e736F6c652e6c6f67");
+
+/// Returns the canonical signature of the `console.log` function with the
+/// given selector, if any.
+///
+/// Legacy selectors, computed from `uint` and `int` instead of `uint256` and
+/// `int256`, are also recognized.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::console::console_log_signature;
+/// use alloy_primitives::Selector;
+///
+/// let canonical = Selector::new([0xf8, 0x2c, 0x50, 0xf1]);
+/// let legacy = Selector::new([0xf5, 0xb1, 0xbb, 0xa9]);
+/// assert_eq!(console_log_signature(canonical), Some("log(uint256)"));
+/// assert_eq!(console_log_signature(legacy), Some("log(uint256)"));
+/// ```
+pub fn console_log_signature(selector: Selector) -> Option<&'static str> {
+    let selector = u32::from_be_bytes(selector.0);
+    SIGNATURES
+        .binary_search_by_key(&selector, |&(s, _)| s)
+        .ok()
+        .map(|i| SIGNATURES[i].1)
+}
+
+/// Decodes and renders the calldata of a call to a `console.log` function.
+///
+/// Returns `None` if the selector is not a `console.log` one, or if the
+/// parameters cannot be decoded.
+///
+/// Values are rendered like Hardhat does: if the first value is a string, it
+/// is used as a format string in which `%s`, `%d`, `%i`, and `%o` are replaced
+/// by the following values and `%%` by `%`; the remaining values are appended,
+/// separated by spaces. Numbers are rendered in decimal, addresses are
+/// checksummed, and bytes are hex-encoded.
+pub fn decode_console_log(data: &[u8]) -> Option<String> {
+    if data.len() < 4 {
+        return None
+    }
+    let (selector, params) = data.split_at(4);
+    let signature = console_log_signature(Selector::from_slice(selector))?;
+    let start = signature.find('(')?;
+    let ty = DynSolType::parse(&signature[start..]).ok()?;
+    let values = match ty.abi_decode_params(params).ok()? {
+        DynSolValue::Tuple(values) => values,
+        _ => return None,
+    };
+    Some(format_values(&values))
+}
+
+fn format_values(values: &[DynSolValue]) -> String {
+    let mut out = String::new();
+    let mut values = values.iter();
+    if let Some(DynSolValue::String(fmt)) = values.as_slice().first() {
+        values.next();
+        let mut chars = fmt.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                out.push(c);
+                continue
+            }
+            match chars.clone().next() {
+                Some('%') => {
+                    chars.next();
+                    out.push('%');
+                }
+                Some('s' | 'd' | 'i' | 'o') if !values.as_slice().is_empty() => {
+                    chars.next();
+                    format_value(&mut out, values.next().unwrap());
+                }
+                _ => out.push('%'),
+            }
+        }
+    } else if let Some(value) = values.next() {
+        format_value(&mut out, value);
+    }
+    for value in values {
+        out.push(' ');
+        format_value(&mut out, value);
+    }
+    out
+}
+
+fn format_value(out: &mut String, value: &DynSolValue) {
+    match value {
+        DynSolValue::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        DynSolValue::Int(i, _) => out.push_str(&i.to_string()),
+        DynSolValue::Uint(u, _) => out.push_str(&u.to_string()),
+        DynSolValue::Address(a) => out.push_str(&a.to_checksum(None)),
+        DynSolValue::String(s) => out.push_str(s),
+        DynSolValue::Bytes(b) => out.push_str(&hex::encode_prefixed(b)),
+        DynSolValue::FixedBytes(word, size) => {
+            out.push_str(&hex::encode_prefixed(&word[..*size]));
+        }
+        // not produced by any `console.log` signature
+        value => {
+            let _ = write!(out, "{value:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use alloy_primitives::{keccak256, I256, U256};
+
+    fn call(signature: &str, values: Vec<DynSolValue>) -> Vec<u8> {
+        let mut data = keccak256(signature)[..4].to_vec();
+        data.extend(DynSolValue::Tuple(values).abi_encode_params());
+        data
+    }
+
+    #[test]
+    fn signatures() {
+        assert!(SIGNATURES.windows(2).all(|w| w[0].0 < w[1].0));
+        for &(selector, signature) in &SIGNATURES {
+            assert!(DynSolType::parse(&signature[signature.find('(').unwrap()..]).is_ok());
+            let legacy = signature.replace("int256", "int");
+            assert!(
+                [signature, &legacy]
+                    .iter()
+                    .any(|s| keccak256(s)[..4] == selector.to_be_bytes()),
+                "{signature}"
+            );
+        }
+        // every `log` overload of up to 4 parameters
+        let count = |n| {
+            SIGNATURES
+                .iter()
+                .filter(|(_, s)| s.matches(',').count() == n)
+                .count()
+        };
+        assert_eq!(count(1), 16 + 7);
+        assert_eq!(count(2), 64 + 37);
+        assert_eq!(count(3), 256 + 175);
+    }
+
+    #[test]
+    fn decode() {
+        let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        let cases = [
+            (call("log()", vec![]), ""),
+            (call("log(uint)", vec![U256::from(42).into()]), "42"),
+            (call("logInt(int256)", vec![I256::MINUS_ONE.into()]), "-1"),
+            (
+                call(
+                    "log(string,address,bool)",
+                    vec!["owner:".to_string().into(), address.into(), true.into()],
+                ),
+                "owner: 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045 true",
+            ),
+            (
+                call(
+                    "log(string,uint256,uint256)",
+                    vec![
+                        "%s%% of %d %x".to_string().into(),
+                        U256::from(50).into(),
+                        U256::from(8).into(),
+                    ],
+                ),
+                "50% of 8 %x",
+            ),
+            (
+                call(
+                    "log(string,string)",
+                    vec!["%s %s".to_string().into(), "a".to_string().into()],
+                ),
+                "a %s",
+            ),
+            (
+                call(
+                    "log(bool,string)",
+                    vec![false.into(), "%s".to_string().into()],
+                ),
+                "false %s",
+            ),
+            (
+                call("logBytes(bytes)", vec![DynSolValue::Bytes(vec![1, 2])]),
+                "0x0102",
+            ),
+            (
+                call(
+                    "logBytes2(bytes2)",
+                    vec![DynSolValue::FixedBytes([0xab; 32].into(), 2)],
+                ),
+                "0xabab",
+            ),
+        ];
+        for (data, expected) in cases {
+            assert_eq!(decode_console_log(&data).as_deref(), Some(expected));
+        }
+
+        assert_eq!(decode_console_log(&[]), None);
+        assert_eq!(decode_console_log(&keccak256("log(bytes)")[..4]), None);
+        assert_eq!(decode_console_log(&keccak256("log(string)")[..4]), None);
+    }
+}
@@ -0,0 +1,613 @@
+//! The selectors of the Hardhat `console.log` functions.
+//!
+//! Generated from the functions of Hardhat's `console.sol`. Functions taking
+//! `uint256` or `int256` parameters also have an entry for their legacy
+//! selector, computed from the `uint` or `int` spelling, mapping to the same
+//! canonical signature.
+
+/// `(selector, signature)` pairs, sorted by selector.
+pub(super) static SIGNATURES: [(u32, &str); 603] = [
+    (0x007150be, "log(address,address,string)"),
+    (0x00dd87b9, "log(bool,uint256,uint256,address)"),
+    (0x00f56bc9, "logBytes19(bytes19)"),
+    (0x01550b04, "log(uint256,address,address,bool)"),
+    (0x018c84c2, "log(address,address,address)"),
+    (0x031c6f73, "log(uint256,address,address,string)"),
+    (0x04289300, "log(address,address,string,uint256)"),
+    (0x0454c079, "log(string,address,bool,string)"),
+    (0x078287f5, "log(uint256,bool,address,uint256)"),
+    (0x07831502, "log(bool,address,bool,uint256)"),
+    (0x07c81217, "log(string,address,uint256)"),
+    (0x088ef9d2, "log(bool,uint256,address)"),
+    (0x08ee5666, "log(string,uint256,uint256,uint256)"),
+    (0x091ffaf5, "log(uint256,address,address,bool)"),
+    (0x0977aefc, "logBytes24(bytes24)"),
+    (0x0aa6cfad, "log(address,bool,uint256,string)"),
+    (0x0b99fc22, "log(bool,address,string,uint256)"),
+    (0x0bb00eab, "log(bool,bool,uint256,uint256)"),
+    (0x0bb563d6, "logString(string)"),
+    (0x0bff950d, "log(bool,bool,uint256,address)"),
+    (0x0c66d1be, "log(bool,address,address,uint256)"),
+    (0x0c9cd9c1, "log(uint256,address,uint256,uint256)"),
+    (0x0d26b925, "log(string,address,uint256)"),
+    (0x0d36fa20, "log(address,string,address,address)"),
+    (0x0d8ce61e, "log(address,bool,uint256,address)"),
+    (0x0df12b76, "log(address,string,address,bool)"),
+    (0x0e378994, "log(address,address,address,bool)"),
+    (0x0ef7e050, "log(address,string,uint256,bool)"),
+    (0x0fa3f345, "log(uint256,string)"),
+    (0x100f650e, "log(address,uint256,address,uint256)"),
+    (0x1023f7b2, "log(string,string,uint256,address)"),
+    (0x1078f68d, "log(bool,bool,address)"),
+    (0x1093ee11, "log(bool,string,uint256)"),
+    (0x1106a8f7, "log(string,uint256,address,bool)"),
+    (0x12862b98, "log(uint256,string,string,bool)"),
+    (0x12d6c788, "log(bool,string,address,string)"),
+    (0x12f21602, "log(bool,bool,uint256)"),
+    (0x136b05dd, "log(bool,address,uint256,address)"),
+    (0x1537dc87, "log(bool,uint256,address,uint256)"),
+    (0x1596a1ce, "log(bool,string,uint256,address)"),
+    (0x159f8927, "log(address,string,string,uint256)"),
+    (0x15c127b5, "log(uint256,address,uint256,address)"),
+    (0x15cac476, "log(uint256,uint256,address,bool)"),
+    (0x1606a393, "log(bool,string,bool,uint256)"),
+    (0x1762e32a, "log(bool,string,string,string)"),
+    (0x178b4685, "log(bool,bool,string,uint256)"),
+    (0x17fe6185, "log(address,address,uint256)"),
+    (0x18091341, "log(bool,uint256,address,string)"),
+    (0x18c9c746, "log(bool,address,bool)"),
+    (0x193fb800, "log(uint256,uint256,uint256,uint256)"),
+    (0x19f67369, "log(uint256,address,uint256,bool)"),
+    (0x19fd4956, "log(address,bool,string,address)"),
+    (0x1ad96de6, "log(bool,string,uint256,string)"),
+    (0x1b0b955b, "log(bool,string,address,uint256)"),
+    (0x1badc9eb, "log(bool,uint256,bool)"),
+    (0x1bb3b09a, "log(bool,uint256,address,string)"),
+    (0x1c41a336, "log(bool,address,bool,address)"),
+    (0x1c7ec448, "log(string,uint256,address)"),
+    (0x1c9d7eb3, "log(uint256,bool)"),
+    (0x1cdaf28a, "log(address,string,uint256)"),
+    (0x1d14d001, "log(bool,address,address,address)"),
+    (0x1da986ea, "log(address,uint256,address,string)"),
+    (0x1dc8e1b8, "log(address,string,uint256,uint256)"),
+    (0x1e4b87e5, "log(bool,string,string,bool)"),
+    (0x1e6dd4ec, "log(uint256,bool)"),
+    (0x1ef63434, "log(address,uint256,uint256,address)"),
+    (0x1f8d7312, "logBytes16(bytes16)"),
+    (0x1f90f24a, "log(uint256,string,address)"),
+    (0x20098014, "log(uint256,bool,uint256)"),
+    (0x205871c2, "log(address,string,bool,address)"),
+    (0x20718650, "log(uint256,bool,bool)"),
+    (0x20bbc9af, "log(bool,string,uint256,bool)"),
+    (0x20e3984d, "log(address,uint256,uint256,address)"),
+    (0x212255cc, "log(address,bool,string)"),
+    (0x21ad0683, "log(uint256,string,string,string)"),
+    (0x21bdaf25, "log(address,address,string,string)"),
+    (0x223603bd, "log(string,address,bool,address)"),
+    (0x2243cfa3, "log(address,uint256)"),
+    (0x22a479a6, "log(uint256,address,string,bool)"),
+    (0x22f6b999, "log(address,uint256,bool,uint256)"),
+    (0x23e54972, "log(address,uint256,bool,address)"),
+    (0x245986f2, "log(string,address,string,string)"),
+    (0x2488b414, "log(uint256,address,address,address)"),
+    (0x24f91465, "log(string,bool,string,uint256)"),
+    (0x2555fa46, "log(bool,bool,string)"),
+    (0x26f560a8, "log(bool,uint256,address,address)"),
+    (0x27d8afd2, "log(uint256,uint256,string,string)"),
+    (0x28863fcb, "log(bool,string,uint256,uint256)"),
+    (0x28df4e96, "log(string,bool,address,uint256)"),
+    (0x291bb9d0, "log(string,bool,uint256)"),
+    (0x2a110e83, "log(bool,bool)"),
+    (0x2ae408d4, "log(bool,bool,bool,string)"),
+    (0x2b2b18dc, "log(bool,string,address,address)"),
+    (0x2c1754ed, "log(string,string,string,bool)"),
+    (0x2c1d0746, "log(uint256,bool,string,uint256)"),
+    (0x2c2ecbc2, "log(address)"),
+    (0x2c468d15, "log(address,bool,uint256)"),
+    (0x2cd4134a, "log(address,address,bool,bool)"),
+    (0x2ced7cef, "log(string,string,string)"),
+    (0x2d21d6f7, "logBytes32(bytes32)"),
+    (0x2d5b6cb9, "log(int256)"),
+    (0x2d8e33a4, "log(string,bool,address,string)"),
+    (0x2dd778e6, "log(address,bool,address,string)"),
+    (0x3052c08f, "logBytes21(bytes21)"),
+    (0x318ae59b, "log(uint256,bool,bool,string)"),
+    (0x319af333, "log(string,address)"),
+    (0x32458eed, "log(bool)"),
+    (0x3254c2e8, "log(string,uint256,address,string)"),
+    (0x32dfa524, "log(bool,uint256,uint256,uint256)"),
+    (0x33e9dd1d, "log(string,bool,address,address)"),
+    (0x346eb8c7, "log(uint256,bool,string,bool)"),
+    (0x34c1d81b, "logBytes13(bytes13)"),
+    (0x34cb308d, "log(string,bool,string,uint256)"),
+    (0x34f0e636, "log(address,uint256,uint256,uint256)"),
+    (0x35085f7b, "log(uint256,bool,address)"),
+    (0x354c36d6, "log(string,uint256,bool,bool)"),
+    (0x35a5071f, "log(address,string,string,bool)"),
+    (0x364b6a92, "log(bool,uint256)"),
+    (0x37103367, "log(bool,uint256,uint256)"),
+    (0x374bb4b2, "log(bool,uint256,uint256,uint256)"),
+    (0x37aa7d4c, "log(uint256,string,uint256)"),
+    (0x382f9a34, "logBytes28(bytes28)"),
+    (0x386ff5f4, "log(address,bool,uint256,uint256)"),
+    (0x3894163d, "log(uint256,uint256,string,uint256)"),
+    (0x3971e78c, "log(address,address,bool,uint256)"),
+    (0x399174d3, "log(bool,uint256)"),
+    (0x3b2279b4, "log(uint256,string,uint256,address)"),
+    (0x3b2a5ce0, "log(bool,bool,bool,bool)"),
+    (0x3b5c03e0, "log(bool,uint256,uint256)"),
+    (0x3bf5e537, "log(address,uint256,bool,bool)"),
+    (0x3cc5b5d3, "log(string,bool,uint256,bool)"),
+    (0x3ceaba65, "logBytes14(bytes14)"),
+    (0x3d0e9de4, "log(address,uint256,uint256,uint256)"),
+    (0x3e128ca3, "log(uint256,address,string,string)"),
+    (0x3e9f866a, "log(string,address,bool,uint256)"),
+    (0x3ed3bd28, "log(uint256,address,uint256,string)"),
+    (0x3f57c295, "log(uint256,string,string)"),
+    (0x3f8a701d, "log(string,bool,string,bool)"),
+    (0x40785869, "log(string,string,bool,bool)"),
+    (0x41304fac, "log(string)"),
+    (0x4180011b, "log(bool,uint256,string,uint256)"),
+    (0x41b5ef3b, "log(uint256,bool,address,uint256)"),
+    (0x424effbf, "log(uint256,bool,address)"),
+    (0x4267c7f8, "log(bool,uint256,bool,address)"),
+    (0x42b9a227, "log(string,bool,uint256,string)"),
+    (0x42d21db7, "log(uint256,uint256,string,address)"),
+    (0x433285a2, "log(uint256,uint256,string,address)"),
+    (0x439c7bef, "log(string,string,address,address)"),
+    (0x448830a8, "log(address,string,uint256,string)"),
+    (0x454d54a5, "log(uint256,bool,address,bool)"),
+    (0x4574afab, "logBytes7(bytes7)"),
+    (0x457fe3cf, "log(address,string,address,uint256)"),
+    (0x46600be0, "log(bool,address,address,bool)"),
+    (0x4667de8e, "log(bool,bool,uint256,uint256)"),
+    (0x46826b5d, "log(uint256,address,string,uint256)"),
+    (0x46a7d0ce, "log(uint256,string,bool)"),
+    (0x475c5c33, "log(address,bool,string,string)"),
+    (0x4766da72, "log(uint256,uint256,bool)"),
+    (0x478d1c62, "log(address,uint256,address,address)"),
+    (0x483d0416, "log(bool,string,bool,string)"),
+    (0x4942adc6, "logBytes6(bytes6)"),
+    (0x496e2bb4, "log(uint256,bool,string,address)"),
+    (0x4979b037, "logBytes23(bytes23)"),
+    (0x4a28c017, "log(address,uint256,uint256,string)"),
+    (0x4a66cb34, "log(bool,address,bool,string)"),
+    (0x4a81a56a, "log(string,string,address,uint256)"),
+    (0x4b5c4277, "log(string,string)"),
+    (0x4c123d57, "log(bool,bool,address,uint256)"),
+    (0x4c55f234, "log(string,address,uint256,string)"),
+    (0x4cb60fd1, "log(bool,address,bool,uint256)"),
+    (0x4ceda75a, "log(uint256,string,bool)"),
+    (0x4e0c1d1d, "log(int256)"),
+    (0x4e6c5315, "log(uint256,bool,bool,bool)"),
+    (0x4f04fdc6, "log(string,uint256,address,uint256)"),
+    (0x4f40058e, "log(uint256,bool,uint256,address)"),
+    (0x50618937, "log(bool,bool,uint256,string)"),
+    (0x50709698, "log(bool,bool,bool)"),
+    (0x50a138df, "logBytes9(bytes9)"),
+    (0x50ad461d, "log(address,bool,string,bool)"),
+    (0x515e38b6, "log(address,string,bool,uint256)"),
+    (0x51973ec9, "log()"),
+    (0x51bc2bc1, "log(uint256,string,bool,bool)"),
+    (0x51f09ff8, "log(bool,address,uint256,string)"),
+    (0x5284bd6c, "log(bool,address,address,uint256)"),
+    (0x5306225d, "log(uint256,bool,bool,address)"),
+    (0x538e06ab, "log(bool,string,bool,address)"),
+    (0x54a7a9a0, "log(bool,bool,uint256,address)"),
+    (0x54fdf3e4, "log(address,address,uint256,uint256)"),
+    (0x550e6ef5, "log(string,uint256,bool,uint256)"),
+    (0x554745f9, "log(uint256,address,address,address)"),
+    (0x5583be2e, "logBytes5(bytes5)"),
+    (0x56828da4, "log(uint256,bool,uint256,uint256)"),
+    (0x56a5d1b1, "log(uint256,uint256,address,address)"),
+    (0x57dd0a11, "log(uint256,string,string,string)"),
+    (0x5821efa1, "log(string,string,uint256)"),
+    (0x58497afe, "log(string,uint256,address,uint256)"),
+    (0x58eb860c, "log(uint256,address)"),
+    (0x591a3da2, "logBytes15(bytes15)"),
+    (0x5970e089, "log(string,uint256,string)"),
+    (0x59cfcbe3, "log(uint256,uint256,uint256,string)"),
+    (0x5a477632, "log(string,address,uint256,string)"),
+    (0x5a4d9922, "log(uint256,bool,uint256)"),
+    (0x5a9b5ed5, "log(uint256,address,uint256)"),
+    (0x5ab84e1f, "log(string,uint256,string,string)"),
+    (0x5abd992a, "log(uint256,address,bool,uint256)"),
+    (0x5ac1c13c, "log(string,address,uint256,bool)"),
+    (0x5b22b938, "log(bool,string,uint256,address)"),
+    (0x5b6de83f, "log(uint256,string,uint256)"),
+    (0x5c430d47, "log(address,uint256,string,address)"),
+    (0x5c96b331, "log(uint256,uint256,address)"),
+    (0x5ca0ad3e, "log(uint256,uint256,uint256,uint256)"),
+    (0x5ccd4e37, "log(string,string,address,bool)"),
+    (0x5d02c50b, "log(address,string,string,string)"),
+    (0x5d08bb05, "log(string,bool,address,uint256)"),
+    (0x5d1365c9, "log(address,string,uint256,string)"),
+    (0x5d1a971a, "log(string,string,uint256,string)"),
+    (0x5d4f4680, "log(string,string,uint256,address)"),
+    (0x5d71f39e, "log(address,uint256,address,string)"),
+    (0x5da297eb, "log(uint256,uint256,string,uint256)"),
+    (0x5dbff038, "log(string,bool,uint256,uint256)"),
+    (0x5ddb2592, "log(bool,string,string,uint256)"),
+    (0x5e84b0ea, "log(string,string,bool,string)"),
+    (0x5ea2b7ae, "log(string,uint256,address,address)"),
+    (0x5f15d28c, "log(string,address,string,bool)"),
+    (0x5f1d5c9f, "log(address,string,bool,bool)"),
+    (0x5f743a7c, "log(uint256,address,uint256,bool)"),
+    (0x5f7b9afb, "log(bool,address,uint256)"),
+    (0x5f91b0af, "logAddress(address)"),
+    (0x609386e7, "log(bool,bool,address,uint256)"),
+    (0x610ba8c0, "log(uint256,uint256,address,uint256)"),
+    (0x6168ed61, "log(uint256,string,address,address)"),
+    (0x619e4d0e, "log(bool,bool,uint256,bool)"),
+    (0x63183678, "log(address,string,uint256,address)"),
+    (0x63cb41f9, "log(uint256,address,string)"),
+    (0x63f0e242, "log(uint256,address,bool,string)"),
+    (0x63fb8bc5, "log(string,address,uint256,address)"),
+    (0x643fd0df, "log(uint256,string)"),
+    (0x6452b9cb, "log(uint256,uint256,uint256,bool)"),
+    (0x64b5bb67, "log(string,bool,uint256,uint256)"),
+    (0x6525b5f5, "logInt(int256)"),
+    (0x65adf408, "log(bool,uint256,address,bool)"),
+    (0x660375dd, "log(address,bool,address,address)"),
+    (0x665bf134, "log(address,address,address,address)"),
+    (0x66f1bc67, "log(address,uint256,uint256,bool)"),
+    (0x67570ff7, "log(uint256,uint256,bool)"),
+    (0x678209a8, "log(address,uint256,bool)"),
+    (0x67dd6ff1, "log(address,string,uint256)"),
+    (0x68c8b8bd, "log(uint256,bool,string,string)"),
+    (0x68f158b5, "log(bool,address,uint256,address)"),
+    (0x691a8f74, "log(uint256,string,uint256,bool)"),
+    (0x69276c86, "log(uint256,address)"),
+    (0x69640b59, "log(uint256,bool,bool,address)"),
+    (0x698f4392, "log(address,uint256,bool,uint256)"),
+    (0x6a1199e2, "log(bool,uint256,string,uint256)"),
+    (0x6a9c478b, "log(bool,address,bool,bool)"),
+    (0x6b0e5d53, "log(bool,string,uint256,bool)"),
+    (0x6c0f6980, "log(uint256,uint256)"),
+    (0x6c366d72, "log(address,address,uint256)"),
+    (0x6c647c8c, "log(uint256,uint256,bool,uint256)"),
+    (0x6c98dae2, "log(string,uint256,string,string)"),
+    (0x6cde40b8, "log(uint256,uint256,address,string)"),
+    (0x6d1e8751, "log(bool,bool,string,string)"),
+    (0x6d572f44, "log(string,string,string,address)"),
+    (0x6d7045c1, "log(bool,bool,bool,uint256)"),
+    (0x6dd434ca, "log(bool,string,address,bool)"),
+    (0x6eb7943d, "log(string,address,address,uint256)"),
+    (0x6f1a594e, "log(address,address,string,bool)"),
+    (0x6f4171c9, "logBytes1(bytes1)"),
+    (0x6f7c603e, "log(bool,address,string,address)"),
+    (0x7190a529, "log(string,bool,bool,address)"),
+    (0x71d04af2, "log(uint256,uint256,string)"),
+    (0x71d3850d, "log(string,bool,uint256,address)"),
+    (0x736efbb6, "log(uint256,address,address,uint256)"),
+    (0x742d6ee7, "log(string,bool,uint256,string)"),
+    (0x7464ce23, "log(uint256,bool,bool,uint256)"),
+    (0x759f86bb, "log(address,string)"),
+    (0x75b605d3, "log(address,bool)"),
+    (0x7626db92, "log(string,uint256,uint256,bool)"),
+    (0x7656d6c7, "logBytes12(bytes12)"),
+    (0x76cc6064, "log(string,uint256,bool,string)"),
+    (0x76ec635e, "log(uint256,string,string,uint256)"),
+    (0x7782fa2d, "logBytes3(bytes3)"),
+    (0x77a1abed, "log(bool,string,uint256,string)"),
+    (0x78ad7a0c, "log(uint256,uint256,uint256,string)"),
+    (0x7943dc66, "log(uint256,address,address,string)"),
+    (0x796f28a0, "log(uint256,string,bool,address)"),
+    (0x79884c2b, "log(string,address,bool,bool)"),
+    (0x7a187641, "logBytes29(bytes29)"),
+    (0x7ad0128e, "log(uint256,address,bool)"),
+    (0x7af6ab25, "log(uint256,uint256,string,bool)"),
+    (0x7afac959, "log(uint256,string,address)"),
+    (0x7b08e8eb, "log(uint256,address,bool,uint256)"),
+    (0x7bc0d848, "log(address,uint256,address)"),
+    (0x7be0c3eb, "log(bool,string,string,uint256)"),
+    (0x7bf181a1, "log(bool,address,uint256,uint256)"),
+    (0x7c032a32, "log(uint256,uint256,string,string)"),
+    (0x7c4632a4, "log(string,uint256,string,address)"),
+    (0x7cc3c607, "log(string,string,address,uint256)"),
+    (0x7d24491d, "log(string,uint256,string,bool)"),
+    (0x7d690ee6, "log(uint256,uint256,string)"),
+    (0x7d77a61b, "log(uint256,address,address)"),
+    (0x7dd4d0e0, "log(bool,bool,uint256,string)"),
+    (0x7e250d5b, "log(address,string,uint256,bool)"),
+    (0x7e27410d, "log(uint256,address,bool,bool)"),
+    (0x7e56c693, "log(address,uint256,string,string)"),
+    (0x7f9bbca2, "log(bool,uint256,bool,uint256)"),
+    (0x7fa5458b, "log(uint256,string,address,address)"),
+    (0x800a1c67, "log(string,address,address,string)"),
+    (0x807531e8, "log(string,bool,bool,uint256)"),
+    (0x807ab434, "logBytes22(bytes22)"),
+    (0x80e6a20b, "log(address,bool,string,uint256)"),
+    (0x81fc8648, "logBytes31(bytes31)"),
+    (0x82112a42, "log(string,uint256,address,bool)"),
+    (0x82c25b74, "log(uint256,string,uint256,uint256)"),
+    (0x8309e8a8, "log(address,uint256)"),
+    (0x850b7ad6, "log(string,bool,bool)"),
+    (0x853c4849, "log(bool,address)"),
+    (0x854b3496, "log(string,uint256,uint256,string)"),
+    (0x85775021, "log(uint256,bool,string)"),
+    (0x85cdc5af, "log(address,bool,uint256,bool)"),
+    (0x86818a7a, "log(string,string,bool,uint256)"),
+    (0x86edc10c, "log(uint256,bool,address,address)"),
+    (0x875a6e2e, "log(uint256,string,uint256,bool)"),
+    (0x8786135e, "log(address,uint256,uint256)"),
+    (0x884343aa, "log(uint256,address,uint256)"),
+    (0x88a8c406, "log(address,uint256,string,string)"),
+    (0x88cb6041, "log(uint256,bool,uint256,address)"),
+    (0x88f6e4b2, "log(uint256,uint256,address,uint256)"),
+    (0x89340dab, "log(address,uint256,uint256,string)"),
+    (0x895af8c5, "log(string,bool,bool,bool)"),
+    (0x8a2f90aa, "log(bool,uint256,address,address)"),
+    (0x8af7cf8a, "log(string,bool,uint256,bool)"),
+    (0x8b0e14fe, "log(uint256,bool,string)"),
+    (0x8c1933a9, "log(address,string,address,uint256)"),
+    (0x8c329b1a, "log(bool,bool,bool,address)"),
+    (0x8c4e5de6, "log(address,bool,bool,uint256)"),
+    (0x8d142cdd, "log(string,string,uint256,string)"),
+    (0x8d489ca0, "log(uint256,string,bool,string)"),
+    (0x8d6f9ca5, "log(bool,string,bool,uint256)"),
+    (0x8d778624, "log(uint256,address,string,string)"),
+    (0x8da6def5, "log(address,address,uint256,address)"),
+    (0x8e3f78a9, "log(string,bool,bool,uint256)"),
+    (0x8e4ae86e, "log(bool,string,uint256,uint256)"),
+    (0x8e69fb5d, "log(bool,uint256,uint256,string)"),
+    (0x8e8e4e75, "log(address,uint256,bool,string)"),
+    (0x8eafb02b, "log(string,string,string,uint256)"),
+    (0x8ef3f399, "log(string,address,address,uint256)"),
+    (0x8f624be9, "log(string,address,string,uint256)"),
+    (0x8f736d16, "log(address,address,string,address)"),
+    (0x8feac525, "log(bool,string)"),
+    (0x90c30a56, "log(uint256,string,address,bool)"),
+    (0x90fb06aa, "log(uint256,address,bool,string)"),
+    (0x9143dbb1, "log(bool,uint256,bool,string)"),
+    (0x915fdb28, "log(uint256,bool,string,uint256)"),
+    (0x91a02e2a, "log(uint256,bool,uint256,bool)"),
+    (0x91d1112e, "log(string,address,string,uint256)"),
+    (0x91d2f813, "log(bool,uint256,string,bool)"),
+    (0x91fb1242, "log(uint256,bool,address,bool)"),
+    (0x932bbb38, "log(string,bool,address)"),
+    (0x935e09bf, "log(string,bool,uint256,address)"),
+    (0x94250d77, "log(address,address,address,uint256)"),
+    (0x94be3bb1, "log(uint256,uint256,bool,bool)"),
+    (0x958c28c6, "log(string,bool,address,bool)"),
+    (0x9591b953, "log(bool,string,address)"),
+    (0x95d65f11, "log(address,address,bool,uint256)"),
+    (0x95ed0195, "log(string,string,address)"),
+    (0x969cdd03, "log(string,uint256,uint256)"),
+    (0x9710a9d0, "log(string,uint256)"),
+    (0x97d394d8, "log(bool,string,string,address)"),
+    (0x97eca394, "log(address,uint256,address)"),
+    (0x98e7f3f3, "log(uint256,string,address,uint256)"),
+    (0x9902e47f, "logBytes8(bytes8)"),
+    (0x9905b744, "logUint(uint256)"),
+    (0x9a3cbf96, "log(uint256,address,address,uint256)"),
+    (0x9a816a83, "log(uint256,uint256,bool,address)"),
+    (0x9acd3616, "log(bool,uint256,bool,address)"),
+    (0x9b4254e2, "log(address,address,uint256,bool)"),
+    (0x9b588ecc, "log(address,bool,uint256,string)"),
+    (0x9b5e614f, "logInt(int256)"),
+    (0x9b5e943e, "logBytes2(bytes2)"),
+    (0x9b6ec042, "log(uint256,address,bool)"),
+    (0x9bfe72bc, "log(bool,address,uint256,uint256)"),
+    (0x9c3adfa1, "log(uint256,string,address,string)"),
+    (0x9c4f99fb, "log(address,bool,uint256)"),
+    (0x9cba8fff, "log(uint256,address,string,address)"),
+    (0x9d22d5dd, "log(string,bool,bool,string)"),
+    (0x9dc2a897, "logBytes10(bytes10)"),
+    (0x9dd12ead, "log(address,address,uint256,string)"),
+    (0x9e01f741, "log(bool,uint256,bool,bool)"),
+    (0x9e127b6e, "log(address,bool,string,uint256)"),
+    (0x9f1bc36e, "log(address,address,bool,address)"),
+    (0x9fd009f5, "log(string,string,string,uint256)"),
+    (0x9ffb2f93, "log(string,uint256,address,string)"),
+    (0xa04e2f87, "log(address,string,string,address)"),
+    (0xa0685833, "log(bool,address,uint256,string)"),
+    (0xa0a47963, "log(bool,bool,address,string)"),
+    (0xa0c414e8, "log(uint256,address,string,uint256)"),
+    (0xa0c4b225, "log(string,uint256,string,uint256)"),
+    (0xa14fd039, "log(address,string,string,uint256)"),
+    (0xa1bcc9b3, "log(address,uint256,address,bool)"),
+    (0xa1ef4cbb, "log(uint256,bool,address,address)"),
+    (0xa1f2e8aa, "log(address,uint256,string)"),
+    (0xa230761e, "log(uint256,bool,address,string)"),
+    (0xa2bc0c99, "log(uint256,string,uint256,string)"),
+    (0xa31bfdcc, "log(address,uint256,bool,address)"),
+    (0xa366ec80, "log(string,address,uint256,address)"),
+    (0xa3f5c739, "log(string,uint256,string)"),
+    (0xa4024f11, "log(address,uint256,string,bool)"),
+    (0xa41d81de, "log(bool,uint256,uint256,bool)"),
+    (0xa433fcfd, "log(uint256,bool,string,string)"),
+    (0xa4b48a7f, "log(uint256,string,bool,uint256)"),
+    (0xa4c92a60, "log(address,string,uint256,uint256)"),
+    (0xa54ed4bd, "log(string,uint256,uint256,string)"),
+    (0xa5b4fc99, "log(uint256,uint256,bool,string)"),
+    (0xa5c70d29, "log(bool,uint256,string,address)"),
+    (0xa5cada94, "log(bool,string,address,uint256)"),
+    (0xa5d98768, "log(address,uint256,address,uint256)"),
+    (0xa6f50b0f, "log(address,bool,address,bool)"),
+    (0xa73c1db6, "log(bool,address,string,string)"),
+    (0xa75c59de, "log(address,bool,address,uint256)"),
+    (0xa7a87853, "log(string,uint256,uint256,uint256)"),
+    (0xa826caeb, "log(string,bool,string,string)"),
+    (0xa8e820ae, "log(uint256,uint256,address,bool)"),
+    (0xaa6540c8, "log(address,address,bool,string)"),
+    (0xaabc9a31, "log(string,address,string,address)"),
+    (0xab085ae6, "log(uint256,uint256,bool,bool)"),
+    (0xab5cc1c4, "log(bool,bool,uint256,bool)"),
+    (0xab7bd9fd, "log(uint256,string,uint256,address)"),
+    (0xabf73a98, "log(string,uint256,bool,string)"),
+    (0xade052c7, "log(uint256,bool,address,string)"),
+    (0xae2ec581, "log(uint256,string,bool,address)"),
+    (0xaea9963f, "logBytes25(bytes25)"),
+    (0xb01365bb, "log(bool,bool,uint256)"),
+    (0xb028c9bd, "log(uint256,string,string,uint256)"),
+    (0xb076847f, "log(bool,string,string)"),
+    (0xb0e0f9b5, "log(string,string,bool)"),
+    (0xb115611f, "log(uint256,string,string)"),
+    (0xb22eaf06, "log(uint256,uint256,string,bool)"),
+    (0xb3a6b6bd, "log(uint256,string,string,bool)"),
+    (0xb4c314ff, "log(bool,uint256,address,bool)"),
+    (0xb59dbd60, "log(string,address,address,bool)"),
+    (0xb60e72cc, "log(string,uint256)"),
+    (0xb6313094, "log(uint256,address,bool,address)"),
+    (0xb69bcaf6, "log(address,uint256,uint256)"),
+    (0xb6d569d4, "log(bool,uint256,bool,string)"),
+    (0xb6f577a1, "log(uint256,bool,bool,bool)"),
+    (0xb7b914ca, "log(uint256,string,uint256,string)"),
+    (0xb857163a, "log(bool,bool,string,bool)"),
+    (0xba535d9c, "log(uint256,string,bool,bool)"),
+    (0xba7ab84e, "logBool(bool)"),
+    (0xbaf96849, "log(address,uint256,string)"),
+    (0xbb7235e9, "log(string,uint256,string,address)"),
+    (0xbc0b61fe, "log(address,string,bool,string)"),
+    (0xbcfd9be0, "log(uint256,address,address)"),
+    (0xbd25ad59, "log(uint256,bool,bool,uint256)"),
+    (0xbe33491b, "log(uint256,uint256,address)"),
+    (0xbe553481, "log(address,address,uint256,uint256)"),
+    (0xbe984353, "log(bool,uint256,uint256,bool)"),
+    (0xbed728bf, "log(string,uint256,uint256,address)"),
+    (0xbf01f891, "log(address,uint256,string,uint256)"),
+    (0xc0043807, "log(uint256,string,uint256,uint256)"),
+    (0xc0382aac, "log(bool,string,uint256)"),
+    (0xc0a302d8, "log(bool,bool,address,bool)"),
+    (0xc210a01e, "log(address,bool,uint256,uint256)"),
+    (0xc21f64c7, "log(bool,address,string,uint256)"),
+    (0xc248834d, "log(bool,bool,bool,uint256)"),
+    (0xc2f688ec, "log(address,address,uint256,bool)"),
+    (0xc371c7db, "log(string,string,bool,address)"),
+    (0xc3a8a654, "log(string,string,uint256,bool)"),
+    (0xc3b55635, "log(string,bool)"),
+    (0xc3fc3970, "log(bool,uint256,string)"),
+    (0xc4340ef6, "logBytes30(bytes30)"),
+    (0xc4643e20, "log(address,bool,uint256,bool)"),
+    (0xc4d23507, "log(bool,uint256,address)"),
+    (0xc598d185, "log(uint256,uint256,uint256,bool)"),
+    (0xc5ad85f9, "log(address,uint256,bool,string)"),
+    (0xc5d1bb8b, "log(string,address,bool,uint256)"),
+    (0xc67ea9d1, "log(string,uint256,string,uint256)"),
+    (0xc6acc7a8, "log(uint256,bool,uint256,uint256)"),
+    (0xc8397eb0, "log(bool,uint256,string)"),
+    (0xc91d5ed4, "log(string,address,bool)"),
+    (0xc95958d6, "log(string,bool,uint256)"),
+    (0xca47c4eb, "log(string,uint256,uint256)"),
+    (0xca7733b1, "log(string,uint256,bool)"),
+    (0xca939b20, "log(uint256,uint256,address,address)"),
+    (0xca9a3eb4, "log(uint256,address,uint256,uint256)"),
+    (0xcaa5236a, "log(bool,uint256,address,uint256)"),
+    (0xcac43479, "log(address,bool,bool,bool)"),
+    (0xcbe58efd, "log(uint256,address,string,address)"),
+    (0xcc32ab07, "log(uint256,address,string,bool)"),
+    (0xcc988aa0, "log(uint256,string,string,address)"),
+    (0xccf790a1, "log(address,bool,uint256,address)"),
+    (0xce83047b, "log(uint256,address,string)"),
+    (0xceb5f4d7, "log(bool,uint256,bool,bool)"),
+    (0xcf009880, "log(uint256,string,bool,uint256)"),
+    (0xcf020fb1, "log(address,string,bool)"),
+    (0xcf18105c, "log(address,uint256,string,bool)"),
+    (0xcf394485, "log(address,bool,bool,address)"),
+    (0xcfb58756, "log(address,bool,bool,uint256)"),
+    (0xd1ed7a3c, "log(uint256,uint256,uint256)"),
+    (0xd2763667, "log(bool,address,address)"),
+    (0xd2abc4fd, "log(uint256,bool,uint256,bool)"),
+    (0xd2d423cd, "log(uint256,string,bool,string)"),
+    (0xd32a6548, "log(bool,uint256,string,string)"),
+    (0xd3635628, "logBytes26(bytes26)"),
+    (0xd3de5593, "log(bool,uint256,bool,uint256)"),
+    (0xd583c602, "log(uint256,string,string,address)"),
+    (0xd5ceace0, "log(uint256,bool,bool)"),
+    (0xd5cf17d0, "log(string,string,uint256,uint256)"),
+    (0xd6019f1c, "log(bool,address,uint256,bool)"),
+    (0xd6a2d1de, "log(uint256,uint256,address,string)"),
+    (0xd6aefad2, "log(string,string,bool,uint256)"),
+    (0xd6c65276, "log(address,address,uint256,address)"),
+    (0xd812a167, "log(bool,address,address,string)"),
+    (0xd8652642, "logBytes18(bytes18)"),
+    (0xda0666c8, "log(bool,uint256,uint256,string)"),
+    (0xdaa394bd, "log(string,address,uint256,uint256)"),
+    (0xdaf0d4aa, "log(address,address)"),
+    (0xdbb4c247, "log(bool,string,bool)"),
+    (0xdc08b6a7, "logBytes11(bytes11)"),
+    (0xdc5e935b, "log(bool,string,bool,bool)"),
+    (0xdc7116d2, "log(address,bool,address,uint256)"),
+    (0xdc792604, "log(address,uint256,string,address)"),
+    (0xddb06521, "log(uint256,address,uint256,string)"),
+    (0xdddb9561, "log(uint256,bool,bool,string)"),
+    (0xde03e774, "log(uint256,bool,uint256,string)"),
+    (0xde68f20a, "log(string,string,string,string)"),
+    (0xde9a9270, "log(bool,address,string)"),
+    (0xdfc4a2e8, "log(address,bool,bool,string)"),
+    (0xdfd7d80b, "log(address,string,uint256,address)"),
+    (0xe0625b29, "log(string,bool,string,address)"),
+    (0xe0853f69, "log(uint256,uint256,uint256,address)"),
+    (0xe0e95b98, "log(string,uint256,bool,address)"),
+    (0xe0e9ad4f, "log(string,address,string)"),
+    (0xe117744f, "log(uint256,uint256,bool,address)"),
+    (0xe17bf956, "logBytes(bytes)"),
+    (0xe21de278, "log(string,uint256,uint256,address)"),
+    (0xe223597f, "logUint(uint256)"),
+    (0xe298f47d, "log(string,bool,string)"),
+    (0xe2bfd60b, "log(bool,address,string,bool)"),
+    (0xe351140f, "log(uint256,address,bool,bool)"),
+    (0xe37ff3d0, "log(string,uint256,bool,bool)"),
+    (0xe3849f79, "log(string,uint256,address)"),
+    (0xe3a9ca2f, "log(bool,bool,string,uint256)"),
+    (0xe41b6f6f, "log(string,uint256,bool,uint256)"),
+    (0xe54ae144, "log(address,uint256,bool)"),
+    (0xe5549d91, "log(string,uint256,bool,address)"),
+    (0xe5e70b2b, "log(bool,uint256,string,bool)"),
+    (0xe65658ca, "log(string,string,uint256,bool)"),
+    (0xe720521c, "log(address,string,bool,uint256)"),
+    (0xe7820a74, "log(uint256,uint256,uint256)"),
+    (0xe8d3018d, "log(uint256,string,address,uint256)"),
+    (0xe8ddbc56, "log(uint256,bool,uint256,string)"),
+    (0xe8defba9, "log(bool,uint256,bool)"),
+    (0xe99f82cf, "log(string,uint256,string,bool)"),
+    (0xeac89281, "log(string,uint256,address,address)"),
+    (0xeb1bff80, "log(string,string,address,string)"),
+    (0xeb704baf, "log(bool,address,uint256)"),
+    (0xeb7f6fd2, "log(uint256,uint256,bool,uint256)"),
+    (0xeb830c92, "log(address,bool,bool)"),
+    (0xeb928d7f, "log(uint256,bool,string,bool)"),
+    (0xec24846f, "log(address,uint256,address,address)"),
+    (0xec4ba8a2, "log(address,uint256,uint256,bool)"),
+    (0xecb8567e, "logBytes20(bytes20)"),
+    (0xed5eac87, "log(address,address,address,uint256)"),
+    (0xed8f28f6, "log(string,address,address,address)"),
+    (0xee8d8672, "log(bool,address,uint256,bool)"),
+    (0xef1cefe7, "log(address,address,string,uint256)"),
+    (0xef529018, "log(uint256,bool,string,address)"),
+    (0xef72c513, "log(uint256,address,bool,address)"),
+    (0xefd9cbee, "log(uint256,uint256,bool,string)"),
+    (0xf08744e8, "log(address,string,address)"),
+    (0xf102ee05, "log(string,uint256,bool)"),
+    (0xf11699ed, "log(address,bool,address)"),
+    (0xf161b221, "log(bool,uint256,uint256,address)"),
+    (0xf181a1e9, "log(address,uint256,address,bool)"),
+    (0xf2a66286, "log(address,address,bool)"),
+    (0xf362ca59, "log(string,string,uint256)"),
+    (0xf45d7d2c, "log(string,string,uint256,uint256)"),
+    (0xf4880ea4, "log(bool,bool,address,address)"),
+    (0xf512cf9b, "log(address,uint256,string,uint256)"),
+    (0xf5b1bba9, "log(uint256)"),
+    (0xf5bc2249, "log(bool,uint256,string,string)"),
+    (0xf666715a, "log(uint256,uint256)"),
+    (0xf73c7e3d, "log(string,uint256,uint256,bool)"),
+    (0xf7e36245, "log(address,string,address,string)"),
+    (0xf808da20, "log(address,address,address,string)"),
+    (0xf82c50f1, "log(uint256)"),
+    (0xf898577f, "log(uint256,string,address,string)"),
+    (0xf89a532f, "logBytes17(bytes17)"),
+    (0xf8f51b1e, "log(string,address,uint256,uint256)"),
+    (0xf93fff37, "log(uint256,string,address,bool)"),
+    (0xf9ad2b89, "log(bool,bool,string,address)"),
+    (0xfa8185af, "log(uint256,uint256,uint256,address)"),
+    (0xfb772265, "log(address,string,string)"),
+    (0xfba3ad39, "logBytes4(bytes4)"),
+    (0xfc372f9f, "logBytes27(bytes27)"),
+    (0xfc4845f0, "log(string,address,uint256,bool)"),
+    (0xfcec75e0, "log(string,address,address)"),
+    (0xfdb2ecd4, "log(uint256,address,uint256,address)"),
+    (0xfdb4f990, "log(address,address,uint256,string)"),
+    (0xfea1d55a, "log(address,uint256,bool,bool)"),
+    (0xfedd1fff, "log(bool,uint256,string,address)"),
+];
@@ -3,11 +3,66 @@ use alloc::{
     string::{String, ToString},
     vec::Vec,
 };
-use alloy_primitives::{Address, Function, I256, U256};
+use alloy_primitives::{Address, Function, Sign, I256, U256};
+
+/// The largest integer that JavaScript numbers, and therefore JSON numbers
+/// produced by most wallets and libraries, can represent exactly:
+/// `Number.MAX_SAFE_INTEGER`.
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// The policy for coercing JSON values to Solidity integers with
+/// [`DynSolType::coerce_json_with`].
+///
+/// EIP-712 messages may encode integers as JSON numbers, decimal strings, or
+/// hexadecimal strings, and different signers accept different subsets of
+/// these. In both policies, values must fit in the integer type, and unsigned
+/// integers cannot be negative.
+///
+/// | Value                          | [`Lenient`](Self::Lenient) | [`Strict`](Self::Strict) |
+/// |--------------------------------|----------------------------|--------------------------|
+/// | `1`, `-1`                      | yes                        | yes                      |
+/// | `9007199254740992` and above   | yes                        | no                       |
+/// | `4.0`, `1e21`                  | yes                        | no                       |
+/// | `4.2`                          | no                         | no                       |
+/// | `"1"`, `"-1"`, `"0x1f"`        | yes                        | yes                      |
+/// | `"-0x1f"`, `"0b101"`, `"1_000"`| yes                        | no                       |
+///
+/// [`Lenient`](Self::Lenient) matches viem's `hashTypedData`, and MetaMask's
+/// `eth_signTypedData_v4`, which accept any JSON number with an integral
+/// value. [`Strict`](Self::Strict) additionally rejects JSON numbers that may
+/// have lost precision when they were produced, which are floats and integers
+/// beyond `Number.MAX_SAFE_INTEGER`, and only accepts canonical strings, so
+/// that large values must be passed as strings.
+///
+/// The behavior of both policies is pinned by the shared vectors in
+/// `tests/fixtures/eip712/number_coercion.json`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NumberCoercion {
+    /// Accept JSON numbers with integral values, and strings in any format
+    /// accepted by [`U256`]'s and [`I256`]'s `FromStr` implementations.
+    #[default]
+    Lenient,
+    /// Accept only JSON integers in `-(2^53 - 1)..=(2^53 - 1)`, and decimal or
+    /// `0x`-prefixed hexadecimal strings, where only decimal strings may be
+    /// negative.
+    Strict,
+}
 
 impl DynSolType {
     /// Coerce a [`serde_json::Value`] to a [`DynSolValue`] via this type.
+    ///
+    /// Integers are coerced with the [`NumberCoercion::Lenient`] policy.
     pub fn coerce_json(&self, value: &serde_json::Value) -> Result<DynSolValue> {
+        self.coerce_json_with(value, NumberCoercion::Lenient)
+    }
+
+    /// Coerce a [`serde_json::Value`] to a [`DynSolValue`] via this type,
+    /// coercing integers with the given [`NumberCoercion`] policy.
+    pub fn coerce_json_with(
+        &self,
+        value: &serde_json::Value,
+        policy: NumberCoercion,
+    ) -> Result<DynSolValue> {
         let err = || Error::eip712_coerce(self, value);
         match self {
             Self::Bool
@@ -17,17 +72,17 @@ impl DynSolType {
             | Self::Address
             | Self::Function
             | Self::String
-            | Self::Bytes => self.coerce_json_simple(value).ok_or_else(err),
+            | Self::Bytes => self.coerce_json_simple(value, policy).ok_or_else(err),
 
-            Self::Array(inner) => array(inner, value)
+            Self::Array(inner) => array(inner, value, policy)
                 .ok_or_else(err)
                 .and_then(core::convert::identity)
                 .map(DynSolValue::Array),
-            Self::FixedArray(inner, n) => fixed_array(inner, *n, value)
+            Self::FixedArray(inner, n) => fixed_array(inner, *n, value, policy)
                 .ok_or_else(err)
                 .and_then(core::convert::identity)
                 .map(DynSolValue::FixedArray),
-            Self::Tuple(inner) => tuple(inner, value)
+            Self::Tuple(inner) => tuple(inner, value, policy)
                 .ok_or_else(err)
                 .and_then(core::convert::identity)
                 .map(DynSolValue::Tuple),
@@ -35,15 +90,19 @@ impl DynSolType {
                 name,
                 prop_names,
                 tuple,
-            } => custom_struct(name, prop_names, tuple, value),
+            } => custom_struct(name, prop_names, tuple, value, policy),
         }
     }
 
-    fn coerce_json_simple(&self, value: &serde_json::Value) -> Option<DynSolValue> {
+    fn coerce_json_simple(
+        &self,
+        value: &serde_json::Value,
+        policy: NumberCoercion,
+    ) -> Option<DynSolValue> {
         match self {
             Self::Bool => bool(value).map(DynSolValue::Bool),
-            &Self::Int(n) => int(n, value).map(|x| DynSolValue::Int(x, n)),
-            &Self::Uint(n) => uint(n, value).map(|x| DynSolValue::Uint(x, n)),
+            &Self::Int(n) => int(n, value, policy).map(|x| DynSolValue::Int(x, n)),
+            &Self::Uint(n) => uint(n, value, policy).map(|x| DynSolValue::Uint(x, n)),
             &Self::FixedBytes(n) => fixed_bytes(n, value).map(|x| DynSolValue::FixedBytes(x, n)),
             Self::Address => address(value).map(DynSolValue::Address),
             Self::Function => function(value).map(DynSolValue::Function),
@@ -60,24 +119,93 @@ fn bool(value: &serde_json::Value) -> Option<bool> {
         .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
 }
 
-fn int(n: usize, value: &serde_json::Value) -> Option<I256> {
-    (|| {
-        if let Some(num) = value.as_i64() {
-            return Some(I256::try_from(num).unwrap())
-        }
-        value.as_str().and_then(|s| s.parse().ok())
-    })()
-    .and_then(|x| (x.bits() <= n as u32).then_some(x))
+fn int(n: usize, value: &serde_json::Value, policy: NumberCoercion) -> Option<I256> {
+    integer(value, policy)
+        .and_then(|(sign, abs)| I256::checked_from_sign_and_abs(sign, abs))
+        .and_then(|x| (x.bits() <= n as u32).then_some(x))
+}
+
+fn uint(n: usize, value: &serde_json::Value, policy: NumberCoercion) -> Option<U256> {
+    integer(value, policy)
+        .and_then(|(sign, abs)| (sign.is_positive() || abs.is_zero()).then_some(abs))
+        .and_then(|x| (x.bit_len() <= n).then_some(x))
 }
 
-fn uint(n: usize, value: &serde_json::Value) -> Option<U256> {
-    (|| {
-        if let Some(num) = value.as_u64() {
-            return Some(U256::from(num))
+/// Parses an integer from a JSON number or string according to `policy`,
+/// returning its sign and absolute value.
+fn integer(value: &serde_json::Value, policy: NumberCoercion) -> Option<(Sign, U256)> {
+    let strict = policy == NumberCoercion::Strict;
+    match value {
+        serde_json::Value::Number(num) => {
+            let (sign, abs) = if let Some(x) = num.as_u64() {
+                (Sign::Positive, U256::from(x))
+            } else if let Some(x) = num.as_i64() {
+                (Sign::Negative, U256::from(x.unsigned_abs()))
+            } else if strict {
+                return None
+            } else {
+                float(num.as_f64()?)?
+            };
+            if strict && abs > U256::from(MAX_SAFE_INTEGER) {
+                return None
+            }
+            Some((sign, abs))
         }
-        value.as_str().and_then(|s| s.parse().ok())
-    })()
-    .and_then(|x| (x.bit_len() <= n).then_some(x))
+        serde_json::Value::String(s) if strict => {
+            let (sign, s) = match s.strip_prefix('-') {
+                Some(s) => (Sign::Negative, s),
+                None => (Sign::Positive, s.as_str()),
+            };
+            let (digits, radix) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+                Some(digits) if sign.is_positive() => (digits, 16),
+                Some(_) => return None,
+                None => (s, 10),
+            };
+            let valid = |c: char| c.is_digit(radix);
+            if digits.is_empty() || !digits.chars().all(valid) {
+                return None
+            }
+            U256::from_str_radix(digits, radix as u64)
+                .ok()
+                .map(|abs| (sign, abs))
+        }
+        serde_json::Value::String(s) => {
+            let (sign, s) = match s.as_bytes().first() {
+                Some(b'+') => (Sign::Positive, &s[1..]),
+                Some(b'-') => (Sign::Negative, &s[1..]),
+                _ => (Sign::Positive, s.as_str()),
+            };
+            s.parse().ok().map(|abs| (sign, abs))
+        }
+        _ => None,
+    }
+}
+
+/// Converts a float to its sign and absolute value, if it is an integer.
+fn float(x: f64) -> Option<(Sign, U256)> {
+    if !x.is_finite() {
+        return None
+    }
+    let (sign, x) = if x.is_sign_negative() {
+        (Sign::Negative, -x)
+    } else {
+        (Sign::Positive, x)
+    };
+
+    // `f64::fract` and float to `U256` conversions are not available in
+    // `no_std`, so check and convert manually
+    const MIN_INTEGRAL: f64 = (1u64 << 52) as f64;
+    let abs = if x < MIN_INTEGRAL {
+        let int = x as u64;
+        (int as f64 == x).then(|| U256::from(int))?
+    } else {
+        // all floats of at least 2^52 are integers: mantissa * 2^(exponent - 52)
+        let bits = x.to_bits();
+        let exponent = (bits >> 52) as usize - 1023;
+        let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+        U256::from(mantissa).checked_shl(exponent - 52)?
+    };
+    Some((sign, abs))
 }
 
 fn fixed_bytes(n: usize, value: &serde_json::Value) -> Option<Word> {
@@ -108,12 +236,16 @@ fn bytes(value: &serde_json::Value) -> Option<Vec<u8>> {
     value.as_str().and_then(|s| hex::decode(s).ok())
 }
 
-fn tuple(inner: &[DynSolType], value: &serde_json::Value) -> Option<Result<Vec<DynSolValue>>> {
+fn tuple(
+    inner: &[DynSolType],
+    value: &serde_json::Value,
+    policy: NumberCoercion,
+) -> Option<Result<Vec<DynSolValue>>> {
     if let Some(arr) = value.as_array() {
         if inner.len() == arr.len() {
             return Some(
                 core::iter::zip(arr, inner)
-                    .map(|(v, t)| t.coerce_json(v))
+                    .map(|(v, t)| t.coerce_json_with(v, policy))
                     .collect(),
             )
         }
@@ -121,9 +253,17 @@ fn tuple(inner: &[DynSolType], value: &serde_json::Value) -> Option<Result<Vec<D
     None
 }
 
-fn array(inner: &DynSolType, value: &serde_json::Value) -> Option<Result<Vec<DynSolValue>>> {
+fn array(
+    inner: &DynSolType,
+    value: &serde_json::Value,
+    policy: NumberCoercion,
+) -> Option<Result<Vec<DynSolValue>>> {
     if let Some(arr) = value.as_array() {
-        return Some(arr.iter().map(|v| inner.coerce_json(v)).collect())
+        return Some(
+            arr.iter()
+                .map(|v| inner.coerce_json_with(v, policy))
+                .collect(),
+        )
     }
     None
 }
@@ -132,10 +272,15 @@ fn fixed_array(
     inner: &DynSolType,
     n: usize,
     value: &serde_json::Value,
+    policy: NumberCoercion,
 ) -> Option<Result<Vec<DynSolValue>>> {
     if let Some(arr) = value.as_array() {
         if arr.len() == n {
-            return Some(arr.iter().map(|v| inner.coerce_json(v)).collect())
+            return Some(
+                arr.iter()
+                    .map(|v| inner.coerce_json_with(v, policy))
+                    .collect(),
+            )
         }
     }
     None
@@ -146,12 +291,13 @@ pub(crate) fn custom_struct(
     prop_names: &[String],
     inner: &[DynSolType],
     value: &serde_json::Value,
+    policy: NumberCoercion,
 ) -> Result<DynSolValue> {
     if let Some(map) = value.as_object() {
         let mut tuple = vec![];
         for (name, ty) in core::iter::zip(prop_names, inner) {
             if let Some(v) = map.get(name) {
-                tuple.push(ty.coerce_json(v)?);
+                tuple.push(ty.coerce_json_with(v, policy)?);
             } else {
                 return Err(Error::eip712_coerce(
                     &DynSolType::CustomStruct {
@@ -186,6 +332,54 @@ mod tests {
     use alloc::{borrow::ToOwned, string::ToString};
     use serde_json::json;
 
+    #[test]
+    fn number_coercion_vectors() {
+        #[derive(serde::Deserialize)]
+        struct Vector {
+            #[serde(rename = "type")]
+            ty: String,
+            value: serde_json::Value,
+            lenient: Option<String>,
+            strict: Option<String>,
+        }
+
+        let vectors: Vec<Vector> = serde_json::from_str(include_str!(
+            "../../tests/fixtures/eip712/number_coercion.json"
+        ))
+        .unwrap();
+        for vector in vectors {
+            let ty = DynSolType::parse(&vector.ty).unwrap();
+            for (policy, expected) in [
+                (NumberCoercion::Lenient, &vector.lenient),
+                (NumberCoercion::Strict, &vector.strict),
+            ] {
+                let coerced = ty.coerce_json_with(&vector.value, policy).ok();
+                let expected = expected.as_ref().map(|x| match ty {
+                    DynSolType::Int(n) => DynSolValue::Int(x.parse().unwrap(), n),
+                    DynSolType::Uint(n) => DynSolValue::Uint(x.parse().unwrap(), n),
+                    _ => unreachable!(),
+                });
+                assert_eq!(
+                    coerced, expected,
+                    "{} {} {policy:?}",
+                    vector.ty, vector.value
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn strict_number_coercion_in_structs() {
+        let ty = DynSolType::CustomStruct {
+            name: "Order".to_owned(),
+            prop_names: vec!["amounts".to_string()],
+            tuple: vec![DynSolType::Array(Box::new(DynSolType::Uint(256)))],
+        };
+        let value = json!({ "amounts": [1, "2", 3.0] });
+        assert!(ty.coerce_json(&value).is_ok());
+        assert!(ty.coerce_json_with(&value, NumberCoercion::Strict).is_err());
+    }
+
     #[test]
     fn it_coerces() {
         let j = json!({
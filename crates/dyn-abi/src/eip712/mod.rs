@@ -16,3 +16,4 @@ mod resolver;
 pub use resolver::{PropertyDef, Resolver, TypeDef};
 
 pub(crate) mod coerce;
+pub use coerce::NumberCoercion;
@@ -210,7 +210,7 @@ impl TypeDef {
 #[derive(Debug, Default)]
 struct DfsContext<'a> {
     visited: BTreeSet<&'a TypeDef>,
-    stack: BTreeSet<&'a str>,
+    stack: Vec<&'a str>,
 }
 
 /// A dependency graph built from the `Eip712Types` object. This is used to
@@ -273,36 +273,53 @@ impl Resolver {
         resolver
     }
 
-    /// Detect cycles in the subgraph rooted at `type_name`
-    fn detect_cycle<'a>(&'a self, type_name: &str, context: &mut DfsContext<'a>) -> bool {
-        let ty = match self.nodes.get(type_name) {
-            Some(ty) => ty,
-            None => return false,
+    /// Detect cycles and missing types in the subgraph rooted at `type_name`.
+    ///
+    /// Cycles are reported with their path, e.g. `A -> B -> A`.
+    fn detect_cycle<'a>(&'a self, type_name: &str, context: &mut DfsContext<'a>) -> Result<()> {
+        let Some(ty) = self.nodes.get(type_name) else {
+            let root_type: RootType<'_> = type_name.try_into()?;
+            return root_type
+                .try_basic_solidity()
+                .map_err(|_| Error::missing_type(type_name))
         };
 
-        if context.stack.contains(type_name) {
-            return true
+        if let Some(start) = context.stack.iter().position(|&name| name == type_name) {
+            let mut path = context.stack[start..].join(" -> ");
+            path.push_str(" -> ");
+            path.push_str(type_name);
+            return Err(Error::circular_dependency(&path))
         }
         if context.visited.contains(ty) {
-            return false
+            return Ok(())
         }
 
         // update visited and stack
         context.visited.insert(ty);
-        context.stack.insert(&ty.type_name);
+        context.stack.push(&ty.type_name);
 
-        if self
-            .edges
-            .get(&ty.type_name)
-            .unwrap()
-            .iter()
-            .any(|edge| self.detect_cycle(edge, context))
-        {
-            return true
+        for prop_type in ty.prop_types() {
+            self.detect_cycle_in(&prop_type.try_into()?, context)?;
         }
 
-        context.stack.remove(type_name);
-        false
+        context.stack.pop();
+        Ok(())
+    }
+
+    /// Detect cycles and missing types in the subgraphs rooted at each of the
+    /// root types of `type_spec`, such as both `A` and `B` in `(A,B[])[2]`.
+    fn detect_cycle_in<'a>(
+        &'a self,
+        type_spec: &TypeSpecifier<'_>,
+        context: &mut DfsContext<'a>,
+    ) -> Result<()> {
+        match &type_spec.stem {
+            TypeStem::Root(root) => self.detect_cycle(root.span(), context),
+            TypeStem::Tuple(tuple) => tuple
+                .types
+                .iter()
+                .try_for_each(|ty| self.detect_cycle_in(ty, context)),
+        }
     }
 
     /// Ingest types from an EIP-712 `encodeType`.
@@ -374,10 +391,7 @@ impl Resolver {
     /// This function linearizes a type into a list of typedefs of its
     /// dependencies.
     pub fn linearize(&self, type_name: &str) -> Result<Vec<&TypeDef>> {
-        let mut context = DfsContext::default();
-        if self.detect_cycle(type_name, &mut context) {
-            return Err(Error::circular_dependency(type_name))
-        }
+        self.detect_cycle(type_name, &mut DfsContext::default())?;
         let root_type = type_name.try_into()?;
         let mut resolution = vec![];
         self.linearize_into(&mut resolution, root_type)?;
@@ -387,10 +401,9 @@ impl Resolver {
     /// Resolve a typename into a [`crate::DynSolType`] or return an error if
     /// the type is missing, or contains a circular dependency.
     pub fn resolve(&self, type_name: &str) -> Result<DynSolType> {
-        if self.detect_cycle(type_name, &mut Default::default()) {
-            return Err(Error::circular_dependency(type_name))
-        }
-        self.unchecked_resolve(&type_name.try_into()?)
+        let type_spec = type_name.try_into()?;
+        self.detect_cycle_in(&type_spec, &mut DfsContext::default())?;
+        self.unchecked_resolve(&type_spec)
     }
 
     /// Resolve a type into a [`crate::DynSolType`] without checking for cycles.
@@ -459,13 +472,31 @@ impl Resolver {
 
     /// Encode the data according to EIP-712 `encodeData` rules.
     pub fn encode_data(&self, value: &DynSolValue) -> Result<Option<Vec<u8>>> {
+        self.encode_data_with(value, &mut BTreeMap::new())
+    }
+
+    /// Encode the data as a struct property according to EIP-712 `encodeData`
+    /// rules. Atomic types are encoded as-is, while non-atomic types are
+    /// encoded as their `encodeData` hash.
+    pub fn eip712_data_word(&self, value: &DynSolValue) -> Result<B256> {
+        self.eip712_data_word_with(value, &mut BTreeMap::new())
+    }
+
+    /// [`encode_data`](Self::encode_data), memoizing struct type hashes in
+    /// `type_hashes` so that each one is only computed once, however many
+    /// values of that struct are encoded.
+    fn encode_data_with<'a>(
+        &self,
+        value: &'a DynSolValue,
+        type_hashes: &mut BTreeMap<&'a str, B256>,
+    ) -> Result<Option<Vec<u8>>> {
         Ok(match value {
             DynSolValue::CustomStruct { tuple: inner, .. }
             | DynSolValue::Array(inner)
             | DynSolValue::FixedArray(inner) => {
                 let mut bytes = Vec::with_capacity(inner.len() * 32);
                 for v in inner {
-                    bytes.extend(self.eip712_data_word(v)?.as_slice());
+                    bytes.extend(self.eip712_data_word_with(v, type_hashes)?.as_slice());
                 }
                 Some(bytes)
             }
@@ -475,10 +506,13 @@ impl Resolver {
         })
     }
 
-    /// Encode the data as a struct property according to EIP-712 `encodeData`
-    /// rules. Atomic types are encoded as-is, while non-atomic types are
-    /// encoded as their `encodeData` hash.
-    pub fn eip712_data_word(&self, value: &DynSolValue) -> Result<B256> {
+    /// [`eip712_data_word`](Self::eip712_data_word), memoizing struct type
+    /// hashes in `type_hashes`.
+    fn eip712_data_word_with<'a>(
+        &self,
+        value: &'a DynSolValue,
+        type_hashes: &mut BTreeMap<&'a str, B256>,
+    ) -> Result<B256> {
         if let Some(word) = value.as_word() {
             return Ok(word)
         }
@@ -486,16 +520,25 @@ impl Resolver {
         let mut bytes;
         let to_hash = match value {
             DynSolValue::CustomStruct { name, tuple, .. } => {
-                bytes = self.type_hash(name)?.to_vec();
+                let type_hash = match type_hashes.get(name.as_str()) {
+                    Some(type_hash) => *type_hash,
+                    None => {
+                        let type_hash = self.type_hash(name)?;
+                        type_hashes.insert(name, type_hash);
+                        type_hash
+                    }
+                };
+                bytes = Vec::with_capacity(32 + tuple.len() * 32);
+                bytes.extend(type_hash);
                 for v in tuple {
-                    bytes.extend(self.eip712_data_word(v)?.as_slice());
+                    bytes.extend(self.eip712_data_word_with(v, type_hashes)?);
                 }
                 &bytes[..]
             }
             DynSolValue::Array(inner) | DynSolValue::FixedArray(inner) => {
                 bytes = Vec::with_capacity(inner.len() * 32);
                 for v in inner {
-                    bytes.extend(self.eip712_data_word(v)?);
+                    bytes.extend(self.eip712_data_word_with(v, type_hashes)?);
                 }
                 &bytes[..]
             }
@@ -529,7 +572,31 @@ mod tests {
             vec![PropertyDef::new_unchecked("A", "myA")],
         ));
 
-        assert!(graph.detect_cycle("A", &mut DfsContext::default()));
+        assert_eq!(
+            graph.detect_cycle("A", &mut DfsContext::default()),
+            Err(Error::CircularDependency("A -> B -> C -> A".into()))
+        );
+        assert_eq!(
+            graph.resolve("B[]"),
+            Err(Error::CircularDependency("B -> C -> A -> B".into()))
+        );
+    }
+
+    #[test]
+    fn it_detects_missing_types() {
+        let mut graph = Resolver::default();
+        graph.ingest(TypeDef::new_unchecked(
+            "A".to_string(),
+            vec![
+                PropertyDef::new_unchecked("uint256", "myUint"),
+                PropertyDef::new_unchecked("B[]", "myBs"),
+            ],
+        ));
+
+        let missing = Error::MissingType("B".into());
+        assert_eq!(graph.resolve("A"), Err(missing.clone()));
+        assert_eq!(graph.encode_type("A"), Err(missing.clone()));
+        assert_eq!(graph.resolve("(uint256,A)[2]"), Err(missing));
     }
 
     #[test]
@@ -663,4 +730,55 @@ mod tests {
             MyStruct::eip712_encode_type()
         );
     }
+
+    #[test]
+    fn hashes_arrays_of_structs() {
+        sol! {
+            struct Item {
+                address token;
+                uint256 amount;
+            }
+
+            struct Order {
+                Item[] offer;
+                Item[] consideration;
+                string memo;
+            }
+        }
+
+        let items = |n: u64| {
+            (0..n)
+                .map(|i| Item {
+                    token: alloy_primitives::Address::with_last_byte(i as u8),
+                    amount: alloy_primitives::U256::from(i),
+                })
+                .collect::<Vec<_>>()
+        };
+        let order = Order {
+            offer: items(100),
+            consideration: items(3),
+            memo: "memo".into(),
+        };
+
+        let item_value = |item: &Item| DynSolValue::CustomStruct {
+            name: "Item".into(),
+            prop_names: vec!["token".into(), "amount".into()],
+            tuple: vec![item.token.into(), item.amount.into()],
+        };
+        let value = DynSolValue::CustomStruct {
+            name: "Order".into(),
+            prop_names: vec!["offer".into(), "consideration".into(), "memo".into()],
+            tuple: vec![
+                DynSolValue::Array(order.offer.iter().map(item_value).collect()),
+                DynSolValue::Array(order.consideration.iter().map(item_value).collect()),
+                DynSolValue::String(order.memo.clone()),
+            ],
+        };
+
+        let graph = Resolver::from_struct::<Order>();
+        assert_eq!(
+            graph.eip712_data_word(&value).unwrap(),
+            order.eip712_hash_struct()
+        );
+    }
 }
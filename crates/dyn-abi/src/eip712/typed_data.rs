@@ -1,5 +1,5 @@
 use crate::{
-    eip712::{PropertyDef, Resolver},
+    eip712::{NumberCoercion, PropertyDef, Resolver},
     DynSolType, DynSolValue, Result,
 };
 use alloc::{collections::BTreeMap, string::String, vec::Vec};
@@ -151,9 +151,20 @@ impl TypedData {
 
     /// Coerce the message to the type specified by `primary_type`, using the
     /// types map as a resolver.
+    ///
+    /// Integers are coerced with the [`NumberCoercion::Lenient`] policy.
     pub fn coerce(&self) -> Result<DynSolValue> {
+        self.coerce_with(NumberCoercion::Lenient)
+    }
+
+    /// Coerce the message to the type specified by `primary_type`, using the
+    /// types map as a resolver, and coercing integers with the given
+    /// [`NumberCoercion`] policy.
+    ///
+    /// This can be used to validate a message strictly before signing it.
+    pub fn coerce_with(&self, policy: NumberCoercion) -> Result<DynSolValue> {
         let ty = self.resolve()?;
-        ty.coerce_json(&self.message)
+        ty.coerce_json_with(&self.message, policy)
     }
 
     /// Calculate the Keccak-256 hash of [`encodeType`] for this value.
@@ -536,7 +547,7 @@ mod tests {
 
         assert_eq!(
             typed_data.eip712_signing_hash(),
-            Err(Error::CircularDependency("Mail".into())),
+            Err(Error::CircularDependency("Mail -> Mail".into())),
         );
     }
 
@@ -1,5 +1,5 @@
 use alloc::{borrow::Cow, string::String};
-use alloy_primitives::B256;
+use alloy_primitives::{Selector, B256};
 use alloy_sol_type_parser::Error as TypeParserError;
 use alloy_sol_types::Error as SolTypesError;
 use core::fmt;
@@ -16,7 +16,8 @@ pub enum Error {
     /// Unknown type referenced from another type.
     #[cfg(feature = "eip712")]
     MissingType(String),
-    /// Detected circular dep during typegraph resolution.
+    /// Detected circular dep during typegraph resolution, with the path of
+    /// the cycle, e.g. `A -> B -> A`.
     #[cfg(feature = "eip712")]
     CircularDependency(String),
     /// Invalid property definition.
@@ -52,6 +53,25 @@ pub enum Error {
         /// The actual signature.
         actual: B256,
     },
+    /// Invalid function selector.
+    SelectorMismatch {
+        /// The expected selector.
+        expected: Selector,
+        /// The actual selector.
+        actual: Selector,
+    },
+
+    /// Invalid entry in a function signature dump.
+    InvalidSelectorDump {
+        /// The line of the entry, starting from 1.
+        line: usize,
+        /// The entry.
+        entry: String,
+    },
+
+    /// The types of a packed encoding do not determine the boundaries of
+    /// its values, e.g. because two of them are dynamically sized.
+    AmbiguousPackedEncoding(String),
 
     /// [`hex`] error.
     Hex(hex::FromHexError),
@@ -119,6 +139,17 @@ impl fmt::Display for Error {
                 f,
                 "invalid event signature: expected {expected}, got {actual}",
             ),
+            Self::SelectorMismatch { expected, actual } => write!(
+                f,
+                "invalid function selector: expected {expected}, got {actual}",
+            ),
+            Self::InvalidSelectorDump { line, entry } => {
+                write!(f, "invalid signature dump entry on line {line}: {entry:?}")
+            }
+
+            Self::AmbiguousPackedEncoding(reason) => {
+                write!(f, "ambiguous packed encoding: {reason}")
+            }
 
             Self::Hex(e) => e.fmt(f),
             Self::TypeParser(e) => e.fmt(f),
@@ -0,0 +1,205 @@
+//! Conversions between [`ethabi`] tokens and parameter types, and
+//! [`DynSolValue`] and [`DynSolType`].
+//!
+//! `ethabi` has no representation for some of the Solidity types supported by
+//! this crate, so the following conversions are lossy:
+//!
+//! - `function` values and types are converted to `bytes24`.
+//! - `CustomStruct` values and types are converted to tuples.
+//! - [`Token::Int`] and [`Token::Uint`] do not carry a bit size, and are
+//!   converted to `int256` and `uint256`.
+
+use crate::{DynSolType, DynSolValue, Error, Result};
+use alloc::{borrow::ToOwned, boxed::Box, vec::Vec};
+use alloy_primitives::{Address, I256, U256};
+use ethabi::{ParamType, Token};
+
+impl From<DynSolValue> for Token {
+    fn from(value: DynSolValue) -> Self {
+        match value {
+            DynSolValue::Bool(b) => Self::Bool(b),
+            DynSolValue::Int(i, _) => Self::Int(to_ethabi_uint(i.into_raw())),
+            DynSolValue::Uint(u, _) => Self::Uint(to_ethabi_uint(u)),
+            DynSolValue::FixedBytes(word, size) => Self::FixedBytes(word[..size].to_vec()),
+            DynSolValue::Address(address) => Self::Address(address.0 .0.into()),
+            DynSolValue::Function(function) => Self::FixedBytes(function.to_vec()),
+            DynSolValue::Bytes(bytes) => Self::Bytes(bytes),
+            DynSolValue::String(s) => Self::String(s),
+            DynSolValue::Array(values) => Self::Array(values_into_tokens(values)),
+            DynSolValue::FixedArray(values) => Self::FixedArray(values_into_tokens(values)),
+            DynSolValue::Tuple(values) => Self::Tuple(values_into_tokens(values)),
+            #[cfg(feature = "eip712")]
+            DynSolValue::CustomStruct { tuple, .. } => Self::Tuple(values_into_tokens(tuple)),
+        }
+    }
+}
+
+impl TryFrom<Token> for DynSolValue {
+    type Error = Error;
+
+    /// Converts an `ethabi` token into a value.
+    ///
+    /// Fails if the token contains fixed bytes longer than 32 bytes.
+    fn try_from(token: Token) -> Result<Self> {
+        Ok(match token {
+            Token::Bool(b) => Self::Bool(b),
+            Token::Int(i) => Self::Int(I256::from_raw(from_ethabi_uint(i)), 256),
+            Token::Uint(u) => Self::Uint(from_ethabi_uint(u), 256),
+            Token::FixedBytes(bytes) => {
+                let size = bytes.len();
+                if size > 32 {
+                    return Err(Error::TypeMismatch {
+                        expected: "fixed bytes of at most 32 bytes".to_owned(),
+                        actual: format!("bytes{size}"),
+                    })
+                }
+                let mut word = [0u8; 32];
+                word[..size].copy_from_slice(&bytes);
+                Self::FixedBytes(word.into(), size)
+            }
+            Token::Address(address) => Self::Address(Address::new(address.0)),
+            Token::Bytes(bytes) => Self::Bytes(bytes),
+            Token::String(s) => Self::String(s),
+            Token::Array(tokens) => Self::Array(tokens_try_into_values(tokens)?),
+            Token::FixedArray(tokens) => Self::FixedArray(tokens_try_into_values(tokens)?),
+            Token::Tuple(tokens) => Self::Tuple(tokens_try_into_values(tokens)?),
+        })
+    }
+}
+
+impl From<DynSolType> for ParamType {
+    fn from(ty: DynSolType) -> Self {
+        match ty {
+            DynSolType::Bool => Self::Bool,
+            DynSolType::Int(size) => Self::Int(size),
+            DynSolType::Uint(size) => Self::Uint(size),
+            DynSolType::FixedBytes(size) => Self::FixedBytes(size),
+            DynSolType::Address => Self::Address,
+            DynSolType::Function => Self::FixedBytes(24),
+            DynSolType::Bytes => Self::Bytes,
+            DynSolType::String => Self::String,
+            DynSolType::Array(ty) => Self::Array(Box::new((*ty).into())),
+            DynSolType::FixedArray(ty, len) => Self::FixedArray(Box::new((*ty).into()), len),
+            DynSolType::Tuple(types) => Self::Tuple(types.into_iter().map(Into::into).collect()),
+            #[cfg(feature = "eip712")]
+            DynSolType::CustomStruct { tuple, .. } => {
+                Self::Tuple(tuple.into_iter().map(Into::into).collect())
+            }
+        }
+    }
+}
+
+impl From<ParamType> for DynSolType {
+    fn from(ty: ParamType) -> Self {
+        match ty {
+            ParamType::Bool => Self::Bool,
+            ParamType::Int(size) => Self::Int(size),
+            ParamType::Uint(size) => Self::Uint(size),
+            ParamType::FixedBytes(size) => Self::FixedBytes(size),
+            ParamType::Address => Self::Address,
+            ParamType::Bytes => Self::Bytes,
+            ParamType::String => Self::String,
+            ParamType::Array(ty) => Self::Array(Box::new((*ty).into())),
+            ParamType::FixedArray(ty, len) => Self::FixedArray(Box::new((*ty).into()), len),
+            ParamType::Tuple(types) => Self::Tuple(types.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+fn values_into_tokens(values: Vec<DynSolValue>) -> Vec<Token> {
+    values.into_iter().map(Into::into).collect()
+}
+
+fn tokens_try_into_values(tokens: Vec<Token>) -> Result<Vec<DynSolValue>> {
+    tokens.into_iter().map(TryInto::try_into).collect()
+}
+
+fn to_ethabi_uint(value: U256) -> ethabi::Uint {
+    ethabi::Uint::from_big_endian(&value.to_be_bytes::<32>())
+}
+
+fn from_ethabi_uint(value: ethabi::Uint) -> U256 {
+    let mut bytes = [0u8; 32];
+    value.to_big_endian(&mut bytes);
+    U256::from_be_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloy_primitives::{Function, B256};
+
+    #[test]
+    fn value_roundtrip() {
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Bool(true),
+            DynSolValue::Int(I256::MINUS_ONE, 256),
+            DynSolValue::Uint(U256::from(0x1234), 256),
+            DynSolValue::FixedBytes(B256::with_last_byte(1), 32),
+            DynSolValue::Address(Address::with_last_byte(2)),
+            DynSolValue::Bytes(vec![1, 2, 3]),
+            DynSolValue::String("hello".into()),
+            DynSolValue::Array(vec![DynSolValue::Uint(U256::MAX, 256)]),
+            DynSolValue::FixedArray(vec![DynSolValue::Bool(false); 2]),
+        ]);
+        let token = Token::from(value.clone());
+        assert_eq!(DynSolValue::try_from(token.clone()).unwrap(), value);
+
+        let ty: DynSolType = "(bool,int256,uint256,bytes32,address,bytes,string,uint256[],bool[2])"
+            .parse()
+            .unwrap();
+        assert_eq!(value.abi_encode(), ethabi::encode(&[token]));
+        assert_eq!(
+            ty.abi_decode_params(&value.abi_encode_params()).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn value_lossy() {
+        let function = Function::repeat_byte(0x42);
+        let token = Token::from(DynSolValue::Function(function));
+        assert_eq!(token, Token::FixedBytes(vec![0x42; 24]));
+        let mut word = B256::ZERO;
+        word[..24].copy_from_slice(&[0x42; 24]);
+        assert_eq!(
+            DynSolValue::try_from(token).unwrap(),
+            DynSolValue::FixedBytes(word, 24)
+        );
+
+        let token = Token::from(DynSolValue::Uint(U256::from(1), 8));
+        assert_eq!(
+            DynSolValue::try_from(token).unwrap(),
+            DynSolValue::Uint(U256::from(1), 256)
+        );
+
+        assert!(DynSolValue::try_from(Token::FixedBytes(vec![0; 33])).is_err());
+    }
+
+    #[test]
+    fn type_roundtrip() {
+        for s in [
+            "bool",
+            "int8",
+            "uint256",
+            "bytes4",
+            "address",
+            "bytes",
+            "string",
+            "uint256[]",
+            "address[3][]",
+            "(bool,(string,bytes32)[2])",
+        ] {
+            let ty: DynSolType = s.parse().unwrap();
+            let param = ParamType::from(ty.clone());
+            assert_eq!(param.to_string(), s);
+            assert_eq!(DynSolType::from(param), ty);
+        }
+
+        assert_eq!(
+            ParamType::from(DynSolType::Function),
+            ParamType::FixedBytes(24)
+        );
+    }
+}
@@ -103,11 +103,12 @@ impl DynSolEvent {
             })
             .collect::<Result<_>>()?;
 
-        let body = self
-            .body
-            .abi_decode_sequence(data)?
-            .into_fixed_seq()
-            .expect("body is a tuple");
+        let body = if validate {
+            self.body.abi_decode_sequence_validate(data)?
+        } else {
+            self.body.abi_decode_sequence(data)?
+        };
+        let body = body.into_fixed_seq().expect("body is a tuple");
 
         if validate {
             let remaining = topics.count();
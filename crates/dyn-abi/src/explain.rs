@@ -0,0 +1,477 @@
+//! Annotated breakdowns of ABI-encoded calldata.
+
+use crate::{DynSolType, Error, Result};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_primitives::{hex, Selector, U256};
+use alloy_sol_types::{
+    utils::{checked_end, checked_next_multiple_of_32},
+    Error as SolTypesError, SolCall,
+};
+use core::fmt;
+
+/// An annotated breakdown of ABI-encoded calldata.
+///
+/// [`AnnotatedCalldata`] splits calldata into its selector and 32-byte words,
+/// and annotates each word with its [role](WordRole) in the encoding and the
+/// parameter it belongs to. This is meant for "decode calldata" views in
+/// debuggers and similar tools, and it is more lenient than decoding: the data
+/// is not validated beyond what is necessary to follow offsets and lengths.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{AnnotatedCalldata, DynSolType, WordRole};
+/// use alloy_primitives::{hex, Selector};
+///
+/// // transfer(address,uint256)
+/// let data = hex!(
+///     "a9059cbb"
+///     "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045"
+///     "0000000000000000000000000000000000000000000000000000000000000064"
+/// );
+/// let params: DynSolType = "(address,uint256)".parse().unwrap();
+/// let annotated = AnnotatedCalldata::new(Selector::new(hex!("a9059cbb")), &params, &data).unwrap();
+///
+/// assert_eq!(annotated.words.len(), 3);
+/// assert_eq!(annotated.words[0].role, WordRole::Selector);
+/// assert_eq!(annotated.words[2].role, WordRole::Value);
+/// assert_eq!(annotated.words[2].path, "1");
+/// assert_eq!(annotated.words[2].ty, Some(DynSolType::Uint(256)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnnotatedCalldata<'a> {
+    /// The selector, if the data starts with one.
+    pub selector: Option<Selector>,
+    /// The annotated words, in order of their offset in the data.
+    ///
+    /// These cover the whole data, without overlapping unless offsets in the
+    /// data point into already annotated words.
+    pub words: Vec<AnnotatedWord<'a>>,
+}
+
+/// A single annotated word of an [`AnnotatedCalldata`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnnotatedWord<'a> {
+    /// The offset of the word in the data, in bytes.
+    pub offset: usize,
+    /// The word itself. This is 4 bytes long for the selector and may be
+    /// shorter than 32 bytes for unused trailing data, and 32 bytes long
+    /// otherwise.
+    pub data: &'a [u8],
+    /// The role of the word in the encoding.
+    pub role: WordRole,
+    /// The path of the parameter this word belongs to, or an empty string.
+    ///
+    /// Parameters are numbered from 0, tuple fields are appended as `.i` (or
+    /// `.name` for structs), and array elements as `[i]`: for example, `1[2].0`
+    /// is the first field of the third element of the second parameter.
+    pub path: String,
+    /// The type of the parameter this word belongs to, if any.
+    pub ty: Option<DynSolType>,
+}
+
+/// The role of an [`AnnotatedWord`] in the encoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WordRole {
+    /// The 4-byte function selector.
+    Selector,
+    /// A value of a static type, such as `uint256` or `address`.
+    Value,
+    /// The offset of a dynamic value. Contains the offset, in bytes, in the
+    /// whole data, including the selector.
+    Offset(usize),
+    /// The length of a dynamic array, `bytes`, or `string`.
+    Length(usize),
+    /// A word of the contents of `bytes` or `string`.
+    Data,
+    /// A word not referenced by the encoding.
+    Unused,
+}
+
+impl fmt::Display for AnnotatedCalldata<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for word in &self.words {
+            writeln!(f, "{word}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AnnotatedWord<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:#06x}: {:<64}  {}",
+            self.offset,
+            hex::encode(self.data),
+            self.role
+        )?;
+        if let Some(ty) = &self.ty {
+            write!(f, " ({}: {})", self.path, ty.sol_type_name())?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for WordRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Selector => f.write_str("selector"),
+            Self::Value => f.write_str("value"),
+            Self::Offset(offset) => write!(f, "offset -> {offset:#06x}"),
+            Self::Length(len) => write!(f, "length {len}"),
+            Self::Data => f.write_str("data"),
+            Self::Unused => f.write_str("unused"),
+        }
+    }
+}
+
+impl<'a> AnnotatedCalldata<'a> {
+    /// Annotates the calldata of a call to `C`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data does not start with `C`'s selector, or if
+    /// it is too short for `C`'s parameters.
+    pub fn from_call<C: SolCall>(data: &'a [u8]) -> Result<Self> {
+        let params = C::SIGNATURE.find('(').map_or("()", |i| &C::SIGNATURE[i..]);
+        Self::new(C::SELECTOR.into(), &DynSolType::parse(params)?, data)
+    }
+
+    /// Annotates calldata made of `selector` followed by the ABI-encoded
+    /// `params`.
+    ///
+    /// `params` is usually a tuple of the function's parameters; any other type
+    /// is treated as a single parameter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data does not start with `selector`, or if it
+    /// is too short for `params`.
+    pub fn new(selector: Selector, params: &DynSolType, data: &'a [u8]) -> Result<Self> {
+        let actual = data.get(..4).ok_or(SolTypesError::Overrun { offset: 0 })?;
+        let actual = Selector::from_slice(actual);
+        if actual != selector {
+            return Err(Error::SelectorMismatch {
+                expected: selector,
+                actual,
+            })
+        }
+
+        let mut annotator = Annotator::new(data, 4);
+        annotator.words.push(AnnotatedWord {
+            offset: 0,
+            data: &data[..4],
+            role: WordRole::Selector,
+            path: String::new(),
+            ty: None,
+        });
+        annotator.params(params)?;
+        Ok(Self {
+            selector: Some(selector),
+            words: annotator.finish(),
+        })
+    }
+
+    /// Annotates ABI-encoded `params` without a selector, such as return data
+    /// or constructor arguments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is too short for `params`.
+    pub fn from_params(params: &DynSolType, data: &'a [u8]) -> Result<Self> {
+        let mut annotator = Annotator::new(data, 0);
+        annotator.params(params)?;
+        Ok(Self {
+            selector: None,
+            words: annotator.finish(),
+        })
+    }
+}
+
+struct Annotator<'a> {
+    data: &'a [u8],
+    /// The offset at which the parameters start in `data`.
+    start: usize,
+    words: Vec<AnnotatedWord<'a>>,
+}
+
+impl<'a> Annotator<'a> {
+    const fn new(data: &'a [u8], start: usize) -> Self {
+        Self {
+            data,
+            start,
+            words: Vec::new(),
+        }
+    }
+
+    fn params(&mut self, params: &DynSolType) -> Result<()> {
+        let types = params.as_tuple().unwrap_or(core::slice::from_ref(params));
+        let fields = types.iter().enumerate().map(|(i, ty)| (i.to_string(), ty));
+        self.seq(fields.collect(), self.start)
+    }
+
+    /// Annotates a sequence of values, encoded starting at `start`.
+    fn seq(&mut self, fields: Vec<(String, &DynSolType)>, start: usize) -> Result<()> {
+        let mut head = start;
+        for (path, ty) in fields {
+            if ty.is_dynamic() {
+                let offset = self.read_usize(head)?;
+                let target = start
+                    .checked_add(offset)
+                    .ok_or(SolTypesError::Overrun { offset: usize::MAX })?;
+                self.push(head, WordRole::Offset(target), &path, ty)?;
+                self.tail(ty, target, &path)?;
+                head += 32;
+            } else {
+                head = self.inline(ty, head, &path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Annotates a value of a static type, encoded in place at `at`. Returns
+    /// the offset following the value.
+    fn inline(&mut self, ty: &DynSolType, mut at: usize, path: &str) -> Result<usize> {
+        match fields(ty, path) {
+            Some(fields) => {
+                for (path, ty) in fields {
+                    at = self.inline(ty, at, &path)?;
+                }
+                Ok(at)
+            }
+            None => {
+                self.push(at, WordRole::Value, path, ty)?;
+                Ok(at + 32)
+            }
+        }
+    }
+
+    /// Annotates a value of a dynamic type, encoded at `at`.
+    fn tail(&mut self, ty: &DynSolType, at: usize, path: &str) -> Result<()> {
+        match ty {
+            DynSolType::Bytes | DynSolType::String => {
+                let len = self.read_usize(at)?;
+                self.push(at, WordRole::Length(len), path, ty)?;
+                let start = at + 32;
+                let end = checked_next_multiple_of_32(len)
+                    .and_then(|padded_len| checked_end(start, padded_len, self.data.len()))
+                    .ok_or(SolTypesError::Overrun { offset: start })?;
+                for word in (start..end).step_by(32) {
+                    self.push(word, WordRole::Data, path, ty)?;
+                }
+                Ok(())
+            }
+            DynSolType::Array(inner) => {
+                let len = self.read_usize(at)?;
+                // each element takes at least one word; this also bounds `len`
+                if len > (self.data.len() - at - 32) / 32 {
+                    return Err(SolTypesError::Overrun {
+                        offset: self.data.len(),
+                    }
+                    .into())
+                }
+                self.push(at, WordRole::Length(len), path, ty)?;
+                let fields = (0..len).map(|i| (format!("{path}[{i}]"), &**inner));
+                self.seq(fields.collect(), at + 32)
+            }
+            _ => match fields(ty, path) {
+                Some(fields) => self.seq(fields, at),
+                None => unreachable!("static type in tail"),
+            },
+        }
+    }
+
+    fn word(&self, at: usize) -> Result<&'a [u8]> {
+        at.checked_add(32)
+            .and_then(|end| self.data.get(at..end))
+            .ok_or_else(|| SolTypesError::Overrun { offset: at }.into())
+    }
+
+    fn read_usize(&self, at: usize) -> Result<usize> {
+        let value = U256::from_be_slice(self.word(at)?);
+        value
+            .try_into()
+            .map_err(|_| SolTypesError::Overrun { offset: usize::MAX }.into())
+    }
+
+    fn push(&mut self, at: usize, role: WordRole, path: &str, ty: &DynSolType) -> Result<()> {
+        let data = self.word(at)?;
+        self.words.push(AnnotatedWord {
+            offset: at,
+            data,
+            role,
+            path: path.into(),
+            ty: Some(ty.clone()),
+        });
+        Ok(())
+    }
+
+    /// Sorts the annotated words and fills the gaps with unused words.
+    fn finish(mut self) -> Vec<AnnotatedWord<'a>> {
+        self.words.sort_by_key(|word| word.offset);
+
+        let mut words = Vec::with_capacity(self.words.len());
+        let mut cursor = 0;
+        for word in self.words {
+            Self::unused(self.data, cursor, word.offset, &mut words);
+            cursor = cursor.max(word.offset + word.data.len());
+            words.push(word);
+        }
+        Self::unused(self.data, cursor, self.data.len(), &mut words);
+        words
+    }
+
+    fn unused(data: &'a [u8], mut start: usize, end: usize, words: &mut Vec<AnnotatedWord<'a>>) {
+        while start < end {
+            let word_end = end.min(start + 32);
+            words.push(AnnotatedWord {
+                offset: start,
+                data: &data[start..word_end],
+                role: WordRole::Unused,
+                path: String::new(),
+                ty: None,
+            });
+            start = word_end;
+        }
+    }
+}
+
+/// Returns the fields of a tuple, struct, or fixed array, with their paths.
+fn fields<'t>(ty: &'t DynSolType, path: &str) -> Option<Vec<(String, &'t DynSolType)>> {
+    match ty {
+        DynSolType::Tuple(types) => Some(
+            types
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| (format!("{path}.{i}"), ty))
+                .collect(),
+        ),
+        #[cfg(feature = "eip712")]
+        DynSolType::CustomStruct {
+            prop_names, tuple, ..
+        } => Some(
+            prop_names
+                .iter()
+                .zip(tuple)
+                .map(|(name, ty)| (format!("{path}.{name}"), ty))
+                .collect(),
+        ),
+        DynSolType::FixedArray(inner, len) => Some(
+            (0..*len)
+                .map(|i| (format!("{path}[{i}]"), &**inner))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Address;
+    use alloy_sol_types::sol;
+
+    sol! {
+        function foo(uint256 a, bytes b, address[] c, (bool, string) d);
+    }
+
+    #[test]
+    fn annotate_call() {
+        let call = fooCall {
+            a: U256::from(1),
+            b: vec![0xaa; 33],
+            c: vec![Address::repeat_byte(0x11), Address::repeat_byte(0x22)],
+            d: (true, "hi".into()),
+        };
+        let data = call.abi_encode();
+        let annotated = AnnotatedCalldata::from_call::<fooCall>(&data).unwrap();
+        assert_eq!(annotated.selector, Some(fooCall::SELECTOR.into()));
+
+        let roles = annotated
+            .words
+            .iter()
+            .map(|w| (w.offset, w.role, w.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            roles,
+            [
+                (0x000, WordRole::Selector, ""),
+                (0x004, WordRole::Value, "0"),
+                (0x024, WordRole::Offset(0x084), "1"),
+                (0x044, WordRole::Offset(0x0e4), "2"),
+                (0x064, WordRole::Offset(0x144), "3"),
+                (0x084, WordRole::Length(33), "1"),
+                (0x0a4, WordRole::Data, "1"),
+                (0x0c4, WordRole::Data, "1"),
+                (0x0e4, WordRole::Length(2), "2"),
+                (0x104, WordRole::Value, "2[0]"),
+                (0x124, WordRole::Value, "2[1]"),
+                (0x144, WordRole::Value, "3.0"),
+                (0x164, WordRole::Offset(0x184), "3.1"),
+                (0x184, WordRole::Length(2), "3.1"),
+                (0x1a4, WordRole::Data, "3.1"),
+            ]
+        );
+        assert_eq!(
+            annotated.words.iter().map(|w| w.data.len()).sum::<usize>(),
+            data.len()
+        );
+        assert_eq!(annotated.words[10].ty, Some(DynSolType::Address));
+        assert_eq!(annotated.to_string().lines().count(), annotated.words.len());
+    }
+
+    #[test]
+    fn unused_and_errors() {
+        let params: DynSolType = "(uint8)".parse().unwrap();
+        let mut data = vec![0; 32 + 40];
+        data[31] = 7;
+        let annotated = AnnotatedCalldata::from_params(&params, &data).unwrap();
+        assert_eq!(annotated.selector, None);
+        let roles = annotated
+            .words
+            .iter()
+            .map(|w| (w.offset, w.data.len(), w.role))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            roles,
+            [
+                (0, 32, WordRole::Value),
+                (32, 32, WordRole::Unused),
+                (64, 8, WordRole::Unused),
+            ]
+        );
+
+        assert!(AnnotatedCalldata::from_params(&params, &data[..31]).is_err());
+        assert_eq!(
+            AnnotatedCalldata::new(Selector::ZERO, &params, &[1, 2, 3, 4]),
+            Err(Error::SelectorMismatch {
+                expected: Selector::ZERO,
+                actual: Selector::new([1, 2, 3, 4]),
+            })
+        );
+
+        // offset and length pointing out of bounds
+        let params: DynSolType = "(bytes)".parse().unwrap();
+        let mut data = vec![0; 64];
+        data[31] = 0x40;
+        assert!(AnnotatedCalldata::from_params(&params, &data).is_err());
+        data[31] = 0x20;
+        data[63] = 1;
+        assert!(AnnotatedCalldata::from_params(&params, &data).is_err());
+        // length close to `usize::MAX`
+        data[56..].fill(0xff);
+        assert_eq!(
+            AnnotatedCalldata::from_params(&params, &data),
+            Err(SolTypesError::Overrun { offset: 64 }.into())
+        );
+        data[32..].fill(0);
+
+        let params: DynSolType = "(uint256[])".parse().unwrap();
+        data[63] = 0xff;
+        assert!(AnnotatedCalldata::from_params(&params, &data).is_err());
+    }
+}
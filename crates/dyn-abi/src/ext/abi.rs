@@ -1,6 +1,6 @@
 use crate::{DynSolValue, Error as CrateError, ResolveSolType, Result};
 use alloc::vec::Vec;
-use alloy_json_abi::{Constructor, Error, Function, Param};
+use alloy_json_abi::{Constructor, Error, Function, JsonAbi, Param};
 use alloy_primitives::Selector;
 use alloy_sol_types::abi::Decoder;
 
@@ -9,6 +9,7 @@ mod sealed {
     impl Sealed for super::Constructor {}
     impl Sealed for super::Error {}
     impl Sealed for super::Function {}
+    impl Sealed for super::JsonAbi {}
 }
 use sealed::Sealed;
 
@@ -81,6 +82,52 @@ pub trait FunctionExt: JsonAbiExt + Sealed {
     fn abi_decode_output(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>>;
 }
 
+/// Provides deployment data encoding for the [`JsonAbi`] type.
+///
+/// This trait is sealed and cannot be implemented for types outside of this
+/// crate. It is implemented only for [`JsonAbi`].
+pub trait DeployExt: Sealed {
+    /// Returns the data of a transaction deploying a contract with this ABI:
+    /// its creation `bytecode`, followed by the ABI-encoded constructor
+    /// arguments.
+    ///
+    /// If the ABI has no constructor, `values` must be empty.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the given values do not match the
+    /// constructor's input types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DeployExt, DynSolValue};
+    /// use alloy_json_abi::JsonAbi;
+    /// use alloy_primitives::U256;
+    ///
+    /// let abi = JsonAbi::parse(["constructor(uint256 supply)"])?;
+    /// let bytecode = [0x60, 0x80, 0x60, 0x40];
+    /// let data = abi.encode_constructor(&bytecode, &[U256::from(1).into()])?;
+    /// assert_eq!(data[..4], bytecode);
+    /// assert_eq!(data[4..], U256::from(1).to_be_bytes::<32>());
+    ///
+    /// assert!(abi.encode_constructor(&bytecode, &[]).is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn encode_constructor(&self, bytecode: &[u8], values: &[DynSolValue]) -> Result<Vec<u8>>;
+}
+
+impl DeployExt for JsonAbi {
+    fn encode_constructor(&self, bytecode: &[u8], values: &[DynSolValue]) -> Result<Vec<u8>> {
+        let inputs = self.constructor().map_or(&[][..], |c| &c.inputs[..]);
+        let encoded = encode_typeck(inputs, values)?;
+        let mut data = Vec::with_capacity(bytecode.len() + encoded.len());
+        data.extend_from_slice(bytecode);
+        data.extend_from_slice(&encoded);
+        Ok(data)
+    }
+}
+
 impl JsonAbiExt for Constructor {
     #[inline]
     fn abi_encode_input(&self, values: &[DynSolValue]) -> Result<Vec<u8>> {
@@ -190,6 +237,9 @@ fn abi_decode(data: &[u8], params: &[Param], validate: bool) -> Result<Vec<DynSo
         let value = ty.abi_decode_inner(&mut decoder, crate::DynToken::decode_single_populate)?;
         values.push(value);
     }
+    if validate && DynSolValue::encode_seq(&values) != data {
+        return Err(alloy_sol_types::Error::ReserMismatch.into())
+    }
     Ok(values)
 }
 
@@ -260,4 +310,38 @@ mod tests {
         assert!(func.abi_decode_output(&bad_response, true).is_err());
         assert!(func.abi_decode_output(&bad_response, false).is_err());
     }
+
+    #[test]
+    fn encode_constructor() {
+        let bytecode = [0x60, 0x80, 0x60, 0x40];
+        let abi = JsonAbi::parse(["constructor(address owner, string name)"]).unwrap();
+        let values = [
+            DynSolValue::Address(Address::repeat_byte(0x11)),
+            DynSolValue::String("token".into()),
+        ];
+        let data = abi.encode_constructor(&bytecode, &values).unwrap();
+        assert_eq!(data[..4], bytecode);
+        assert_eq!(
+            data[4..],
+            abi.constructor()
+                .unwrap()
+                .abi_encode_input(&values)
+                .unwrap()
+        );
+        assert_eq!(
+            abi.encode_constructor(&bytecode, &values[..1]),
+            Err(CrateError::EncodeLengthMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert!(matches!(
+            abi.encode_constructor(&bytecode, &[values[1].clone(), values[0].clone()]),
+            Err(CrateError::TypeMismatch { .. })
+        ));
+
+        let abi = JsonAbi::new();
+        assert_eq!(abi.encode_constructor(&bytecode, &[]).unwrap(), bytecode);
+        assert!(abi.encode_constructor(&bytecode, &values).is_err());
+    }
 }
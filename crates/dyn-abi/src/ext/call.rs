@@ -0,0 +1,114 @@
+use crate::{call::encode_typeck, DynSolType, DynSolValue, Error, Result};
+use alloy_sol_types::SolCall;
+
+/// Provides construction of [`SolCall`]s from dynamic values.
+///
+/// This trait is implemented for all [`SolCall`]s, such as the call structs
+/// generated by [`sol!`](alloy_sol_types::sol!).
+pub trait SolCallExt: SolCall {
+    /// Constructs a call from its arguments, type-checking them against the
+    /// function's parameters.
+    ///
+    /// Structs are represented as tuples or custom structs, like in the
+    /// function's [`SIGNATURE`](SolCall::SIGNATURE).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of values does not match the number of
+    /// parameters, if a value does not match its parameter's type, or if an
+    /// integer or fixed-size byte array does not fit in its type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolValue, SolCallExt};
+    /// use alloy_primitives::{Address, U256};
+    /// use alloy_sol_types::sol;
+    ///
+    /// sol! {
+    ///     function transfer(address to, uint256 amount);
+    /// }
+    ///
+    /// let to = Address::repeat_byte(0x11);
+    /// let call = transferCall::from_dyn_values(&[to.into(), U256::from(100).into()])?;
+    /// assert_eq!(call.to, to);
+    /// assert_eq!(call.amount, U256::from(100));
+    ///
+    /// assert!(transferCall::from_dyn_values(&[to.into(), true.into()]).is_err());
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    fn from_dyn_values(values: &[DynSolValue]) -> Result<Self>;
+}
+
+impl<T: SolCall> SolCallExt for T {
+    fn from_dyn_values(values: &[DynSolValue]) -> Result<Self> {
+        let params = Self::SIGNATURE
+            .find('(')
+            .map(|i| &Self::SIGNATURE[i..])
+            .ok_or_else(|| Error::custom("invalid function signature"))?;
+        let DynSolType::Tuple(types) = DynSolType::parse(params)? else {
+            return Err(Error::custom("invalid function signature"))
+        };
+        let data = encode_typeck(&types, values)?;
+        Self::abi_decode_raw(&data, true).map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, B256, U256};
+    use alloy_sol_types::sol;
+
+    sol! {
+        struct Order {
+            address maker;
+            uint8 kind;
+            bytes4[] tags;
+        }
+
+        function place(Order order, string memo, int16 delta);
+        function ping();
+    }
+
+    #[test]
+    fn from_dyn_values() {
+        let mut tag = B256::ZERO;
+        tag[..4].fill(0xab);
+        let order = |kind| {
+            DynSolValue::Tuple(vec![
+                Address::repeat_byte(0x11).into(),
+                DynSolValue::Uint(U256::from(kind), 8),
+                DynSolValue::Array(vec![DynSolValue::FixedBytes(tag, 4)]),
+            ])
+        };
+        let delta = |bits| DynSolValue::Int((-2).try_into().unwrap(), bits);
+        let memo = || DynSolValue::String("hello".into());
+
+        let call = placeCall::from_dyn_values(&[order(3), memo(), delta(16)]).unwrap();
+        assert_eq!(call.order.maker, Address::repeat_byte(0x11));
+        assert_eq!(call.order.kind, 3);
+        assert_eq!(call.order.tags, [[0xab; 4]]);
+        assert_eq!(call.memo, "hello");
+        assert_eq!(call.delta, -2);
+
+        assert!(pingCall::from_dyn_values(&[]).is_ok());
+        assert_eq!(
+            pingCall::from_dyn_values(&[true.into()]).err(),
+            Some(Error::EncodeLengthMismatch {
+                expected: 0,
+                actual: 1
+            })
+        );
+        assert!(matches!(
+            placeCall::from_dyn_values(&[order(3), memo(), delta(8)]),
+            Err(Error::TypeMismatch { .. })
+        ));
+        assert!(matches!(
+            placeCall::from_dyn_values(&[order(256), memo(), delta(16)]),
+            Err(Error::SolTypes(
+                alloy_sol_types::Error::TypeCheckFail { .. }
+            ))
+        ));
+    }
+}
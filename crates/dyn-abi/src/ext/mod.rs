@@ -1,5 +1,8 @@
 mod abi;
-pub use abi::{FunctionExt, JsonAbiExt};
+pub use abi::{DeployExt, FunctionExt, JsonAbiExt};
+
+mod call;
+pub use call::SolCallExt;
 
 mod event;
 pub use event::EventExt;
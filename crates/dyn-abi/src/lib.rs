@@ -33,31 +33,63 @@ mod arbitrary;
 
 mod coerce;
 
+pub mod console;
+
+#[cfg(feature = "ethabi")]
+mod ethabi;
+
 mod error;
 pub use error::{Error, Result};
 
+mod explain;
+pub use explain::{AnnotatedCalldata, AnnotatedWord, WordRole};
+
+mod literal;
+pub use literal::SolLiteral;
+
 mod ext;
-pub use ext::{EventExt, FunctionExt, JsonAbiExt};
+pub use ext::{DeployExt, EventExt, FunctionExt, JsonAbiExt, SolCallExt};
+
+mod call;
+pub use call::{event_topic, selector, signature, DynSolCall};
 
 mod event;
 pub use event::{DecodedEvent, DynSolEvent};
 
+mod packed;
+
 mod ty;
 pub use ty::DynSolType;
 
 mod value;
 pub use value::DynSolValue;
 
+mod value_ref;
+pub use value_ref::DynSolValueRef;
+
 mod token;
 pub use token::DynToken;
 
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
+pub use stream::{ForwardReader, StreamDecoder};
+
 mod resolve;
 pub use resolve::{ResolveSolEvent, ResolveSolType};
 
+pub mod selectors;
+pub use selectors::{DecodedCall, SelectorDatabase, SelectorLookup};
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
 #[cfg(feature = "eip712")]
 pub mod eip712;
 #[cfg(feature = "eip712")]
-pub use eip712::{parser as eip712_parser, Eip712Types, PropertyDef, Resolver, TypeDef, TypedData};
+pub use eip712::{
+    parser as eip712_parser, Eip712Types, NumberCoercion, PropertyDef, Resolver, TypeDef, TypedData,
+};
 
 #[doc(no_inline)]
 pub use alloy_sol_type_parser as parser;
@@ -0,0 +1,201 @@
+//! Formatting of values as Solidity literals.
+
+use crate::DynSolValue;
+use alloy_primitives::hex;
+use core::fmt::{self, Write};
+
+/// Formats a [`DynSolValue`] as a Solidity literal.
+///
+/// The literal is a Solidity expression which evaluates to the same value, so
+/// that decoded data can be pasted into tests and scripts:
+///
+/// | Value          | Literal                   |
+/// |----------------|---------------------------|
+/// | `bool`         | `true`                    |
+/// | `uintN`/`intN` | `-42`                     |
+/// | `bytesN`       | `bytes4(0x12345678)`      |
+/// | `address`      | `address(0xd8dA...6045)`  |
+/// | `function`     | `bytes24(0x...)`          |
+/// | `bytes`        | `hex"0102"`               |
+/// | `string`       | `"hi"` or `unicode"hé"`   |
+/// | arrays         | `[1, 2, 3]`               |
+/// | tuples         | `(1, true)`               |
+/// | structs        | `MyStruct({a: 1, b: 2})`  |
+///
+/// Addresses are checksummed, as the compiler requires. Note that array
+/// literals are typed by their first element, so arrays of integers may need
+/// an explicit conversion of their first element, and that empty dynamic
+/// arrays have no literal form.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{DynSolType, DynSolValue};
+///
+/// let ty: DynSolType = "(address,bytes,uint8[])".parse()?;
+/// let value = ty.coerce_str("(0xd8da6bf26964af9d7eed9e03e53415d37aa96045, 0x0102, [1, 2, 3])")?;
+/// assert_eq!(
+///     value.sol_literal().to_string(),
+///     r#"(address(0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045), hex"0102", [1, 2, 3])"#,
+/// );
+/// # Ok::<_, alloy_dyn_abi::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SolLiteral<'a>(&'a DynSolValue);
+
+impl DynSolValue {
+    /// Returns an object which formats this value as a Solidity literal
+    /// expression, such as `address(0x...)` or `[1, 2, 3]`.
+    ///
+    /// See [`SolLiteral`] for more details.
+    #[inline]
+    pub const fn sol_literal(&self) -> SolLiteral<'_> {
+        SolLiteral(self)
+    }
+}
+
+impl fmt::Display for SolLiteral<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            DynSolValue::Bool(b) => fmt::Display::fmt(b, f),
+            DynSolValue::Int(i, _) => fmt::Display::fmt(i, f),
+            DynSolValue::Uint(u, _) => fmt::Display::fmt(u, f),
+            DynSolValue::FixedBytes(word, size) => {
+                write!(f, "bytes{size}({})", hex::encode_prefixed(&word[..*size]))
+            }
+            DynSolValue::Address(address) => write!(f, "address({address})"),
+            DynSolValue::Function(function) => write!(f, "bytes24({function})"),
+            DynSolValue::Bytes(bytes) => write!(f, "hex\"{}\"", hex::encode(bytes)),
+            DynSolValue::String(s) => fmt_string(s, f),
+            DynSolValue::Array(values) | DynSolValue::FixedArray(values) => {
+                f.write_char('[')?;
+                fmt_list(values, f)?;
+                f.write_char(']')
+            }
+            DynSolValue::Tuple(values) => {
+                f.write_char('(')?;
+                fmt_list(values, f)?;
+                f.write_char(')')
+            }
+            #[cfg(feature = "eip712")]
+            DynSolValue::CustomStruct {
+                name,
+                prop_names,
+                tuple,
+            } => {
+                write!(f, "{name}({{")?;
+                for (i, (prop, value)) in prop_names.iter().zip(tuple).enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{prop}: {}", value.sol_literal())?;
+                }
+                f.write_str("})")
+            }
+        }
+    }
+}
+
+fn fmt_list(values: &[DynSolValue], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{}", value.sol_literal())?;
+    }
+    Ok(())
+}
+
+/// Formats a string literal, which must be prefixed with `unicode` if it
+/// contains non-ASCII characters.
+fn fmt_string(s: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if !s.is_ascii() {
+        f.write_str("unicode")?;
+    }
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_ascii_control() => write!(f, "\\x{:02x}", c as u8)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec::Vec};
+    use alloy_primitives::{address, Function, I256, U256};
+
+    fn lit(value: DynSolValue) -> alloc::string::String {
+        value.sol_literal().to_string()
+    }
+
+    #[test]
+    fn literals() {
+        assert_eq!(lit(true.into()), "true");
+        assert_eq!(lit(U256::from(42).into()), "42");
+        assert_eq!(
+            lit(DynSolValue::Int(I256::try_from(-42).unwrap(), 8)),
+            "-42"
+        );
+
+        let mut word = alloy_primitives::B256::ZERO;
+        word[..4].copy_from_slice(&[0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(lit(DynSolValue::FixedBytes(word, 4)), "bytes4(0x12345678)");
+        assert_eq!(
+            lit(address!("d8da6bf26964af9d7eed9e03e53415d37aa96045").into()),
+            "address(0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045)"
+        );
+        assert_eq!(
+            lit(DynSolValue::Function(Function::repeat_byte(0xab))),
+            format!("bytes24(0x{})", "ab".repeat(24))
+        );
+
+        assert_eq!(lit(vec![1u8, 2].into()), "hex\"0102\"");
+        assert_eq!(lit(Vec::<u8>::new().into()), "hex\"\"");
+        assert_eq!(lit("hi".to_string().into()), "\"hi\"");
+        assert_eq!(
+            lit("a \"b\" \\ c\n\u{7}".to_string().into()),
+            r#""a \"b\" \\ c\n\x07""#
+        );
+        assert_eq!(lit("héllo".to_string().into()), "unicode\"héllo\"");
+
+        assert_eq!(
+            lit(DynSolValue::Array(vec![
+                U256::from(1).into(),
+                U256::from(2).into(),
+                U256::from(3).into()
+            ])),
+            "[1, 2, 3]"
+        );
+        assert_eq!(lit(DynSolValue::FixedArray(vec![])), "[]");
+        assert_eq!(
+            lit(DynSolValue::Tuple(vec![
+                U256::from(1).into(),
+                DynSolValue::Tuple(vec![false.into()])
+            ])),
+            "(1, (false))"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "eip712")]
+    fn custom_struct() {
+        let value = DynSolValue::CustomStruct {
+            name: "MyStruct".into(),
+            prop_names: vec!["a".into(), "b".into()],
+            tuple: vec![
+                U256::from(1).into(),
+                DynSolValue::Array(vec!["x".to_string().into()]),
+            ],
+        };
+        assert_eq!(lit(value), r#"MyStruct({a: 1, b: ["x"]})"#);
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{ty::as_tuple, DynSolType, DynSolValue, Error, Result};
+use alloc::vec::Vec;
+use alloy_primitives::{Address, Function, I256, U256};
+use alloy_sol_types::{Error as SolTypesError, Word};
+
+impl DynSolType {
+    /// Decodes a sequence of values of the given types from their packed
+    /// encoding, as produced by [`DynSolValue::abi_encode_packed`].
+    ///
+    /// The packed encoding does not store the lengths of dynamically sized
+    /// values, so at most one value in each sequence may have a dynamic
+    /// length, which is then inferred from the length of the data and the
+    /// sizes of the other values. Arrays are never decodable, as Solidity's
+    /// `abi.encodePacked` pads their elements to 32 bytes while
+    /// [`DynSolValue::abi_encode_packed`] does not.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AmbiguousPackedEncoding`] if the types do not
+    /// uniquely determine the boundaries of the values, and an error if the
+    /// data is too short, too long, or contains invalid values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    ///
+    /// let types = [DynSolType::Uint(16), DynSolType::String, DynSolType::Bool];
+    /// let data = b"\x01\x02hello\x01";
+    /// assert_eq!(
+    ///     DynSolType::decode_packed(&types, data)?,
+    ///     [
+    ///         DynSolValue::Uint(0x0102.try_into().unwrap(), 16),
+    ///         DynSolValue::String("hello".into()),
+    ///         DynSolValue::Bool(true),
+    ///     ],
+    /// );
+    ///
+    /// let types = [DynSolType::Bytes, DynSolType::String];
+    /// assert!(DynSolType::decode_packed(&types, data).is_err());
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn decode_packed(types: &[Self], data: &[u8]) -> Result<Vec<DynSolValue>> {
+        decode_packed_seq(&types.iter().collect::<Vec<_>>(), data, 0)
+    }
+
+    /// Returns the size of this type's packed encoding, or `None` if it is
+    /// dynamically sized.
+    fn packed_size(&self) -> Option<usize> {
+        match self {
+            Self::Address => Some(20),
+            Self::Function => Some(24),
+            Self::Bool => Some(1),
+            Self::Int(bits) | Self::Uint(bits) => Some(bits / 8),
+            Self::FixedBytes(size) => Some(*size),
+            Self::Bytes | Self::String | Self::Array(_) => None,
+            Self::FixedArray(ty, len) => ty.packed_size().map(|size| size * len),
+            as_tuple!(Self tuple) => tuple.iter().map(Self::packed_size).sum(),
+        }
+    }
+
+    /// Decodes a single value which spans all of `data`. `offset` is the
+    /// position of `data` in the full input, for error reporting.
+    fn decode_packed_single(&self, data: &[u8], offset: usize) -> Result<DynSolValue> {
+        let value = match self {
+            // these would index into or overflow a word
+            &Self::Int(bits) | &Self::Uint(bits) if bits == 0 || bits > 256 || bits % 8 != 0 => {
+                return Err(Error::custom(format!(
+                    "cannot decode invalid type `{self}`"
+                )))
+            }
+            &Self::FixedBytes(size) if size == 0 || size > 32 => {
+                return Err(Error::custom(format!(
+                    "cannot decode invalid type `{self}`"
+                )))
+            }
+            Self::Address => DynSolValue::Address(Address::from_slice(data)),
+            Self::Function => DynSolValue::Function(Function::from_slice(data)),
+            Self::Bool => match data[0] {
+                0 => DynSolValue::Bool(false),
+                1 => DynSolValue::Bool(true),
+                _ => return Err(SolTypesError::type_check_fail(data, "bool").into()),
+            },
+            &Self::Int(bits) => {
+                let fill = if data[0] & 0x80 != 0 { 0xff } else { 0 };
+                let mut word = [fill; 32];
+                word[32 - data.len()..].copy_from_slice(data);
+                DynSolValue::Int(I256::from_be_bytes(word), bits)
+            }
+            &Self::Uint(bits) => DynSolValue::Uint(U256::from_be_slice(data), bits),
+            &Self::FixedBytes(size) => {
+                let mut word = Word::ZERO;
+                word[..size].copy_from_slice(data);
+                DynSolValue::FixedBytes(word, size)
+            }
+            Self::Bytes => DynSolValue::Bytes(data.to_vec()),
+            Self::String => match core::str::from_utf8(data) {
+                Ok(s) => DynSolValue::String(s.into()),
+                Err(_) => return Err(SolTypesError::type_check_fail(data, "string").into()),
+            },
+            // `abi.encodePacked` pads array elements to 32 bytes, unlike
+            // `abi_encode_packed`, so the element boundaries are ambiguous.
+            Self::Array(_) | Self::FixedArray(..) => {
+                return Err(Error::AmbiguousPackedEncoding(format!(
+                    "`{self}` is an array, whose elements may be padded"
+                )))
+            }
+            Self::Tuple(tuple) => DynSolValue::Tuple(decode_packed_seq(
+                &tuple.iter().collect::<Vec<_>>(),
+                data,
+                offset,
+            )?),
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct {
+                name,
+                prop_names,
+                tuple,
+            } => DynSolValue::CustomStruct {
+                name: name.clone(),
+                prop_names: prop_names.clone(),
+                tuple: decode_packed_seq(&tuple.iter().collect::<Vec<_>>(), data, offset)?,
+            },
+        };
+        Ok(value)
+    }
+}
+
+/// Decodes a sequence of packed values which spans all of `data`.
+fn decode_packed_seq(
+    types: &[&DynSolType],
+    data: &[u8],
+    offset: usize,
+) -> Result<Vec<DynSolValue>> {
+    let mut dynamic = None;
+    let mut static_len = 0;
+    for &ty in types {
+        match ty.packed_size() {
+            Some(size) => static_len += size,
+            None => {
+                if let Some(first) = dynamic {
+                    return Err(Error::AmbiguousPackedEncoding(format!(
+                        "`{first}` and `{ty}` are both dynamically sized"
+                    )))
+                }
+                dynamic = Some(ty);
+            }
+        }
+    }
+
+    let dynamic_len = data
+        .len()
+        .checked_sub(static_len)
+        .ok_or(SolTypesError::Overrun {
+            offset: offset + data.len(),
+        })?;
+    if dynamic.is_none() && dynamic_len != 0 {
+        return Err(SolTypesError::BufferNotEmpty.into())
+    }
+
+    let mut pos = 0;
+    types
+        .iter()
+        .map(|ty| {
+            let size = ty.packed_size().unwrap_or(dynamic_len);
+            let value = ty.decode_packed_single(&data[pos..pos + size], offset + pos);
+            pos += size;
+            value
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn decode_packed_roundtrip() {
+        let values = [
+            DynSolValue::Int(I256::MINUS_ONE, 24),
+            DynSolValue::Int(I256::try_from(0x1234).unwrap(), 16),
+            DynSolValue::Uint(U256::from(0xabcdef), 64),
+            DynSolValue::Address(Address::repeat_byte(0x11)),
+            DynSolValue::Bool(true),
+            DynSolValue::FixedBytes(Word::repeat_byte(0x22), 32),
+            DynSolValue::Tuple(vec![
+                DynSolValue::Uint(U256::from(3), 8),
+                DynSolValue::String("abc".into()),
+            ]),
+        ];
+        let types = values
+            .iter()
+            .map(|value| value.as_type().unwrap())
+            .collect::<Vec<_>>();
+        let data = DynSolValue::Tuple(values.to_vec()).abi_encode_packed();
+        assert_eq!(DynSolType::decode_packed(&types, &data).unwrap(), values);
+
+        let types = [DynSolType::Bytes];
+        assert_eq!(
+            DynSolType::decode_packed(&types, &[]).unwrap(),
+            [DynSolValue::Bytes(vec![])]
+        );
+    }
+
+    #[test]
+    fn decode_packed_errors() {
+        let data = hex!("0102030405");
+        let decode = |types: &[DynSolType]| DynSolType::decode_packed(types, &data).unwrap_err();
+
+        assert_eq!(
+            decode(&[DynSolType::Bytes, DynSolType::Uint(8), DynSolType::String]),
+            Error::AmbiguousPackedEncoding(
+                "`bytes` and `string` are both dynamically sized".into()
+            )
+        );
+        assert_eq!(
+            decode(&[DynSolType::Array(Box::new(DynSolType::Uint(8)))]),
+            Error::AmbiguousPackedEncoding(
+                "`uint8[]` is an array, whose elements may be padded".into()
+            )
+        );
+        assert_eq!(
+            decode(&[DynSolType::FixedArray(Box::new(DynSolType::Uint(8)), 5)]),
+            Error::AmbiguousPackedEncoding(
+                "`uint8[5]` is an array, whose elements may be padded".into()
+            )
+        );
+        assert_eq!(
+            decode(&[
+                DynSolType::Uint(8),
+                DynSolType::Tuple(vec![DynSolType::Array(Box::new(DynSolType::Bool))])
+            ]),
+            Error::AmbiguousPackedEncoding(
+                "`bool[]` is an array, whose elements may be padded".into()
+            )
+        );
+        assert_eq!(
+            decode(&[DynSolType::Tuple(vec![
+                DynSolType::Uint(32),
+                DynSolType::Uint(16)
+            ])]),
+            Error::SolTypes(SolTypesError::Overrun { offset: 5 })
+        );
+        assert_eq!(
+            decode(&[DynSolType::Uint(32)]),
+            Error::SolTypes(SolTypesError::BufferNotEmpty)
+        );
+        assert_eq!(
+            decode(&[DynSolType::Uint(32), DynSolType::Bool]),
+            Error::SolTypes(SolTypesError::type_check_fail(&data[4..], "bool"))
+        );
+    }
+
+    #[test]
+    fn decode_packed_invalid_sizes() {
+        for ty in [
+            DynSolType::Int(0),
+            DynSolType::Uint(0),
+            DynSolType::Int(4),
+            DynSolType::Int(264),
+            DynSolType::Uint(264),
+            DynSolType::FixedBytes(0),
+            DynSolType::FixedBytes(33),
+        ] {
+            let data = vec![0xff; ty.packed_size().unwrap()];
+            assert_eq!(
+                DynSolType::decode_packed(core::slice::from_ref(&ty), &data).unwrap_err(),
+                Error::custom(format!("cannot decode invalid type `{ty}`"))
+            );
+        }
+    }
+}
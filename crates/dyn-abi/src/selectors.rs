@@ -0,0 +1,361 @@
+//! Function selector lookups, for decoding calldata without an ABI.
+//!
+//! A [`SelectorLookup`] maps 4-byte function selectors to the signatures of
+//! candidate functions, such as those collected by [openchain] and [4byte].
+//! [`SelectorDatabase`] is an in-memory implementation which can be loaded
+//! from their dumps, and [`SelectorLookup::decode_calldata`] uses any lookup
+//! to decode calldata on a best-effort basis, for example to display
+//! transactions to contracts with unknown ABIs.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_dyn_abi::{DynSolValue, SelectorDatabase, SelectorLookup};
+//! use alloy_primitives::{hex, Address, U256};
+//!
+//! let db = SelectorDatabase::parse_dump(
+//!     "0xa9059cbb,transfer(address,uint256)\n\
+//!      approve(address,uint256)\n",
+//! )?;
+//!
+//! let data = hex!(
+//!     "a9059cbb"
+//!     "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045"
+//!     "0000000000000000000000000000000000000000000000000000000000000064"
+//! );
+//! let calls = db.decode_calldata(&data);
+//! assert_eq!(calls.len(), 1);
+//! assert_eq!(calls[0].signature, "transfer(address,uint256)");
+//! assert_eq!(calls[0].args[1], DynSolValue::from(U256::from(100)));
+//! # Ok::<(), alloy_dyn_abi::Error>(())
+//! ```
+//!
+//! [openchain]: https://openchain.xyz/signatures
+//! [4byte]: https://www.4byte.directory
+
+use crate::{DynSolType, DynSolValue, Error, Result};
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_json_abi::JsonAbi;
+use alloy_primitives::{keccak256, Selector};
+
+/// A source of candidate function signatures for function selectors.
+///
+/// See the [module-level documentation](self) for more details.
+pub trait SelectorLookup {
+    /// Returns the signatures of the functions with the given selector, such
+    /// as `transfer(address,uint256)` for `0xa9059cbb`.
+    ///
+    /// Selectors are only 4 bytes long, so there may be more than one.
+    fn signatures(&self, selector: Selector) -> Vec<String>;
+
+    /// Decodes `data`, a selector followed by ABI-encoded arguments, as a call
+    /// to each of the functions returned by [`signatures`](Self::signatures).
+    ///
+    /// Returns the calls which `data` is the canonical encoding of, which
+    /// rules out most selector collisions. Signatures which cannot be parsed,
+    /// or which do not hash to the selector, are ignored.
+    fn decode_calldata(&self, data: &[u8]) -> Vec<DecodedCall> {
+        let Some(selector) = data.get(..4) else {
+            return Vec::new()
+        };
+        let selector = Selector::from_slice(selector);
+        let encoded_args = &data[4..];
+        self.signatures(selector)
+            .into_iter()
+            .filter_map(|signature| {
+                if signature_selector(&signature) != selector {
+                    return None
+                }
+                let params = DynSolType::parse(&signature[signature.find('(')?..]).ok()?;
+                let args = params.abi_decode_sequence(encoded_args).ok()?;
+                // decoding is lenient, so check that this is how the arguments
+                // would have been encoded
+                if !fits(&args) || args.abi_encode_sequence()? != encoded_args {
+                    return None
+                }
+                let DynSolValue::Tuple(args) = args else {
+                    return None
+                };
+                Some(DecodedCall { signature, args })
+            })
+            .collect()
+    }
+}
+
+impl<T: SelectorLookup + ?Sized> SelectorLookup for &T {
+    #[inline]
+    fn signatures(&self, selector: Selector) -> Vec<String> {
+        (**self).signatures(selector)
+    }
+}
+
+impl<T: SelectorLookup + ?Sized> SelectorLookup for Box<T> {
+    #[inline]
+    fn signatures(&self, selector: Selector) -> Vec<String> {
+        (**self).signatures(selector)
+    }
+}
+
+impl SelectorLookup for JsonAbi {
+    fn signatures(&self, selector: Selector) -> Vec<String> {
+        self.functions()
+            .filter(|f| f.selector() == selector)
+            .map(|f| f.signature())
+            .collect()
+    }
+}
+
+/// A call decoded by [`SelectorLookup::decode_calldata`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedCall {
+    /// The signature of the function, such as `transfer(address,uint256)`.
+    pub signature: String,
+    /// The decoded arguments.
+    pub args: Vec<DynSolValue>,
+}
+
+impl DecodedCall {
+    /// Returns the name of the function.
+    #[inline]
+    pub fn name(&self) -> &str {
+        self.signature
+            .split_once('(')
+            .map_or(&self.signature, |(name, _)| name)
+    }
+}
+
+/// An in-memory [`SelectorLookup`], which can be loaded from dumps of
+/// signature databases.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SelectorDatabase {
+    signatures: BTreeMap<Selector, Vec<String>>,
+}
+
+impl SelectorLookup for SelectorDatabase {
+    #[inline]
+    fn signatures(&self, selector: Selector) -> Vec<String> {
+        self.signatures.get(&selector).cloned().unwrap_or_default()
+    }
+}
+
+impl<S: Into<String>> Extend<S> for SelectorDatabase {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        for signature in iter {
+            self.insert(signature);
+        }
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for SelectorDatabase {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut db = Self::new();
+        db.extend(iter);
+        db
+    }
+}
+
+impl SelectorDatabase {
+    /// Creates a new, empty database.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a database from a dump. See [`extend_from_dump`] for the format.
+    ///
+    /// [`extend_from_dump`]: Self::extend_from_dump
+    pub fn parse_dump(dump: &str) -> Result<Self> {
+        let mut db = Self::new();
+        db.extend_from_dump(dump)?;
+        Ok(db)
+    }
+
+    /// Inserts the signatures of a dump into the database.
+    ///
+    /// Dumps have one signature per line, optionally preceded by its selector
+    /// and a comma, tab, or space, as in
+    /// `0xa9059cbb,transfer(address,uint256)`, which covers the text
+    /// exports of both openchain and 4byte. Empty lines and lines starting
+    /// with `#` are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a line is not a signature, or if its selector is
+    /// not the selector of the signature. Signatures from the previous lines
+    /// are inserted regardless.
+    pub fn extend_from_dump(&mut self, dump: &str) -> Result<()> {
+        for (i, line) in dump.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue
+            }
+            let invalid = || Error::InvalidSelectorDump {
+                line: i + 1,
+                entry: line.to_string(),
+            };
+
+            let (selector, signature) = match line.split_once([',', '\t', ' ']) {
+                Some((selector, signature)) if is_selector(selector) => (
+                    Some(selector.parse::<Selector>().map_err(|_| invalid())?),
+                    signature.trim_start(),
+                ),
+                _ => (None, line),
+            };
+            if !signature.contains('(') || !signature.ends_with(')') {
+                return Err(invalid())
+            }
+            if selector.map_or(false, |selector| selector != signature_selector(signature)) {
+                return Err(invalid())
+            }
+            self.insert(signature.to_owned());
+        }
+        Ok(())
+    }
+
+    /// Inserts a signature into the database, returning its selector.
+    pub fn insert(&mut self, signature: impl Into<String>) -> Selector {
+        let signature = signature.into();
+        let selector = signature_selector(&signature);
+        let signatures = self.signatures.entry(selector).or_default();
+        if !signatures.contains(&signature) {
+            signatures.push(signature);
+        }
+        selector
+    }
+
+    /// Returns the number of signatures in the database.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.signatures.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if the database contains no signatures.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.signatures.is_empty()
+    }
+}
+
+fn signature_selector(signature: &str) -> Selector {
+    Selector::from_slice(&keccak256(signature)[..4])
+}
+
+/// Returns `true` if all the integers and fixed-size byte arrays in `value`
+/// fit in their sizes, which decoding does not check.
+fn fits(value: &DynSolValue) -> bool {
+    match value {
+        DynSolValue::Int(x, size) => x.bits() as usize <= *size,
+        DynSolValue::Uint(x, size) => x.bit_len() <= *size,
+        DynSolValue::FixedBytes(word, size) => word[*size..].iter().all(|&b| b == 0),
+        _ => value
+            .as_fixed_seq()
+            .or_else(|| value.as_array())
+            .map_or(true, |values| values.iter().all(fits)),
+    }
+}
+
+/// Returns `true` if `s` looks like a hex selector, with or without prefix.
+fn is_selector(s: &str) -> bool {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    s.len() == 8 && s.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{hex, Address, U256};
+
+    const TRANSFER: [u8; 68] = hex!(
+        "a9059cbb"
+        "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045"
+        "0000000000000000000000000000000000000000000000000000000000000064"
+    );
+
+    #[test]
+    fn parse_dump() {
+        let db = SelectorDatabase::parse_dump(
+            "# comment\n\
+             \n\
+             0xa9059cbb,transfer(address,uint256)\n\
+             095ea7b3\tapprove(address,uint256)\n\
+             0x70a08231 balanceOf(address)\n\
+             many_msg_babbage(bytes1)\n\
+             transfer(address,uint256)\n",
+        )
+        .unwrap();
+        assert_eq!(db.len(), 4);
+        assert_eq!(
+            db.signatures(Selector::new(hex!("a9059cbb"))),
+            ["transfer(address,uint256)", "many_msg_babbage(bytes1)"]
+        );
+        assert_eq!(
+            db.signatures(Selector::new(hex!("70a08231"))),
+            ["balanceOf(address)"]
+        );
+        assert!(db.signatures(Selector::new(hex!("deadbeef"))).is_empty());
+    }
+
+    #[test]
+    fn parse_invalid_dump() {
+        assert_eq!(
+            SelectorDatabase::parse_dump("transfer(address,uint256)\n0xdeadbeef,foo()"),
+            Err(Error::InvalidSelectorDump {
+                line: 2,
+                entry: "0xdeadbeef,foo()".into()
+            })
+        );
+        assert!(SelectorDatabase::parse_dump("0xa9059cbb").is_err());
+        assert!(SelectorDatabase::parse_dump("transfer").is_err());
+    }
+
+    #[test]
+    fn decode_calldata() {
+        // `many_msg_babbage(bytes1)` collides with `transfer(address,uint256)`
+        let db: SelectorDatabase = [
+            "many_msg_babbage(bytes1)",
+            "transfer(address,uint256)",
+            "bad(uint7)",
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(db.signatures(Selector::new(hex!("a9059cbb"))).len(), 2);
+
+        let calls = db.decode_calldata(&TRANSFER);
+        assert_eq!(
+            calls,
+            [DecodedCall {
+                signature: "transfer(address,uint256)".into(),
+                args: vec![
+                    Address::from(hex!("d8da6bf26964af9d7eed9e03e53415d37aa96045")).into(),
+                    U256::from(100).into(),
+                ],
+            }]
+        );
+        assert_eq!(calls[0].name(), "transfer");
+
+        // the address is not a left-aligned `bytes1`
+        assert!(db.decode_calldata(&TRANSFER[..36]).is_empty());
+        assert!(db.decode_calldata(&TRANSFER[..3]).is_empty());
+        assert!(SelectorDatabase::new()
+            .decode_calldata(&TRANSFER)
+            .is_empty());
+    }
+
+    #[test]
+    fn json_abi_lookup() {
+        let abi: JsonAbi = serde_json::from_str(
+            r#"[{"type":"function","name":"transfer","inputs":[{"name":"to","type":"address"},{"name":"amount","type":"uint256"}],"outputs":[],"stateMutability":"nonpayable"}]"#,
+        )
+        .unwrap();
+        let lookup: &dyn SelectorLookup = &abi;
+        assert_eq!(lookup.decode_calldata(&TRANSFER).len(), 1);
+    }
+}
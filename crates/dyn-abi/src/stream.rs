@@ -0,0 +1,664 @@
+//! Decoding of ABI-encoded data from a reader.
+
+use crate::{DynSolType, DynSolValue, Error, Result};
+use alloc::{string::String, vec::Vec};
+use alloy_sol_types::{
+    utils::{checked_next_multiple_of_32, WordExt},
+    Error as SolTypesError, Word,
+};
+use std::io::{self, Read, Seek, SeekFrom};
+
+/// Decodes ABI-encoded values from a [`Read`] + [`Seek`] source.
+///
+/// The source can be a [`File`](std::fs::File) or a
+/// [`BufReader`](std::io::BufReader) over one, and the whole encoding is never
+/// loaded into memory. Offsets are followed
+/// by seeking, so only the head words on the way to a value and the value
+/// itself are read. This is meant for very large blobs, like state dumps or
+/// huge return data, of which only a few fields are needed.
+///
+/// Sources which cannot seek, like pipes and sockets, can be decoded with
+/// [`forward_only`](StreamDecoder::forward_only), as long as the reads only
+/// move forward. See [`ForwardReader`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{DynSolType, DynSolValue, StreamDecoder};
+/// use alloy_primitives::U256;
+/// use std::io::Cursor;
+///
+/// let ty: DynSolType = "(uint256,string[],bool)".parse().unwrap();
+/// let value = DynSolValue::Tuple(vec![
+///     DynSolValue::Uint(U256::from(1), 256),
+///     DynSolValue::Array(vec![
+///         DynSolValue::String("a".into()),
+///         DynSolValue::String("b".into()),
+///         DynSolValue::String("c".into()),
+///     ]),
+///     DynSolValue::Bool(true),
+/// ]);
+/// let data = value.abi_encode_sequence().unwrap();
+///
+/// let mut decoder = StreamDecoder::new(Cursor::new(data), true).unwrap();
+/// assert_eq!(decoder.decode_len(&ty, &[1]).unwrap(), 3);
+/// assert_eq!(
+///     decoder.decode_field(&ty, &[1, 2]).unwrap(),
+///     DynSolValue::String("c".into())
+/// );
+/// assert_eq!(decoder.decode_field(&ty, &[2]).unwrap(), true.into());
+/// assert_eq!(decoder.decode_sequence(&ty).unwrap(), value);
+/// ```
+///
+/// Positions are relative to the position of the reader when the decoder is
+/// created, and reads past the end of the reader fail with
+/// [`Overrun`](SolTypesError::Overrun). When the length of the data is known,
+/// they fail before anything is allocated for them; otherwise, the data is
+/// read incrementally until it runs out.
+///
+/// If `validate` is true, every decoded word is type-checked like
+/// [`DynSolType::abi_decode`] does, and the padding of `bytes` and `string`
+/// must be zero and strings must be valid UTF-8. Unlike the slice decoding
+/// methods, the value is not re-encoded and compared to the data, since only
+/// part of the data is read.
+#[derive(Debug)]
+pub struct StreamDecoder<R> {
+    reader: R,
+    start: u64,
+    len: Option<u64>,
+    validate: bool,
+}
+
+impl<R: Read> StreamDecoder<ForwardReader<R>> {
+    /// Creates a new decoder for the data from a reader which cannot seek.
+    ///
+    /// The length of the data is unknown, and positions are relative to the
+    /// first byte read from the reader. See [`ForwardReader`] for the
+    /// restrictions on the order of the reads.
+    pub const fn forward_only(reader: R, validate: bool) -> Self {
+        Self {
+            reader: ForwardReader::new(reader),
+            start: 0,
+            len: None,
+            validate,
+        }
+    }
+}
+
+impl<R: Read + Seek> StreamDecoder<R> {
+    /// Creates a new decoder for the data from the current position of the
+    /// reader to its end.
+    pub fn new(mut reader: R, validate: bool) -> io::Result<Self> {
+        let start = reader.stream_position()?;
+        let end = reader.seek(SeekFrom::End(0))?;
+        reader.seek(SeekFrom::Start(start))?;
+        Ok(Self {
+            reader,
+            start,
+            len: Some(end.saturating_sub(start)),
+            validate,
+        })
+    }
+
+    /// Returns the length of the data, in bytes, or `None` if it is unknown
+    /// because the decoder was created with
+    /// [`forward_only`](Self::forward_only).
+    #[inline]
+    pub const fn data_len(&self) -> Option<u64> {
+        self.len
+    }
+
+    /// Consumes the decoder, returning the underlying reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Decodes a single value, like [`DynSolType::abi_decode`].
+    pub fn decode(&mut self, ty: &DynSolType) -> Result<DynSolValue> {
+        self.decode_heads(core::iter::once(ty), head_size(ty), 0, 0)
+            .map(|mut values| values.remove(0))
+    }
+
+    /// Decodes a sequence, like [`DynSolType::abi_decode_sequence`].
+    pub fn decode_sequence(&mut self, ty: &DynSolType) -> Result<DynSolValue> {
+        self.decode_in_place(ty, 0)
+    }
+
+    /// Decodes a single field of a sequence, like
+    /// [`DynSolType::abi_decode_sequence`] would, reading only the words on
+    /// the way to it.
+    ///
+    /// `path` holds the index of the field at each level: the indices of tuple
+    /// and struct fields, and of fixed and dynamic array elements. For
+    /// example, `[1, 2]` is the third element of the second field of `ty`. An
+    /// empty path decodes the whole sequence.
+    pub fn decode_field(&mut self, ty: &DynSolType, path: &[usize]) -> Result<DynSolValue> {
+        let (ty, pos) = self.locate(ty, path)?;
+        self.decode_in_place(ty, pos)
+    }
+
+    /// Returns the length of the fixed or dynamic array at `path` in a
+    /// sequence, without decoding its elements.
+    ///
+    /// See [`decode_field`](Self::decode_field) for the format of `path`.
+    pub fn decode_len(&mut self, ty: &DynSolType, path: &[usize]) -> Result<usize> {
+        match self.locate(ty, path)? {
+            (DynSolType::Array(_), pos) => self.read_usize(pos),
+            (DynSolType::FixedArray(_, len), _) => Ok(*len),
+            (ty, _) => Err(Error::custom(format!("type {ty} is not an array"))),
+        }
+    }
+
+    /// Follows `path` from a sequence of type `ty` at the start of the data,
+    /// returning the type of the field and the position of its encoding.
+    fn locate<'t>(
+        &mut self,
+        mut ty: &'t DynSolType,
+        path: &[usize],
+    ) -> Result<(&'t DynSolType, u64)> {
+        let mut pos = 0;
+        for &index in path {
+            let (base, head, field) = match ty {
+                DynSolType::Array(inner) => {
+                    let len = self.read_usize(pos)?;
+                    if index >= len {
+                        return Err(out_of_bounds(ty, index))
+                    }
+                    let base = pos + 32;
+                    (base, offset(base, index, inner), &**inner)
+                }
+                DynSolType::FixedArray(inner, len) => {
+                    if index >= *len {
+                        return Err(out_of_bounds(ty, index))
+                    }
+                    (pos, offset(pos, index, inner), &**inner)
+                }
+                _ => {
+                    let types = fields(ty).ok_or_else(|| {
+                        Error::custom(format!("cannot index into a value of type {ty}"))
+                    })?;
+                    let field = types.get(index).ok_or_else(|| out_of_bounds(ty, index))?;
+                    let head = types[..index]
+                        .iter()
+                        .map(head_size)
+                        .fold(pos, u64::saturating_add);
+                    (pos, head, field)
+                }
+            };
+            pos = if field.is_dynamic() {
+                base.saturating_add(self.read_usize(head)? as u64)
+            } else {
+                head
+            };
+            ty = field;
+        }
+        Ok((ty, pos))
+    }
+
+    /// Decodes values whose heads, of `size` bytes in total, are consecutive
+    /// from `head`, in an encoding starting at `base`, which offsets are
+    /// relative to.
+    ///
+    /// All the heads are read before any of the tails, so that the tails of a
+    /// canonical encoding are read in order, after the heads.
+    fn decode_heads<'t>(
+        &mut self,
+        types: impl Iterator<Item = &'t DynSolType>,
+        size: u64,
+        base: u64,
+        head: u64,
+    ) -> Result<Vec<DynSolValue>> {
+        let heads = self.read(head, size)?;
+        let mut values = Vec::with_capacity(types.size_hint().0);
+        let mut pos = 0;
+        for ty in types {
+            let size = head_size(ty) as usize;
+            let data = &heads[pos..pos + size];
+            values.push(if !ty.is_dynamic() {
+                self.decode_static(ty, data)?
+            } else {
+                let offset = as_usize(data)?;
+                self.decode_in_place(ty, base.saturating_add(offset as u64))?
+            });
+            pos += size;
+        }
+        Ok(values)
+    }
+
+    /// Decodes a value encoded at `pos`, that is its tail if it is dynamic,
+    /// and its head otherwise.
+    fn decode_in_place(&mut self, ty: &DynSolType, pos: u64) -> Result<DynSolValue> {
+        if !ty.is_dynamic() {
+            let data = self.read(pos, head_size(ty))?;
+            return self.decode_static(ty, &data)
+        }
+
+        match ty {
+            DynSolType::Bytes => self.read_packed(pos).map(DynSolValue::Bytes),
+            DynSolType::String => {
+                let data = self.read_packed(pos)?;
+                let s = if self.validate {
+                    String::from_utf8(data)
+                        .map_err(|e| SolTypesError::type_check_fail(e.as_bytes(), "string"))?
+                } else {
+                    String::from_utf8_lossy(&data).into_owned()
+                };
+                Ok(DynSolValue::String(s))
+            }
+            DynSolType::Array(inner) => {
+                let len = self.read_usize(pos)?;
+                let base = pos.saturating_add(32);
+                let size = head_size(inner);
+                // the length of an array of empty tuples is not bounded by the data
+                if size == 0 {
+                    return Err(Error::custom(format!(
+                        "cannot decode array of zero-size type {inner}"
+                    )))
+                }
+                let types = core::iter::repeat(&**inner).take(len);
+                self.decode_heads(types, (len as u64).saturating_mul(size), base, base)
+                    .map(DynSolValue::Array)
+            }
+            DynSolType::FixedArray(inner, len) => {
+                let types = core::iter::repeat(&**inner).take(*len);
+                self.decode_heads(types, head_size(ty), pos, pos)
+                    .map(DynSolValue::FixedArray)
+            }
+            DynSolType::Tuple(types) => self.decode_fields(types, pos).map(DynSolValue::Tuple),
+            #[cfg(feature = "eip712")]
+            DynSolType::CustomStruct {
+                name,
+                prop_names,
+                tuple,
+            } => Ok(DynSolValue::CustomStruct {
+                name: name.clone(),
+                prop_names: prop_names.clone(),
+                tuple: self.decode_fields(tuple, pos)?,
+            }),
+            _ => unreachable!("static type {ty} is dynamic"),
+        }
+    }
+
+    fn decode_fields(&mut self, types: &[DynSolType], base: u64) -> Result<Vec<DynSolValue>> {
+        let size = types.iter().map(head_size).fold(0, u64::saturating_add);
+        self.decode_heads(types.iter(), size, base, base)
+    }
+
+    /// Decodes a static value from its head.
+    fn decode_static(&self, ty: &DynSolType, data: &[u8]) -> Result<DynSolValue> {
+        if self.validate {
+            ty.abi_decode_validate(data)
+        } else {
+            ty.abi_decode(data)
+        }
+    }
+
+    /// Reads the length-prefixed, padded payload of `bytes` or `string` at
+    /// `pos`.
+    fn read_packed(&mut self, pos: u64) -> Result<Vec<u8>> {
+        let len = self.read_usize(pos)?;
+        let padded = checked_next_multiple_of_32(len).ok_or_else(|| overrun(pos))?;
+        let mut data = self.read(pos.saturating_add(32), padded as u64)?;
+        if self.validate && data[len..].iter().any(|&b| b != 0) {
+            return Err(SolTypesError::type_check_fail(&data, "bytes").into())
+        }
+        data.truncate(len);
+        Ok(data)
+    }
+
+    /// Reads an offset or length word at `pos`.
+    fn read_usize(&mut self, pos: u64) -> Result<usize> {
+        let word = self.read(pos, 32)?;
+        as_usize(&word)
+    }
+
+    /// Reads `len` bytes at `pos`.
+    ///
+    /// If the length of the data is unknown, the bytes are read incrementally,
+    /// so that nothing is allocated for a length which is not backed by data.
+    fn read(&mut self, pos: u64, len: u64) -> Result<Vec<u8>> {
+        let end = pos.checked_add(len).ok_or_else(|| overrun(pos))?;
+        if self.len.map_or(false, |data_len| end > data_len) {
+            return Err(overrun(pos))
+        }
+        let mut data = Vec::new();
+        self.reader
+            .seek(SeekFrom::Start(self.start + pos))
+            .and_then(|_| (&mut self.reader).take(len).read_to_end(&mut data))
+            .map_err(|e| io_error(e, pos))?;
+        if (data.len() as u64) < len {
+            return Err(overrun(pos))
+        }
+        Ok(data)
+    }
+}
+
+/// A reader which can only seek forward, by reading and discarding the skipped
+/// bytes.
+///
+/// This allows a [`StreamDecoder`] to decode data from a reader which cannot
+/// seek, like a pipe or a socket, with
+/// [`StreamDecoder::forward_only`]. Since the decoder reads all the heads of a
+/// tuple or array before their tails, a canonical encoding can be decoded
+/// completely in a single call. Several fields can only be decoded if each of
+/// them is located after the data read for the previous ones, for example a
+/// static field followed by a dynamic one. Reading a position before the last
+/// one read, like decoding the same field twice, fails with an I/O error.
+#[derive(Debug)]
+pub struct ForwardReader<R> {
+    inner: R,
+    pos: u64,
+}
+
+impl<R> ForwardReader<R> {
+    /// Wraps the given reader.
+    #[inline]
+    pub const fn new(inner: R) -> Self {
+        Self { inner, pos: 0 }
+    }
+
+    /// Consumes the reader, returning the underlying reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for ForwardReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<R: Read> Seek for ForwardReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(target) => Some(target),
+            SeekFrom::Current(delta) => u64::try_from(delta)
+                .ok()
+                .map(|delta| self.pos.saturating_add(delta)),
+            SeekFrom::End(_) => None,
+        };
+        let Some(target) = target.filter(|&target| target >= self.pos) else {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "cannot seek backwards or from the end in a forward-only reader",
+            ))
+        };
+        let skip = target - self.pos;
+        let skipped = io::copy(&mut (&mut self.inner).take(skip), &mut io::sink())?;
+        self.pos += skipped;
+        if skipped < skip {
+            return Err(io::ErrorKind::UnexpectedEof.into())
+        }
+        Ok(self.pos)
+    }
+}
+
+/// Interprets a word as an offset or length.
+fn as_usize(data: &[u8]) -> Result<usize> {
+    Word::from_slice(data)
+        .try_as_usize()
+        .ok_or_else(|| SolTypesError::type_check_fail(data, "offset (usize)").into())
+}
+
+/// Returns the size of the head of a value of the given type, in bytes.
+fn head_size(ty: &DynSolType) -> u64 {
+    if ty.is_dynamic() {
+        return 32
+    }
+    match ty {
+        DynSolType::FixedArray(inner, len) => head_size(inner).saturating_mul(*len as u64),
+        _ => fields(ty).map_or(32, |types| types.iter().map(head_size).sum()),
+    }
+}
+
+/// Returns the position of the head of the element at `index` of an array
+/// whose heads start at `base`.
+fn offset(base: u64, index: usize, inner: &DynSolType) -> u64 {
+    base.saturating_add((index as u64).saturating_mul(head_size(inner)))
+}
+
+/// Returns the field types of a tuple or struct.
+fn fields(ty: &DynSolType) -> Option<&[DynSolType]> {
+    match ty {
+        DynSolType::Tuple(types) => Some(types),
+        #[cfg(feature = "eip712")]
+        DynSolType::CustomStruct { tuple, .. } => Some(tuple),
+        _ => None,
+    }
+}
+
+fn out_of_bounds(ty: &DynSolType, index: usize) -> Error {
+    Error::custom(format!("index {index} out of bounds for type {ty}"))
+}
+
+fn overrun(pos: u64) -> Error {
+    SolTypesError::Overrun {
+        offset: usize::try_from(pos).unwrap_or(usize::MAX),
+    }
+    .into()
+}
+
+fn io_error(e: io::Error, pos: u64) -> Error {
+    if e.kind() == io::ErrorKind::UnexpectedEof {
+        overrun(pos)
+    } else {
+        Error::custom(format!("I/O error at offset {pos}: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{hex, Address, U256};
+    use std::io::Cursor;
+
+    fn decoder(data: Vec<u8>) -> StreamDecoder<Cursor<Vec<u8>>> {
+        StreamDecoder::new(Cursor::new(data), true).unwrap()
+    }
+
+    fn value() -> (DynSolType, DynSolValue) {
+        let ty: DynSolType = "(address,bytes,(uint8,string)[],uint16[2],(bool,bytes32))"
+            .parse()
+            .unwrap();
+        let value = DynSolValue::Tuple(vec![
+            Address::repeat_byte(0x11).into(),
+            DynSolValue::Bytes(vec![0xaa; 33]),
+            DynSolValue::Array(vec![
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Uint(U256::from(1), 8),
+                    DynSolValue::String("one".into()),
+                ]),
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Uint(U256::from(2), 8),
+                    DynSolValue::String("two".into()),
+                ]),
+            ]),
+            DynSolValue::FixedArray(vec![
+                DynSolValue::Uint(U256::from(3), 16),
+                DynSolValue::Uint(U256::from(4), 16),
+            ]),
+            DynSolValue::Tuple(vec![true.into(), DynSolValue::FixedBytes(Word::ZERO, 32)]),
+        ]);
+        (ty, value)
+    }
+
+    #[test]
+    fn decode_whole() {
+        let (ty, value) = value();
+        let mut dec = decoder(value.abi_encode_sequence().unwrap());
+        assert_eq!(dec.decode_sequence(&ty).unwrap(), value);
+        assert_eq!(dec.decode_field(&ty, &[]).unwrap(), value);
+
+        let mut dec = decoder(value.abi_encode());
+        assert_eq!(dec.decode(&ty).unwrap(), value);
+
+        let ty = DynSolType::Uint(256);
+        let mut dec = decoder(U256::from(5).to_be_bytes_vec());
+        assert_eq!(
+            dec.decode(&ty).unwrap(),
+            DynSolValue::Uint(U256::from(5), 256)
+        );
+    }
+
+    #[test]
+    fn decode_fields() {
+        let (ty, value) = value();
+        let DynSolValue::Tuple(fields) = &value else {
+            unreachable!()
+        };
+        let mut dec = decoder(value.abi_encode_sequence().unwrap());
+
+        for (i, field) in fields.iter().enumerate() {
+            assert_eq!(dec.decode_field(&ty, &[i]).unwrap(), *field, "{i}");
+        }
+        assert_eq!(
+            dec.decode_field(&ty, &[2, 1, 1]).unwrap(),
+            DynSolValue::String("two".into())
+        );
+        assert_eq!(
+            dec.decode_field(&ty, &[2, 0, 0]).unwrap(),
+            DynSolValue::Uint(U256::from(1), 8)
+        );
+        assert_eq!(
+            dec.decode_field(&ty, &[3, 1]).unwrap(),
+            DynSolValue::Uint(U256::from(4), 16)
+        );
+        assert_eq!(dec.decode_field(&ty, &[4, 0]).unwrap(), true.into());
+
+        assert_eq!(dec.decode_len(&ty, &[2]).unwrap(), 2);
+        assert_eq!(dec.decode_len(&ty, &[3]).unwrap(), 2);
+        assert!(dec.decode_len(&ty, &[1]).is_err());
+
+        assert!(dec.decode_field(&ty, &[5]).is_err());
+        assert!(dec.decode_field(&ty, &[2, 2]).is_err());
+        assert!(dec.decode_field(&ty, &[3, 2]).is_err());
+        assert!(dec.decode_field(&ty, &[0, 0]).is_err());
+    }
+
+    #[test]
+    fn relative_to_reader_position() {
+        let (ty, value) = value();
+        let mut data = vec![0xff; 7];
+        data.extend(value.abi_encode_sequence().unwrap());
+        let mut reader = Cursor::new(data);
+        reader.set_position(7);
+        let mut dec = StreamDecoder::new(reader, true).unwrap();
+        assert_eq!(
+            dec.decode_field(&ty, &[2, 1, 1]).unwrap(),
+            DynSolValue::String("two".into())
+        );
+        assert_eq!(dec.decode_sequence(&ty).unwrap(), value);
+    }
+
+    #[test]
+    fn forward_only() {
+        let (ty, value) = value();
+        let data = value.abi_encode_sequence().unwrap();
+
+        let mut dec = StreamDecoder::forward_only(&data[..], true);
+        assert_eq!(dec.data_len(), None);
+        assert_eq!(dec.decode_sequence(&ty).unwrap(), value);
+
+        // fields can only be decoded while the reads move forward
+        let mut dec = StreamDecoder::forward_only(&data[..], true);
+        assert_eq!(
+            dec.decode_field(&ty, &[0]).unwrap(),
+            Address::repeat_byte(0x11).into()
+        );
+        assert_eq!(
+            dec.decode_field(&ty, &[2, 1, 1]).unwrap(),
+            DynSolValue::String("two".into())
+        );
+        assert_eq!(
+            dec.decode_field(&ty, &[2, 1, 1]),
+            Err(Error::custom(
+                "I/O error at offset 64: cannot seek backwards or from the end in a \
+                 forward-only reader"
+            ))
+        );
+
+        let mut dec = StreamDecoder::forward_only(&data[..data.len() - 1], true);
+        assert!(matches!(
+            dec.decode_sequence(&ty),
+            Err(Error::SolTypes(SolTypesError::Overrun { .. }))
+        ));
+
+        // huge length, not backed by data
+        let ty: DynSolType = "(bytes)".parse().unwrap();
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "00000000000000000000000000000000000000000000000000000000ffffffff"
+        );
+        let mut dec = StreamDecoder::forward_only(&data[..], true);
+        assert_eq!(
+            dec.decode_sequence(&ty),
+            Err(SolTypesError::Overrun { offset: 0x40 }.into())
+        );
+    }
+
+    #[test]
+    fn overrun() {
+        let ty: DynSolType = "(bytes)".parse().unwrap();
+        // huge length, not backed by data
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "00000000000000000000000000000000000000000000000000000000ffffffff"
+        );
+        let mut dec = decoder(data.to_vec());
+        assert_eq!(
+            dec.decode_sequence(&ty),
+            Err(SolTypesError::Overrun { offset: 0x40 }.into())
+        );
+
+        let ty: DynSolType = "(uint256[])".parse().unwrap();
+        let mut dec = decoder(data.to_vec());
+        assert_eq!(
+            dec.decode_sequence(&ty),
+            Err(SolTypesError::Overrun { offset: 0x40 }.into())
+        );
+
+        let mut dec = decoder(data[..40].to_vec());
+        assert_eq!(
+            dec.decode_sequence(&ty),
+            Err(SolTypesError::Overrun { offset: 0x20 }.into())
+        );
+
+        // zero-size elements
+        let ty = DynSolType::Tuple(vec![DynSolType::Array(Box::new(DynSolType::Tuple(vec![])))]);
+        let mut dec = decoder(data.to_vec());
+        assert_eq!(
+            dec.decode_sequence(&ty),
+            Err(Error::custom("cannot decode array of zero-size type ()"))
+        );
+    }
+
+    #[test]
+    fn validate() {
+        let ty: DynSolType = "(string)".parse().unwrap();
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+            "ff61000000000000000000000000000000000000000000000000000000000001"
+        );
+        let mut dec = StreamDecoder::new(Cursor::new(data), false).unwrap();
+        assert_eq!(
+            dec.decode_sequence(&ty).unwrap(),
+            DynSolValue::Tuple(vec![DynSolValue::String("\u{FFFD}a".into())])
+        );
+        let mut dec = decoder(data.to_vec());
+        assert!(dec.decode_sequence(&ty).is_err());
+
+        let ty: DynSolType = "(bool)".parse().unwrap();
+        let data = Word::with_last_byte(2).to_vec();
+        assert!(decoder(data.clone()).decode_sequence(&ty).is_err());
+        assert!(StreamDecoder::new(Cursor::new(data), false)
+            .unwrap()
+            .decode_sequence(&ty)
+            .is_ok());
+    }
+}
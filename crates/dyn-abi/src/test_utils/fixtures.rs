@@ -0,0 +1,260 @@
+use crate::{DynSolType, DynSolValue};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use alloy_primitives::Bytes;
+use alloy_sol_types::SolType;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+/// A golden encoding vector, such as one generated by `solc` with
+/// `abi.encode(value)`.
+///
+/// Fixtures are stored as JSON arrays of objects:
+///
+/// ```json
+/// [
+///     { "type": "uint32[]", "value": [1110, 1929], "encoded": "0x0000..." }
+/// ]
+/// ```
+///
+/// Values use the JSON representation accepted by
+/// [`DynSolType::coerce_json`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fixture {
+    /// The Solidity type.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The value, as JSON.
+    pub value: serde_json::Value,
+    /// The expected ABI encoding of the value.
+    pub encoded: Bytes,
+}
+
+/// An error returned when loading or checking a [`Fixture`].
+#[derive(Debug)]
+pub enum FixtureError {
+    /// Failed to read a fixture file.
+    Io(PathBuf, io::Error),
+    /// Failed to deserialize a fixture file.
+    Json(PathBuf, serde_json::Error),
+    /// Failed to parse the type, coerce the value, or decode the encoding.
+    Abi(crate::Error),
+    /// The static type does not have the type of the fixture.
+    TypeName {
+        /// The type of the fixture.
+        expected: String,
+        /// The name of the static type.
+        actual: String,
+    },
+    /// The encoding differs from the expected one.
+    Encode {
+        /// The type of the fixture.
+        ty: String,
+        /// The expected encoding.
+        expected: Bytes,
+        /// The actual encoding.
+        actual: Bytes,
+    },
+    /// The decoded value differs from the fixture's value.
+    Decode {
+        /// The type of the fixture.
+        ty: String,
+        /// The fixture's value.
+        expected: Box<DynSolValue>,
+        /// The decoded value.
+        actual: Box<DynSolValue>,
+    },
+}
+
+impl fmt::Display for FixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, e) => write!(f, "failed to read {}: {e}", path.display()),
+            Self::Json(path, e) => write!(f, "failed to deserialize {}: {e}", path.display()),
+            Self::Abi(e) => e.fmt(f),
+            Self::TypeName { expected, actual } => {
+                write!(f, "type mismatch: expected {expected}, got {actual}")
+            }
+            Self::Encode {
+                ty,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "encodings of {ty} value differ: expected {expected}, got {actual}"
+            ),
+            Self::Decode {
+                ty,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "decoded {ty} values differ: expected {expected:?}, got {actual:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FixtureError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(_, e) => Some(e),
+            Self::Json(_, e) => Some(e),
+            Self::Abi(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<crate::Error> for FixtureError {
+    fn from(e: crate::Error) -> Self {
+        Self::Abi(e)
+    }
+}
+
+impl From<alloy_sol_types::Error> for FixtureError {
+    fn from(e: alloy_sol_types::Error) -> Self {
+        Self::Abi(e.into())
+    }
+}
+
+impl Fixture {
+    /// Checks the fixture against the dynamic encoder and decoder.
+    ///
+    /// The value is coerced to the parsed type, and must encode to the
+    /// expected bytes, which in turn must decode back to the value.
+    pub fn check(&self) -> Result<(), FixtureError> {
+        let ty = DynSolType::parse(&self.ty)?;
+        let value = ty.coerce_json(&self.value)?;
+
+        let encoded = value.abi_encode();
+        if encoded != self.encoded {
+            return Err(FixtureError::Encode {
+                ty: self.ty.clone(),
+                expected: self.encoded.clone(),
+                actual: encoded.into(),
+            })
+        }
+
+        let decoded = ty.abi_decode(&self.encoded)?;
+        if decoded != value {
+            return Err(FixtureError::Decode {
+                ty: self.ty.clone(),
+                expected: Box::new(value),
+                actual: Box::new(decoded),
+            })
+        }
+        Ok(())
+    }
+
+    /// Checks the fixture against the static encoder and decoder of `T`.
+    ///
+    /// `T` must have the fixture's type, and the expected bytes must decode
+    /// with validation and re-encode to themselves.
+    pub fn check_static<T: SolType>(&self) -> Result<(), FixtureError> {
+        let expected = DynSolType::parse(&self.ty)?.sol_type_name();
+        let actual = T::sol_type_name();
+        if expected != actual {
+            return Err(FixtureError::TypeName {
+                expected: expected.into_owned(),
+                actual: actual.into_owned(),
+            })
+        }
+
+        let decoded = T::abi_decode(&self.encoded, true)?;
+        let encoded = T::abi_encode(&decoded);
+        if encoded != self.encoded {
+            return Err(FixtureError::Encode {
+                ty: self.ty.clone(),
+                expected: self.encoded.clone(),
+                actual: encoded.into(),
+            })
+        }
+        Ok(())
+    }
+}
+
+/// Loads all fixtures from the `.json` files in `dir`, in file name order.
+pub fn load_fixtures(dir: impl AsRef<Path>) -> Result<Vec<Fixture>, FixtureError> {
+    let dir = dir.as_ref();
+    let mut paths = std::fs::read_dir(dir)
+        .and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<io::Result<Vec<_>>>()
+        })
+        .map_err(|e| FixtureError::Io(dir.to_path_buf(), e))?;
+    paths.retain(|path| path.extension().map_or(false, |ext| ext == "json"));
+    paths.sort();
+
+    let mut fixtures = Vec::new();
+    for path in paths {
+        let contents = std::fs::read(&path).map_err(|e| FixtureError::Io(path.clone(), e))?;
+        let file: Vec<Fixture> =
+            serde_json::from_slice(&contents).map_err(|e| FixtureError::Json(path, e))?;
+        fixtures.extend(file);
+    }
+    Ok(fixtures)
+}
+
+/// Loads all fixtures from `dir` with [`load_fixtures`], and checks them with
+/// [`Fixture::check`].
+///
+/// Returns the number of checked fixtures.
+pub fn check_fixtures(dir: impl AsRef<Path>) -> Result<usize, FixtureError> {
+    let fixtures = load_fixtures(dir)?;
+    fixtures.iter().try_for_each(Fixture::check)?;
+    Ok(fixtures.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixtures() -> Vec<Fixture> {
+        load_fixtures(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/solc")).unwrap()
+    }
+
+    #[test]
+    fn dynamic() {
+        let fixtures = fixtures();
+        assert!(!fixtures.is_empty());
+        for fixture in &fixtures {
+            fixture
+                .check()
+                .unwrap_or_else(|e| panic!("{}: {e}", fixture.ty));
+        }
+    }
+
+    #[test]
+    fn static_() {
+        use alloy_sol_types::sol_data::{Array, Bool, FixedArray, Int, String, Uint};
+
+        let fixtures = fixtures();
+        let fixture = |ty: &str| fixtures.iter().find(|f| f.ty == ty).unwrap();
+        fixture("uint256").check_static::<Uint<256>>().unwrap();
+        fixture("int8").check_static::<Int<8>>().unwrap();
+        fixture("string").check_static::<String>().unwrap();
+        fixture("uint32[]")
+            .check_static::<Array<Uint<32>>>()
+            .unwrap();
+        fixture("(uint256,string,bool[2])")
+            .check_static::<(Uint<256>, String, FixedArray<Bool, 2>)>()
+            .unwrap();
+
+        assert!(matches!(
+            fixture("uint256").check_static::<Uint<128>>(),
+            Err(FixtureError::TypeName { .. })
+        ));
+    }
+
+    #[test]
+    fn mismatch() {
+        let mut fixture = fixtures()[0].clone();
+        fixture.encoded = Bytes::from_static(&[0; 32]);
+        assert!(matches!(fixture.check(), Err(FixtureError::Encode { .. })));
+    }
+}
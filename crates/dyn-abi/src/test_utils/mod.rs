@@ -0,0 +1,223 @@
+//! Testing utilities.
+//!
+//! # Differential testing
+//!
+//! [`check_value`] encodes and decodes values with both this crate and
+//! [`ethabi`], and reports any [`Mismatch`] between the two. It can be used
+//! directly in `cargo fuzz` targets or `proptest` tests, for example by EVM
+//! implementations that want to check their ABI handling.
+//!
+//! `ethabi` has no representation for some Solidity types: values are compared
+//! after the lossy conversions of the `ethabi` feature, such as `function` to
+//! `bytes24`, which all preserve the encoding. Empty encodings, such as that of
+//! an empty tuple, are not decoded with `ethabi`, which rejects them.
+//!
+//! With `proptest`:
+//!
+//! ```
+//! use alloy_dyn_abi::test_utils::{check_value, type_and_value_strategy};
+//! use proptest::prelude::*;
+//!
+//! proptest!(|((ty, value) in type_and_value_strategy())| {
+//!     check_value(&ty, &value).unwrap();
+//! });
+//! ```
+//!
+//! With `cargo fuzz`:
+//!
+//! ```ignore
+//! #![no_main]
+//! use libfuzzer_sys::fuzz_target;
+//!
+//! fuzz_target!(|data: &[u8]| {
+//!     alloy_dyn_abi::test_utils::check_arbitrary(data).unwrap();
+//! });
+//! ```
+//!
+//! # Golden vectors
+//!
+//! [`Fixture`]s are encoding vectors generated by an external tool such as
+//! `solc`, which can be checked against both the dynamic encoder, with
+//! [`Fixture::check`], and static [`SolType`](alloy_sol_types::SolType)s, with
+//! [`Fixture::check_static`]. [`check_fixtures`] checks a whole directory of
+//! them, so that new compiler releases can be validated mechanically:
+//!
+//! ```no_run
+//! use alloy_dyn_abi::test_utils::check_fixtures;
+//!
+//! let n = check_fixtures("tests/fixtures/solc").unwrap();
+//! assert!(n > 0);
+//! ```
+
+use crate::{DynSolType, DynSolValue};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use arbitrary::{Arbitrary, Unstructured};
+use core::fmt;
+use ethabi::{ParamType, Token};
+use proptest::prelude::*;
+
+mod fixtures;
+pub use fixtures::{check_fixtures, load_fixtures, Fixture, FixtureError};
+
+/// A difference between this crate and `ethabi`, found by [`check_value`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch {
+    /// The type of the value.
+    pub ty: DynSolType,
+    /// The value.
+    pub value: DynSolValue,
+    /// The kind of the difference.
+    pub kind: MismatchKind,
+}
+
+/// The kind of a [`Mismatch`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MismatchKind {
+    /// The encodings of the value differ.
+    Encode {
+        /// The encoding of this crate.
+        ours: Vec<u8>,
+        /// The encoding of `ethabi`.
+        ethabi: Vec<u8>,
+    },
+    /// This crate failed to decode the encoded value, or decoded a different
+    /// value.
+    Decode(String),
+    /// `ethabi` failed to decode the encoded value, or decoded a different
+    /// value.
+    EthabiDecode(String),
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ty = self.ty.sol_type_name();
+        match &self.kind {
+            MismatchKind::Encode { ours, ethabi } => write!(
+                f,
+                "encodings of {ty} value differ: {} != {} (ethabi)",
+                hex::encode_prefixed(ours),
+                hex::encode_prefixed(ethabi),
+            ),
+            MismatchKind::Decode(e) => write!(f, "failed to decode {ty} value: {e}"),
+            MismatchKind::EthabiDecode(e) => {
+                write!(f, "ethabi failed to decode {ty} value: {e}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+/// ABI-encodes `value` with both this crate and `ethabi`, and decodes the
+/// encoding with both, returning the first difference.
+///
+/// # Panics
+///
+/// Panics if `value` does not match `ty`.
+pub fn check_value(ty: &DynSolType, value: &DynSolValue) -> Result<(), Box<Mismatch>> {
+    assert!(value.matches(ty), "value does not match type");
+    let mismatch = |kind| {
+        Box::new(Mismatch {
+            ty: ty.clone(),
+            value: value.clone(),
+            kind,
+        })
+    };
+
+    let token = Token::from(value.clone());
+    let ours = value.abi_encode();
+    let theirs = ethabi::encode(core::slice::from_ref(&token));
+    if ours != theirs {
+        return Err(mismatch(MismatchKind::Encode {
+            ours,
+            ethabi: theirs,
+        }))
+    }
+
+    match ty.abi_decode(&ours) {
+        // compare tokens, which do not carry struct and property names
+        Ok(decoded) if Token::from(decoded.clone()) == token => {}
+        Ok(decoded) => {
+            return Err(mismatch(MismatchKind::Decode(format!(
+                "decoded {decoded:?}"
+            ))))
+        }
+        Err(e) => return Err(mismatch(MismatchKind::Decode(e.to_string()))),
+    }
+
+    // `ethabi` refuses to decode empty data, which zero-sized types encode to
+    if ours.is_empty() {
+        return Ok(())
+    }
+    match ethabi::decode(&[ParamType::from(ty.clone())], &ours) {
+        Ok(tokens) if tokens == [token] => Ok(()),
+        Ok(tokens) => Err(mismatch(MismatchKind::EthabiDecode(format!(
+            "decoded {tokens:?}"
+        )))),
+        Err(e) => Err(mismatch(MismatchKind::EthabiDecode(e.to_string()))),
+    }
+}
+
+/// Generates an arbitrary type and value from raw fuzzer input, and checks
+/// them with [`check_value`].
+///
+/// Returns `Ok(())` if the input is not enough to generate a value.
+pub fn check_arbitrary(data: &[u8]) -> Result<(), Box<Mismatch>> {
+    let mut u = Unstructured::new(data);
+    let Ok(ty) = DynSolType::arbitrary(&mut u) else {
+        return Ok(())
+    };
+    let Ok(value) = ty.arbitrary_value(&mut u) else {
+        return Ok(())
+    };
+    check_value(&ty, &value)
+}
+
+/// Returns a `proptest` strategy generating arbitrary types along with
+/// matching values, to be checked with [`check_value`].
+pub fn type_and_value_strategy() -> impl Strategy<Value = (DynSolType, DynSolValue)> {
+    any::<DynSolType>().prop_flat_map(|ty| {
+        let values = ty.value_strategy();
+        (Just(ty), values)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1024))]
+
+        #[test]
+        fn differential((ty, value) in type_and_value_strategy()) {
+            check_value(&ty, &value).unwrap();
+        }
+
+        #[test]
+        fn differential_arbitrary(data: Vec<u8>) {
+            check_arbitrary(&data).unwrap();
+        }
+    }
+
+    #[test]
+    fn mismatch_display() {
+        let mismatch = Mismatch {
+            ty: DynSolType::Uint(256),
+            value: U256::from(1).into(),
+            kind: MismatchKind::Encode {
+                ours: vec![1],
+                ethabi: vec![2],
+            },
+        };
+        assert_eq!(
+            mismatch.to_string(),
+            "encodings of uint256 value differ: 0x01 != 0x02 (ethabi)"
+        );
+    }
+}
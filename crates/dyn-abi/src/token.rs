@@ -133,6 +133,17 @@ impl<'a> DynToken<'a> {
         }
     }
 
+    /// The number of words in the head of this token.
+    #[cfg(feature = "rayon")]
+    fn head_words(&self) -> usize {
+        match self {
+            Self::FixedSeq(inner, size) if !self.is_dynamic() => {
+                inner.iter().take(*size).map(Self::head_words).sum()
+            }
+            _ => 1,
+        }
+    }
+
     /// Decodes from a decoder, populating the structure with the decoded data.
     #[inline]
     pub(crate) fn decode_populate(&mut self, dec: &mut Decoder<'a>) -> Result<()> {
@@ -177,9 +188,7 @@ impl<'a> DynToken<'a> {
                     vec![*t; size]
                 };
 
-                for t in &mut new_tokens {
-                    t.decode_populate(&mut child)?;
-                }
+                decode_seq_populate(&mut new_tokens, &mut child)?;
 
                 *contents = new_tokens.into();
             }
@@ -214,3 +223,35 @@ impl<'a> DynToken<'a> {
         self.decode_populate(dec)
     }
 }
+
+/// The minimum length of a dynamic sequence to decode its elements in
+/// parallel.
+#[cfg(feature = "rayon")]
+const PAR_DECODE_MIN_LEN: usize = 1 << 12;
+
+/// Decodes the elements of a sequence, consuming words from the decoder.
+///
+/// With the `rayon` feature, the elements of long sequences are decoded in
+/// parallel, each from its own position in the head of the sequence. If
+/// several elements are invalid, which one's error is returned is then
+/// unspecified.
+#[inline]
+fn decode_seq_populate<'a>(tokens: &mut [DynToken<'a>], dec: &mut Decoder<'a>) -> Result<()> {
+    #[cfg(feature = "rayon")]
+    if tokens.len() >= PAR_DECODE_MIN_LEN {
+        use rayon::prelude::*;
+
+        let head_bytes = tokens[0].head_words() * Word::len_bytes();
+        let start = dec.offset();
+        let parent = *dec;
+        tokens.par_iter_mut().enumerate().try_for_each(|(i, t)| {
+            let mut child = parent;
+            child.set_offset(start.saturating_add(i.saturating_mul(head_bytes)));
+            t.decode_populate(&mut child)
+        })?;
+        dec.set_offset(start.saturating_add(tokens.len().saturating_mul(head_bytes)));
+        return Ok(())
+    }
+
+    tokens.iter_mut().try_for_each(|t| t.decode_populate(dec))
+}
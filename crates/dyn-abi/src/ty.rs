@@ -1,7 +1,12 @@
-use crate::{resolve::ResolveSolType, DynSolValue, DynToken, Error, Result, SolType, Word};
+use crate::{
+    resolve::ResolveSolType, DynSolValue, DynSolValueRef, DynToken, Error, Result, SolType, Word,
+};
 use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
-use alloy_sol_type_parser::TypeSpecifier;
-use alloy_sol_types::{abi::Decoder, sol_data};
+use alloy_sol_type_parser::{Error as ParserError, TypeSpecifier, TypeStem};
+use alloy_sol_types::{
+    abi::{token::PackedSeqToken, Decoder},
+    sol_data,
+};
 use core::{fmt, iter::zip, num::NonZeroUsize, str::FromStr};
 
 #[cfg(feature = "eip712")]
@@ -159,6 +164,36 @@ impl DynSolType {
             .and_then(|t| t.resolve())
     }
 
+    /// Parses a Solidity type name string whose tuple components are supplied
+    /// separately, as in JSON ABI parameters, where tuples are spelled as a
+    /// bare `tuple`, optionally followed by array sizes.
+    ///
+    /// If `s` is not a bare `tuple`, `components` must be empty and this is
+    /// equivalent to [`parse`](Self::parse), which also accepts the
+    /// `tuple(uint256,address)` spelling of tuples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_dyn_abi::DynSolType;
+    /// let components = [DynSolType::Uint(256), DynSolType::Address];
+    /// let ty = DynSolType::parse_with_components("tuple[]", &components)?;
+    /// assert_eq!(ty, DynSolType::parse("tuple(uint256,address)[]")?);
+    /// assert_eq!(ty.sol_type_name(), "(uint256,address)[]");
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn parse_with_components(s: &str, components: &[Self]) -> Result<Self> {
+        let spec = TypeSpecifier::parse(s).map_err(Error::TypeParser)?;
+        match spec.stem {
+            TypeStem::Root(root) if root.span() == "tuple" => {
+                Ok(Self::Tuple(components.to_vec())
+                    .array_wrap_from_iter(spec.sizes.iter().copied()))
+            }
+            _ if components.is_empty() => spec.resolve(),
+            _ => Err(Error::TypeParser(ParserError::invalid_type_string(s))),
+        }
+    }
+
     /// Fallible cast to the contents of a variant.
     #[inline]
     pub fn as_tuple(&self) -> Option<&[Self]> {
@@ -370,6 +405,103 @@ impl DynSolType {
         Ok(values)
     }
 
+    /// Dynamic detokenization into a value borrowing from the type and the
+    /// token.
+    ///
+    /// See [`detokenize`](Self::detokenize).
+    pub(crate) fn detokenize_ref<'a>(&'a self, token: DynToken<'a>) -> Result<DynSolValueRef<'a>> {
+        match (self, token) {
+            (Self::Bytes, DynToken::PackedSeq(buf)) => Ok(DynSolValueRef::Bytes(buf)),
+            (Self::String, DynToken::PackedSeq(buf)) => {
+                Ok(DynSolValueRef::String(String::from_utf8_lossy(buf)))
+            }
+
+            (Self::Array(t), DynToken::DynSeq { contents, .. }) => t
+                .detokenize_array_ref(contents.into_owned())
+                .map(DynSolValueRef::Array),
+
+            (Self::FixedArray(t, size), DynToken::FixedSeq(tokens, _)) => {
+                if *size != tokens.len() {
+                    return Err(crate::Error::custom(
+                        "array length mismatch on dynamic detokenization",
+                    ))
+                }
+                t.detokenize_array_ref(tokens.into_owned())
+                    .map(DynSolValueRef::FixedArray)
+            }
+
+            (Self::Tuple(types), DynToken::FixedSeq(tokens, _)) => {
+                if types.len() != tokens.len() {
+                    return Err(crate::Error::custom(
+                        "tuple length mismatch on dynamic detokenization",
+                    ))
+                }
+                Self::detokenize_many_ref(types, tokens.into_owned()).map(DynSolValueRef::Tuple)
+            }
+
+            #[cfg(feature = "eip712")]
+            (
+                Self::CustomStruct {
+                    name,
+                    tuple,
+                    prop_names,
+                },
+                DynToken::FixedSeq(tokens, len),
+            ) => {
+                if len != tokens.len() || len != tuple.len() {
+                    return Err(crate::Error::custom(
+                        "custom length mismatch on dynamic detokenization",
+                    ))
+                }
+                Self::detokenize_many_ref(tuple, tokens.into_owned()).map(|tuple| {
+                    DynSolValueRef::CustomStruct {
+                        name,
+                        prop_names,
+                        tuple,
+                    }
+                })
+            }
+
+            // single words are copied anyway
+            (_, token @ DynToken::Word(_)) => self.detokenize(token).map(|value| match value {
+                DynSolValue::Bool(b) => DynSolValueRef::Bool(b),
+                DynSolValue::Int(i, size) => DynSolValueRef::Int(i, size),
+                DynSolValue::Uint(u, size) => DynSolValueRef::Uint(u, size),
+                DynSolValue::FixedBytes(w, size) => DynSolValueRef::FixedBytes(w, size),
+                DynSolValue::Address(a) => DynSolValueRef::Address(a),
+                DynSolValue::Function(f) => DynSolValueRef::Function(f),
+                _ => unreachable!("non-word value from a word token"),
+            }),
+
+            _ => Err(crate::Error::custom(
+                "mismatched types on dynamic detokenization",
+            )),
+        }
+    }
+
+    fn detokenize_array_ref<'a>(
+        &'a self,
+        tokens: Vec<DynToken<'a>>,
+    ) -> Result<Vec<DynSolValueRef<'a>>> {
+        let mut values = Vec::with_capacity(tokens.len());
+        for token in tokens {
+            values.push(self.detokenize_ref(token)?);
+        }
+        Ok(values)
+    }
+
+    fn detokenize_many_ref<'a>(
+        types: &'a [Self],
+        tokens: Vec<DynToken<'a>>,
+    ) -> Result<Vec<DynSolValueRef<'a>>> {
+        assert_eq!(types.len(), tokens.len());
+        let mut values = Vec::with_capacity(tokens.len());
+        for (ty, token) in zip(types, tokens) {
+            values.push(ty.detokenize_ref(token)?);
+        }
+        Ok(values)
+    }
+
     #[inline]
     #[allow(clippy::missing_const_for_fn)]
     fn sol_type_name_simple(&self) -> Option<&'static str> {
@@ -427,12 +559,41 @@ impl DynSolType {
         }
     }
 
+    /// Formats the type as in function, error, and event signatures, which
+    /// differs from [`sol_type_name_raw`](Self::sol_type_name_raw) only in
+    /// that tuples with a single element have no trailing comma.
+    pub(crate) fn signature_raw(&self, out: &mut String) {
+        match self {
+            as_tuple!(Self tuple) => {
+                out.push('(');
+                for (i, ty) in tuple.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    ty.signature_raw(out);
+                }
+                out.push(')');
+            }
+            Self::Array(t) => {
+                t.signature_raw(out);
+                out.push_str("[]");
+            }
+            Self::FixedArray(t, len) => {
+                t.signature_raw(out);
+                out.push('[');
+                out.push_str(itoa::Buffer::new().format(*len));
+                out.push(']');
+            }
+            _ => self.sol_type_name_raw(out),
+        }
+    }
+
     /// Returns an estimate of the number of bytes needed to format this type.
     ///
     /// This calculation is meant to be an upper bound for valid types to avoid
     /// a second allocation in `sol_type_name_raw` and thus is almost never
     /// going to be exact.
-    fn sol_type_name_capacity(&self) -> usize {
+    pub(crate) fn sol_type_name_capacity(&self) -> usize {
         match self {
             | Self::Address // 7
             | Self::Function // 8
@@ -476,6 +637,17 @@ impl DynSolType {
         self.sol_type_name().into_owned()
     }
 
+    /// Returns `true` if values of this type are encoded in the tail, that is
+    /// if the type is or contains `bytes`, `string`, or a dynamic array.
+    pub(crate) fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Bytes | Self::String | Self::Array(_) => true,
+            Self::FixedArray(inner, _) => inner.is_dynamic(),
+            as_tuple!(Self tuple) => tuple.iter().any(Self::is_dynamic),
+            _ => false,
+        }
+    }
+
     /// Instantiate an empty dyn token, to be decoded into.
     pub(crate) fn empty_dyn_token<'a>(&self) -> DynToken<'a> {
         match self {
@@ -520,6 +692,9 @@ impl DynSolType {
     ///
     /// This method is used for decoding single values. It assumes the `data`
     /// argument is an encoded single-element sequence wrapping the `self` type.
+    ///
+    /// This does not validate the data; see
+    /// [`abi_decode_validate`](Self::abi_decode_validate).
     #[inline]
     #[cfg_attr(debug_assertions, track_caller)]
     pub fn abi_decode(&self, data: &[u8]) -> Result<DynSolValue> {
@@ -529,6 +704,24 @@ impl DynSolType {
         )
     }
 
+    /// Decode a [`DynSolValue`] from a byte slice, performing the same checks
+    /// as [`SolType::abi_decode`] with `validate` set to true. See
+    /// [`abi_decode_sequence_validate`] for details.
+    ///
+    /// [`abi_decode_sequence_validate`]: Self::abi_decode_sequence_validate
+    #[inline]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn abi_decode_validate(&self, data: &[u8]) -> Result<DynSolValue> {
+        let value = self.abi_decode_inner(
+            &mut Decoder::new(data, true),
+            DynToken::decode_single_populate,
+        )?;
+        if value.abi_encode() != data {
+            return Err(alloy_sol_types::Error::ReserMismatch.into())
+        }
+        Ok(value)
+    }
+
     /// Decode a [`DynSolValue`] from a byte slice. Fails if the value does not
     /// match this type.
     ///
@@ -558,8 +751,27 @@ impl DynSolType {
         }
     }
 
+    /// Decode a [`DynSolValue`] from a byte slice as function parameters,
+    /// performing the same checks as [`SolType::abi_decode_params`] with
+    /// `validate` set to true.
+    ///
+    /// See [`abi_decode_params`](Self::abi_decode_params) and
+    /// [`abi_decode_sequence_validate`](Self::abi_decode_sequence_validate)
+    /// for details.
+    #[inline]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn abi_decode_params_validate(&self, data: &[u8]) -> Result<DynSolValue> {
+        match self {
+            Self::Tuple(_) => self.abi_decode_sequence_validate(data),
+            _ => self.abi_decode_validate(data),
+        }
+    }
+
     /// Decode a [`DynSolValue`] from a byte slice. Fails if the value does not
     /// match this type.
+    ///
+    /// This does not validate the data; see
+    /// [`abi_decode_sequence_validate`](Self::abi_decode_sequence_validate).
     #[inline]
     #[cfg_attr(debug_assertions, track_caller)]
     pub fn abi_decode_sequence(&self, data: &[u8]) -> Result<DynSolValue> {
@@ -569,6 +781,91 @@ impl DynSolType {
         )
     }
 
+    /// Decode a [`DynSolValue`] from a byte slice, performing the same checks
+    /// as [`SolType::abi_decode_sequence`] with `validate` set to true:
+    /// - offsets and lengths must fit in a `usize`, and the padding of `bytes`
+    ///   and `string` must be zero;
+    /// - every word must be a valid value of its type: `bool`s must be 0 or 1,
+    ///   the padding of addresses, functions, and fixed-size byte arrays must
+    ///   be zero, and integers must fit in their size (which range-checks
+    ///   `uint8`-encoded enums), and strings must be valid UTF-8;
+    /// - re-encoding the result must produce `data` exactly.
+    #[inline]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn abi_decode_sequence_validate(&self, data: &[u8]) -> Result<DynSolValue> {
+        let value = self.abi_decode_inner(
+            &mut Decoder::new(data, true),
+            DynToken::decode_sequence_populate,
+        )?;
+        if value.abi_encode_sequence().as_deref() != Some(data) {
+            return Err(alloy_sol_types::Error::ReserMismatch.into())
+        }
+        Ok(value)
+    }
+
+    /// Decode a [`DynSolValueRef`] from a byte slice, borrowing its `bytes`
+    /// and `string` payloads instead of copying them. Fails if the value does
+    /// not match this type.
+    ///
+    /// See [`abi_decode`](Self::abi_decode) and
+    /// [`abi_decode_validate`](Self::abi_decode_validate) for more details.
+    /// When `validate` is true, an owned copy of the value is re-encoded.
+    #[inline]
+    pub fn abi_decode_ref<'a>(
+        &'a self,
+        data: &'a [u8],
+        validate: bool,
+    ) -> Result<DynSolValueRef<'a>> {
+        let value = self.abi_decode_ref_inner(
+            &mut Decoder::new(data, validate),
+            DynToken::decode_single_populate,
+        )?;
+        if validate && value.clone().into_owned().abi_encode() != data {
+            return Err(alloy_sol_types::Error::ReserMismatch.into())
+        }
+        Ok(value)
+    }
+
+    /// Decode a [`DynSolValueRef`] from a byte slice as function parameters,
+    /// borrowing its `bytes` and `string` payloads instead of copying them.
+    ///
+    /// See [`abi_decode_params`](Self::abi_decode_params) for more details.
+    #[inline]
+    pub fn abi_decode_params_ref<'a>(
+        &'a self,
+        data: &'a [u8],
+        validate: bool,
+    ) -> Result<DynSolValueRef<'a>> {
+        match self {
+            Self::Tuple(_) => self.abi_decode_sequence_ref(data, validate),
+            _ => self.abi_decode_ref(data, validate),
+        }
+    }
+
+    /// Decode a [`DynSolValueRef`] from a byte slice as a sequence, borrowing
+    /// its `bytes` and `string` payloads instead of copying them.
+    ///
+    /// See [`abi_decode_sequence`](Self::abi_decode_sequence) and
+    /// [`abi_decode_sequence_validate`](Self::abi_decode_sequence_validate)
+    /// for more details. When `validate` is true, an owned copy of the value
+    /// is re-encoded.
+    #[inline]
+    pub fn abi_decode_sequence_ref<'a>(
+        &'a self,
+        data: &'a [u8],
+        validate: bool,
+    ) -> Result<DynSolValueRef<'a>> {
+        let value = self.abi_decode_ref_inner(
+            &mut Decoder::new(data, validate),
+            DynToken::decode_sequence_populate,
+        )?;
+        if validate && value.clone().into_owned().abi_encode_sequence().as_deref() != Some(data) {
+            return Err(alloy_sol_types::Error::ReserMismatch.into())
+        }
+        Ok(value)
+    }
+
+    /// Decodes a value, type-checking its token if the decoder validates.
     #[inline]
     #[cfg_attr(debug_assertions, track_caller)]
     pub(crate) fn abi_decode_inner<'d, F>(
@@ -579,8 +876,7 @@ impl DynSolType {
     where
         F: FnOnce(&mut DynToken<'d>, &mut Decoder<'d>) -> Result<()>,
     {
-        let mut token = self.empty_dyn_token();
-        f(&mut token, decoder)?;
+        let token = self.decode_token(decoder, f)?;
         let value = self.detokenize(token).expect("invalid empty_dyn_token");
         debug_assert!(
             self.matches(&value),
@@ -589,6 +885,72 @@ impl DynSolType {
         Ok(value)
     }
 
+    /// Decodes a value borrowing from the type and the data, type-checking
+    /// its token if the decoder validates.
+    #[inline]
+    fn abi_decode_ref_inner<'a, F>(
+        &'a self,
+        decoder: &mut Decoder<'a>,
+        f: F,
+    ) -> Result<DynSolValueRef<'a>>
+    where
+        F: FnOnce(&mut DynToken<'a>, &mut Decoder<'a>) -> Result<()>,
+    {
+        let token = self.decode_token(decoder, f)?;
+        Ok(self.detokenize_ref(token).expect("invalid empty_dyn_token"))
+    }
+
+    /// Decodes this type's token, type-checking it if the decoder validates.
+    #[inline]
+    fn decode_token<'d, F>(&self, decoder: &mut Decoder<'d>, f: F) -> Result<DynToken<'d>>
+    where
+        F: FnOnce(&mut DynToken<'d>, &mut Decoder<'d>) -> Result<()>,
+    {
+        let mut token = self.empty_dyn_token();
+        f(&mut token, decoder)?;
+        if decoder.validate() {
+            self.type_check(&token)?;
+        }
+        Ok(token)
+    }
+
+    /// Returns an error if the token is not a valid encoding of a value of
+    /// this type, like [`SolType::type_check`].
+    pub(crate) fn type_check(&self, token: &DynToken<'_>) -> Result<()> {
+        let valid = match (self, token) {
+            (Self::Bool, DynToken::Word(word)) => is_zero(&word[..31]) && word[31] <= 1,
+            (Self::Address, DynToken::Word(word)) => is_zero(&word[..12]),
+            (Self::Function, DynToken::Word(word)) => is_zero(&word[24..]),
+            (&Self::FixedBytes(size), DynToken::Word(word)) => is_zero(&word[size..]),
+            (&Self::Uint(size), DynToken::Word(word)) => is_zero(&word[..32 - size / 8]),
+            (&Self::Int(size), DynToken::Word(word)) => {
+                // all upper bytes must be an extension of the sign bit
+                let msb = 32 - size / 8;
+                let sign_extension = (word[msb] & 0x80 == 0x80) as u8 * 0xff;
+                word[..msb].iter().all(|&b| b == sign_extension)
+            }
+            (Self::Bytes, DynToken::PackedSeq(_)) => true,
+            (Self::String, DynToken::PackedSeq(buf)) => core::str::from_utf8(buf).is_ok(),
+            (Self::Array(ty), DynToken::DynSeq { contents, .. })
+            | (Self::FixedArray(ty, _), DynToken::FixedSeq(contents, _)) => {
+                return contents.iter().try_for_each(|token| ty.type_check(token))
+            }
+            (as_tuple!(Self types), DynToken::FixedSeq(tokens, _)) => {
+                return zip(types, tokens.iter()).try_for_each(|(ty, token)| ty.type_check(token))
+            }
+            _ => false,
+        };
+        if valid {
+            return Ok(())
+        }
+        let data = match *token {
+            DynToken::Word(word) => word.to_vec(),
+            DynToken::PackedSeq(buf) => alloy_sol_types::abi::encode(&PackedSeqToken(buf)),
+            _ => Vec::new(),
+        };
+        Err(alloy_sol_types::Error::type_check_fail(&data, self.sol_type_name()).into())
+    }
+
     /// Wrap in an array of the specified size
     #[inline]
     pub(crate) fn array_wrap(self, size: Option<NonZeroUsize>) -> Self {
@@ -608,10 +970,160 @@ impl DynSolType {
     }
 }
 
+#[inline]
+fn is_zero(bytes: &[u8]) -> bool {
+    bytes.iter().all(|&b| b == 0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::{hex, Address};
+    use alloc::string::ToString;
+    use alloy_primitives::{hex, Address, I256, U256};
+
+    #[test]
+    fn decode_large_arrays() {
+        let n = 10_000usize;
+        let uints = (0..n)
+            .map(|i| DynSolValue::from(U256::from(i)))
+            .collect::<Vec<_>>();
+        let strings = (0..n)
+            .map(|i| DynSolValue::String(i.to_string()))
+            .collect::<Vec<_>>();
+        let tuples = (0..n)
+            .map(|i| {
+                DynSolValue::Tuple(vec![
+                    U256::from(i).into(),
+                    Address::with_last_byte(i as u8).into(),
+                ])
+            })
+            .collect::<Vec<_>>();
+        let nested = (0..n)
+            .map(|i| DynSolValue::Array(vec![U256::from(i).into(); i % 3]))
+            .collect::<Vec<_>>();
+
+        for (ty, values) in [
+            ("uint256[]", uints),
+            ("string[]", strings),
+            ("(uint256,address)[]", tuples),
+            ("uint256[][]", nested),
+        ] {
+            let ty = DynSolType::parse(ty).unwrap();
+            let value = DynSolValue::Array(values);
+            let encoded = value.abi_encode();
+            assert_eq!(ty.abi_decode(&encoded).unwrap(), value);
+
+            // corrupt the last element's data
+            let truncated = &encoded[..encoded.len() - 32];
+            assert!(ty.abi_decode(truncated).is_err());
+        }
+    }
+
+    #[test]
+    fn validate_parity() {
+        fn check<T: SolType>(ty: &str, data: &[u8]) {
+            let ty = DynSolType::parse(ty).unwrap();
+            assert!(ty.abi_decode(data).is_ok(), "{ty}");
+            assert!(T::abi_decode(data, false).is_ok(), "{ty}");
+            assert_eq!(
+                ty.abi_decode_validate(data).unwrap_err(),
+                Error::SolTypes(T::abi_decode(data, true).err().unwrap()),
+                "{ty}"
+            );
+        }
+
+        let mut word = [0u8; 32];
+        word[31] = 2;
+        check::<sol_data::Bool>("bool", &word);
+        word[31] = 0;
+        word[0] = 1;
+        check::<sol_data::Address>("address", &word);
+        check::<sol_data::Uint<8>>("uint8", &word);
+        check::<sol_data::Int<16>>("int16", &word);
+        check::<sol_data::FixedBytes<4>>("bytes4", Word::with_last_byte(1).as_slice());
+        check::<sol_data::Int<16>>(
+            "int16",
+            &hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7fff"),
+        );
+
+        // trailing data, found by re-encoding
+        check::<sol_data::Uint<256>>("uint256", &[0u8; 64]);
+        // non-zero padding of a `bytes`
+        check::<sol_data::Bytes>(
+            "bytes",
+            &hex!(
+                "0000000000000000000000000000000000000000000000000000000000000020"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "0102000000000000000000000000000000000000000000000000000000000000"
+            ),
+        );
+        // invalid UTF-8
+        check::<sol_data::String>(
+            "string",
+            &hex!(
+                "0000000000000000000000000000000000000000000000000000000000000020"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "ff00000000000000000000000000000000000000000000000000000000000000"
+            ),
+        );
+
+        let valid = DynSolValue::Tuple(vec![
+            DynSolValue::Int(I256::MINUS_ONE, 8),
+            DynSolValue::Array(vec![DynSolValue::Bool(true)]),
+        ]);
+        let ty = DynSolType::parse("(int8,bool[])").unwrap();
+        assert_eq!(
+            ty.abi_decode_sequence_validate(&valid.abi_encode_params()),
+            Ok(valid)
+        );
+    }
+
+    #[test]
+    fn parse_tuple_keyword() {
+        let tuple = DynSolType::Tuple(vec![DynSolType::Uint(256), DynSolType::Address]);
+        let array = DynSolType::Array(Box::new(tuple.clone()));
+        assert_eq!(
+            DynSolType::parse("tuple(uint256,address)"),
+            Ok(tuple.clone())
+        );
+        assert_eq!(
+            DynSolType::parse("tuple(uint256,address)[]"),
+            Ok(array.clone())
+        );
+        assert_eq!(
+            DynSolType::parse("tuple(bool,tuple(uint256,address)[])"),
+            Ok(DynSolType::Tuple(vec![DynSolType::Bool, array.clone()]))
+        );
+        assert_eq!(DynSolType::parse("tuple()"), Ok(DynSolType::Tuple(vec![])));
+        assert!(DynSolType::parse("tuple").is_err());
+
+        let components = tuple.as_tuple().unwrap();
+        assert_eq!(
+            DynSolType::parse_with_components("tuple", components),
+            Ok(tuple.clone())
+        );
+        assert_eq!(
+            DynSolType::parse_with_components("tuple[]", components),
+            Ok(array)
+        );
+        assert_eq!(
+            DynSolType::parse_with_components("tuple[2][]", components),
+            Ok(DynSolType::Array(Box::new(DynSolType::FixedArray(
+                Box::new(tuple.clone()),
+                2
+            ))))
+        );
+        assert_eq!(
+            DynSolType::parse_with_components("tuple", &[]),
+            Ok(DynSolType::Tuple(vec![]))
+        );
+        assert_eq!(
+            DynSolType::parse_with_components("uint8[]", &[]),
+            DynSolType::parse("uint8[]")
+        );
+        assert!(DynSolType::parse_with_components("uint8", components).is_err());
+        assert!(DynSolType::parse_with_components("tuple(bool)", components).is_err());
+    }
 
     #[test]
     fn dynamically_encodes() {
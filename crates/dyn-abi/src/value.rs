@@ -1,8 +1,11 @@
 use super::ty::as_tuple;
 use crate::{DynSolType, DynToken, Word};
 use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
-use alloy_primitives::{Address, Function, I256, U256};
-use alloy_sol_types::{abi::Encoder, utils::words_for_len};
+use alloy_primitives::{keccak256, Address, Function, I256, U256};
+use alloy_sol_types::{
+    abi::{Encoder, ErasedToken, SeqKind, TokenVisitor},
+    utils::{next_multiple_of_32, words_for_len},
+};
 
 #[cfg(feature = "eip712")]
 macro_rules! as_fixed_seq {
@@ -63,7 +66,20 @@ macro_rules! as_fixed_seq {
 /// );
 /// # Ok::<(), alloy_dyn_abi::Error>(())
 /// ```
+/// 
+/// # Serde
+///
+/// With the `serde` feature, values are (de)serialized with their types, as
+/// externally tagged enums, e.g. `{"Uint":["0x2a",8]}` in JSON, so that they
+/// can be persisted and loaded without their [`DynSolType`]. This
+/// representation is also supported by non-self-describing formats, such as
+/// `bincode`.
+///
+/// Deserialization fails for sizes that do not correspond to a Solidity type,
+/// such as `Uint` values with a bit size that is not a multiple of 8.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "DynSolValueRepr"))]
 pub enum DynSolValue {
     /// A boolean.
     Bool(bool),
@@ -102,6 +118,73 @@ pub enum DynSolValue {
     },
 }
 
+/// The deserialization representation of [`DynSolValue`], validated by its
+/// `TryFrom` implementation.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "DynSolValue")]
+enum DynSolValueRepr {
+    Bool(bool),
+    Int(I256, usize),
+    Uint(U256, usize),
+    FixedBytes(Word, usize),
+    Address(Address),
+    Function(Function),
+    Bytes(Vec<u8>),
+    String(String),
+    Array(Vec<DynSolValue>),
+    FixedArray(Vec<DynSolValue>),
+    Tuple(Vec<DynSolValue>),
+    #[cfg(feature = "eip712")]
+    CustomStruct {
+        name: String,
+        prop_names: Vec<String>,
+        tuple: Vec<DynSolValue>,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DynSolValueRepr> for DynSolValue {
+    type Error = &'static str;
+
+    fn try_from(value: DynSolValueRepr) -> Result<Self, Self::Error> {
+        let valid_int_size = |size: usize| size > 0 && size <= 256 && size % 8 == 0;
+        Ok(match value {
+            DynSolValueRepr::Bool(b) => Self::Bool(b),
+            DynSolValueRepr::Int(i, size) if valid_int_size(size) => Self::Int(i, size),
+            DynSolValueRepr::Int(..) => return Err("invalid int size"),
+            DynSolValueRepr::Uint(u, size) if valid_int_size(size) => Self::Uint(u, size),
+            DynSolValueRepr::Uint(..) => return Err("invalid uint size"),
+            DynSolValueRepr::FixedBytes(w, size) if size > 0 && size <= 32 => {
+                Self::FixedBytes(w, size)
+            }
+            DynSolValueRepr::FixedBytes(..) => return Err("invalid fixed bytes size"),
+            DynSolValueRepr::Address(a) => Self::Address(a),
+            DynSolValueRepr::Function(f) => Self::Function(f),
+            DynSolValueRepr::Bytes(b) => Self::Bytes(b),
+            DynSolValueRepr::String(s) => Self::String(s),
+            DynSolValueRepr::Array(values) => Self::Array(values),
+            DynSolValueRepr::FixedArray(values) => Self::FixedArray(values),
+            DynSolValueRepr::Tuple(values) => Self::Tuple(values),
+            #[cfg(feature = "eip712")]
+            DynSolValueRepr::CustomStruct {
+                name,
+                prop_names,
+                tuple,
+            } => {
+                if prop_names.len() != tuple.len() {
+                    return Err("custom struct prop names and values length mismatch")
+                }
+                Self::CustomStruct {
+                    name,
+                    prop_names,
+                    tuple,
+                }
+            }
+        })
+    }
+}
+
 impl From<Address> for DynSolValue {
     #[inline]
     fn from(value: Address) -> Self {
@@ -195,6 +278,57 @@ impl From<U256> for DynSolValue {
     }
 }
 
+/// Allows encoding values alongside static tokens with
+/// [`abi::encode_tokens`](alloy_sol_types::abi::encode_tokens).
+impl ErasedToken for DynSolValue {
+    #[inline]
+    fn dyn_head_words(&self) -> usize {
+        self.head_words()
+    }
+
+    #[inline]
+    fn dyn_tail_words(&self) -> usize {
+        self.tail_words()
+    }
+
+    #[inline]
+    fn dyn_head_append(&self, enc: &mut Encoder) {
+        self.head_append(enc)
+    }
+
+    #[inline]
+    fn dyn_tail_append(&self, enc: &mut Encoder) {
+        self.tail_append(enc)
+    }
+
+    fn dyn_visit(&self, visitor: &mut dyn TokenVisitor) {
+        let (kind, values) = match self {
+            Self::Address(_)
+            | Self::Function(_)
+            | Self::Bool(_)
+            | Self::FixedBytes(..)
+            | Self::Int(..)
+            | Self::Uint(..) => {
+                return visitor.visit_word(&unsafe { self.as_word().unwrap_unchecked() })
+            }
+
+            Self::String(s) => return visitor.visit_packed_seq(s.as_bytes()),
+            Self::Bytes(bytes) => return visitor.visit_packed_seq(bytes),
+
+            Self::Array(values) => (SeqKind::Dynamic, values),
+            Self::FixedArray(values) => (SeqKind::Fixed, values),
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { tuple: values, .. } => (SeqKind::Tuple, values),
+            Self::Tuple(values) => (SeqKind::Tuple, values),
+        };
+        visitor.enter_seq(kind, values.len(), self.is_dynamic());
+        for value in values {
+            value.dyn_visit(visitor);
+        }
+        visitor.leave_seq(kind);
+    }
+}
+
 impl DynSolValue {
     /// The Solidity type. This returns the Solidity type corresponding to this
     /// value, if it is known. A type will not be known if the value contains
@@ -698,7 +832,7 @@ impl DynSolValue {
             Self::FixedBytes(word, size) => buf.extend_from_slice(&word[..*size]),
             Self::Int(num, size) => {
                 let mut bytes = num.to_be_bytes::<32>();
-                let start = 32 - *size;
+                let start = 32 - *size / 8;
                 if num.is_negative() {
                     bytes[start] |= 0x80;
                 } else {
@@ -707,7 +841,7 @@ impl DynSolValue {
                 buf.extend_from_slice(&bytes[start..]);
             }
             Self::Uint(num, size) => {
-                buf.extend_from_slice(&num.to_be_bytes::<32>()[(32 - *size)..]);
+                buf.extend_from_slice(&num.to_be_bytes::<32>()[(32 - *size / 8)..]);
             }
             as_fixed_seq!(inner) | Self::Array(inner) => {
                 for val in inner {
@@ -717,11 +851,28 @@ impl DynSolValue {
         }
     }
 
+    /// Returns the size of this value's packed encoding in bytes.
+    ///
+    /// This is the length of [`abi_encode_packed`](Self::abi_encode_packed).
+    pub fn abi_packed_encoded_size(&self) -> usize {
+        match self {
+            Self::Address(_) => 20,
+            Self::Function(_) => 24,
+            Self::Bool(_) => 1,
+            Self::String(s) => s.len(),
+            Self::Bytes(b) => b.len(),
+            Self::FixedBytes(_, size) => *size,
+            Self::Int(_, size) | Self::Uint(_, size) => *size / 8,
+            as_fixed_seq!(inner) | Self::Array(inner) => {
+                inner.iter().map(Self::abi_packed_encoded_size).sum()
+            }
+        }
+    }
+
     /// Encodes the value into a packed byte array.
     #[inline]
     pub fn abi_encode_packed(&self) -> Vec<u8> {
-        // TODO: capacity
-        let mut buf = Vec::new();
+        let mut buf = Vec::with_capacity(self.abi_packed_encoded_size());
         self.abi_encode_packed_to(&mut buf);
         buf
     }
@@ -767,6 +918,91 @@ impl DynSolValue {
         enc.pop_offset();
     }
 
+    /// Returns the number of bytes this value occupies in the preimage of an
+    /// indexed event parameter's topic. See
+    /// [`encode_topic_preimage`](Self::encode_topic_preimage).
+    pub fn topic_preimage_length(&self) -> usize {
+        match self {
+            Self::Bytes(b) => next_multiple_of_32(b.len()),
+            Self::String(s) => next_multiple_of_32(s.len()),
+            Self::Array(values) | as_fixed_seq!(values) => {
+                values.iter().map(Self::topic_preimage_length).sum()
+            }
+            _ => 32,
+        }
+    }
+
+    /// Encodes this value as part of the preimage of an indexed event
+    /// parameter's topic: words are encoded as-is, `bytes` and `string` are
+    /// right-padded to a multiple of 32 bytes, and the elements of arrays,
+    /// tuples and structs are concatenated, without any lengths or offsets.
+    ///
+    /// See the [Solidity ABI spec][ref] for more details.
+    ///
+    /// [ref]: https://docs.soliditylang.org/en/latest/abi-spec.html#encoding-of-indexed-event-parameters
+    pub fn encode_topic_preimage(&self, out: &mut Vec<u8>) {
+        if let Some(word) = self.as_word() {
+            out.extend_from_slice(word.as_slice());
+            return
+        }
+        let bytes = match self {
+            Self::Bytes(b) => &b[..],
+            Self::String(s) => s.as_bytes(),
+            Self::Array(values) | as_fixed_seq!(values) => {
+                out.reserve(self.topic_preimage_length());
+                for value in values {
+                    value.encode_topic_preimage(out);
+                }
+                return
+            }
+            _ => unreachable!("all types are words or covered in the match"),
+        };
+        out.extend_from_slice(bytes);
+        out.resize(
+            out.len() + next_multiple_of_32(bytes.len()) - bytes.len(),
+            0,
+        );
+    }
+
+    /// Encodes this value as an indexed event parameter's topic.
+    ///
+    /// Values of single-word types are the topic itself, and `bytes` and
+    /// `string` are hashed, while arrays, tuples and structs hash their
+    /// [`encode_topic_preimage`](Self::encode_topic_preimage).
+    ///
+    /// See the [Solidity ABI spec][ref] for more details.
+    ///
+    /// [ref]: https://docs.soliditylang.org/en/latest/abi-spec.html#encoding-of-indexed-event-parameters
+    pub fn encode_topic(&self) -> Word {
+        match self {
+            Self::Bytes(b) => keccak256(b),
+            Self::String(s) => keccak256(s),
+            _ => self.as_word().unwrap_or_else(|| {
+                let mut out = Vec::with_capacity(self.topic_preimage_length());
+                self.encode_topic_preimage(&mut out);
+                keccak256(out)
+            }),
+        }
+    }
+
+    /// Calculates the ABI-encoded size of this value in bytes, counting both
+    /// head and tail words. For a single-word value this will always be 32.
+    ///
+    /// Like [`SolType::abi_encoded_size`], this does not include the offset
+    /// word which points to a dynamic value, so the length of
+    /// [`abi_encode`](Self::abi_encode) is 32 bytes more for dynamic values.
+    ///
+    /// [`SolType::abi_encoded_size`]: alloy_sol_types::SolType::abi_encoded_size
+    #[inline]
+    pub fn abi_encoded_size(&self) -> usize {
+        let words = if self.is_dynamic() {
+            self.tail_words()
+        } else {
+            self.head_words()
+        };
+        words * 32
+    }
+
     /// Encode this value into a byte array by wrapping it into a 1-element
     /// sequence.
     #[inline]
@@ -805,3 +1041,218 @@ impl DynSolValue {
         self.as_fixed_seq().map(Self::encode_seq)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloy_sol_types::{abi::TokenType, sol_data, SolType};
+
+    #[derive(Default, PartialEq, Debug)]
+    struct Events(Vec<String>);
+
+    impl TokenVisitor for Events {
+        fn visit_word(&mut self, word: &Word) {
+            self.0.push(format!("word {word}"));
+        }
+
+        fn visit_packed_seq(&mut self, bytes: &[u8]) {
+            self.0.push(format!("packed {bytes:?}"));
+        }
+
+        fn enter_seq(&mut self, kind: SeqKind, len: usize, dynamic: bool) {
+            self.0.push(format!("enter {kind:?} {len} {dynamic}"));
+        }
+
+        fn leave_seq(&mut self, kind: SeqKind) {
+            self.0.push(format!("leave {kind:?}"));
+        }
+    }
+
+    #[test]
+    fn visit_matches_static() {
+        type MyTy = (
+            sol_data::Address,
+            sol_data::FixedArray<sol_data::Uint<256>, 2>,
+            sol_data::Array<sol_data::Bytes>,
+            sol_data::String,
+        );
+        let value = (
+            Address::repeat_byte(0x11),
+            [U256::from(1), U256::from(2)],
+            vec![vec![1, 2], vec![]],
+            "hello".to_string(),
+        );
+
+        let mut expected = Events::default();
+        MyTy::tokenize(&value).visit(&mut expected);
+
+        let dyn_value = DynSolValue::Tuple(vec![
+            DynSolValue::Address(value.0),
+            DynSolValue::FixedArray(vec![
+                DynSolValue::Uint(value.1[0], 256),
+                DynSolValue::Uint(value.1[1], 256),
+            ]),
+            DynSolValue::Array(vec![
+                DynSolValue::Bytes(value.2[0].clone()),
+                DynSolValue::Bytes(value.2[1].clone()),
+            ]),
+            DynSolValue::String(value.3.clone()),
+        ]);
+        let mut events = Events::default();
+        dyn_value.dyn_visit(&mut events);
+        assert_eq!(events, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_roundtrip() {
+        let mut values = vec![
+            DynSolValue::Bool(true),
+            DynSolValue::Int(I256::MINUS_ONE, 24),
+            DynSolValue::Uint(U256::from(42), 8),
+            DynSolValue::FixedBytes(Word::repeat_byte(0x22), 3),
+            DynSolValue::Address(Address::repeat_byte(0x11)),
+            DynSolValue::Function(Function::repeat_byte(0x33)),
+            DynSolValue::Bytes(vec![1, 2, 3]),
+            DynSolValue::String("hello".into()),
+            DynSolValue::Array(vec![]),
+            DynSolValue::FixedArray(vec![DynSolValue::Bool(false)]),
+        ];
+        values.push(DynSolValue::Tuple(values.clone()));
+        #[cfg(feature = "eip712")]
+        values.push(DynSolValue::CustomStruct {
+            name: "Mail".into(),
+            prop_names: vec!["from".into(), "contents".into()],
+            tuple: vec![values[4].clone(), values[7].clone()],
+        });
+
+        assert_eq!(
+            serde_json::to_string(&values[2]).unwrap(),
+            r#"{"Uint":["0x2a",8]}"#
+        );
+        for value in values {
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(serde_json::from_str::<DynSolValue>(&json).unwrap(), value);
+            assert_eq!(
+                serde_json::from_str::<DynSolValue>(&json)
+                    .unwrap()
+                    .as_type(),
+                value.as_type()
+            );
+
+            let bin = bincode::serialize(&value).unwrap();
+            assert_eq!(bincode::deserialize::<DynSolValue>(&bin).unwrap(), value);
+        }
+
+        let word = format!("{:?}", Word::ZERO);
+        for invalid in [
+            format!(r#"{{"FixedBytes":["{word}",33]}}"#),
+            format!(r#"{{"FixedBytes":["{word}",0]}}"#),
+            r#"{"Uint":["0x2a",7]}"#.to_string(),
+            r#"{"Uint":["0x2a",264]}"#.to_string(),
+            r#"{"Int":["0x2a",0]}"#.to_string(),
+            r#"{"Array":[{"Int":["0x2a",12]}]}"#.to_string(),
+        ] {
+            assert!(
+                serde_json::from_str::<DynSolValue>(&invalid).is_err(),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn abi_encoded_size() {
+        let strings = vec!["a".repeat(32), String::new(), "b".repeat(33)];
+        let tuple = (U256::from(1), vec![1u8, 2, 3]);
+
+        let string = DynSolValue::from(strings[2].clone());
+        assert_eq!(
+            string.abi_encoded_size(),
+            sol_data::String::abi_encoded_size(&strings[2])
+        );
+        assert_eq!(string.abi_encode().len(), string.abi_encoded_size() + 32);
+        assert_eq!(DynSolValue::Bool(true).abi_encoded_size(), 32);
+
+        let array = DynSolValue::Array(strings.iter().cloned().map(Into::into).collect());
+        assert_eq!(
+            array.abi_encoded_size(),
+            sol_data::Array::<sol_data::String>::abi_encoded_size(&strings)
+        );
+        assert_eq!(array.abi_encode().len(), array.abi_encoded_size() + 32);
+
+        let fixed = DynSolValue::FixedArray(vec![U256::from(1).into(); 3]);
+        assert_eq!(
+            fixed.abi_encoded_size(),
+            sol_data::FixedArray::<sol_data::Uint<256>, 3>::abi_encoded_size(&[U256::from(1); 3])
+        );
+        assert_eq!(fixed.abi_encode().len(), fixed.abi_encoded_size());
+
+        let tuple_value = DynSolValue::Tuple(vec![tuple.0.into(), tuple.1.clone().into()]);
+        assert_eq!(
+            tuple_value.abi_encoded_size(),
+            <(sol_data::Uint<256>, sol_data::Bytes)>::abi_encoded_size(&tuple)
+        );
+        assert_eq!(
+            tuple_value.abi_encode_params().len(),
+            tuple_value.abi_encoded_size()
+        );
+    }
+
+    #[test]
+    fn abi_packed_encoded_size() {
+        let values = [
+            DynSolValue::Bool(true),
+            DynSolValue::Int(I256::MINUS_ONE, 24),
+            DynSolValue::Uint(U256::from(42), 8),
+            DynSolValue::FixedBytes(Word::repeat_byte(0x22), 3),
+            DynSolValue::Address(Address::repeat_byte(0x11)),
+            DynSolValue::Function(Function::repeat_byte(0x33)),
+            DynSolValue::Bytes(vec![1, 2, 3]),
+            DynSolValue::String("hello".into()),
+            DynSolValue::Array(vec![DynSolValue::Uint(U256::from(1), 16); 2]),
+        ];
+        for value in values.iter().chain([&DynSolValue::Tuple(values.to_vec())]) {
+            assert_eq!(
+                value.abi_packed_encoded_size(),
+                value.abi_encode_packed().len(),
+                "{value:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn encode_topic() {
+        use alloy_sol_types::EventTopic;
+
+        let strings = vec!["a".repeat(32), String::new(), "b".repeat(33)];
+        let uints = [U256::from(1), U256::MAX];
+        let tuple = (I256::MINUS_ONE, Address::repeat_byte(0x11), vec![1u8, 2, 3]);
+
+        assert_eq!(
+            DynSolValue::from(I256::MINUS_ONE).encode_topic(),
+            sol_data::Int::<256>::encode_topic(&I256::MINUS_ONE).0
+        );
+        assert_eq!(
+            DynSolValue::from(strings[0].clone()).encode_topic(),
+            keccak256(&strings[0])
+        );
+        assert_eq!(
+            DynSolValue::Array(strings.iter().cloned().map(Into::into).collect()).encode_topic(),
+            sol_data::Array::<sol_data::String>::encode_topic(&strings).0
+        );
+        assert_eq!(
+            DynSolValue::FixedArray(uints.iter().map(|&u| u.into()).collect()).encode_topic(),
+            sol_data::FixedArray::<sol_data::Uint<256>, 2>::encode_topic(&uints).0
+        );
+        assert_eq!(
+            DynSolValue::Tuple(vec![
+                tuple.0.into(),
+                tuple.1.into(),
+                DynSolValue::Bytes(tuple.2.clone())
+            ])
+            .encode_topic(),
+            <(sol_data::Int<256>, sol_data::Address, sol_data::Bytes)>::encode_topic(&tuple).0
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{DynSolValue, Word};
+use alloc::{borrow::Cow, vec::Vec};
+use alloy_primitives::{Address, Function, I256, U256};
+
+#[cfg(feature = "eip712")]
+use alloc::string::String;
+
+/// A dynamic Solidity value which borrows its `bytes` and `string` payloads.
+///
+/// This is the borrowed counterpart of [`DynSolValue`], returned by
+/// [`DynSolType::abi_decode_ref`](crate::DynSolType::abi_decode_ref) and
+/// related methods, which do not copy the payloads out of the decoded data.
+/// Use [`into_owned`](Self::into_owned) to convert it to a [`DynSolValue`],
+/// for example to store it.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{DynSolType, DynSolValue, DynSolValueRef};
+///
+/// let ty: DynSolType = "(bytes,string)".parse()?;
+/// let value = DynSolValue::Tuple(vec![
+///     DynSolValue::Bytes(vec![1, 2, 3]),
+///     DynSolValue::String("hello".into()),
+/// ]);
+/// let encoded = value.abi_encode_params();
+///
+/// let decoded = ty.abi_decode_params_ref(&encoded, true)?;
+/// let DynSolValueRef::Tuple(items) = &decoded else {
+///     unreachable!()
+/// };
+/// assert_eq!(items[0], DynSolValueRef::Bytes(&[1, 2, 3]));
+/// assert_eq!(items[1].as_str(), Some("hello"));
+///
+/// assert_eq!(decoded.into_owned(), value);
+/// # Ok::<(), alloy_dyn_abi::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum DynSolValueRef<'a> {
+    /// A boolean.
+    Bool(bool),
+    /// A signed integer.
+    Int(I256, usize),
+    /// An unsigned integer.
+    Uint(U256, usize),
+    /// A fixed-length byte string.
+    FixedBytes(Word, usize),
+    /// An address.
+    Address(Address),
+    /// A function pointer.
+    Function(Function),
+
+    /// A dynamic-length byte array.
+    Bytes(&'a [u8]),
+    /// A string.
+    ///
+    /// This is only owned if it was decoded from invalid UTF-8, which is
+    /// replaced with [`U+FFFD`](char::REPLACEMENT_CHARACTER).
+    String(Cow<'a, str>),
+
+    /// A dynamically-sized array of values.
+    Array(Vec<DynSolValueRef<'a>>),
+    /// A fixed-size array of values.
+    FixedArray(Vec<DynSolValueRef<'a>>),
+    /// A tuple of values.
+    Tuple(Vec<DynSolValueRef<'a>>),
+
+    /// A named struct, treated as a tuple with a name parameter.
+    #[cfg(feature = "eip712")]
+    CustomStruct {
+        /// The name of the struct.
+        name: &'a str,
+        /// The struct's prop names, in declaration order.
+        prop_names: &'a [String],
+        /// The inner types.
+        tuple: Vec<DynSolValueRef<'a>>,
+    },
+}
+
+impl<'a> From<&'a DynSolValue> for DynSolValueRef<'a> {
+    fn from(value: &'a DynSolValue) -> Self {
+        match value {
+            DynSolValue::Bool(b) => Self::Bool(*b),
+            DynSolValue::Int(i, size) => Self::Int(*i, *size),
+            DynSolValue::Uint(u, size) => Self::Uint(*u, *size),
+            DynSolValue::FixedBytes(w, size) => Self::FixedBytes(*w, *size),
+            DynSolValue::Address(a) => Self::Address(*a),
+            DynSolValue::Function(f) => Self::Function(*f),
+            DynSolValue::Bytes(b) => Self::Bytes(b),
+            DynSolValue::String(s) => Self::String(Cow::Borrowed(s)),
+            DynSolValue::Array(a) => Self::Array(a.iter().map(Into::into).collect()),
+            DynSolValue::FixedArray(a) => Self::FixedArray(a.iter().map(Into::into).collect()),
+            DynSolValue::Tuple(t) => Self::Tuple(t.iter().map(Into::into).collect()),
+            #[cfg(feature = "eip712")]
+            DynSolValue::CustomStruct {
+                name,
+                prop_names,
+                tuple,
+            } => Self::CustomStruct {
+                name,
+                prop_names,
+                tuple: tuple.iter().map(Into::into).collect(),
+            },
+        }
+    }
+}
+
+impl From<DynSolValueRef<'_>> for DynSolValue {
+    #[inline]
+    fn from(value: DynSolValueRef<'_>) -> Self {
+        value.into_owned()
+    }
+}
+
+impl DynSolValueRef<'_> {
+    /// Converts this value into an owned [`DynSolValue`], copying its
+    /// payloads.
+    pub fn into_owned(self) -> DynSolValue {
+        match self {
+            Self::Bool(b) => DynSolValue::Bool(b),
+            Self::Int(i, size) => DynSolValue::Int(i, size),
+            Self::Uint(u, size) => DynSolValue::Uint(u, size),
+            Self::FixedBytes(w, size) => DynSolValue::FixedBytes(w, size),
+            Self::Address(a) => DynSolValue::Address(a),
+            Self::Function(f) => DynSolValue::Function(f),
+            Self::Bytes(b) => DynSolValue::Bytes(b.to_vec()),
+            Self::String(s) => DynSolValue::String(s.into_owned()),
+            Self::Array(a) => DynSolValue::Array(into_owned_seq(a)),
+            Self::FixedArray(a) => DynSolValue::FixedArray(into_owned_seq(a)),
+            Self::Tuple(t) => DynSolValue::Tuple(into_owned_seq(t)),
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct {
+                name,
+                prop_names,
+                tuple,
+            } => DynSolValue::CustomStruct {
+                name: name.into(),
+                prop_names: prop_names.to_vec(),
+                tuple: into_owned_seq(tuple),
+            },
+        }
+    }
+
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub const fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+fn into_owned_seq(values: Vec<DynSolValueRef<'_>>) -> Vec<DynSolValue> {
+    values.into_iter().map(DynSolValueRef::into_owned).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynSolType;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn decode_ref() {
+        let ty: DynSolType = "(uint8,bytes[],string[2],(bool,bytes))".parse().unwrap();
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Uint(U256::from(7), 8),
+            DynSolValue::Array(vec![
+                DynSolValue::Bytes(vec![0xaa; 40]),
+                DynSolValue::Bytes(vec![]),
+            ]),
+            DynSolValue::FixedArray(vec![
+                DynSolValue::String("foo".into()),
+                DynSolValue::String("bar".repeat(20)),
+            ]),
+            DynSolValue::Tuple(vec![
+                DynSolValue::Bool(true),
+                DynSolValue::Bytes(vec![1, 2, 3]),
+            ]),
+        ]);
+        let encoded = value.abi_encode_params();
+
+        for validate in [false, true] {
+            let decoded = ty.abi_decode_params_ref(&encoded, validate).unwrap();
+            assert_eq!(decoded, DynSolValueRef::from(&value));
+
+            // payloads point into the encoded data
+            let DynSolValueRef::Tuple(items) = &decoded else {
+                panic!("{decoded:?}")
+            };
+            let DynSolValueRef::Array(bytes) = &items[1] else {
+                panic!("{decoded:?}")
+            };
+            let bytes = bytes[0].as_bytes().unwrap();
+            assert!(encoded.as_ptr_range().contains(&bytes.as_ptr()));
+            let DynSolValueRef::FixedArray(strings) = &items[2] else {
+                panic!("{decoded:?}")
+            };
+            assert!(matches!(
+                strings[1],
+                DynSolValueRef::String(Cow::Borrowed(_))
+            ));
+
+            assert_eq!(decoded.into_owned(), value);
+        }
+
+        let single = DynSolValue::String("baz".into());
+        let encoded = single.abi_encode();
+        let decoded = DynSolType::String.abi_decode_ref(&encoded, true).unwrap();
+        assert_eq!(decoded.as_str(), Some("baz"));
+        assert_eq!(DynSolValue::from(decoded), single);
+    }
+
+    #[test]
+    fn decode_ref_invalid_utf8() {
+        let encoded = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+            "ff61000000000000000000000000000000000000000000000000000000000000"
+        );
+        let decoded = DynSolType::String.abi_decode_ref(&encoded, false).unwrap();
+        assert_eq!(
+            decoded,
+            DynSolValueRef::String(Cow::Owned("\u{fffd}a".into()))
+        );
+        assert!(DynSolType::String.abi_decode_ref(&encoded, true).is_err());
+    }
+}
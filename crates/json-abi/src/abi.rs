@@ -1,4 +1,7 @@
-use crate::{AbiItem, Constructor, Error, Event, Fallback, Function, Receive};
+use crate::{
+    AbiItem, Constructor, DevDoc, Error, Event, Fallback, Function, Receive, StateMutability,
+    UserDoc,
+};
 use alloc::{collections::btree_map, string::String, vec::Vec};
 use alloy_primitives::Bytes;
 use alloy_sol_type_parser::{Error as ParserError, Result as ParserResult};
@@ -127,6 +130,35 @@ impl JsonAbi {
         Self::from_json_str(&json)
     }
 
+    /// Serializes this ABI into a compact JSON string with a deterministic
+    /// item order, independent of the order in which the items were inserted.
+    ///
+    /// Items are grouped by kind, in the order: constructor, fallback,
+    /// receive, functions, events, errors. Within each kind, items are sorted
+    /// by name, and overloads by their full signature. Fields are always
+    /// emitted in the same order.
+    ///
+    /// This is useful for ABI files which are checked into repositories or
+    /// content-addressed, so that regenerating them does not produce spurious
+    /// changes.
+    #[cfg(feature = "serde_json")]
+    pub fn to_json_canonical(&self) -> String {
+        let mut abi = self.clone();
+        for functions in abi.functions.values_mut() {
+            functions.sort_by_cached_key(Function::signature_full);
+        }
+        for events in abi.events.values_mut() {
+            events.sort_by_cached_key(|event| {
+                let indexed = event.inputs.iter().map(|p| p.indexed).collect::<Vec<_>>();
+                (event.signature(), indexed, event.anonymous)
+            });
+        }
+        for errors in abi.errors.values_mut() {
+            errors.sort_by_cached_key(Error::signature);
+        }
+        serde_json::to_string(&abi).expect("failed to serialize JSON ABI")
+    }
+
     /// Returns the total number of items (of any type).
     pub fn len(&self) -> usize {
         self.constructor.is_some() as usize
@@ -225,6 +257,54 @@ impl JsonAbi {
         out.push('}');
     }
 
+    /// Renders this JSON ABI as a Markdown reference, with optional NatSpec
+    /// documentation.
+    ///
+    /// The output starts with a `# {name}` heading, followed by the contract's
+    /// documentation and a section for each of the constructor, functions,
+    /// events, and errors. Each item is rendered as a Solidity declaration,
+    /// followed by its selector or topic, documentation, and a table of its
+    /// parameters and return values.
+    ///
+    /// See also [`ContractObject::to_markdown`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::JsonAbi;
+    ///
+    /// let abi = JsonAbi::parse(["function transfer(address to, uint256 amount) returns (bool)"])?;
+    /// let markdown = abi.to_markdown("Token", None, None);
+    /// assert!(markdown.starts_with("# Token\n"));
+    /// assert!(markdown.contains("- Selector: `0xa9059cbb`"));
+    /// assert!(markdown.contains("| `amount` | `uint256` |  |"));
+    /// # Ok::<(), alloy_sol_type_parser::Error>(())
+    /// ```
+    #[inline]
+    pub fn to_markdown(
+        &self,
+        name: &str,
+        devdoc: Option<&DevDoc>,
+        userdoc: Option<&UserDoc>,
+    ) -> String {
+        let mut out = String::new();
+        self.to_markdown_raw(name, devdoc, userdoc, &mut out);
+        out
+    }
+
+    /// Renders this JSON ABI as a Markdown reference into the given string.
+    ///
+    /// See [`to_markdown`](JsonAbi::to_markdown) for more information.
+    pub fn to_markdown_raw(
+        &self,
+        name: &str,
+        devdoc: Option<&DevDoc>,
+        userdoc: Option<&UserDoc>,
+        out: &mut String,
+    ) {
+        crate::to_markdown::Markdown { devdoc, userdoc }.render(self, name, out);
+    }
+
     /// Returns this contract's constructor.
     #[inline]
     pub const fn constructor(&self) -> Option<&Constructor> {
@@ -267,6 +347,69 @@ impl JsonAbi {
         self.errors.values().flatten()
     }
 
+    /// Iterates over all the functions of the contract which do not modify the
+    /// state, that is `view` and `pure` functions, in arbitrary order.
+    #[inline]
+    pub fn view_functions(&self) -> impl Iterator<Item = &Function> + '_ {
+        self.functions().filter(|f| {
+            matches!(
+                f.state_mutability,
+                StateMutability::View | StateMutability::Pure
+            )
+        })
+    }
+
+    /// Iterates over all the `payable` functions of the contract in arbitrary
+    /// order.
+    #[inline]
+    pub fn payable_functions(&self) -> impl Iterator<Item = &Function> + '_ {
+        self.functions()
+            .filter(|f| f.state_mutability == StateMutability::Payable)
+    }
+
+    /// Returns a copy of this ABI with only the functions for which `f`
+    /// returns `true`.
+    ///
+    /// All the other items, such as events and errors, are kept.
+    ///
+    /// # Examples
+    ///
+    /// Keeping only the functions of a read-only client:
+    ///
+    /// ```
+    /// # use alloy_json_abi::{JsonAbi, StateMutability};
+    /// let mut abi = JsonAbi::parse([
+    ///     "function balanceOf(address owner)(uint256)",
+    ///     "function transfer(address to, uint256 amount)(bool)",
+    ///     "event Transfer(address indexed from, address indexed to, uint256 amount)",
+    /// ])?;
+    /// abi.functions.get_mut("balanceOf").unwrap()[0].state_mutability = StateMutability::View;
+    ///
+    /// let read_only = abi.subset(|f| f.state_mutability == StateMutability::View);
+    /// assert!(read_only.function("balanceOf").is_some());
+    /// assert!(read_only.function("transfer").is_none());
+    /// assert!(read_only.event("Transfer").is_some());
+    /// # Ok::<(), alloy_sol_type_parser::Error>(())
+    /// ```
+    pub fn subset<F: FnMut(&Function) -> bool>(&self, mut f: F) -> Self {
+        let functions = self
+            .functions
+            .iter()
+            .filter_map(|(name, overloads)| {
+                let overloads: Vec<_> = overloads.iter().filter(|&func| f(func)).cloned().collect();
+                (!overloads.is_empty()).then(|| (name.clone(), overloads))
+            })
+            .collect();
+        Self {
+            constructor: self.constructor.clone(),
+            fallback: self.fallback,
+            receive: self.receive,
+            functions,
+            events: self.events.clone(),
+            errors: self.errors.clone(),
+        }
+    }
+
     /// Inserts an item into the ABI.
     fn insert_item(&mut self, item: AbiItem<'_>) -> Result<(), &'static str> {
         match item {
@@ -433,10 +576,11 @@ impl<'de> Visitor<'de> for JsonAbiVisitor {
     }
 }
 
-/// Represents a generic contract's ABI, bytecode and deployed bytecode.
+/// Represents a generic contract's ABI, bytecode, deployed bytecode, and
+/// documentation.
 ///
 /// Can be deserialized from both an ABI array, and a JSON object with the `abi`
-/// field with optionally the bytecode fields.
+/// field with optionally the bytecode and `devdoc`/`userdoc` fields.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ContractObject {
@@ -449,6 +593,26 @@ pub struct ContractObject {
     /// The contract deployed bytecode.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deployed_bytecode: Option<Bytes>,
+    /// The contract developer documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub devdoc: Option<DevDoc>,
+    /// The contract user documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub userdoc: Option<UserDoc>,
+}
+
+impl ContractObject {
+    /// Renders this contract's ABI as a Markdown reference, with its NatSpec
+    /// documentation.
+    ///
+    /// Returns `None` if the contract has no ABI. See
+    /// [`JsonAbi::to_markdown`] for more information.
+    #[inline]
+    pub fn to_markdown(&self, name: &str) -> Option<String> {
+        self.abi
+            .as_ref()
+            .map(|abi| abi.to_markdown(name, self.devdoc.as_ref(), self.userdoc.as_ref()))
+    }
 }
 
 impl<'de> Deserialize<'de> for ContractObject {
@@ -498,6 +662,8 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
         let mut abi = None;
         let mut bytecode = None;
         let mut deployed_bytecode = None;
+        let mut devdoc = None;
+        let mut userdoc = None;
 
         while let Some(key) = map.next_key::<&str>()? {
             match key {
@@ -518,6 +684,8 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
                 | "runtimebin" | "runtime " => {
                     set_if_none!(@serde deployed_bytecode, map.next_value::<Bytecode>()?.bytes());
                 }
+                "devdoc" => set_if_none!(@serde devdoc, map.next_value()?),
+                "userdoc" => set_if_none!(@serde userdoc, map.next_value()?),
                 _ => {
                     map.next_value::<serde::de::IgnoredAny>()?;
                 }
@@ -528,6 +696,8 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
             abi,
             bytecode,
             deployed_bytecode,
+            devdoc,
+            userdoc,
         })
     }
 
@@ -537,6 +707,8 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
             abi: Some(abi),
             bytecode: None,
             deployed_bytecode: None,
+            devdoc: None,
+            userdoc: None,
         })
     }
 }
@@ -0,0 +1,191 @@
+use crate::JsonAbi;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use alloy_primitives::{Selector, B256};
+
+/// Items of several [`JsonAbi`]s sharing the same selector or event topic.
+///
+/// Returned by [`JsonAbi::function_collisions`],
+/// [`JsonAbi::error_collisions`], and [`JsonAbi::event_collisions`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Collision<S> {
+    /// The shared selector or event topic.
+    pub selector: S,
+    /// The colliding items, in order of ABI and then of declaration.
+    pub items: Vec<CollidingItem>,
+}
+
+/// An item involved in a [`Collision`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CollidingItem {
+    /// The index of the ABI declaring the item, in the order the ABIs were
+    /// passed.
+    pub abi: usize,
+    /// The signature of the item.
+    pub signature: String,
+}
+
+impl JsonAbi {
+    /// Returns the function selectors shared by several ABIs, or by functions
+    /// with different signatures.
+    ///
+    /// Proxies and diamonds route calls to an implementation based on their
+    /// selector only, so a function declared by several ABIs is silently routed
+    /// to just one of them, even if the signatures are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::JsonAbi;
+    ///
+    /// let proxy = JsonAbi::parse(["function upgradeTo(address)"]).unwrap();
+    /// let implementation = JsonAbi::parse([
+    ///     "function upgradeTo(address)",
+    ///     "function transfer(address,uint256)",
+    /// ])
+    /// .unwrap();
+    ///
+    /// let collisions = JsonAbi::function_collisions([&proxy, &implementation]);
+    /// assert_eq!(collisions.len(), 1);
+    /// assert_eq!(collisions[0].items[0].signature, "upgradeTo(address)");
+    /// assert_eq!(collisions[0].items[1].abi, 1);
+    /// ```
+    pub fn function_collisions<'a, I>(abis: I) -> Vec<Collision<Selector>>
+    where
+        I: IntoIterator<Item = &'a Self>,
+    {
+        collisions(
+            abis,
+            |abi| abi.functions().map(|f| (f.selector(), f.signature())),
+            true,
+        )
+    }
+
+    /// Returns the error selectors shared by errors with different signatures.
+    ///
+    /// Such errors cannot be told apart when decoding revert data. The same
+    /// error declared by several ABIs is not a collision.
+    pub fn error_collisions<'a, I>(abis: I) -> Vec<Collision<Selector>>
+    where
+        I: IntoIterator<Item = &'a Self>,
+    {
+        collisions(
+            abis,
+            |abi| abi.errors().map(|e| (e.selector(), e.signature())),
+            false,
+        )
+    }
+
+    /// Returns the event topics shared by events with different signatures.
+    ///
+    /// Such events cannot be told apart when decoding logs. The same event
+    /// declared by several ABIs is not a collision, and anonymous events are
+    /// ignored.
+    pub fn event_collisions<'a, I>(abis: I) -> Vec<Collision<B256>>
+    where
+        I: IntoIterator<Item = &'a Self>,
+    {
+        collisions(
+            abis,
+            |abi| {
+                abi.events()
+                    .filter(|e| !e.anonymous)
+                    .map(|e| (e.selector(), e.signature()))
+            },
+            false,
+        )
+    }
+}
+
+/// Groups the items of `abis` by selector, and returns the groups containing
+/// different signatures, or different ABIs if `across_abis` is `true`.
+fn collisions<'a, S, I, F>(
+    abis: impl IntoIterator<Item = &'a JsonAbi>,
+    mut items: F,
+    across_abis: bool,
+) -> Vec<Collision<S>>
+where
+    S: Ord,
+    I: Iterator<Item = (S, String)>,
+    F: FnMut(&'a JsonAbi) -> I,
+{
+    let mut map = BTreeMap::<S, Vec<CollidingItem>>::new();
+    for (abi, json) in abis.into_iter().enumerate() {
+        for (selector, signature) in items(json) {
+            let item = CollidingItem { abi, signature };
+            let items = map.entry(selector).or_default();
+            if !items.contains(&item) {
+                items.push(item);
+            }
+        }
+    }
+    map.into_iter()
+        .filter(|(_, items)| {
+            let first = &items[0];
+            items.iter().any(|item| {
+                item.signature != first.signature || (across_abis && item.abi != first.abi)
+            })
+        })
+        .map(|(selector, items)| Collision { selector, items })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn functions() {
+        // famous clash: both have selector 0x42966c68
+        let a = JsonAbi::parse(["function burn(uint256)", "function f()"]).unwrap();
+        let b = JsonAbi::parse(["function collate_propagate_storage(bytes16)"]).unwrap();
+        assert!(JsonAbi::function_collisions([&a]).is_empty());
+        assert!(JsonAbi::function_collisions([&a, &a.clone()])
+            .iter()
+            .all(|c| c.items.len() == 2));
+
+        let collisions = JsonAbi::function_collisions([&a, &b]);
+        assert_eq!(
+            collisions,
+            [Collision {
+                selector: Selector::new([0x42, 0x96, 0x6c, 0x68]),
+                items: vec![
+                    CollidingItem {
+                        abi: 0,
+                        signature: "burn(uint256)".into()
+                    },
+                    CollidingItem {
+                        abi: 1,
+                        signature: "collate_propagate_storage(bytes16)".into()
+                    },
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn errors_and_events() {
+        let a = JsonAbi::parse([
+            "error E(uint256)",
+            "event Transfer(address indexed, address indexed, uint256)",
+            "event Anon() anonymous",
+        ])
+        .unwrap();
+        let b = a.clone();
+        assert!(JsonAbi::error_collisions([&a, &b]).is_empty());
+        assert!(JsonAbi::event_collisions([&a, &b]).is_empty());
+        assert!(JsonAbi::function_collisions([&a, &b]).is_empty());
+
+        let c = JsonAbi::parse(["error burn(uint256)"]).unwrap();
+        let d = JsonAbi::parse(["error collate_propagate_storage(bytes16)"]).unwrap();
+        let collisions = JsonAbi::error_collisions([&a, &c, &d]);
+        assert_eq!(collisions.len(), 1);
+        assert_eq!(
+            collisions[0]
+                .items
+                .iter()
+                .map(|i| i.abi)
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+    }
+}
@@ -403,6 +403,16 @@ impl Error {
         signature(&self.name, &self.inputs, None)
     }
 
+    /// Returns this error's signature with parameter names, and with tuple
+    /// types unrolled into their named components: `$name($($type $name),*)`.
+    ///
+    /// This is meant for display purposes, such as in explorers and exports.
+    /// Use [`signature`](Self::signature) for the selector preimage.
+    #[inline]
+    pub fn unrolled_signature(&self) -> String {
+        unrolled_signature(&self.name, &self.inputs, &[])
+    }
+
     /// Computes this error's selector: `keccak256(self.signature())[..4]`
     #[inline]
     pub fn selector(&self) -> Selector {
@@ -491,6 +501,48 @@ impl Function {
         signature(&self.name, &self.inputs, Some(&self.outputs))
     }
 
+    /// Returns this function's signature with parameter names, and with tuple
+    /// types unrolled into their named components:
+    /// `$name($($type $name),*)$( returns ($($type $name),*))?`.
+    ///
+    /// This is meant for display purposes, such as in explorers and exports.
+    /// Use [`signature`](Self::signature) for the selector preimage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::Function;
+    /// let function: Function = serde_json::from_str(
+    ///     r#"{
+    ///         "type": "function",
+    ///         "name": "fill",
+    ///         "inputs": [
+    ///             {
+    ///                 "name": "orders",
+    ///                 "type": "tuple[]",
+    ///                 "components": [
+    ///                     { "name": "maker", "type": "address" },
+    ///                     { "name": "amount", "type": "uint256" }
+    ///                 ]
+    ///             },
+    ///             { "name": "sig", "type": "bytes" }
+    ///         ],
+    ///         "outputs": [{ "name": "ok", "type": "bool" }],
+    ///         "stateMutability": "nonpayable"
+    ///     }"#,
+    /// )?;
+    /// assert_eq!(
+    ///     function.unrolled_signature(),
+    ///     "fill((address maker, uint256 amount)[] orders, bytes sig) returns (bool ok)"
+    /// );
+    /// assert_eq!(function.signature(), "fill((address,uint256)[],bytes)");
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    #[inline]
+    pub fn unrolled_signature(&self) -> String {
+        unrolled_signature(&self.name, &self.inputs, &self.outputs)
+    }
+
     /// Computes this error's selector: `keccak256(self.signature())[..4]`
     #[inline]
     pub fn selector(&self) -> Selector {
@@ -550,6 +602,41 @@ impl Event {
         event_signature(&self.name, &self.inputs)
     }
 
+    /// Returns this event's full signature, including parameter names and
+    /// `indexed` and `anonymous` markers:
+    /// `$name($($type $(indexed)? $name),*)$( anonymous)?`.
+    ///
+    /// This is meant for display purposes, such as in explorers and logs.
+    /// Use [`signature`](Self::signature) for the selector preimage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::Event;
+    /// let event = Event::parse("Transfer(address indexed from, address indexed to, uint value)")?;
+    /// assert_eq!(
+    ///     event.full_signature(),
+    ///     "Transfer(address indexed from, address indexed to, uint256 value)"
+    /// );
+    /// assert_eq!(event.signature(), "Transfer(address,address,uint256)");
+    /// # Ok::<(), alloy_sol_type_parser::Error>(())
+    /// ```
+    #[inline]
+    pub fn full_signature(&self) -> String {
+        event_full_signature(&self.name, &self.inputs, self.anonymous, false)
+    }
+
+    /// Returns this event's full signature like
+    /// [`full_signature`](Self::full_signature), but with tuple types unrolled
+    /// into their named components: `Filled((address maker, uint256 amount)
+    /// indexed order)`.
+    ///
+    /// This is meant for display purposes, such as in explorers and exports.
+    #[inline]
+    pub fn unrolled_signature(&self) -> String {
+        event_full_signature(&self.name, &self.inputs, self.anonymous, true)
+    }
+
     /// Computes this event's selector: `keccak256(self.signature())`
     #[inline]
     pub fn selector(&self) -> B256 {
@@ -34,15 +34,23 @@ use serde::{Deserialize, Serialize};
 mod abi;
 pub use abi::{ContractObject, IntoItems, Items, JsonAbi};
 
+mod collisions;
+pub use collisions::{CollidingItem, Collision};
+
 mod item;
 pub use item::{AbiItem, Constructor, Error, Event, Fallback, Function, Receive};
 
+mod natspec;
+pub use natspec::{DevDoc, DevDocItem, UserDoc, UserDocItem};
+
 mod param;
 pub use param::{EventParam, Param};
 
 mod internal_type;
 pub use internal_type::InternalType;
 
+mod to_markdown;
+
 mod to_sol;
 
 pub(crate) mod utils;
@@ -0,0 +1,324 @@
+use crate::{Error, Event, Function};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+/// The developer documentation of a contract, as output by the Solidity
+/// compiler in the `devdoc` field.
+///
+/// Items are keyed by their [signature](Function::signature), and can be
+/// looked up with [`function`](Self::function), [`event`](Self::event), and
+/// [`error`](Self::error).
+///
+/// See the [NatSpec documentation][ref] for more details.
+///
+/// [ref]: https://docs.soliditylang.org/en/latest/natspec-format.html
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DevDoc {
+    /// The `@title` of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The `@author` of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// The `@dev` details of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// The documentation of the constructor and functions, indexed by their
+    /// signature. The constructor is indexed by `constructor`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub methods: BTreeMap<String, DevDocItem>,
+    /// The documentation of the events, indexed by their signature.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub events: BTreeMap<String, DevDocItem>,
+    /// The documentation of the errors, indexed by their signature.
+    ///
+    /// There may be more than one entry per signature if errors with the same
+    /// signature are declared in several places.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub errors: BTreeMap<String, Vec<DevDocItem>>,
+}
+
+/// The developer documentation of a single item in a [`DevDoc`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DevDocItem {
+    /// The `@dev` details of the item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// The `@param` documentation, indexed by parameter name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+    /// The `@return` documentation, indexed by return value name, or by
+    /// `_{index}` for unnamed return values.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub returns: BTreeMap<String, String>,
+}
+
+/// The user documentation of a contract, as output by the Solidity compiler
+/// in the `userdoc` field.
+///
+/// Items are keyed by their [signature](Function::signature), and can be
+/// looked up with [`function`](Self::function), [`event`](Self::event), and
+/// [`error`](Self::error).
+///
+/// See the [NatSpec documentation][ref] for more details.
+///
+/// [ref]: https://docs.soliditylang.org/en/latest/natspec-format.html
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UserDoc {
+    /// The `@notice` of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notice: Option<String>,
+    /// The documentation of the constructor and functions, indexed by their
+    /// signature. The constructor is indexed by `constructor`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub methods: BTreeMap<String, UserDocItem>,
+    /// The documentation of the events, indexed by their signature.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub events: BTreeMap<String, UserDocItem>,
+    /// The documentation of the errors, indexed by their signature.
+    ///
+    /// There may be more than one entry per signature if errors with the same
+    /// signature are declared in several places.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub errors: BTreeMap<String, Vec<UserDocItem>>,
+}
+
+/// The user documentation of a single item in a [`UserDoc`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UserDocItem {
+    /// The `@notice` of the item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notice: Option<String>,
+}
+
+macro_rules! lookups {
+    ($item:ty) => {
+        /// Returns the documentation of the constructor.
+        #[inline]
+        pub fn constructor(&self) -> Option<&$item> {
+            self.methods.get("constructor")
+        }
+
+        /// Returns the documentation of the given function.
+        #[inline]
+        pub fn function(&self, function: &Function) -> Option<&$item> {
+            self.methods.get(&function.signature())
+        }
+
+        /// Returns the documentation of the given event.
+        #[inline]
+        pub fn event(&self, event: &Event) -> Option<&$item> {
+            self.events.get(&event.signature())
+        }
+
+        /// Returns the documentation of the given error.
+        ///
+        /// If several entries share the error's signature, returns the first
+        /// one.
+        #[inline]
+        pub fn error(&self, error: &Error) -> Option<&$item> {
+            self.errors.get(&error.signature())?.first()
+        }
+    };
+}
+
+impl DevDoc {
+    lookups!(DevDocItem);
+}
+
+impl UserDoc {
+    lookups!(UserDocItem);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ContractObject, JsonAbi};
+
+    const CONTRACT: &str = r#"{
+        "abi": [
+            {"type":"constructor","inputs":[{"name":"owner","type":"address"}],"stateMutability":"nonpayable"},
+            {"type":"function","name":"transfer","inputs":[{"name":"to","type":"address"},{"name":"amount","type":"uint256"}],"outputs":[{"name":"","type":"bool"}],"stateMutability":"nonpayable"},
+            {"type":"event","name":"Transfer","inputs":[{"name":"from","type":"address","indexed":true},{"name":"to","type":"address","indexed":true},{"name":"amount","type":"uint256","indexed":false}],"anonymous":false},
+            {"type":"error","name":"Insufficient","inputs":[{"name":"balance","type":"uint256"}]}
+        ],
+        "devdoc": {
+            "kind": "dev",
+            "version": 1,
+            "title": "A token",
+            "author": "Alice",
+            "methods": {
+                "constructor": {"params": {"owner": "The initial owner."}},
+                "transfer(address,uint256)": {
+                    "details": "Emits a {Transfer} event.",
+                    "params": {"to": "The recipient.", "amount": "The amount."},
+                    "returns": {"_0": "Whether the transfer succeeded."}
+                }
+            },
+            "events": {
+                "Transfer(address,address,uint256)": {"params": {"from": "The sender."}}
+            },
+            "errors": {
+                "Insufficient(uint256)": [{"params": {"balance": "The balance."}}]
+            }
+        },
+        "userdoc": {
+            "kind": "user",
+            "version": 1,
+            "notice": "Transfers tokens.",
+            "methods": {
+                "transfer(address,uint256)": {"notice": "Sends `amount` tokens to `to`."}
+            },
+            "errors": {
+                "Insufficient(uint256)": [{"notice": "Not enough tokens."}]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn contract_natspec() {
+        let contract: ContractObject = serde_json::from_str(CONTRACT).unwrap();
+        let abi: &JsonAbi = contract.abi.as_ref().unwrap();
+        let devdoc = contract.devdoc.as_ref().unwrap();
+        let userdoc = contract.userdoc.as_ref().unwrap();
+        let transfer = &abi.function("transfer").unwrap()[0];
+
+        assert_eq!(devdoc.title.as_deref(), Some("A token"));
+        assert_eq!(devdoc.author.as_deref(), Some("Alice"));
+        assert_eq!(
+            devdoc.constructor().unwrap().params["owner"],
+            "The initial owner."
+        );
+
+        let doc = devdoc.function(transfer).unwrap();
+        assert_eq!(doc.details.as_deref(), Some("Emits a {Transfer} event."));
+        assert_eq!(doc.params["amount"], "The amount.");
+        assert_eq!(doc.returns["_0"], "Whether the transfer succeeded.");
+
+        let event = &abi.event("Transfer").unwrap()[0];
+        assert_eq!(devdoc.event(event).unwrap().params["from"], "The sender.");
+        let error = &abi.error("Insufficient").unwrap()[0];
+        assert_eq!(
+            devdoc.error(error).unwrap().params["balance"],
+            "The balance."
+        );
+
+        assert_eq!(userdoc.notice.as_deref(), Some("Transfers tokens."));
+        assert_eq!(
+            userdoc.function(transfer).unwrap().notice.as_deref(),
+            Some("Sends `amount` tokens to `to`.")
+        );
+        assert_eq!(
+            userdoc.error(error).unwrap().notice.as_deref(),
+            Some("Not enough tokens.")
+        );
+        assert!(userdoc.event(event).is_none());
+        assert!(userdoc.constructor().is_none());
+
+        let json = serde_json::to_string(&contract).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ContractObject>(&json).unwrap(),
+            contract
+        );
+    }
+
+    #[test]
+    fn contract_markdown() {
+        let contract: ContractObject = serde_json::from_str(CONTRACT).unwrap();
+        assert_eq!(contract.to_markdown("Token").unwrap(), MARKDOWN);
+
+        let abi = contract.abi.as_ref().unwrap();
+        let markdown = abi.to_markdown("", None, None);
+        assert!(markdown.starts_with("# Contract\n\n## Constructor\n"));
+        assert!(!markdown.contains("The initial owner."));
+        assert!(markdown.contains("| `amount` | `uint256` |  |"));
+        assert_eq!(ContractObject::default().to_markdown("Token"), None);
+    }
+
+    const MARKDOWN: &str = r#"# Token
+
+**A token**
+
+Transfers tokens.
+
+*Author: Alice*
+
+## Constructor
+
+```solidity
+constructor(address owner);
+```
+
+**Parameters**
+
+| Name | Type | Description |
+| ---- | ---- | ----------- |
+| `owner` | `address` | The initial owner. |
+
+## Functions
+
+### `transfer`
+
+```solidity
+function transfer(address to, uint256 amount) external returns (bool);
+```
+
+- Selector: `0xa9059cbb`
+- Signature: `transfer(address,uint256)`
+
+Sends `amount` tokens to `to`.
+
+Emits a {Transfer} event.
+
+**Parameters**
+
+| Name | Type | Description |
+| ---- | ---- | ----------- |
+| `to` | `address` | The recipient. |
+| `amount` | `uint256` | The amount. |
+
+**Returns**
+
+| Name | Type | Description |
+| ---- | ---- | ----------- |
+|  | `bool` | Whether the transfer succeeded. |
+
+## Events
+
+### `Transfer`
+
+```solidity
+event Transfer(address indexed from, address indexed to, uint256 amount);
+```
+
+- Topic 0: `0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef`
+- Signature: `Transfer(address,address,uint256)`
+
+**Parameters**
+
+| Name | Type | Indexed | Description |
+| ---- | ---- | ------- | ----------- |
+| `from` | `address` | yes | The sender. |
+| `to` | `address` | yes |  |
+| `amount` | `uint256` | no |  |
+
+## Errors
+
+### `Insufficient`
+
+```solidity
+error Insufficient(uint256 balance);
+```
+
+- Selector: `0x91bcc564`
+- Signature: `Insufficient(uint256)`
+
+Not enough tokens.
+
+**Parameters**
+
+| Name | Type | Description |
+| ---- | ---- | ----------- |
+| `balance` | `uint256` | The balance. |
+"#;
+}
@@ -0,0 +1,242 @@
+use crate::{
+    item::{Constructor, Error, Event, Function},
+    to_sol::{param_type, ToSol},
+    DevDoc, DevDocItem, EventParam, JsonAbi, Param, UserDoc, UserDocItem,
+};
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Renders a [`JsonAbi`] as a Markdown reference, with optional NatSpec
+/// documentation.
+pub(crate) struct Markdown<'a> {
+    pub(crate) devdoc: Option<&'a DevDoc>,
+    pub(crate) userdoc: Option<&'a UserDoc>,
+}
+
+impl Markdown<'_> {
+    pub(crate) fn render(&self, abi: &JsonAbi, name: &str, out: &mut String) {
+        out.reserve((abi.len() + 1) * 256);
+
+        out.push_str("# ");
+        out.push_str(if name.is_empty() { "Contract" } else { name });
+        out.push('\n');
+
+        if let Some(devdoc) = self.devdoc {
+            if let Some(title) = &devdoc.title {
+                paragraph(&format!("**{}**", title.trim()), out);
+            }
+        }
+        if let Some(notice) = self.userdoc.and_then(|doc| doc.notice.as_deref()) {
+            paragraph(notice, out);
+        }
+        if let Some(devdoc) = self.devdoc {
+            if let Some(details) = &devdoc.details {
+                paragraph(details, out);
+            }
+            if let Some(author) = &devdoc.author {
+                paragraph(&format!("*Author: {}*", author.trim()), out);
+            }
+        }
+
+        if let Some(constructor) = &abi.constructor {
+            out.push_str("\n## Constructor\n");
+            self.constructor(constructor, out);
+        }
+
+        if abi.functions.values().any(|f| !f.is_empty())
+            || abi.fallback.is_some()
+            || abi.receive.is_some()
+        {
+            out.push_str("\n## Functions\n");
+            for function in abi.functions() {
+                self.function(function, out);
+            }
+            if let Some(fallback) = &abi.fallback {
+                out.push_str("\n### `fallback`\n");
+                code(fallback, out);
+            }
+            if let Some(receive) = &abi.receive {
+                out.push_str("\n### `receive`\n");
+                code(receive, out);
+            }
+        }
+
+        if abi.events.values().any(|e| !e.is_empty()) {
+            out.push_str("\n## Events\n");
+            for event in abi.events() {
+                self.event(event, out);
+            }
+        }
+
+        if abi.errors.values().any(|e| !e.is_empty()) {
+            out.push_str("\n## Errors\n");
+            for error in abi.errors() {
+                self.error(error, out);
+            }
+        }
+    }
+
+    fn constructor(&self, constructor: &Constructor, out: &mut String) {
+        code(constructor, out);
+        let devdoc = self.devdoc.and_then(DevDoc::constructor);
+        docs(self.userdoc.and_then(UserDoc::constructor), devdoc, out);
+        params(&constructor.inputs, devdoc, false, out);
+    }
+
+    fn function(&self, function: &Function, out: &mut String) {
+        heading(&function.name, out);
+        code(function, out);
+        out.push('\n');
+        let _ = writeln!(out, "- Selector: `{}`", function.selector());
+        let _ = writeln!(out, "- Signature: `{}`", function.signature());
+
+        let devdoc = self.devdoc.and_then(|doc| doc.function(function));
+        docs(
+            self.userdoc.and_then(|doc| doc.function(function)),
+            devdoc,
+            out,
+        );
+        params(&function.inputs, devdoc, false, out);
+        params(&function.outputs, devdoc, true, out);
+    }
+
+    fn event(&self, event: &Event, out: &mut String) {
+        heading(&event.name, out);
+        code(event, out);
+        out.push('\n');
+        if event.anonymous {
+            out.push_str("- Anonymous\n");
+        } else {
+            let _ = writeln!(out, "- Topic 0: `{}`", event.selector());
+        }
+        let _ = writeln!(out, "- Signature: `{}`", event.signature());
+
+        let devdoc = self.devdoc.and_then(|doc| doc.event(event));
+        docs(self.userdoc.and_then(|doc| doc.event(event)), devdoc, out);
+        event_params(&event.inputs, devdoc, out);
+    }
+
+    fn error(&self, error: &Error, out: &mut String) {
+        heading(&error.name, out);
+        code(error, out);
+        out.push('\n');
+        let _ = writeln!(out, "- Selector: `{}`", error.selector());
+        let _ = writeln!(out, "- Signature: `{}`", error.signature());
+
+        let devdoc = self.devdoc.and_then(|doc| doc.error(error));
+        docs(self.userdoc.and_then(|doc| doc.error(error)), devdoc, out);
+        params(&error.inputs, devdoc, false, out);
+    }
+}
+
+fn heading(name: &str, out: &mut String) {
+    out.push_str("\n### `");
+    out.push_str(name);
+    out.push_str("`\n");
+}
+
+fn paragraph(text: &str, out: &mut String) {
+    out.push('\n');
+    out.push_str(text.trim());
+    out.push('\n');
+}
+
+/// Formats the item as a Solidity code block.
+fn code<T: ToSol>(item: &T, out: &mut String) {
+    out.push_str("\n```solidity\n");
+    item.to_sol(out);
+    out.push_str("\n```\n");
+}
+
+fn docs(userdoc: Option<&UserDocItem>, devdoc: Option<&DevDocItem>, out: &mut String) {
+    if let Some(notice) = userdoc.and_then(|doc| doc.notice.as_deref()) {
+        paragraph(notice, out);
+    }
+    if let Some(details) = devdoc.and_then(|doc| doc.details.as_deref()) {
+        paragraph(details, out);
+    }
+}
+
+/// Formats parameters or return values as a table. Return values are
+/// documented either by name or by `_{index}`.
+fn params(params: &[Param], devdoc: Option<&DevDocItem>, returns: bool, out: &mut String) {
+    if params.is_empty() {
+        return
+    }
+
+    out.push_str(if returns {
+        "\n**Returns**\n"
+    } else {
+        "\n**Parameters**\n"
+    });
+    out.push_str("\n| Name | Type | Description |\n| ---- | ---- | ----------- |\n");
+    for (i, param) in params.iter().enumerate() {
+        let doc = devdoc.and_then(|doc| {
+            let docs = if returns { &doc.returns } else { &doc.params };
+            match docs.get(&param.name) {
+                None if returns => docs.get(&format!("_{i}")),
+                doc => doc,
+            }
+        });
+        let mut ty = String::new();
+        param_type(
+            &param.ty,
+            param.internal_type.as_ref(),
+            &param.components,
+            &mut ty,
+        );
+        row(&param.name, &ty, None, doc, out);
+    }
+}
+
+fn event_params(params: &[EventParam], devdoc: Option<&DevDocItem>, out: &mut String) {
+    if params.is_empty() {
+        return
+    }
+
+    out.push_str("\n**Parameters**\n");
+    out.push_str(
+        "\n| Name | Type | Indexed | Description |\n| ---- | ---- | ------- | ----------- |\n",
+    );
+    for param in params {
+        let doc = devdoc.and_then(|doc| doc.params.get(&param.name));
+        let mut ty = String::new();
+        param_type(
+            &param.ty,
+            param.internal_type.as_ref(),
+            &param.components,
+            &mut ty,
+        );
+        row(&param.name, &ty, Some(param.indexed), doc, out);
+    }
+}
+
+fn row(name: &str, ty: &str, indexed: Option<bool>, doc: Option<&String>, out: &mut String) {
+    out.push_str("| ");
+    if !name.is_empty() {
+        out.push('`');
+        out.push_str(name);
+        out.push('`');
+    }
+    out.push_str(" | `");
+    out.push_str(ty);
+    out.push_str("` | ");
+    if let Some(indexed) = indexed {
+        out.push_str(if indexed { "yes" } else { "no" });
+        out.push_str(" | ");
+    }
+    if let Some(doc) = doc {
+        cell(doc, out);
+    }
+    out.push_str(" |\n");
+}
+
+/// Escapes text for a single table cell.
+fn cell(text: &str, out: &mut String) {
+    for (i, line) in text.trim().lines().enumerate() {
+        if i > 0 {
+            out.push_str("<br>");
+        }
+        out.push_str(&line.trim().replace('|', "\\|"));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    item::{Error, Event, Fallback, Function, Receive},
+    item::{Constructor, Error, Event, Fallback, Function, Receive},
     EventParam, InternalType, JsonAbi, Param, StateMutability,
 };
 use alloc::{collections::BTreeSet, string::String, vec::Vec};
@@ -231,6 +231,21 @@ impl ToSol for Error {
     }
 }
 
+impl ToSol for Constructor {
+    fn to_sol(&self, out: &mut String) {
+        AbiFunction::<'_, Param> {
+            kw: "constructor",
+            name: None,
+            inputs: &self.inputs,
+            visibility: None,
+            state_mutability: Some(self.state_mutability),
+            anonymous: false,
+            outputs: &[],
+        }
+        .to_sol(out);
+    }
+}
+
 impl ToSol for Fallback {
     fn to_sol(&self, out: &mut String) {
         AbiFunction::<'_, Param> {
@@ -360,6 +375,16 @@ impl ToSol for EventParam {
     }
 }
 
+/// Formats the Solidity type of a parameter, without its name.
+pub(crate) fn param_type(
+    type_name: &str,
+    internal_type: Option<&InternalType>,
+    components: &[Param],
+    out: &mut String,
+) {
+    param(type_name, internal_type, false, "", components, out);
+}
+
 fn param<'a>(
     mut type_name: &'a str,
     internal_type: Option<&'a InternalType>,
@@ -60,6 +60,85 @@ pub(crate) fn event_signature(name: &str, inputs: &[EventParam]) -> String {
     preimage
 }
 
+/// `$name($($type $(indexed)? $name),*)$( anonymous)?`
+///
+/// If `unroll` is true, tuple types are formatted as in
+/// [`unrolled_signature_raw`].
+pub(crate) fn event_full_signature(
+    name: &str,
+    inputs: &[EventParam],
+    anonymous: bool,
+    unroll: bool,
+) -> String {
+    let mut out = String::with_capacity(name.len() + 2 + inputs.len() * PARAM * 2);
+    out.push_str(name);
+    out.push('(');
+    for (i, input) in inputs.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        if unroll {
+            unrolled_type_raw(&input.ty, &input.components, &mut out);
+        } else {
+            input.selector_type_raw(&mut out);
+        }
+        if input.indexed {
+            out.push_str(" indexed");
+        }
+        if !input.name.is_empty() {
+            out.push(' ');
+            out.push_str(&input.name);
+        }
+    }
+    out.push(')');
+    if anonymous {
+        out.push_str(" anonymous");
+    }
+    out
+}
+
+/// `$name($($inputs),*)$( returns ($($outputs),*))?`, with the parameters
+/// formatted as in [`unrolled_signature_raw`].
+pub(crate) fn unrolled_signature(name: &str, inputs: &[Param], outputs: &[Param]) -> String {
+    let cap = name.len() + 2 + (inputs.len() + outputs.len()) * PARAM * 2;
+    let mut out = String::with_capacity(cap);
+    out.push_str(name);
+    unrolled_signature_raw(inputs, &mut out);
+    if !outputs.is_empty() {
+        out.push_str(" returns ");
+        unrolled_signature_raw(outputs, &mut out);
+    }
+    out
+}
+
+/// `($($type $name),*)`, where tuple types are unrolled into the same list of
+/// their components.
+pub(crate) fn unrolled_signature_raw(params: &[Param], out: &mut String) {
+    out.push('(');
+    for (i, param) in params.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        unrolled_type_raw(&param.ty, &param.components, out);
+        if !param.name.is_empty() {
+            out.push(' ');
+            out.push_str(&param.name);
+        }
+    }
+    out.push(')');
+}
+
+fn unrolled_type_raw(ty: &str, components: &[Param], out: &mut String) {
+    if components.is_empty() {
+        out.push_str(ty);
+    } else {
+        unrolled_signature_raw(components, out);
+        if let Some(suffix) = ty.strip_prefix("tuple") {
+            out.push_str(suffix);
+        }
+    }
+}
+
 /// `keccak256(preimage)[..4]`
 pub(crate) fn selector(preimage: &str) -> Selector {
     // SAFETY: splitting an array
@@ -601,3 +601,200 @@ fn fallback() {
 
     assert_ser_de!(JsonAbi, deserialized);
 }
+
+#[test]
+fn state_mutability_subsets() {
+    let mut abi = JsonAbi::parse([
+        "constructor(address owner)",
+        "function get()(uint256)",
+        "function get(uint256 id)(uint256)",
+        "function hash(bytes data)(bytes32)",
+        "function set(uint256 value)",
+        "function deposit()",
+        "event Deposit(address indexed from, uint256 amount)",
+        "error Unauthorized()",
+    ])
+    .unwrap();
+    for (name, state_mutability) in [
+        ("get", StateMutability::View),
+        ("hash", StateMutability::Pure),
+        ("deposit", StateMutability::Payable),
+    ] {
+        for f in abi.functions.get_mut(name).unwrap() {
+            f.state_mutability = state_mutability;
+        }
+    }
+
+    let mut views = abi
+        .view_functions()
+        .map(|f| f.signature())
+        .collect::<Vec<_>>();
+    views.sort();
+    assert_eq!(views, ["get()", "get(uint256)", "hash(bytes)"]);
+
+    let payable = abi
+        .payable_functions()
+        .map(|f| f.signature())
+        .collect::<Vec<_>>();
+    assert_eq!(payable, ["deposit()"]);
+
+    let read_only = abi.subset(|f| f.state_mutability <= StateMutability::View);
+    assert_eq!(read_only.len(), abi.len() - 2);
+    assert_eq!(read_only.function("get").map(<[_]>::len), Some(2));
+    assert!(read_only.function("set").is_none());
+    assert!(read_only.function("deposit").is_none());
+    assert_eq!(read_only.constructor, abi.constructor);
+    assert_eq!(read_only.events, abi.events);
+    assert_eq!(read_only.errors, abi.errors);
+
+    let overload = abi.subset(|f| f.inputs.len() == 1 && f.name == "get");
+    assert_eq!(overload.function("get").map(<[_]>::len), Some(1));
+    assert_eq!(overload.functions().count(), 1);
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn canonical_json() {
+    use alloy_json_abi::AbiItem;
+
+    let items = [
+        "constructor(address owner)",
+        "function get(uint256 id)(uint256)",
+        "function get()(uint256)",
+        "function set(uint256 value)",
+        "event Transfer(address indexed from, address indexed to, uint256 value)",
+        "event Transfer(address indexed from, address to)",
+        "error Unauthorized(address account)",
+        "error Unauthorized()",
+    ];
+    let abi = JsonAbi::parse(items).unwrap();
+    let reversed = JsonAbi::parse(items.into_iter().rev()).unwrap();
+    assert_ne!(abi, reversed);
+
+    let json = abi.to_json_canonical();
+    assert_eq!(json, reversed.to_json_canonical());
+    assert!(!json.contains('\n'));
+
+    let roundtrip = JsonAbi::from_json_str(&json).unwrap();
+    assert_eq!(roundtrip.to_json_canonical(), json);
+    let signatures = roundtrip
+        .items()
+        .map(|item| match item {
+            AbiItem::Function(f) => f.signature_full(),
+            AbiItem::Event(e) => e.signature(),
+            AbiItem::Error(e) => e.signature(),
+            _ => "constructor".into(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        signatures,
+        [
+            "constructor",
+            "get()(uint256)",
+            "get(uint256)(uint256)",
+            "set(uint256)()",
+            "Transfer(address,address)",
+            "Transfer(address,address,uint256)",
+            "Unauthorized()",
+            "Unauthorized(address)",
+        ]
+    );
+}
+
+#[test]
+fn event_full_signature() {
+    let event = Event::parse("Log((uint, bytes[])[] indexed data, address) anonymous").unwrap();
+    assert!(event.anonymous);
+    assert_eq!(
+        event.full_signature(),
+        "Log((uint256,bytes[])[] indexed data, address) anonymous"
+    );
+    assert_eq!(event.signature(), "Log((uint256,bytes[])[],address)");
+
+    let event = Event::parse("Empty()").unwrap();
+    assert_eq!(event.full_signature(), "Empty()");
+}
+
+#[test]
+fn unrolled_signatures() {
+    let abi: JsonAbi = serde_json::from_str(
+        r#"[
+            {
+                "type": "function",
+                "name": "swap",
+                "inputs": [
+                    {
+                        "name": "route",
+                        "type": "tuple",
+                        "components": [
+                            { "name": "pools", "type": "address[]" },
+                            {
+                                "name": "legs",
+                                "type": "tuple[2]",
+                                "components": [
+                                    { "name": "amount", "type": "uint256" },
+                                    { "name": "", "type": "bool" }
+                                ]
+                            }
+                        ]
+                    },
+                    { "name": "", "type": "uint256" }
+                ],
+                "outputs": [],
+                "stateMutability": "payable"
+            },
+            {
+                "type": "event",
+                "name": "Swapped",
+                "inputs": [
+                    {
+                        "name": "leg",
+                        "type": "tuple",
+                        "indexed": true,
+                        "components": [{ "name": "amount", "type": "uint256" }]
+                    },
+                    { "name": "to", "type": "address", "indexed": false }
+                ],
+                "anonymous": true
+            },
+            {
+                "type": "error",
+                "name": "Failed",
+                "inputs": [
+                    {
+                        "name": "legs",
+                        "type": "tuple[]",
+                        "components": [{ "name": "amount", "type": "uint256" }]
+                    }
+                ]
+            }
+        ]"#,
+    )
+    .unwrap();
+
+    let swap = &abi.function("swap").unwrap()[0];
+    assert_eq!(
+        swap.unrolled_signature(),
+        "swap((address[] pools, (uint256 amount, bool)[2] legs) route, uint256)"
+    );
+    assert_eq!(
+        swap.signature(),
+        "swap((address[],(uint256,bool)[2]),uint256)"
+    );
+
+    let swapped = &abi.event("Swapped").unwrap()[0];
+    assert_eq!(
+        swapped.unrolled_signature(),
+        "Swapped((uint256 amount) indexed leg, address to) anonymous"
+    );
+    assert_eq!(
+        swapped.full_signature(),
+        "Swapped((uint256) indexed leg, address to) anonymous"
+    );
+
+    let failed = &abi.error("Failed").unwrap()[0];
+    assert_eq!(
+        failed.unrolled_signature(),
+        "Failed((uint256 amount)[] legs)"
+    );
+}
@@ -30,6 +30,9 @@ int_aliases! {
     I192<192, 3>,
     I256<256, 4>,
     I512<512, 8>,
+    I1024<1024, 16>,
+    I2048<2048, 32>,
+    I4096<4096, 64>,
 }
 
 macro_rules! fixed_bytes_aliases {
@@ -57,6 +60,7 @@ fixed_bytes_aliases! {
     B512<64>,
     B1024<128>,
     B2048<256>,
+    B4096<512>,
 }
 
 /// A block hash.
@@ -75,6 +79,7 @@ pub type TxNumber = u64;
 pub type TxIndex = u64;
 
 /// Chain identifier type (introduced in EIP-155).
+#[deprecated(note = "use the `ChainId` newtype instead, which converts from and to `u64`")]
 pub type ChainId = u64;
 
 /// An account storage key.
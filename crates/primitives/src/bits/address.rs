@@ -1,4 +1,8 @@
-use crate::{aliases::U160, utils::keccak256, wrap_fixed_bytes, FixedBytes};
+use crate::{
+    aliases::U160,
+    utils::{keccak256, DisplayFn},
+    wrap_fixed_bytes, FixedBytes,
+};
 use alloc::{
     borrow::Borrow,
     string::{String, ToString},
@@ -11,7 +15,7 @@ pub enum AddressError {
     /// Error while decoding hex.
     Hex(hex::FromHexError),
 
-    /// Invalid ERC-55 checksum.
+    /// Invalid ERC-55 or ICAP checksum.
     InvalidChecksum,
 }
 
@@ -103,7 +107,7 @@ impl From<Address> for U160 {
 impl fmt::Display for Address {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut buf = [0; 42];
-        let checksum = self.to_checksum_raw(&mut buf, None);
+        let checksum = self.to_checksum_buffer(&mut buf, None);
         if f.alternate() {
             // If the alternate flag is set, use middle-out compression
             // "0x" + first 4 bytes + "…" + last 4 bytes
@@ -189,7 +193,7 @@ impl Address {
             }
 
             let address: Address = s.parse()?;
-            if s == address.to_checksum_raw(&mut [0; 42], chain_id) {
+            if s == address.to_checksum_buffer(&mut [0; 42], chain_id) {
                 Ok(address)
             } else {
                 Err(AddressError::InvalidChecksum)
@@ -275,6 +279,88 @@ impl Address {
         unsafe { str::from_utf8_unchecked(buf) }
     }
 
+    /// Encodes an Ethereum address to its [EIP-55] checksum into a stack
+    /// buffer, without allocating.
+    ///
+    /// This is the same as [`to_checksum_raw`](Self::to_checksum_raw), but
+    /// the buffer's length is checked at compile time.
+    ///
+    /// You can optionally specify an [EIP-155 chain ID] to encode the address
+    /// using [EIP-1191].
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    /// [EIP-155 chain ID]: https://eips.ethereum.org/EIPS/eip-155
+    /// [EIP-1191]: https://eips.ethereum.org/EIPS/eip-1191
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// let mut buf = [0; 42];
+    ///
+    /// let checksummed: &str = address.to_checksum_buffer(&mut buf, None);
+    /// assert_eq!(checksummed, "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+    ///
+    /// let checksummed: &str = address.to_checksum_buffer(&mut buf, Some(1));
+    /// assert_eq!(checksummed, "0xD8Da6bf26964Af9d7EEd9e03e53415d37AA96045");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_checksum_buffer<'a>(&self, buf: &'a mut [u8; 42], chain_id: Option<u64>) -> &'a str {
+        self.to_checksum_raw(buf, chain_id)
+    }
+
+    /// Returns an adapter which displays the [EIP-55] checksummed address in
+    /// the truncated `0x1234…abcd` form, as with the alternate
+    /// [`Display`](fmt::Display) flag (`{:#}`).
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::address;
+    /// let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// assert_eq!(address.display_short().to_string(), "0xd8dA…6045");
+    /// ```
+    #[inline]
+    pub fn display_short(&self) -> impl fmt::Display + '_ {
+        DisplayFn(move |f: &mut fmt::Formatter<'_>| write!(f, "{self:#}"))
+    }
+
+    /// Returns an adapter which displays the [EIP-55] checksummed address,
+    /// without allocating like [`to_checksum`](Self::to_checksum) does.
+    ///
+    /// You can optionally specify an [EIP-155 chain ID] to encode the address
+    /// using [EIP-1191]. Without one, this is the same as the
+    /// [`Display`](fmt::Display) implementation.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    /// [EIP-155 chain ID]: https://eips.ethereum.org/EIPS/eip-155
+    /// [EIP-1191]: https://eips.ethereum.org/EIPS/eip-1191
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::address;
+    /// let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// assert_eq!(
+    ///     format!("{}", address.display_checksum(None)),
+    ///     "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"
+    /// );
+    /// assert_eq!(
+    ///     format!("{}", address.display_checksum(Some(1))),
+    ///     "0xD8Da6bf26964Af9d7EEd9e03e53415d37AA96045"
+    /// );
+    /// ```
+    #[inline]
+    pub fn display_checksum(&self, chain_id: Option<u64>) -> impl fmt::Display + '_ {
+        DisplayFn(move |f: &mut fmt::Formatter<'_>| {
+            f.write_str(self.to_checksum_buffer(&mut [0; 42], chain_id))
+        })
+    }
+
     /// Encodes an Ethereum address to its [EIP-55] checksum.
     ///
     /// You can optionally specify an [EIP-155 chain ID] to encode the address
@@ -299,7 +385,108 @@ impl Address {
     #[inline]
     #[must_use]
     pub fn to_checksum(&self, chain_id: Option<u64>) -> String {
-        self.to_checksum_raw(&mut [0u8; 42], chain_id).to_string()
+        self.to_checksum_buffer(&mut [0u8; 42], chain_id)
+            .to_string()
+    }
+
+    /// Encodes an Ethereum address in the [ICAP] format, such as
+    /// `XE65GB6LDNXYOFTX0NSV3FUWKOWIXAMJK36`.
+    ///
+    /// This is the address in base 36, padded to 30 digits, preceded by the
+    /// `XE` country code and two IBAN (ISO 13616) check digits. Addresses
+    /// which do not fit in 30 digits are encoded with 31, in the non
+    /// IBAN-compliant "basic" format.
+    ///
+    /// [ICAP]: https://github.com/ethereum/wiki/wiki/Inter-exchange-Client-Address-Protocol-(ICAP)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// let address = address!("8ba1f109551bd432803012645ac136ddd64dba72");
+    /// assert_eq!(address.to_icap(), "XE65GB6LDNXYOFTX0NSV3FUWKOWIXAMJK36");
+    /// ```
+    #[must_use]
+    pub fn to_icap(&self) -> String {
+        let mut digits: alloc::vec::Vec<u8> = U160::from_be_bytes(self.0 .0)
+            .to_base_le(36)
+            .map(|digit| {
+                char::from_digit(digit as u32, 36)
+                    .unwrap()
+                    .to_ascii_uppercase() as u8
+            })
+            .collect();
+        digits.resize(digits.len().max(ICAP_DIRECT_DIGITS), b'0');
+        digits.reverse();
+
+        let check = 98 - iban_mod97(digits.iter().chain(b"XE00"));
+        let mut icap = String::with_capacity(4 + digits.len());
+        icap.push_str("XE");
+        icap.push(char::from(b'0' + check / 10));
+        icap.push(char::from(b'0' + check % 10));
+        // SAFETY: all digits are ASCII
+        icap.push_str(unsafe { str::from_utf8_unchecked(&digits) });
+        icap
+    }
+
+    /// Parses an Ethereum address in the [ICAP] format, verifying its IBAN
+    /// (ISO 13616) check digits. See [`to_icap`](Self::to_icap) for more
+    /// details.
+    ///
+    /// Both the "direct" (30 digits) and "basic" (31 digits) formats are
+    /// accepted, case-insensitively.
+    ///
+    /// [ICAP]: https://github.com/ethereum/wiki/wiki/Inter-exchange-Client-Address-Protocol-(ICAP)
+    ///
+    /// # Errors
+    ///
+    /// This method returns an [`AddressError::Hex`] error if the provided
+    /// string does not have the length or characters of an ICAP address, or
+    /// does not fit in an address, and an [`AddressError::InvalidChecksum`]
+    /// error if its check digits are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// let address = Address::parse_icap("XE65GB6LDNXYOFTX0NSV3FUWKOWIXAMJK36").unwrap();
+    /// assert_eq!(
+    ///     address,
+    ///     address!("8ba1f109551bd432803012645ac136ddd64dba72")
+    /// );
+    ///
+    /// assert!(Address::parse_icap("XE66GB6LDNXYOFTX0NSV3FUWKOWIXAMJK36").is_err());
+    /// ```
+    pub fn parse_icap<S: AsRef<str>>(s: S) -> Result<Self, AddressError> {
+        fn parse_icap(s: &str) -> Result<Address, AddressError> {
+            if s.len() != 4 + ICAP_DIRECT_DIGITS && s.len() != 5 + ICAP_DIRECT_DIGITS {
+                return Err(hex::FromHexError::InvalidStringLength.into())
+            }
+            let invalid = s.char_indices().find(|&(index, c)| match index {
+                0 => !c.eq_ignore_ascii_case(&'X'),
+                1 => !c.eq_ignore_ascii_case(&'E'),
+                2 | 3 => !c.is_ascii_digit(),
+                _ => !c.is_ascii_alphanumeric(),
+            });
+            if let Some((index, c)) = invalid {
+                return Err(hex::FromHexError::InvalidHexCharacter { c, index }.into())
+            }
+
+            let s = s.as_bytes();
+
+            if iban_mod97(s[4..].iter().chain(&s[..4])) != 1 {
+                return Err(AddressError::InvalidChecksum)
+            }
+
+            // SAFETY: all bytes are ASCII
+            let digits = unsafe { str::from_utf8_unchecked(&s[4..]) };
+            // only the basic format can encode values that overflow 160 bits
+            U160::from_str_radix(digits, 36)
+                .map(Into::into)
+                .map_err(|_| hex::FromHexError::InvalidStringLength.into())
+        }
+
+        parse_icap(s.as_ref())
     }
 
     /// Computes the `create` address for this address and nonce:
@@ -411,18 +598,44 @@ impl Address {
 
     // non-generic inner function
     fn _create2(&self, salt: &[u8; 32], init_code_hash: &[u8; 32]) -> Self {
+        let mut bytes = self.create2_preimage(init_code_hash);
+        bytes[CREATE2_SALT].copy_from_slice(salt);
+        let hash = keccak256(bytes);
+        Self::from_word(hash)
+    }
+
+    /// Returns the `CREATE2` preimage `0xff ++ address ++ salt ++
+    /// init_code_hash`, with a zero salt which is to be written at
+    /// [`CREATE2_SALT`].
+    #[inline]
+    pub(crate) fn create2_preimage(&self, init_code_hash: &[u8; 32]) -> [u8; 85] {
         // note: creating a temporary buffer and copying everything over performs
         // much better than calling `Keccak::update` multiple times
         let mut bytes = [0; 85];
         bytes[0] = 0xff;
         bytes[1..21].copy_from_slice(self.as_slice());
-        bytes[21..53].copy_from_slice(salt);
         bytes[53..85].copy_from_slice(init_code_hash);
-        let hash = keccak256(bytes);
-        Self::from_word(hash)
+        bytes
     }
 }
 
+/// The range of the salt in [`Address::create2_preimage`].
+pub(crate) const CREATE2_SALT: core::ops::Range<usize> = 21..53;
+
+/// The number of base 36 digits of a "direct" ICAP address.
+const ICAP_DIRECT_DIGITS: usize = 30;
+
+/// Computes the ISO 7064 MOD 97-10 remainder of an IBAN, already rearranged
+/// with its first 4 characters at the end. Letters count as 10 to 35.
+fn iban_mod97<'a>(chars: impl IntoIterator<Item = &'a u8>) -> u8 {
+    chars
+        .into_iter()
+        .fold(0u32, |rem, &c| match c.to_ascii_uppercase() {
+            c @ b'0'..=b'9' => (rem * 10 + u32::from(c - b'0')) % 97,
+            c => (rem * 100 + u32::from(c - b'A' + 10)) % 97,
+        }) as u8
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -530,6 +743,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn icap() {
+        for address in [
+            Address::ZERO,
+            Address::with_last_byte(1),
+            Address::repeat_byte(0x11),
+            Address::repeat_byte(0xff),
+        ] {
+            let icap = address.to_icap();
+            assert!(icap.starts_with("XE"));
+            assert_eq!(Address::parse_icap(&icap).unwrap(), address);
+            assert_eq!(
+                Address::parse_icap(icap.to_ascii_lowercase()).unwrap(),
+                address
+            );
+        }
+        assert_eq!(Address::ZERO.to_icap().len(), 34);
+        assert_eq!(Address::repeat_byte(0xff).to_icap().len(), 35);
+
+        let icap = "XE65GB6LDNXYOFTX0NSV3FUWKOWIXAMJK36";
+        assert!(Address::parse_icap(icap).is_ok());
+        assert!(matches!(
+            Address::parse_icap("XE65GB6LDNXYOFTX0NSV3FUWKOWIXAMJK37"),
+            Err(AddressError::InvalidChecksum)
+        ));
+        for (invalid, c, index) in [
+            ("DE65GB6LDNXYOFTX0NSV3FUWKOWIXAMJK36", 'D', 0),
+            ("XE6AGB6LDNXYOFTX0NSV3FUWKOWIXAMJK36", 'A', 3),
+            ("XE65GB6LDNXYOFTX0NSV3FUWKOWIXAMJK3_", '_', 34),
+        ] {
+            assert!(matches!(
+                Address::parse_icap(invalid),
+                Err(AddressError::Hex(hex::FromHexError::InvalidHexCharacter { c: c2, index: i2 }))
+                    if c2 == c && i2 == index
+            ));
+        }
+        for invalid in ["", "XE65", "XE65GB6LDNXYOFTX0NSV3FUWKOWIXAMJK360"] {
+            assert!(matches!(
+                Address::parse_icap(invalid),
+                Err(AddressError::Hex(hex::FromHexError::InvalidStringLength))
+            ));
+        }
+    }
+
     // https://ethereum.stackexchange.com/questions/760/how-is-the-address-of-an-ethereum-contract-computed
     #[test]
     #[cfg(feature = "rlp")]
@@ -145,6 +145,115 @@ impl Bloom {
         }
         bloom
     }
+
+    /// Combines the given bloom filters with bitwise OR, e.g. to compute a
+    /// block's logs bloom from its receipts' blooms.
+    ///
+    /// This is also available through the [`FromIterator`] implementations.
+    pub fn combine<I>(blooms: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        let mut bloom = Self::ZERO;
+        for other in blooms {
+            bloom |= *other.borrow();
+        }
+        bloom
+    }
+
+    /// Estimates the probability that a bloom filter with `items` accrued
+    /// inputs contains an input that was not accrued.
+    ///
+    /// This is `(1 - (1 - 1/m)^(k*n))^k`, where `m` is [`BLOOM_SIZE_BITS`], `k`
+    /// is [`BLOOM_BITS_PER_ITEM`] and `n` is `items`. For logs blooms, each
+    /// log accrues its address and each of its topics.
+    pub fn false_positive_probability(items: usize) -> f64 {
+        let bit_unset = 1.0 - 1.0 / BLOOM_SIZE_BITS as f64;
+        let bit_set = 1.0 - powu(bit_unset, (BLOOM_BITS_PER_ITEM as u64) * items as u64);
+        powu(bit_set, BLOOM_BITS_PER_ITEM as u64)
+    }
+
+    /// Returns true if this logs bloom possibly contains a log matching the
+    /// given filter, using the same semantics as `eth_getLogs`, admitting
+    /// false positives.
+    ///
+    /// - `addresses` matches if it is empty, or if any of the addresses is
+    ///   contained;
+    /// - `topic_sets` is a list of alternatives for each topic position, and
+    ///   matches if every position is empty or has any of its topics contained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{Address, Bloom, B256};
+    ///
+    /// let address = Address::repeat_byte(0x11);
+    /// let topic = B256::repeat_byte(0x22);
+    /// let bloom = Bloom::logs_bloom([(address, [topic])]);
+    ///
+    /// let other = B256::repeat_byte(0x33);
+    /// assert!(bloom.matches_filter([address], [vec![], vec![other, topic]]));
+    /// assert!(bloom.matches_filter([] as [Address; 0], [] as [Vec<B256>; 0]));
+    /// assert!(!bloom.matches_filter([address], [vec![other]]));
+    /// ```
+    pub fn matches_filter<A, I, TS, T>(&self, addresses: A, topic_sets: I) -> bool
+    where
+        A: IntoIterator,
+        A::Item: Borrow<[u8; 20]>,
+        I: IntoIterator<Item = TS>,
+        TS: IntoIterator<Item = T>,
+        T: Borrow<[u8; 32]>,
+    {
+        self.contains_any(addresses)
+            && topic_sets
+                .into_iter()
+                .all(|topics| self.contains_any(topics))
+    }
+
+    /// Returns true if `items` is empty, or if any of its items is possibly
+    /// contained.
+    fn contains_any<I, const N: usize>(&self, items: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: Borrow<[u8; N]>,
+    {
+        let mut empty = true;
+        for item in items {
+            if self.contains_input(BloomInput::Raw(item.borrow())) {
+                return true
+            }
+            empty = false;
+        }
+        empty
+    }
+}
+
+impl FromIterator<Bloom> for Bloom {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Bloom>>(iter: I) -> Self {
+        Self::combine(iter)
+    }
+}
+
+impl<'a> FromIterator<&'a Bloom> for Bloom {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = &'a Bloom>>(iter: I) -> Self {
+        Self::combine(iter)
+    }
+}
+
+/// `base.powi(exp)`, which is not available in `core`.
+fn powu(mut base: f64, mut exp: u64) -> f64 {
+    let mut acc = 1.0;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc *= base;
+        }
+        base *= base;
+        exp >>= 1;
+    }
+    acc
 }
 
 const fn log2(x: usize) -> usize {
@@ -197,4 +306,48 @@ mod tests {
 
         assert_eq!(my_bloom, bloom);
     }
+
+    #[test]
+    fn combine() {
+        let a = Bloom::from(BloomInput::Raw(b"a"));
+        let b = Bloom::from(BloomInput::Raw(b"b"));
+        let combined = Bloom::combine([a, b]);
+        assert_eq!(combined, a | b);
+        assert_eq!([a, b].iter().collect::<Bloom>(), combined);
+        assert_eq!([a, b].into_iter().collect::<Bloom>(), combined);
+        assert_eq!(Bloom::combine([] as [Bloom; 0]), Bloom::ZERO);
+    }
+
+    #[test]
+    fn false_positive_probability() {
+        assert_eq!(Bloom::false_positive_probability(0), 0.0);
+        let one = Bloom::false_positive_probability(1);
+        let expected = (1.0 - (1.0 - 1.0 / 2048.0f64).powi(3)).powi(3);
+        assert!((one - expected).abs() < 1e-15, "{one} != {expected}");
+        let many = Bloom::false_positive_probability(1000);
+        let expected = (1.0 - (-3000.0f64 / 2048.0).exp()).powi(3);
+        assert!((many - expected).abs() < 1e-3, "{many} != {expected}");
+        assert!(Bloom::false_positive_probability(100_000) > 0.999);
+    }
+
+    #[test]
+    fn matches_filter() {
+        let address = [0x11; 20];
+        let topic0 = [0x22; 32];
+        let topic1 = [0x33; 32];
+        let other_address = [0x44; 20];
+        let other_topic = [0x55; 32];
+        let bloom = Bloom::logs_bloom([(address, [topic0, topic1])]);
+
+        let no_topics: [&[[u8; 32]]; 0] = [];
+        assert!(bloom.matches_filter([] as [[u8; 20]; 0], no_topics));
+        assert!(bloom.matches_filter([address], no_topics));
+        assert!(bloom.matches_filter([other_address, address], no_topics));
+        assert!(!bloom.matches_filter([other_address], no_topics));
+
+        assert!(bloom.matches_filter([address], [&[topic0][..], &[topic1]]));
+        assert!(bloom.matches_filter([address], [&[][..], &[other_topic, topic1]]));
+        assert!(!bloom.matches_filter([address], [&[topic0][..], &[other_topic]]));
+        assert!(!bloom.matches_filter([other_address], [&[topic0][..]]));
+    }
 }
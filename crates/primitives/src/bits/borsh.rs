@@ -0,0 +1,49 @@
+use super::FixedBytes;
+use borsh::{io, BorshDeserialize, BorshSerialize};
+
+impl<const N: usize> BorshSerialize for FixedBytes<N> {
+    #[inline]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.0)
+    }
+}
+
+impl<const N: usize> BorshDeserialize for FixedBytes<N> {
+    #[inline]
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = [0u8; N];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, Bloom};
+
+    #[test]
+    fn roundtrip() {
+        let bytes = FixedBytes([0, 0, 0, 0, 1, 35, 69, 103, 137, 171, 205, 239]);
+        let bin = borsh::to_vec(&bytes).unwrap();
+        assert_eq!(bin, bytes.0);
+        assert_eq!(FixedBytes::<12>::try_from_slice(&bin).unwrap(), bytes);
+
+        let address = Address::repeat_byte(0x11);
+        let bin = borsh::to_vec(&address).unwrap();
+        assert_eq!(bin, address.0 .0);
+        assert_eq!(Address::try_from_slice(&bin).unwrap(), address);
+
+        let bloom = Bloom::repeat_byte(0x22);
+        assert_eq!(
+            Bloom::try_from_slice(&borsh::to_vec(&bloom).unwrap()).unwrap(),
+            bloom
+        );
+    }
+
+    #[test]
+    fn invalid_length() {
+        assert!(FixedBytes::<4>::try_from_slice(&[1, 2, 3]).is_err());
+        assert!(FixedBytes::<4>::try_from_slice(&[1, 2, 3, 4, 5]).is_err());
+    }
+}
@@ -0,0 +1,270 @@
+//! Compile-time parsing of [`address!`](crate::address!) literals.
+//!
+//! `const fn`s cannot call into `tiny-keccak`, so this contains a minimal
+//! `const` Keccak-256, which is only meant for hashing the 40 hex characters of
+//! an address at compile time.
+
+/// Parses the hex characters of an address literal, with an optional `0x`
+/// prefix and ignoring whitespace, verifying its [EIP-55] checksum if it is
+/// mixed-case.
+///
+/// # Panics
+///
+/// Panics if the literal is not 20 hex-encoded bytes, or if its checksum is
+/// invalid. In `const` contexts this is a compile-time error.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+#[doc(hidden)]
+pub const fn parse_address_literal(s: &str) -> [u8; 20] {
+    let s = s.as_bytes();
+    let mut i = if s.len() >= 2 && s[0] == b'0' && s[1] == b'x' {
+        2
+    } else {
+        0
+    };
+
+    let mut chars = [0u8; 40];
+    let mut len = 0;
+    let (mut upper, mut lower) = (false, false);
+    while i < s.len() {
+        let c = s[i];
+        i += 1;
+        if c.is_ascii_whitespace() {
+            continue
+        }
+        if !c.is_ascii_hexdigit() {
+            panic!("invalid hex character in address literal")
+        }
+        if len == 40 {
+            panic!("address literal is longer than 20 bytes")
+        }
+        upper |= c.is_ascii_uppercase();
+        lower |= c.is_ascii_lowercase();
+        chars[len] = c.to_ascii_lowercase();
+        len += 1;
+    }
+    if len != 40 {
+        panic!("address literal is shorter than 20 bytes")
+    }
+
+    // all-lowercase and all-uppercase literals are not checksummed
+    if upper && lower {
+        let hash = keccak256(&chars);
+        let mut i = 0;
+        // skip the prefix again, to compare the original characters
+        let mut j = if s.len() >= 2 && s[0] == b'0' && s[1] == b'x' {
+            2
+        } else {
+            0
+        };
+        while i < 40 {
+            let c = s[j];
+            j += 1;
+            if c.is_ascii_whitespace() {
+                continue
+            }
+            let nibble = if i % 2 == 0 {
+                hash[i / 2] >> 4
+            } else {
+                hash[i / 2] & 0xf
+            };
+            if c.is_ascii_alphabetic() && c.is_ascii_uppercase() != (nibble >= 8) {
+                panic!("invalid EIP-55 checksum in address literal; use all lowercase to skip it")
+            }
+            i += 1;
+        }
+    }
+
+    let mut bytes = [0u8; 20];
+    let mut i = 0;
+    while i < 20 {
+        bytes[i] = (hex_value(chars[2 * i]) << 4) | hex_value(chars[2 * i + 1]);
+        i += 1;
+    }
+    bytes
+}
+
+const fn hex_value(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        _ => c - b'a' + 10,
+    }
+}
+
+/// The Keccak-256 rate, in bytes.
+const RATE: usize = 136;
+
+const RC: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+const RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+const PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// `const` Keccak-256.
+const fn keccak256(input: &[u8]) -> [u8; 32] {
+    let mut state = [0u64; 25];
+    let mut i = 0;
+    // absorb the full blocks, then the padded last one
+    loop {
+        let block_len = if input.len() - i >= RATE {
+            RATE
+        } else {
+            input.len() - i
+        };
+        let mut j = 0;
+        while j < block_len {
+            state[j / 8] ^= (input[i + j] as u64) << (8 * (j % 8));
+            j += 1;
+        }
+        i += block_len;
+        if block_len < RATE {
+            state[block_len / 8] ^= 0x01 << (8 * (block_len % 8));
+            state[(RATE - 1) / 8] ^= 0x80 << (8 * ((RATE - 1) % 8));
+            state = keccak_f(state);
+            break
+        }
+        state = keccak_f(state);
+    }
+
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        out[i] = (state[i / 8] >> (8 * (i % 8))) as u8;
+        i += 1;
+    }
+    out
+}
+
+/// The Keccak-f\[1600\] permutation.
+const fn keccak_f(mut a: [u64; 25]) -> [u64; 25] {
+    let mut round = 0;
+    while round < 24 {
+        // θ
+        let mut c = [0u64; 5];
+        let mut x = 0;
+        while x < 5 {
+            c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+            x += 1;
+        }
+        x = 0;
+        while x < 5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            let mut y = 0;
+            while y < 25 {
+                a[y + x] ^= d;
+                y += 5;
+            }
+            x += 1;
+        }
+
+        // ρ and π
+        let mut last = a[1];
+        let mut i = 0;
+        while i < 24 {
+            let tmp = a[PI[i]];
+            a[PI[i]] = last.rotate_left(RHO[i]);
+            last = tmp;
+            i += 1;
+        }
+
+        // χ
+        let mut y = 0;
+        while y < 25 {
+            let row = [a[y], a[y + 1], a[y + 2], a[y + 3], a[y + 4]];
+            x = 0;
+            while x < 5 {
+                a[y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+                x += 1;
+            }
+            y += 5;
+        }
+
+        // ι
+        a[0] ^= RC[round];
+        round += 1;
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn const_keccak256() {
+        for len in [0, 1, 40, RATE - 1, RATE, RATE + 1, 3 * RATE + 7] {
+            let input: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            assert_eq!(keccak256(&input), crate::keccak256(&input).0, "{len}");
+        }
+    }
+
+    #[test]
+    fn address_literal() {
+        let address = Address::repeat_byte(0xab);
+        let checksummed = address.to_checksum(None);
+        assert_eq!(parse_address_literal(&checksummed), address.0 .0);
+        assert_eq!(parse_address_literal(&checksummed[2..]), address.0 .0);
+        assert_eq!(
+            parse_address_literal(&checksummed.to_lowercase()),
+            address.0 .0
+        );
+        assert_eq!(
+            parse_address_literal(&checksummed[2..].to_uppercase()),
+            address.0 .0
+        );
+        assert_eq!(
+            parse_address_literal("abababababababababab abababababababababab"),
+            address.0 .0
+        );
+    }
+
+    #[test]
+    #[should_panic = "invalid EIP-55 checksum"]
+    fn address_literal_bad_checksum() {
+        // the first letter's case is flipped
+        parse_address_literal("0xD8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+    }
+
+    #[test]
+    #[should_panic = "shorter than 20 bytes"]
+    fn address_literal_short() {
+        parse_address_literal("0x1234");
+    }
+
+    #[test]
+    #[should_panic = "invalid hex character"]
+    fn address_literal_invalid_char() {
+        parse_address_literal("0xg8da6bf26964af9d7eed9e03e53415d37aa96045");
+    }
+}
@@ -0,0 +1,41 @@
+//! `defmt` support for fixed-size byte arrays.
+//!
+//! `Uint` is defined in `ruint`, which does not support `defmt`, so this crate
+//! cannot implement `Format` for it.
+
+use super::FixedBytes;
+use defmt::{write, Format, Formatter};
+
+/// Formats as a `0x`-prefixed lowercase hex string, like [`LowerHex`].
+///
+/// [`LowerHex`]: core::fmt::LowerHex
+impl<const N: usize> Format for FixedBytes<N> {
+    fn format(&self, f: Formatter<'_>) {
+        write_hex(f, &self.0)
+    }
+}
+
+/// Writes `bytes` as a `0x`-prefixed lowercase hex string.
+pub(crate) fn write_hex(f: Formatter<'_>, bytes: &[u8]) {
+    write!(f, "0x");
+    for byte in bytes {
+        write!(f, "{=u8:02x}", byte);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, Bloom, Bytes, FixedBytes, Function, B256};
+
+    fn assert_format<T: defmt::Format>() {}
+
+    #[test]
+    fn impls() {
+        assert_format::<Address>();
+        assert_format::<B256>();
+        assert_format::<Bloom>();
+        assert_format::<Bytes>();
+        assert_format::<FixedBytes<0>>();
+        assert_format::<Function>();
+    }
+}
@@ -0,0 +1,114 @@
+//! Diesel support for fixed-size byte arrays.
+//!
+//! [`Uint`](ruint::Uint) maps to [`Numeric`](crate::diesel_types::Numeric)
+//! instead, see the [`diesel_types`](crate::diesel_types) module.
+
+use super::FixedBytes;
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql},
+    expression::AsExpression,
+    internal::derives::as_expression::Bound,
+    serialize::{self, Output, ToSql},
+    sql_types::{Binary, Nullable, SingleValue},
+    Queryable,
+};
+
+/// `FixedBytes<N>` maps to the `Binary` SQL type (`BYTEA` in Postgres).
+impl<const N: usize, Db: Backend> ToSql<Binary, Db> for FixedBytes<N>
+where
+    [u8]: ToSql<Binary, Db>,
+{
+    #[inline]
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Db>) -> serialize::Result {
+        <[u8] as ToSql<Binary, Db>>::to_sql(&self.0, out)
+    }
+}
+
+impl<const N: usize, Db: Backend> ToSql<Nullable<Binary>, Db> for FixedBytes<N>
+where
+    Self: ToSql<Binary, Db>,
+{
+    #[inline]
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Db>) -> serialize::Result {
+        ToSql::<Binary, Db>::to_sql(self, out)
+    }
+}
+
+impl<const N: usize, Db: Backend> FromSql<Binary, Db> for FixedBytes<N>
+where
+    *const [u8]: FromSql<Binary, Db>,
+{
+    #[inline]
+    fn from_sql(bytes: Db::RawValue<'_>) -> deserialize::Result<Self> {
+        let bytes = <*const [u8] as FromSql<Binary, Db>>::from_sql(bytes)?;
+        // SAFETY: the pointer is valid for the lifetime of the raw value, which
+        // outlives this function. This is how Diesel implements `Vec<u8>`.
+        let bytes = unsafe { &*bytes };
+        Ok(Self::try_from(bytes)?)
+    }
+}
+
+impl<const N: usize, Db: Backend, St: SingleValue> Queryable<St, Db> for FixedBytes<N>
+where
+    Self: FromSql<St, Db>,
+{
+    type Row = Self;
+
+    #[inline]
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+macro_rules! impl_as_expression {
+    ($($t:ty),+) => {$(
+        impl<const N: usize> AsExpression<Binary> for $t {
+            type Expression = Bound<Binary, Self>;
+
+            #[inline]
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+
+        impl<const N: usize> AsExpression<Nullable<Binary>> for $t {
+            type Expression = Bound<Nullable<Binary>, Self>;
+
+            #[inline]
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+    )+};
+}
+
+impl_as_expression!(FixedBytes<N>, &FixedBytes<N>, &&FixedBytes<N>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{diesel_types::tests::roundtrip, Address, FixedBytes, B256};
+    use diesel::{
+        deserialize::FromSql,
+        pg::{Pg, PgValue},
+        sql_types::Binary,
+    };
+
+    #[test]
+    fn binary() {
+        let hash = B256::repeat_byte(0x11);
+        assert_eq!(roundtrip::<Binary, _>(&hash), hash.as_slice());
+
+        let address = Address::repeat_byte(0x22);
+        assert_eq!(roundtrip::<Binary, _>(&address), address.as_slice());
+
+        assert_eq!(roundtrip::<Binary, _>(&FixedBytes::<0>::ZERO), [0u8; 0]);
+    }
+
+    #[test]
+    fn binary_wrong_length() {
+        let oid = core::num::NonZeroU32::new(1).unwrap();
+        let value = PgValue::new(&[0; 32], &oid);
+        assert!(<Address as FromSql<Binary, Pg>>::from_sql(value).is_err());
+    }
+}
@@ -1,4 +1,7 @@
-use crate::aliases;
+use crate::{
+    aliases,
+    utils::{fmt_hex_short, DisplayFn},
+};
 use core::{fmt, iter, ops, str};
 use derive_more::{Deref, DerefMut, From, Index, IndexMut, IntoIterator};
 
@@ -171,6 +174,47 @@ fixed_bytes_uint_conversions! {
     aliases::U512 => aliases::B512,
     aliases::I512 => aliases::B512,
 
+    aliases::U1024 => aliases::B1024,
+    aliases::I1024 => aliases::B1024,
+
+    aliases::U2048 => aliases::B2048,
+    aliases::I2048 => aliases::B2048,
+
+    aliases::U4096 => aliases::B4096,
+    aliases::I4096 => aliases::B4096,
+}
+
+impl aliases::B64 {
+    /// Converts a `u64` into its big-endian byte representation, as used by
+    /// block nonces.
+    ///
+    /// This is the `const` equivalent of `B64::from(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{b64, B64};
+    /// assert_eq!(B64::from_u64(0x42), b64!("0000000000000042"));
+    /// ```
+    #[inline]
+    pub const fn from_u64(value: u64) -> Self {
+        Self(value.to_be_bytes())
+    }
+
+    /// Interprets the bytes as a big-endian `u64`, as used by block nonces.
+    ///
+    /// This is the `const` equivalent of `u64::from(self)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::b64;
+    /// assert_eq!(b64!("0000000000000042").to_u64(), 0x42);
+    /// ```
+    #[inline]
+    pub const fn to_u64(self) -> u64 {
+        u64::from_be_bytes(self.0)
+    }
 }
 
 impl<const N: usize> From<FixedBytes<N>> for [u8; N] {
@@ -304,6 +348,16 @@ impl<const N: usize> ops::BitXorAssign for FixedBytes<N> {
     }
 }
 
+impl<const N: usize> ops::Not for FixedBytes<N> {
+    type Output = Self;
+
+    #[inline]
+    fn not(mut self) -> Self::Output {
+        self.iter_mut().for_each(|byte| *byte = !*byte);
+        self
+    }
+}
+
 impl<const N: usize> str::FromStr for FixedBytes<N> {
     type Err = hex::FromHexError;
 
@@ -386,10 +440,13 @@ impl<const N: usize> FixedBytes<N> {
     }
 
     /// Creates a new [`FixedBytes`] with the given random number generator.
-    #[cfg(feature = "rand")]
+    ///
+    /// This only requires [`RngCore`](rand_core::RngCore), so it is available
+    /// in `no_std` environments with just the `rand_core` feature.
+    #[cfg(feature = "rand_core")]
     #[inline]
     #[doc(alias = "random_using")]
-    pub fn random_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+    pub fn random_with<R: rand_core::RngCore + ?Sized>(rng: &mut R) -> Self {
         let mut bytes = Self::ZERO;
         bytes.randomize_with(rng);
         bytes
@@ -421,9 +478,9 @@ impl<const N: usize> FixedBytes<N> {
     }
 
     /// Fills this [`FixedBytes`] with the given random number generator.
-    #[cfg(feature = "rand")]
+    #[cfg(feature = "rand_core")]
     #[doc(alias = "randomize_using")]
-    pub fn randomize_with<R: rand::Rng + ?Sized>(&mut self, rng: &mut R) {
+    pub fn randomize_with<R: rand_core::RngCore + ?Sized>(&mut self, rng: &mut R) {
         rng.fill_bytes(&mut self.0);
     }
 
@@ -453,6 +510,77 @@ impl<const N: usize> FixedBytes<N> {
         FixedBytes(result)
     }
 
+    /// Splits a `FixedBytes` into two, at index `L`. This is the inverse of
+    /// [`concat_const`](Self::concat_const).
+    ///
+    /// Due to constraints in the language, the user must specify the values of
+    /// both output sizes `L` and `R`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `L + R` is not equal to `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{fixed_bytes, FixedBytes};
+    /// const WORD: FixedBytes<4> = fixed_bytes!("01234567");
+    /// const PARTS: (FixedBytes<1>, FixedBytes<3>) = WORD.split_at_const();
+    /// assert_eq!(PARTS, (fixed_bytes!("01"), fixed_bytes!("234567")));
+    /// ```
+    pub const fn split_at_const<const L: usize, const R: usize>(
+        self,
+    ) -> (FixedBytes<L>, FixedBytes<R>) {
+        assert!(
+            L + R == N,
+            "Output sizes `L` and `R` must sum up to the input size `N`"
+        );
+
+        let mut left = [0u8; L];
+        let mut right = [0u8; R];
+        let mut i = 0;
+        while i < N {
+            if i < L {
+                left[i] = self.0[i];
+            } else {
+                right[i - L] = self.0[i];
+            }
+            i += 1;
+        }
+        (FixedBytes(left), FixedBytes(right))
+    }
+
+    /// Returns `true` if `prefix` is a prefix of `self`, at compile time.
+    pub const fn const_starts_with(&self, prefix: &[u8]) -> bool {
+        if prefix.len() > N {
+            return false
+        }
+        let mut i = 0;
+        while i < prefix.len() {
+            if self.0[i] != prefix[i] {
+                return false
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns `true` if `suffix` is a suffix of `self`, at compile time.
+    pub const fn const_ends_with(&self, suffix: &[u8]) -> bool {
+        if suffix.len() > N {
+            return false
+        }
+        let offset = N - suffix.len();
+        let mut i = 0;
+        while i < suffix.len() {
+            if self.0[offset + i] != suffix[i] {
+                return false
+            }
+            i += 1;
+        }
+        true
+    }
+
     /// Create a new [`FixedBytes`] from the given slice `src`.
     ///
     /// # Note
@@ -468,6 +596,52 @@ impl<const N: usize> FixedBytes<N> {
         Self::try_from(value).unwrap()
     }
 
+    /// Returns an adapter which displays the bytes as `0x`-prefixed uppercase
+    /// hex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::fixed_bytes;
+    /// let bytes = fixed_bytes!("deadbeef");
+    /// assert_eq!(bytes.display_upper().to_string(), "0xDEADBEEF");
+    /// ```
+    #[inline]
+    pub fn display_upper(&self) -> impl fmt::Display + '_ {
+        DisplayFn(move |f: &mut fmt::Formatter<'_>| self.fmt_hex::<true>(f, true))
+    }
+
+    /// Returns an adapter which displays the bytes as lowercase hex, without
+    /// the `0x` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::fixed_bytes;
+    /// let bytes = fixed_bytes!("deadbeef");
+    /// assert_eq!(bytes.display_unprefixed().to_string(), "deadbeef");
+    /// ```
+    #[inline]
+    pub fn display_unprefixed(&self) -> impl fmt::Display + '_ {
+        DisplayFn(move |f: &mut fmt::Formatter<'_>| self.fmt_hex::<false>(f, false))
+    }
+
+    /// Returns an adapter which displays the bytes in the truncated
+    /// `0x1234…abcd` form, as with the alternate [`Display`](fmt::Display)
+    /// flag (`{:#}`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::b256;
+    /// let hash = b256!("a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2");
+    /// assert_eq!(hash.display_short().to_string(), "0xa1de…78f2");
+    /// ```
+    #[inline]
+    pub fn display_short(&self) -> impl fmt::Display + '_ {
+        DisplayFn(move |f: &mut fmt::Formatter<'_>| fmt_hex_short(f, &self.0))
+    }
+
     /// Returns a slice containing the entire array. Equivalent to `&s[..]`.
     #[inline]
     pub const fn as_slice(&self) -> &[u8] {
@@ -550,6 +724,108 @@ impl<const N: usize> FixedBytes<N> {
         ret
     }
 
+    /// Computes the bitwise NOT of a `FixedBytes`.
+    pub const fn bit_not(self) -> Self {
+        let mut ret = Self::ZERO;
+        let mut i = 0;
+        while i < N {
+            ret.0[i] = !self.0[i];
+            i += 1;
+        }
+        ret
+    }
+
+    /// Returns the number of bits set.
+    pub const fn count_ones(&self) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < N {
+            count += self.0[i].count_ones() as usize;
+            i += 1;
+        }
+        count
+    }
+
+    /// Returns the number of leading zero bits, starting from the most
+    /// significant bit of the first byte.
+    pub const fn leading_zeros(&self) -> usize {
+        let mut i = 0;
+        while i < N {
+            if self.0[i] != 0 {
+                return i * 8 + self.0[i].leading_zeros() as usize
+            }
+            i += 1;
+        }
+        N * 8
+    }
+
+    /// Returns the bit at `index`, or `false` if `index` is out of bounds.
+    ///
+    /// Bits are indexed like those of the big-endian integer represented by
+    /// the bytes, as in [`Uint::bit`](ruint::Uint::bit): index 0 is the least
+    /// significant bit of the last byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::fixed_bytes;
+    /// let bytes = fixed_bytes!("8001");
+    /// assert!(bytes.bit(0));
+    /// assert!(!bytes.bit(1));
+    /// assert!(bytes.bit(15));
+    /// assert!(!bytes.bit(16));
+    /// ```
+    #[inline]
+    pub const fn bit(&self, index: usize) -> bool {
+        if index >= N * 8 {
+            return false
+        }
+        self.0[N - 1 - index / 8] & (1 << (index % 8)) != 0
+    }
+
+    /// Sets the bit at `index` to `value`. Does nothing if `index` is out of
+    /// bounds.
+    ///
+    /// See [`bit`](Self::bit) for how bits are indexed.
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, value: bool) {
+        if index >= N * 8 {
+            return
+        }
+        let byte = &mut self.0[N - 1 - index / 8];
+        let mask = 1 << (index % 8);
+        if value {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    }
+
+    /// Returns an iterator over the bits, from index 0 (the least significant
+    /// bit of the last byte) upwards. See [`bit`](Self::bit) for how bits are
+    /// indexed.
+    ///
+    /// Use [`rev`](Iterator::rev) to iterate from the most significant bit of
+    /// the first byte instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::fixed_bytes;
+    /// let bytes = fixed_bytes!("0105");
+    /// let set: Vec<usize> = bytes
+    ///     .bits()
+    ///     .enumerate()
+    ///     .filter(|(_, b)| *b)
+    ///     .map(|(i, _)| i)
+    ///     .collect();
+    /// assert_eq!(set, [0, 2, 8]);
+    /// ```
+    #[inline]
+    pub fn bits(&self) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator + Clone + '_ {
+        (0..N * 8).map(move |i| self.bit(i))
+    }
+
     fn fmt_hex<const UPPER: bool>(&self, f: &mut fmt::Formatter<'_>, prefix: bool) -> fmt::Result {
         let mut buf = hex::Buffer::<N, true>::new();
         let s = if UPPER {
@@ -565,6 +841,7 @@ impl<const N: usize> FixedBytes<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec::Vec;
 
     macro_rules! test_fmt {
         ($($fmt:literal, $hex:literal => $expected:literal;)+) => {$(
@@ -585,6 +862,65 @@ mod tests {
         assert_eq!(ACTUAL, EXPECTED);
     }
 
+    #[test]
+    fn split_at_const() {
+        const WORD: FixedBytes<4> = fixed_bytes!("01234567");
+        const LEFT: (FixedBytes<2>, FixedBytes<2>) = WORD.split_at_const();
+        const EMPTY: (FixedBytes<0>, FixedBytes<4>) = WORD.split_at_const();
+
+        assert_eq!(LEFT, (fixed_bytes!("0123"), fixed_bytes!("4567")));
+        assert_eq!(LEFT.0.concat_const::<2, 4>(LEFT.1), WORD);
+        assert_eq!(EMPTY.1, WORD);
+        assert!(WORD.const_starts_with(&[0x01, 0x23]));
+        assert!(WORD.const_ends_with(&[0x45, 0x67]));
+        assert!(WORD.const_starts_with(&[]));
+        assert!(!WORD.const_starts_with(&[0x23]));
+        assert!(!WORD.const_ends_with(&[0x45]));
+        assert!(!WORD.const_ends_with(&[0; 5]));
+    }
+
+    #[test]
+    #[should_panic = "must sum up to"]
+    fn split_at_const_wrong_size() {
+        let _ = fixed_bytes!("01234567").split_at_const::<2, 3>();
+    }
+
+    #[test]
+    fn bits() {
+        let mut bytes = fixed_bytes!("00f001");
+        assert_eq!(!bytes, fixed_bytes!("ff0ffe"));
+        assert_eq!(bytes.bit_not(), !bytes);
+        assert_eq!(bytes.count_ones(), 5);
+        assert_eq!(bytes.leading_zeros(), 8);
+        assert_eq!(FixedBytes::<3>::ZERO.leading_zeros(), 24);
+        assert_eq!(FixedBytes::<3>::repeat_byte(0xff).count_ones(), 24);
+
+        assert!(bytes.bit(0));
+        assert!(!bytes.bit(1));
+        assert!(bytes.bit(15));
+        assert!(!bytes.bit(16));
+        assert!(!bytes.bit(24));
+
+        bytes.set_bit(23, true);
+        bytes.set_bit(15, false);
+        bytes.set_bit(24, true);
+        assert_eq!(bytes, fixed_bytes!("807001"));
+        assert_eq!(bytes.leading_zeros(), 0);
+
+        let set: Vec<usize> = bytes
+            .bits()
+            .enumerate()
+            .filter_map(|(i, bit)| bit.then_some(i))
+            .collect();
+        assert_eq!(set, [0, 12, 13, 14, 23]);
+        assert_eq!(bytes.bits().len(), 24);
+        assert_eq!(bytes.bits().next_back(), Some(true));
+
+        let address = crate::Address::ZERO;
+        assert_eq!(!address, crate::Address::repeat_byte(0xff));
+        assert_eq!(address.bit_not().count_ones(), 160);
+    }
+
     #[test]
     fn display() {
         test_fmt! {
@@ -618,4 +954,67 @@ mod tests {
             "{:#X}", "0123456789abcdef" => "0x0123456789ABCDEF";
         }
     }
+
+    /// A deterministic RNG that only implements `rand_core`.
+    #[cfg(feature = "rand_core")]
+    struct CounterRng(u8);
+
+    #[cfg(feature = "rand_core")]
+    impl rand_core::RngCore for CounterRng {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for byte in dest {
+                *byte = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rand_core")]
+    fn random_with() {
+        use crate::{Address, Signed, I256};
+
+        let bytes = FixedBytes::<4>::random_with(&mut CounterRng(1));
+        assert_eq!(bytes, FixedBytes([1, 2, 3, 4]));
+
+        let mut address = Address::ZERO;
+        address.randomize_with(&mut CounterRng(0));
+        assert_eq!(address, Address::random_with(&mut CounterRng(0)));
+        assert_eq!(address[19], 19);
+
+        let int = I256::random_with(&mut CounterRng(7));
+        assert_eq!(int, I256::random_with(&mut CounterRng(7)));
+        assert_eq!(int.into_raw().as_limbs()[0], 0x0e0d0c0b0a090807);
+        assert_eq!(int.into_raw().as_limbs()[3], 0x262524232221201f);
+
+        // The unused high bits are cleared.
+        let int = Signed::<12, 1>::random_with(&mut CounterRng(0xff));
+        assert_eq!(int.into_raw().as_limbs(), &[0x0ff]);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn distributions() {
+        use crate::{Address, I256, U256};
+        use rand::Rng;
+
+        let mut rng = CounterRng(0);
+        let _: Address = rng.gen();
+        let _: FixedBytes<12> = rng.gen();
+        let _: U256 = rng.gen();
+        let _: I256 = rng.gen();
+    }
 }
@@ -14,6 +14,47 @@
 /// wrap_fixed_bytes!(pub struct KeccakOutput<32>;);
 /// wrap_fixed_bytes!(pub struct MerkleTreeItem<32>;);
 /// ```
+///
+/// # Customization
+///
+/// The macro optionally accepts, in this order, before the struct definition:
+///
+/// - `extra_derives: [...]`: the derives to add on top of the default set.
+///   Defaults to `[derive_more::Display]`; pass a list without `Display` to
+///   provide your own [`Display`](core::fmt::Display) implementation.
+/// - `skip: [...]`: trait implementations to leave out, so that they can be
+///   implemented manually. Accepted values are `Debug`, `rlp`, `serde`,
+///   `borsh`, `rkyv`, `ssz`, `scale`, `postgres`, `sqlx`, `diesel`, `schemars`,
+///   `zeroize`, `defmt`, `arbitrary` and `rand`. Feature-gated implementations
+///   are only generated when the feature is enabled anyway.
+///
+/// ```
+/// use alloy_primitives::wrap_fixed_bytes;
+/// use core::fmt;
+///
+/// wrap_fixed_bytes!(
+///     extra_derives: [],
+///     skip: [Debug],
+///     /// A transaction hash which is always displayed truncated.
+///     pub struct TxHash<32>;
+/// );
+///
+/// impl fmt::Display for TxHash {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "{:#}", self.0)
+///     }
+/// }
+///
+/// impl fmt::Debug for TxHash {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "TxHash({self})")
+///     }
+/// }
+///
+/// let hash = TxHash::repeat_byte(0x42);
+/// assert_eq!(hash.to_string(), "0x4242…4242");
+/// assert_eq!(format!("{hash:?}"), "TxHash(0x4242…4242)");
+/// ```
 #[macro_export]
 macro_rules! wrap_fixed_bytes {
     (
@@ -22,6 +63,7 @@ macro_rules! wrap_fixed_bytes {
     ) => {
         $crate::wrap_fixed_bytes!(
             extra_derives: [$crate::private::derive_more::Display],
+            skip: [],
             $(#[$attrs])*
             $vis struct $name<$n>;
         );
@@ -31,6 +73,33 @@ macro_rules! wrap_fixed_bytes {
         extra_derives: [$($extra_derives:path),* $(,)?],
         $(#[$attrs:meta])*
         $vis:vis struct $name:ident<$n:literal>;
+    ) => {
+        $crate::wrap_fixed_bytes!(
+            extra_derives: [$($extra_derives),*],
+            skip: [],
+            $(#[$attrs])*
+            $vis struct $name<$n>;
+        );
+    };
+
+    (
+        skip: [$($skip:ident),* $(,)?],
+        $(#[$attrs:meta])*
+        $vis:vis struct $name:ident<$n:literal>;
+    ) => {
+        $crate::wrap_fixed_bytes!(
+            extra_derives: [$crate::private::derive_more::Display],
+            skip: [$($skip),*],
+            $(#[$attrs])*
+            $vis struct $name<$n>;
+        );
+    };
+
+    (
+        extra_derives: [$($extra_derives:path),* $(,)?],
+        skip: [$($skip:ident),* $(,)?],
+        $(#[$attrs:meta])*
+        $vis:vis struct $name:ident<$n:literal>;
     ) => {
         $(#[$attrs])*
         #[derive(
@@ -50,6 +119,7 @@ macro_rules! wrap_fixed_bytes {
             $crate::private::derive_more::BitOrAssign,
             $crate::private::derive_more::BitXor,
             $crate::private::derive_more::BitXorAssign,
+            $crate::private::derive_more::Not,
             $crate::private::derive_more::Deref,
             $crate::private::derive_more::DerefMut,
             $crate::private::derive_more::From,
@@ -167,17 +237,8 @@ macro_rules! wrap_fixed_bytes {
             }
         }
 
-        impl $crate::private::core::fmt::Debug for $name {
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                $crate::private::core::fmt::Debug::fmt(&self.0, f)
-            }
-        }
-
         $crate::impl_fb_traits!($name, $n);
-        $crate::impl_rlp!($name, $n);
-        $crate::impl_serde!($name);
-        $crate::impl_arbitrary!($name, $n);
-        $crate::impl_rand!($name);
+        $crate::impl_fb_skippable!($name, $n, skip: [$($skip),*]);
 
         impl $name {
             /// Array of Zero bytes.
@@ -208,7 +269,7 @@ macro_rules! wrap_fixed_bytes {
             }
 
             $crate::impl_getrandom!();
-            $crate::impl_rand!();
+            $crate::impl_rand_core!();
 
             /// Create a new fixed-hash from the given slice `src`.
             ///
@@ -255,10 +316,105 @@ macro_rules! wrap_fixed_bytes {
             pub const fn bit_xor(self, rhs: Self) -> Self {
                 Self(self.0.bit_xor(rhs.0))
             }
+
+            /// Computes the bitwise NOT of a `FixedBytes`.
+            pub const fn bit_not(self) -> Self {
+                Self(self.0.bit_not())
+            }
         }
     };
 }
 
+// Implements the traits that can be opted out of with `skip: [...]` in
+// `wrap_fixed_bytes!`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_fb_skippable {
+    (@check Debug) => {};
+    (@check rlp) => {};
+    (@check serde) => {};
+    (@check borsh) => {};
+    (@check rkyv) => {};
+    (@check ssz) => {};
+    (@check scale) => {};
+    (@check postgres) => {};
+    (@check sqlx) => {};
+    (@check diesel) => {};
+    (@check schemars) => {};
+    (@check zeroize) => {};
+    (@check defmt) => {};
+    (@check arbitrary) => {};
+    (@check rand) => {};
+    (@check $other:ident) => {
+        compile_error!(concat!("unknown `skip` value: `", stringify!($other), "`"));
+    };
+
+    // Skipped: the implementation is the head of the skip list.
+    (@impl Debug, $name:ident, $n:literal, [Debug $(, $rest:ident)*]) => {};
+    (@impl rlp, $name:ident, $n:literal, [rlp $(, $rest:ident)*]) => {};
+    (@impl serde, $name:ident, $n:literal, [serde $(, $rest:ident)*]) => {};
+    (@impl borsh, $name:ident, $n:literal, [borsh $(, $rest:ident)*]) => {};
+    (@impl rkyv, $name:ident, $n:literal, [rkyv $(, $rest:ident)*]) => {};
+    (@impl ssz, $name:ident, $n:literal, [ssz $(, $rest:ident)*]) => {};
+    (@impl scale, $name:ident, $n:literal, [scale $(, $rest:ident)*]) => {};
+    (@impl postgres, $name:ident, $n:literal, [postgres $(, $rest:ident)*]) => {};
+    (@impl sqlx, $name:ident, $n:literal, [sqlx $(, $rest:ident)*]) => {};
+    (@impl diesel, $name:ident, $n:literal, [diesel $(, $rest:ident)*]) => {};
+    (@impl schemars, $name:ident, $n:literal, [schemars $(, $rest:ident)*]) => {};
+    (@impl zeroize, $name:ident, $n:literal, [zeroize $(, $rest:ident)*]) => {};
+    (@impl defmt, $name:ident, $n:literal, [defmt $(, $rest:ident)*]) => {};
+    (@impl arbitrary, $name:ident, $n:literal, [arbitrary $(, $rest:ident)*]) => {};
+    (@impl rand, $name:ident, $n:literal, [rand $(, $rest:ident)*]) => {};
+
+    // Not the head of the skip list: keep looking.
+    (@impl $impl:ident, $name:ident, $n:literal, [$head:ident $(, $rest:ident)*]) => {
+        $crate::impl_fb_skippable!(@impl $impl, $name, $n, [$($rest),*]);
+    };
+
+    // Not skipped.
+    (@impl Debug, $name:ident, $n:literal, []) => {
+        impl $crate::private::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::private::core::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+    };
+    (@impl rlp, $name:ident, $n:literal, []) => { $crate::impl_rlp!($name, $n); };
+    (@impl serde, $name:ident, $n:literal, []) => { $crate::impl_serde!($name); };
+    (@impl borsh, $name:ident, $n:literal, []) => { $crate::impl_borsh!($name); };
+    (@impl rkyv, $name:ident, $n:literal, []) => { $crate::impl_rkyv!($name); };
+    (@impl ssz, $name:ident, $n:literal, []) => { $crate::impl_ssz!($name, $n); };
+    (@impl scale, $name:ident, $n:literal, []) => { $crate::impl_scale!($name, $n); };
+    (@impl postgres, $name:ident, $n:literal, []) => { $crate::impl_postgres!($name, $n); };
+    (@impl sqlx, $name:ident, $n:literal, []) => { $crate::impl_sqlx!($name, $n); };
+    (@impl diesel, $name:ident, $n:literal, []) => { $crate::impl_diesel!($name, $n); };
+    (@impl schemars, $name:ident, $n:literal, []) => { $crate::impl_schemars!($name, $n); };
+    (@impl zeroize, $name:ident, $n:literal, []) => { $crate::impl_zeroize!($name); };
+    (@impl defmt, $name:ident, $n:literal, []) => { $crate::impl_defmt!($name); };
+    (@impl arbitrary, $name:ident, $n:literal, []) => { $crate::impl_arbitrary!($name, $n); };
+    (@impl rand, $name:ident, $n:literal, []) => { $crate::impl_rand!($name); };
+
+    ($name:ident, $n:literal, skip: [$($skip:ident),*]) => {
+        $($crate::impl_fb_skippable!(@check $skip);)*
+
+        $crate::impl_fb_skippable!(@impl Debug, $name, $n, [$($skip),*]);
+        $crate::impl_fb_skippable!(@impl rlp, $name, $n, [$($skip),*]);
+        $crate::impl_fb_skippable!(@impl serde, $name, $n, [$($skip),*]);
+        $crate::impl_fb_skippable!(@impl borsh, $name, $n, [$($skip),*]);
+        $crate::impl_fb_skippable!(@impl rkyv, $name, $n, [$($skip),*]);
+        $crate::impl_fb_skippable!(@impl ssz, $name, $n, [$($skip),*]);
+        $crate::impl_fb_skippable!(@impl scale, $name, $n, [$($skip),*]);
+        $crate::impl_fb_skippable!(@impl postgres, $name, $n, [$($skip),*]);
+        $crate::impl_fb_skippable!(@impl sqlx, $name, $n, [$($skip),*]);
+        $crate::impl_fb_skippable!(@impl diesel, $name, $n, [$($skip),*]);
+        $crate::impl_fb_skippable!(@impl schemars, $name, $n, [$($skip),*]);
+        $crate::impl_fb_skippable!(@impl zeroize, $name, $n, [$($skip),*]);
+        $crate::impl_fb_skippable!(@impl defmt, $name, $n, [$($skip),*]);
+        $crate::impl_fb_skippable!(@impl arbitrary, $name, $n, [$($skip),*]);
+        $crate::impl_fb_skippable!(@impl rand, $name, $n, [$($skip),*]);
+    };
+}
+
 // Extra traits that cannot be derived automatically
 #[doc(hidden)]
 #[macro_export]
@@ -451,24 +607,39 @@ macro_rules! impl_getrandom {
 
 #[doc(hidden)]
 #[macro_export]
-#[cfg(feature = "rand")]
-macro_rules! impl_rand {
+#[cfg(feature = "rand_core")]
+macro_rules! impl_rand_core {
     () => {
         /// Creates a new fixed byte array with the given random number generator.
         #[inline]
         #[doc(alias = "random_using")]
-        pub fn random_with<R: $crate::private::rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        pub fn random_with<R: $crate::private::rand_core::RngCore + ?Sized>(rng: &mut R) -> Self {
             Self($crate::FixedBytes::random_with(rng))
         }
 
         /// Fills this fixed byte array with the given random number generator.
         #[inline]
         #[doc(alias = "randomize_using")]
-        pub fn randomize_with<R: $crate::private::rand::Rng + ?Sized>(&mut self, rng: &mut R) {
+        pub fn randomize_with<R: $crate::private::rand_core::RngCore + ?Sized>(
+            &mut self,
+            rng: &mut R,
+        ) {
             self.0.randomize_with(rng);
         }
     };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "rand_core"))]
+macro_rules! impl_rand_core {
+    () => {};
+}
 
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "rand")]
+macro_rules! impl_rand {
     ($t:ty) => {
         impl $crate::private::rand::distributions::Distribution<$t>
             for $crate::private::rand::distributions::Standard
@@ -485,7 +656,537 @@ macro_rules! impl_rand {
 #[macro_export]
 #[cfg(not(feature = "rand"))]
 macro_rules! impl_rand {
-    ($($t:tt)*) => {};
+    ($t:ty) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "borsh")]
+macro_rules! impl_borsh {
+    ($t:ty) => {
+        impl $crate::private::borsh::BorshSerialize for $t {
+            #[inline]
+            fn serialize<W: $crate::private::borsh::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> $crate::private::borsh::io::Result<()> {
+                $crate::private::borsh::BorshSerialize::serialize(&self.0, writer)
+            }
+        }
+
+        impl $crate::private::borsh::BorshDeserialize for $t {
+            #[inline]
+            fn deserialize_reader<R: $crate::private::borsh::io::Read>(
+                reader: &mut R,
+            ) -> $crate::private::borsh::io::Result<Self> {
+                $crate::private::borsh::BorshDeserialize::deserialize_reader(reader).map(Self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "borsh"))]
+macro_rules! impl_borsh {
+    ($t:ty) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "rkyv")]
+macro_rules! impl_rkyv {
+    ($t:ty) => {
+        // SAFETY: `$t` is a `#[repr(transparent)]` wrapper around a byte array,
+        // which has no padding, no alignment requirements and no invalid bit
+        // patterns.
+        unsafe impl $crate::private::rkyv::Portable for $t {}
+
+        unsafe impl $crate::private::rkyv::traits::NoUndef for $t {}
+
+        unsafe impl<C: $crate::private::rkyv::rancor::Fallible + ?Sized>
+            $crate::private::rkyv::bytecheck::CheckBytes<C> for $t
+        {
+            #[inline]
+            unsafe fn check_bytes(_value: *const Self, _context: &mut C) -> Result<(), C::Error> {
+                Ok(())
+            }
+        }
+
+        impl $crate::private::rkyv::Archive for $t {
+            const COPY_OPTIMIZATION: $crate::private::rkyv::traits::CopyOptimization<Self> =
+                unsafe { $crate::private::rkyv::traits::CopyOptimization::enable() };
+
+            type Archived = Self;
+            type Resolver = ();
+
+            #[inline]
+            fn resolve(&self, _resolver: (), out: $crate::private::rkyv::Place<Self>) {
+                out.write(*self);
+            }
+        }
+
+        impl<S: $crate::private::rkyv::rancor::Fallible + ?Sized>
+            $crate::private::rkyv::Serialize<S> for $t
+        {
+            #[inline]
+            fn serialize(&self, _serializer: &mut S) -> Result<(), S::Error> {
+                Ok(())
+            }
+        }
+
+        impl<D: $crate::private::rkyv::rancor::Fallible + ?Sized>
+            $crate::private::rkyv::Deserialize<$t, D> for $t
+        {
+            #[inline]
+            fn deserialize(&self, _deserializer: &mut D) -> Result<Self, D::Error> {
+                Ok(*self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "rkyv"))]
+macro_rules! impl_rkyv {
+    ($t:ty) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "ssz")]
+macro_rules! impl_ssz {
+    ($t:ty, $n:literal) => {
+        impl $crate::private::ssz::Encode for $t {
+            #[inline]
+            fn is_ssz_fixed_len() -> bool {
+                true
+            }
+
+            #[inline]
+            fn ssz_fixed_len() -> usize {
+                $n
+            }
+
+            #[inline]
+            fn ssz_bytes_len(&self) -> usize {
+                $n
+            }
+
+            #[inline]
+            fn ssz_append(&self, buf: &mut $crate::private::Vec<u8>) {
+                $crate::private::ssz::Encode::ssz_append(&self.0, buf)
+            }
+        }
+
+        impl $crate::private::ssz::Decode for $t {
+            #[inline]
+            fn is_ssz_fixed_len() -> bool {
+                true
+            }
+
+            #[inline]
+            fn ssz_fixed_len() -> usize {
+                $n
+            }
+
+            #[inline]
+            fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, $crate::private::ssz::DecodeError> {
+                $crate::private::ssz::Decode::from_ssz_bytes(bytes).map(Self)
+            }
+        }
+
+        impl $crate::private::tree_hash::TreeHash for $t {
+            #[inline]
+            fn tree_hash_type() -> $crate::private::tree_hash::TreeHashType {
+                <$crate::FixedBytes<$n> as $crate::private::tree_hash::TreeHash>::tree_hash_type()
+            }
+
+            #[inline]
+            fn tree_hash_packed_encoding(&self) -> $crate::private::tree_hash::PackedEncoding {
+                $crate::private::tree_hash::TreeHash::tree_hash_packed_encoding(&self.0)
+            }
+
+            #[inline]
+            fn tree_hash_packing_factor() -> usize {
+                <$crate::FixedBytes<$n> as $crate::private::tree_hash::TreeHash>::tree_hash_packing_factor()
+            }
+
+            #[inline]
+            fn tree_hash_root(&self) -> $crate::private::tree_hash::Hash256 {
+                $crate::private::tree_hash::TreeHash::tree_hash_root(&self.0)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "ssz"))]
+macro_rules! impl_ssz {
+    ($t:ty, $n:literal) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "scale")]
+macro_rules! impl_scale {
+    ($t:ty, $n:literal) => {
+        impl $crate::private::parity_scale_codec::Encode for $t {
+            #[inline]
+            fn size_hint(&self) -> usize {
+                $n
+            }
+
+            #[inline]
+            fn encode_to<O: $crate::private::parity_scale_codec::Output + ?Sized>(
+                &self,
+                dest: &mut O,
+            ) {
+                $crate::private::parity_scale_codec::Encode::encode_to(&self.0, dest)
+            }
+
+            #[inline]
+            fn encoded_size(&self) -> usize {
+                $n
+            }
+        }
+
+        impl $crate::private::parity_scale_codec::EncodeLike for $t {}
+
+        impl $crate::private::parity_scale_codec::EncodeLike<$crate::FixedBytes<$n>> for $t {}
+
+        impl $crate::private::parity_scale_codec::Decode for $t {
+            #[inline]
+            fn decode<I: $crate::private::parity_scale_codec::Input>(
+                input: &mut I,
+            ) -> Result<Self, $crate::private::parity_scale_codec::Error> {
+                $crate::private::parity_scale_codec::Decode::decode(input).map(Self)
+            }
+        }
+
+        impl $crate::private::parity_scale_codec::MaxEncodedLen for $t {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                $n
+            }
+        }
+
+        impl $crate::private::scale_info::TypeInfo for $t {
+            type Identity = Self;
+
+            fn type_info() -> $crate::private::scale_info::Type {
+                $crate::private::scale_info::Type::builder()
+                    .path($crate::private::scale_info::Path::new(
+                        stringify!($t),
+                        module_path!(),
+                    ))
+                    .composite(
+                        $crate::private::scale_info::build::Fields::unnamed().field(|f| {
+                            f.ty::<$crate::FixedBytes<$n>>().type_name(concat!(
+                                "FixedBytes<",
+                                stringify!($n),
+                                ">"
+                            ))
+                        }),
+                    )
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "scale"))]
+macro_rules! impl_scale {
+    ($t:ty, $n:literal) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "postgres")]
+macro_rules! impl_postgres {
+    ($t:ty, $n:literal) => {
+        impl $crate::private::postgres_types::ToSql for $t {
+            #[inline]
+            fn to_sql(
+                &self,
+                ty: &$crate::private::postgres_types::Type,
+                out: &mut $crate::private::BytesMut,
+            ) -> Result<$crate::private::postgres_types::IsNull, $crate::private::BoxedError> {
+                $crate::private::postgres_types::ToSql::to_sql(&self.0, ty, out)
+            }
+
+            #[inline]
+            fn accepts(ty: &$crate::private::postgres_types::Type) -> bool {
+                <$crate::FixedBytes<$n> as $crate::private::postgres_types::ToSql>::accepts(ty)
+            }
+
+            $crate::private::postgres_types::to_sql_checked!();
+        }
+
+        impl<'a> $crate::private::postgres_types::FromSql<'a> for $t {
+            #[inline]
+            fn from_sql(
+                ty: &$crate::private::postgres_types::Type,
+                raw: &'a [u8],
+            ) -> Result<Self, $crate::private::BoxedError> {
+                $crate::private::postgres_types::FromSql::from_sql(ty, raw).map(Self)
+            }
+
+            #[inline]
+            fn accepts(ty: &$crate::private::postgres_types::Type) -> bool {
+                <$crate::FixedBytes<$n> as $crate::private::postgres_types::FromSql<'_>>::accepts(
+                    ty,
+                )
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "postgres"))]
+macro_rules! impl_postgres {
+    ($t:ty, $n:literal) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "sqlx")]
+macro_rules! impl_sqlx {
+    ($t:ty, $n:literal) => {
+        impl<DB> $crate::private::sqlx_core::types::Type<DB> for $t
+        where
+            DB: $crate::private::sqlx_core::database::Database,
+            $crate::FixedBytes<$n>: $crate::private::sqlx_core::types::Type<DB>,
+        {
+            #[inline]
+            fn type_info() -> DB::TypeInfo {
+                <$crate::FixedBytes<$n> as $crate::private::sqlx_core::types::Type<DB>>::type_info()
+            }
+
+            #[inline]
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <$crate::FixedBytes<$n> as $crate::private::sqlx_core::types::Type<DB>>::compatible(
+                    ty,
+                )
+            }
+        }
+
+        impl<'a, DB> $crate::private::sqlx_core::encode::Encode<'a, DB> for $t
+        where
+            DB: $crate::private::sqlx_core::database::Database,
+            $crate::FixedBytes<$n>: $crate::private::sqlx_core::encode::Encode<'a, DB>,
+        {
+            #[inline]
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as $crate::private::sqlx_core::database::Database>::ArgumentBuffer<'a>,
+            ) -> Result<
+                $crate::private::sqlx_core::encode::IsNull,
+                $crate::private::sqlx_core::error::BoxDynError,
+            > {
+                self.0.encode_by_ref(buf)
+            }
+        }
+
+        impl<'a, DB> $crate::private::sqlx_core::decode::Decode<'a, DB> for $t
+        where
+            DB: $crate::private::sqlx_core::database::Database,
+            $crate::FixedBytes<$n>: $crate::private::sqlx_core::decode::Decode<'a, DB>,
+        {
+            #[inline]
+            fn decode(
+                value: <DB as $crate::private::sqlx_core::database::Database>::ValueRef<'a>,
+            ) -> Result<Self, $crate::private::sqlx_core::error::BoxDynError> {
+                <$crate::FixedBytes<$n> as $crate::private::sqlx_core::decode::Decode<'a, DB>>::decode(
+                    value,
+                )
+                .map(Self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "sqlx"))]
+macro_rules! impl_sqlx {
+    ($t:ty, $n:literal) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "diesel")]
+macro_rules! impl_diesel {
+    ($t:ty, $n:literal) => {
+        const _: () = {
+            use $crate::{
+                private::diesel::{
+                    backend::Backend,
+                    deserialize::{self, FromSql},
+                    expression::AsExpression,
+                    internal::derives::as_expression::Bound,
+                    serialize::{self, Output, ToSql},
+                    sql_types::{Binary, Nullable, SingleValue},
+                    Queryable,
+                },
+                FixedBytes,
+            };
+
+            impl<Db: Backend> ToSql<Binary, Db> for $t
+            where
+                FixedBytes<$n>: ToSql<Binary, Db>,
+            {
+                #[inline]
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Db>) -> serialize::Result {
+                    ToSql::<Binary, Db>::to_sql(&self.0, out)
+                }
+            }
+
+            impl<Db: Backend> ToSql<Nullable<Binary>, Db> for $t
+            where
+                Self: ToSql<Binary, Db>,
+            {
+                #[inline]
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Db>) -> serialize::Result {
+                    ToSql::<Binary, Db>::to_sql(self, out)
+                }
+            }
+
+            impl<Db: Backend> FromSql<Binary, Db> for $t
+            where
+                FixedBytes<$n>: FromSql<Binary, Db>,
+            {
+                #[inline]
+                fn from_sql(bytes: Db::RawValue<'_>) -> deserialize::Result<Self> {
+                    <FixedBytes<$n> as FromSql<Binary, Db>>::from_sql(bytes).map(Self)
+                }
+            }
+
+            impl<Db: Backend, St: SingleValue> Queryable<St, Db> for $t
+            where
+                Self: FromSql<St, Db>,
+            {
+                type Row = Self;
+
+                #[inline]
+                fn build(row: Self::Row) -> deserialize::Result<Self> {
+                    Ok(row)
+                }
+            }
+
+    $crate::impl_diesel!(@as_expression $t);
+    $crate::impl_diesel!(@as_expression &$t);
+    $crate::impl_diesel!(@as_expression &&$t);
+        };
+    };
+
+    (@as_expression $t:ty) => {
+        impl AsExpression<Binary> for $t {
+            type Expression = Bound<Binary, Self>;
+
+            #[inline]
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+
+        impl AsExpression<Nullable<Binary>> for $t {
+            type Expression = Bound<Nullable<Binary>, Self>;
+
+            #[inline]
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "diesel"))]
+macro_rules! impl_diesel {
+    ($t:ty, $n:literal) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "json-schema")]
+macro_rules! impl_schemars {
+    ($t:ty, $n:literal) => {
+        impl $crate::private::schemars::JsonSchema for $t {
+            #[inline]
+            fn inline_schema() -> bool {
+                true
+            }
+
+            #[inline]
+            fn schema_name() -> $crate::private::Cow<'static, str> {
+                stringify!($t).into()
+            }
+
+            #[inline]
+            fn schema_id() -> $crate::private::Cow<'static, str> {
+                concat!(module_path!(), "::", stringify!($t)).into()
+            }
+
+            #[inline]
+            fn json_schema(
+                generator: &mut $crate::private::schemars::SchemaGenerator,
+            ) -> $crate::private::schemars::Schema {
+                <$crate::FixedBytes<$n> as $crate::private::schemars::JsonSchema>::json_schema(
+                    generator,
+                )
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "json-schema"))]
+macro_rules! impl_schemars {
+    ($t:ty, $n:literal) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "zeroize")]
+macro_rules! impl_zeroize {
+    ($t:ty) => {
+        impl $crate::private::zeroize::DefaultIsZeroes for $t {}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "zeroize"))]
+macro_rules! impl_zeroize {
+    ($t:ty) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "defmt")]
+macro_rules! impl_defmt {
+    ($t:ty) => {
+        impl $crate::private::defmt::Format for $t {
+            #[inline]
+            fn format(&self, f: $crate::private::defmt::Formatter<'_>) {
+                $crate::private::defmt::Format::format(&self.0, f)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "defmt"))]
+macro_rules! impl_defmt {
+    ($t:ty) => {};
 }
 
 #[doc(hidden)]
@@ -648,8 +1349,6 @@ macro_rules! fixed_bytes_macros {
 }
 
 fixed_bytes_macros! { $
-    macro address(Address);
-
     macro b64(B64);
 
     macro b128(B128);
@@ -663,6 +1362,51 @@ fixed_bytes_macros! { $
     macro fixed_bytes(FixedBytes<0>); // <0> is just for the doctest
 }
 
+/// Converts a sequence of string literals containing a hex-encoded address
+/// into a new [`Address`][crate::Address] at compile time.
+///
+/// If the input is empty, the zero address is returned.
+///
+/// Unlike the other fixed-bytes macros, the input may be prefixed with `0x`.
+/// If it is mixed-case, its [EIP-55] checksum is verified, and an invalid
+/// checksum is a compile-time error. All-lowercase (or all-uppercase) input
+/// is not checksummed.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{address, Address};
+///
+/// const ZERO: Address = address!();
+/// assert_eq!(ZERO, Address::ZERO);
+///
+/// let checksummed = address!("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+/// let lowercase = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+/// assert_eq!(checksummed, lowercase);
+/// ```
+///
+/// An invalid checksum fails to compile:
+///
+/// ```compile_fail
+/// use alloy_primitives::address;
+///
+/// let _ = address!("0xD8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+/// ```
+#[macro_export]
+macro_rules! address {
+    () => {
+        $crate::Address::ZERO
+    };
+
+    ($($s:literal)+) => {{
+        const ADDRESS: $crate::Address =
+            $crate::Address::new($crate::private::parse_address_literal(::core::concat!($($s),+)));
+        ADDRESS
+    }};
+}
+
 /// Converts a sequence of string literals containing hex-encoded data into a
 /// new [`Bytes`][crate::Bytes] at compile time.
 ///
@@ -2,19 +2,56 @@
 mod macros;
 
 mod address;
+pub(crate) use address::CREATE2_SALT;
 pub use address::{Address, AddressError};
 
 mod bloom;
 pub use bloom::{Bloom, BloomInput, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES};
 
+mod checksum;
+pub use checksum::parse_address_literal;
+
 mod fixed;
 pub use fixed::FixedBytes;
 
 mod function;
 pub use function::Function;
 
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "defmt")]
+pub(crate) mod defmt;
+
+#[cfg(feature = "diesel")]
+mod diesel;
+
+#[cfg(feature = "postgres")]
+mod postgres;
+
+#[cfg(feature = "primitive-types")]
+mod primitive_types;
+
+#[cfg(feature = "rkyv")]
+mod rkyv;
+
 #[cfg(feature = "rlp")]
 mod rlp;
 
+#[cfg(feature = "scale")]
+mod scale;
+
+#[cfg(feature = "json-schema")]
+mod schemars;
+
 #[cfg(feature = "serde")]
 mod serde;
+
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
+#[cfg(feature = "ssz")]
+mod ssz;
+
+#[cfg(feature = "zeroize")]
+mod zeroize;
@@ -0,0 +1,99 @@
+use super::FixedBytes;
+use crate::private::BoxedError;
+use bytes::{BufMut, BytesMut};
+use core::str;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type, WrongType};
+
+/// Converts to Postgres types.
+///
+/// The mapping is selected by the type of the target column:
+/// - `BYTEA` stores the raw bytes;
+/// - `TEXT`, `VARCHAR` and `CHAR(n)` store a `0x`-prefixed lowercase hex
+///   string.
+impl<const N: usize> ToSql for FixedBytes<N> {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxedError> {
+        match *ty {
+            Type::BYTEA => out.put_slice(&self.0),
+            Type::TEXT | Type::VARCHAR | Type::BPCHAR => {
+                out.put_slice(hex::encode_prefixed(self.0).as_bytes())
+            }
+            _ => return Err(Box::new(WrongType::new::<Self>(ty.clone()))),
+        }
+        Ok(IsNull::No)
+    }
+
+    accepts!(BYTEA, TEXT, VARCHAR, BPCHAR);
+
+    to_sql_checked!();
+}
+
+/// Converts from Postgres types.
+///
+/// See [`ToSql`](#impl-ToSql-for-FixedBytes<N>) for the supported mappings.
+/// Hex strings are accepted with or without the `0x` prefix, in any case.
+impl<'a, const N: usize> FromSql<'a> for FixedBytes<N> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxedError> {
+        match *ty {
+            Type::BYTEA => Ok(Self::try_from(raw)?),
+            // `CHAR(n)` values are right-padded with spaces.
+            _ => Ok(str::from_utf8(raw)?.trim_end().parse()?),
+        }
+    }
+
+    accepts!(BYTEA, TEXT, VARCHAR, BPCHAR);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, B256};
+
+    fn roundtrip<T>(value: T, ty: Type) -> BytesMut
+    where
+        T: ToSql + for<'a> FromSql<'a> + PartialEq + core::fmt::Debug,
+    {
+        let mut out = BytesMut::new();
+        assert!(<T as ToSql>::accepts(&ty));
+        value.to_sql_checked(&ty, &mut out).unwrap();
+        assert_eq!(T::from_sql(&ty, &out).unwrap(), value);
+        out
+    }
+
+    #[test]
+    fn bytea() {
+        let hash = B256::repeat_byte(0x11);
+        assert_eq!(roundtrip(hash, Type::BYTEA), hash.as_slice());
+
+        let address = Address::repeat_byte(0x22);
+        assert_eq!(roundtrip(address, Type::BYTEA), address.as_slice());
+
+        assert!(Address::from_sql(&Type::BYTEA, &[0; 32]).is_err());
+    }
+
+    #[test]
+    fn text() {
+        let address = Address::repeat_byte(0xab);
+        let out = roundtrip(address, Type::TEXT);
+        assert_eq!(&out[..], format!("{address:#x}").as_bytes());
+        roundtrip(address, Type::VARCHAR);
+        roundtrip(address, Type::BPCHAR);
+
+        let checksummed = address.to_checksum(None);
+        assert_eq!(
+            Address::from_sql(&Type::TEXT, checksummed.as_bytes()).unwrap(),
+            address
+        );
+        assert_eq!(
+            B256::from_sql(&Type::BPCHAR, format!("{:x}  ", B256::ZERO).as_bytes()).unwrap(),
+            B256::ZERO
+        );
+        assert!(Address::from_sql(&Type::TEXT, b"0x1234").is_err());
+    }
+
+    #[test]
+    fn wrong_type() {
+        assert!(!<Address as ToSql>::accepts(&Type::INT8));
+        let mut out = BytesMut::new();
+        assert!(B256::ZERO.to_sql_checked(&Type::INT8, &mut out).is_err());
+    }
+}
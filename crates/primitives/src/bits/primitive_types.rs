@@ -0,0 +1,77 @@
+//! Conversions to and from the [`primitive_types`] hash types, which are also
+//! re-exported by `ethereum-types` and `ethers-core`.
+//!
+//! Conversions between [`Uint`](crate::Uint) and the `primitive_types`
+//! integer types are provided by `ruint`. Note that these must be called
+//! through [`Into`] or `<U256 as From<_>>::from`, as the inherent
+//! `Uint::from` takes precedence over the trait method.
+
+use super::{Address, FixedBytes};
+use primitive_types::{H128, H160, H256, H384, H512};
+
+macro_rules! fixed_bytes_hash_conversions {
+    ($($n:literal => $h:ty),* $(,)?) => {$(
+        impl From<$h> for FixedBytes<$n> {
+            #[inline]
+            fn from(value: $h) -> Self {
+                Self(value.0)
+            }
+        }
+
+        impl From<FixedBytes<$n>> for $h {
+            #[inline]
+            fn from(value: FixedBytes<$n>) -> Self {
+                Self(value.0)
+            }
+        }
+    )*};
+}
+
+fixed_bytes_hash_conversions! {
+    16 => H128,
+    20 => H160,
+    32 => H256,
+    48 => H384,
+    64 => H512,
+}
+
+impl From<H160> for Address {
+    #[inline]
+    fn from(value: H160) -> Self {
+        Self(FixedBytes(value.0))
+    }
+}
+
+impl From<Address> for H160 {
+    #[inline]
+    fn from(value: Address) -> Self {
+        Self(value.0 .0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{B256, U256};
+
+    #[test]
+    fn hashes() {
+        let h = H256::repeat_byte(0x42);
+        let b = B256::from(h);
+        assert_eq!(b, B256::repeat_byte(0x42));
+        assert_eq!(H256::from(b), h);
+
+        let h = H160::from_low_u64_be(1);
+        let address = Address::from(h);
+        assert_eq!(address, Address::with_last_byte(1));
+        assert_eq!(H160::from(address), h);
+    }
+
+    #[test]
+    fn uints() {
+        let u = primitive_types::U256::from(0x1234);
+        let v: U256 = u.into();
+        assert_eq!(v, U256::from(0x1234));
+        assert_eq!(primitive_types::U256::from(U256::from(0x1234)), u);
+    }
+}
@@ -0,0 +1,85 @@
+//! `FixedBytes` and its wrappers archive as themselves: they are byte arrays,
+//! so they have an alignment of 1, no padding and no invalid bit patterns, and
+//! can be accessed in place from any position in an archive.
+
+use super::FixedBytes;
+use rkyv::{
+    bytecheck::CheckBytes,
+    rancor::Fallible,
+    traits::{CopyOptimization, NoUndef},
+    Archive, Deserialize, Place, Portable, Serialize,
+};
+
+// SAFETY: `FixedBytes<N>` is `repr(transparent)` for `[u8; N]`.
+unsafe impl<const N: usize> Portable for FixedBytes<N> {}
+
+// SAFETY: `FixedBytes<N>` is `repr(transparent)` for `[u8; N]`.
+unsafe impl<const N: usize> NoUndef for FixedBytes<N> {}
+
+// SAFETY: every bit pattern is a valid `[u8; N]`.
+unsafe impl<C: Fallible + ?Sized, const N: usize> CheckBytes<C> for FixedBytes<N> {
+    #[inline]
+    unsafe fn check_bytes(_value: *const Self, _context: &mut C) -> Result<(), C::Error> {
+        Ok(())
+    }
+}
+
+impl<const N: usize> Archive for FixedBytes<N> {
+    // SAFETY: `FixedBytes<N>` archives as itself.
+    const COPY_OPTIMIZATION: CopyOptimization<Self> = unsafe { CopyOptimization::enable() };
+
+    type Archived = Self;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, _resolver: (), out: Place<Self>) {
+        out.write(*self);
+    }
+}
+
+impl<S: Fallible + ?Sized, const N: usize> Serialize<S> for FixedBytes<N> {
+    #[inline]
+    fn serialize(&self, _serializer: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized, const N: usize> Deserialize<Self, D> for FixedBytes<N> {
+    #[inline]
+    fn deserialize(&self, _deserializer: &mut D) -> Result<Self, D::Error> {
+        Ok(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, Bloom, B256};
+    use alloc::vec::Vec;
+    use rkyv::rancor::Error;
+
+    #[test]
+    fn roundtrip() {
+        let hash = B256::repeat_byte(0x11);
+        let bytes = rkyv::to_bytes::<Error>(&hash).unwrap();
+        assert_eq!(&bytes[..], hash.as_slice());
+        let archived = rkyv::access::<B256, Error>(&bytes).unwrap();
+        assert_eq!(*archived, hash);
+        assert_eq!(rkyv::deserialize::<B256, Error>(archived).unwrap(), hash);
+
+        let address = Address::repeat_byte(0x22);
+        let bytes = rkyv::to_bytes::<Error>(&address).unwrap();
+        assert_eq!(*rkyv::access::<Address, Error>(&bytes).unwrap(), address);
+
+        let bloom = Bloom::repeat_byte(0x33);
+        let bytes = rkyv::to_bytes::<Error>(&bloom).unwrap();
+        assert_eq!(*rkyv::access::<Bloom, Error>(&bytes).unwrap(), bloom);
+    }
+
+    #[test]
+    fn vec_roundtrip() {
+        let addresses: Vec<Address> = (0..4).map(Address::with_last_byte).collect();
+        let bytes = rkyv::to_bytes::<Error>(&addresses).unwrap();
+        let archived = rkyv::access::<rkyv::Archived<Vec<Address>>, Error>(&bytes).unwrap();
+        assert_eq!(archived.as_slice(), addresses.as_slice());
+    }
+}
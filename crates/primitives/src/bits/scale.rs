@@ -0,0 +1,107 @@
+use super::FixedBytes;
+use parity_scale_codec::{Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output};
+use scale_info::{meta_type, Path, Type, TypeInfo, TypeParameter};
+
+/// `FixedBytes<N>` is encoded as its `N` raw bytes, without a length prefix.
+impl<const N: usize> Encode for FixedBytes<N> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        dest.write(&self.0)
+    }
+
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> EncodeLike for FixedBytes<N> {}
+
+impl<const N: usize> EncodeLike<[u8; N]> for FixedBytes<N> {}
+
+impl<const N: usize> Decode for FixedBytes<N> {
+    #[inline]
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        <[u8; N]>::decode(input).map(Self)
+    }
+}
+
+impl<const N: usize> MaxEncodedLen for FixedBytes<N> {
+    #[inline]
+    fn max_encoded_len() -> usize {
+        N
+    }
+}
+
+/// The const parameter `N` is described as a type parameter bound to
+/// `[u8; N]`, so that each length is a distinct type carrying its length.
+impl<const N: usize> TypeInfo for FixedBytes<N> {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        Type::builder()
+            .path(Path::new("FixedBytes", module_path!()))
+            .type_params([TypeParameter::new("N", Some(meta_type::<[u8; N]>()))])
+            .composite(
+                scale_info::build::Fields::unnamed()
+                    .field(|f| f.ty::<[u8; N]>().type_name("[u8; N]")),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, Bloom, B256};
+    use scale_info::TypeDef;
+
+    #[test]
+    fn roundtrip() {
+        let bytes = FixedBytes([0, 1, 2, 3]);
+        let bin = bytes.encode();
+        assert_eq!(bin, [0, 1, 2, 3]);
+        assert_eq!(FixedBytes::<4>::decode(&mut &bin[..]).unwrap(), bytes);
+
+        let address = Address::repeat_byte(0x11);
+        let bin = address.encode();
+        assert_eq!(bin, address.as_slice());
+        assert_eq!(Address::decode(&mut &bin[..]).unwrap(), address);
+
+        let bloom = Bloom::repeat_byte(0x22);
+        assert_eq!(Bloom::decode(&mut &bloom.encode()[..]).unwrap(), bloom);
+
+        assert!(B256::decode(&mut &[0u8; 31][..]).is_err());
+    }
+
+    #[test]
+    fn max_encoded_len() {
+        assert_eq!(Address::max_encoded_len(), 20);
+        assert_eq!(B256::max_encoded_len(), 32);
+        assert_eq!(Bloom::max_encoded_len(), 256);
+    }
+
+    #[test]
+    fn type_info() {
+        let info = FixedBytes::<4>::type_info();
+        assert_eq!(info.path.ident(), Some("FixedBytes"));
+        assert_eq!(info.type_params.len(), 1);
+        assert_eq!(info.type_params[0].name, "N");
+        let param = info.type_params[0].ty.unwrap().type_info();
+        assert!(matches!(param.type_def, TypeDef::Array(ref array) if array.len == 4));
+        assert_ne!(
+            FixedBytes::<4>::type_info().type_params,
+            FixedBytes::<8>::type_info().type_params
+        );
+
+        assert_eq!(Address::type_info().path.ident(), Some("Address"));
+        assert_eq!(
+            Bloom::type_info().path.segments,
+            ["alloy_primitives", "bits", "bloom", "Bloom"]
+        );
+    }
+}
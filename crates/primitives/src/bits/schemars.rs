@@ -0,0 +1,64 @@
+use super::FixedBytes;
+use alloc::{borrow::Cow, format};
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+/// `FixedBytes<N>` is described as a `0x`-prefixed hex string of exactly `2 *
+/// N` digits, matching its serde representation.
+impl<const N: usize> JsonSchema for FixedBytes<N> {
+    #[inline]
+    fn inline_schema() -> bool {
+        true
+    }
+
+    #[inline]
+    fn schema_name() -> Cow<'static, str> {
+        format!("FixedBytes{N}").into()
+    }
+
+    #[inline]
+    fn schema_id() -> Cow<'static, str> {
+        format!("alloy_primitives::FixedBytes<{N}>").into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": format!("^0x[0-9a-fA-F]{{{}}}$", N * 2),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, Bloom, B256};
+    use schemars::schema_for;
+
+    #[test]
+    fn schema() {
+        let schema = schema_for!(B256);
+        assert_eq!(schema.get("type").unwrap(), "string");
+        assert_eq!(schema.get("pattern").unwrap(), "^0x[0-9a-fA-F]{64}$");
+
+        let schema = schema_for!(Address);
+        assert_eq!(schema.get("pattern").unwrap(), "^0x[0-9a-fA-F]{40}$");
+        assert_eq!(schema.get("title").unwrap(), "Address");
+
+        let schema = schema_for!(Bloom);
+        assert_eq!(schema.get("pattern").unwrap(), "^0x[0-9a-fA-F]{512}$");
+    }
+
+    #[test]
+    fn inlined() {
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct Log {
+            address: Address,
+            topics: Vec<B256>,
+        }
+
+        let schema = schema_for!(Log);
+        assert!(schema.get("$defs").is_none());
+        let address = &schema.get("properties").unwrap()["address"];
+        assert_eq!(address["pattern"], "^0x[0-9a-fA-F]{40}$");
+    }
+}
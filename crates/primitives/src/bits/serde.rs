@@ -93,6 +93,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn serde_borrowed() {
+        use serde::de::value::{BorrowedBytesDeserializer, BorrowedStrDeserializer, Error};
+
+        let expected = FixedBytes([0x12, 0x34, 0x56, 0x78]);
+        let de = BorrowedStrDeserializer::<Error>::new("0x12345678");
+        assert_eq!(FixedBytes::<4>::deserialize(de).unwrap(), expected);
+        let de = BorrowedBytesDeserializer::<Error>::new(&[0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(FixedBytes::<4>::deserialize(de).unwrap(), expected);
+
+        #[derive(Deserialize)]
+        struct Borrowed<'a> {
+            name: &'a str,
+            fixed: FixedBytes<4>,
+        }
+        let json = r#"{"name":"a","fixed":"0x12345678"}"#;
+        let v = serde_json::from_str::<Borrowed<'_>>(json).unwrap();
+        assert_eq!(v.name, "a");
+        assert_eq!(v.fixed, expected);
+    }
+
     #[test]
     fn serde_num_array() {
         let json = serde_json::json! {{"fixed": [0,1,2,3,4]}};
@@ -117,6 +138,16 @@ mod tests {
         let bytes = FixedBytes([0, 0, 0, 0, 1, 35, 69, 103, 137, 171, 205, 239]);
 
         let bin = bincode::serialize(&bytes).unwrap();
+        // length prefix + raw bytes
+        assert_eq!(bin.len(), 8 + 12);
         assert_eq!(bincode::deserialize::<FixedBytes<12>>(&bin).unwrap(), bytes);
+
+        let address = crate::Address::repeat_byte(0x11);
+        let bin = bincode::serialize(&address).unwrap();
+        assert_eq!(bin.len(), 8 + 20);
+        assert_eq!(
+            bincode::deserialize::<crate::Address>(&bin).unwrap(),
+            address
+        );
     }
 }
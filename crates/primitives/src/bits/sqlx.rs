@@ -0,0 +1,102 @@
+use super::FixedBytes;
+use alloc::vec::Vec;
+use sqlx_core::{
+    database::Database,
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+
+/// `FixedBytes<N>` is stored in the database's binary type, like `Vec<u8>`:
+/// `BYTEA` in Postgres, `VARBINARY`/`BLOB` in MySQL and `BLOB` in SQLite.
+impl<const N: usize, DB: Database> Type<DB> for FixedBytes<N>
+where
+    Vec<u8>: Type<DB>,
+{
+    #[inline]
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as Type<DB>>::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'a, const N: usize, DB: Database> Encode<'a, DB> for FixedBytes<N>
+where
+    Vec<u8>: Encode<'a, DB>,
+{
+    #[inline]
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'a>) -> Result<IsNull, BoxDynError> {
+        self.0.to_vec().encode_by_ref(buf)
+    }
+}
+
+impl<'a, const N: usize, DB: Database> Decode<'a, DB> for FixedBytes<N>
+where
+    &'a [u8]: Decode<'a, DB>,
+{
+    #[inline]
+    fn decode(value: DB::ValueRef<'a>) -> Result<Self, BoxDynError> {
+        let bytes = <&[u8] as Decode<DB>>::decode(value)?;
+        Ok(Self::try_from(bytes)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, FixedBytes, B256};
+    use sqlx_core::{connection::Connection, query_scalar::query_scalar};
+    use sqlx_sqlite::{Sqlite, SqliteConnection};
+
+    fn roundtrip<T>(value: T)
+    where
+        T: for<'a> super::Encode<'a, Sqlite>
+            + for<'a> super::Decode<'a, Sqlite>
+            + super::Type<Sqlite>
+            + Clone
+            + PartialEq
+            + core::fmt::Debug
+            + Send
+            + Unpin,
+    {
+        futures_executor::block_on(async {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            let decoded: T = query_scalar("SELECT ?")
+                .bind(value.clone())
+                .fetch_one(&mut conn)
+                .await
+                .unwrap();
+            assert_eq!(decoded, value);
+
+            let blob: Vec<u8> = query_scalar("SELECT ?")
+                .bind(value)
+                .fetch_one(&mut conn)
+                .await
+                .unwrap();
+            assert_eq!(blob.len(), core::mem::size_of::<T>());
+        });
+    }
+
+    #[test]
+    fn blob() {
+        roundtrip(B256::repeat_byte(0x11));
+        roundtrip(Address::repeat_byte(0x22));
+        roundtrip(FixedBytes::<0>::ZERO);
+    }
+
+    #[test]
+    fn blob_wrong_length() {
+        futures_executor::block_on(async {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            let result = query_scalar::<_, Address>("SELECT ?")
+                .bind(B256::ZERO)
+                .fetch_one(&mut conn)
+                .await;
+            assert!(result.is_err());
+        });
+    }
+}
@@ -0,0 +1,139 @@
+//! SSZ encoding and tree hashing for fixed-size byte arrays.
+//!
+//! [`Uint`](ruint::Uint) gets its SSZ encoding from `ruint`'s `ssz` feature,
+//! and its tree hash from the [`uint_tree_hash`](crate::uint_tree_hash)
+//! module.
+
+use super::FixedBytes;
+use alloc::vec::Vec;
+use ssz::{Decode, DecodeError, Encode};
+use tree_hash::{merkle_root, Hash256, PackedEncoding, TreeHash, TreeHashType, BYTES_PER_CHUNK};
+
+/// `FixedBytes<N>` is encoded as an SSZ `Vector[byte, N]`.
+impl<const N: usize> Encode for FixedBytes<N> {
+    #[inline]
+    fn is_ssz_fixed_len() -> bool {
+        true
+    }
+
+    #[inline]
+    fn ssz_fixed_len() -> usize {
+        N
+    }
+
+    #[inline]
+    fn ssz_bytes_len(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.0);
+    }
+}
+
+impl<const N: usize> Decode for FixedBytes<N> {
+    #[inline]
+    fn is_ssz_fixed_len() -> bool {
+        true
+    }
+
+    #[inline]
+    fn ssz_fixed_len() -> usize {
+        N
+    }
+
+    #[inline]
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        Self::try_from(bytes).map_err(|_| DecodeError::InvalidByteLength {
+            len: bytes.len(),
+            expected: N,
+        })
+    }
+}
+
+/// `FixedBytes<N>` is hashed as an SSZ `Vector[byte, N]`: the bytes are
+/// right-padded to a multiple of 32 and merkleized.
+impl<const N: usize> TreeHash for FixedBytes<N> {
+    #[inline]
+    fn tree_hash_type() -> TreeHashType {
+        TreeHashType::Vector
+    }
+
+    #[inline]
+    fn tree_hash_packed_encoding(&self) -> PackedEncoding {
+        let mut encoding = PackedEncoding::from_slice(&self.0);
+        encoding.resize(
+            (N + BYTES_PER_CHUNK - 1) / BYTES_PER_CHUNK * BYTES_PER_CHUNK,
+            0,
+        );
+        encoding
+    }
+
+    #[inline]
+    fn tree_hash_packing_factor() -> usize {
+        1
+    }
+
+    #[inline]
+    fn tree_hash_root(&self) -> Hash256 {
+        merkle_root(&self.0, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, Bloom, B256, U256};
+
+    #[test]
+    fn encode_decode() {
+        let hash = B256::repeat_byte(0x11);
+        assert_eq!(hash.as_ssz_bytes(), hash.to_vec());
+        assert_eq!(B256::from_ssz_bytes(&hash.as_ssz_bytes()).unwrap(), hash);
+
+        let address = Address::repeat_byte(0x22);
+        assert_eq!(<Address as Encode>::ssz_fixed_len(), 20);
+        assert_eq!(address.as_ssz_bytes(), address.to_vec());
+        assert_eq!(
+            Address::from_ssz_bytes(&address.as_ssz_bytes()).unwrap(),
+            address
+        );
+
+        let bloom = Bloom::repeat_byte(0x33);
+        assert_eq!(Bloom::from_ssz_bytes(&bloom.as_ssz_bytes()).unwrap(), bloom);
+
+        let value = U256::from(0x0102);
+        let bytes = value.as_ssz_bytes();
+        assert_eq!(bytes[..2], [0x02, 0x01]);
+        assert_eq!(U256::from_ssz_bytes(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn decode_invalid_length() {
+        assert!(matches!(
+            Address::from_ssz_bytes(&[0; 19]),
+            Err(DecodeError::InvalidByteLength {
+                len: 19,
+                expected: 20
+            })
+        ));
+        assert!(B256::from_ssz_bytes(&[0; 33]).is_err());
+    }
+
+    #[test]
+    fn tree_hash() {
+        let hash = B256::repeat_byte(0x11);
+        assert_eq!(hash.tree_hash_root().as_bytes(), hash.as_slice());
+
+        let address = Address::repeat_byte(0x22);
+        let mut padded = [0u8; 32];
+        padded[..20].copy_from_slice(address.as_slice());
+        assert_eq!(address.tree_hash_root().as_bytes(), &padded);
+        assert_eq!(&address.tree_hash_packed_encoding()[..], &padded);
+
+        let bloom = Bloom::repeat_byte(0x33);
+        assert_eq!(bloom.tree_hash_root(), merkle_root(bloom.as_slice(), 0));
+        assert_ne!(bloom.tree_hash_root(), Bloom::ZERO.tree_hash_root());
+    }
+}
@@ -0,0 +1,30 @@
+//! Zeroize support for fixed-size byte arrays.
+//!
+//! `FixedBytes<N>` is `Copy`, so it cannot implement `Drop` or
+//! `ZeroizeOnDrop`. Wrap secrets in [`Zeroizing`](zeroize::Zeroizing) to wipe
+//! them when they go out of scope.
+
+use super::FixedBytes;
+use zeroize::DefaultIsZeroes;
+
+impl<const N: usize> DefaultIsZeroes for FixedBytes<N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, B256};
+    use zeroize::{Zeroize, Zeroizing};
+
+    #[test]
+    fn zeroize() {
+        let mut key = B256::repeat_byte(0x42);
+        key.zeroize();
+        assert_eq!(key, B256::ZERO);
+
+        let mut address = Address::repeat_byte(0x42);
+        address.zeroize();
+        assert_eq!(address, Address::ZERO);
+
+        let key = Zeroizing::new(B256::repeat_byte(0x42));
+        assert_eq!(*key, B256::repeat_byte(0x42));
+    }
+}
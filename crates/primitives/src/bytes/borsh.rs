@@ -0,0 +1,32 @@
+use crate::Bytes;
+use alloc::vec::Vec;
+use borsh::{io, BorshDeserialize, BorshSerialize};
+
+impl BorshSerialize for Bytes {
+    #[inline]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.as_ref().serialize(writer)
+    }
+}
+
+impl BorshDeserialize for Bytes {
+    #[inline]
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Vec::<u8>::deserialize_reader(reader).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let bytes = Bytes::from_static(&[1, 35, 69, 103, 137, 171, 205, 239]);
+        let bin = borsh::to_vec(&bytes).unwrap();
+        // u32 little-endian length prefix, same as `Vec<u8>`
+        assert_eq!(bin[..4], [8, 0, 0, 0]);
+        assert_eq!(bin, borsh::to_vec(&bytes.to_vec()).unwrap());
+        assert_eq!(Bytes::try_from_slice(&bin).unwrap(), bytes);
+    }
+}
@@ -0,0 +1,11 @@
+use super::Bytes;
+use defmt::{Format, Formatter};
+
+/// Formats as a `0x`-prefixed lowercase hex string, like [`LowerHex`].
+///
+/// [`LowerHex`]: core::fmt::LowerHex
+impl Format for Bytes {
+    fn format(&self, f: Formatter<'_>) {
+        crate::bits::defmt::write_hex(f, self)
+    }
+}
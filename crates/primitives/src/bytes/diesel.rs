@@ -0,0 +1,91 @@
+use super::Bytes;
+use alloc::vec::Vec;
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql},
+    expression::AsExpression,
+    internal::derives::as_expression::Bound,
+    serialize::{self, Output, ToSql},
+    sql_types::{Binary, Nullable, SingleValue},
+    Queryable,
+};
+
+/// `Bytes` maps to the `Binary` SQL type (`BYTEA` in Postgres).
+impl<Db: Backend> ToSql<Binary, Db> for Bytes
+where
+    [u8]: ToSql<Binary, Db>,
+{
+    #[inline]
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Db>) -> serialize::Result {
+        <[u8] as ToSql<Binary, Db>>::to_sql(self.as_ref(), out)
+    }
+}
+
+impl<Db: Backend> ToSql<Nullable<Binary>, Db> for Bytes
+where
+    Self: ToSql<Binary, Db>,
+{
+    #[inline]
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Db>) -> serialize::Result {
+        ToSql::<Binary, Db>::to_sql(self, out)
+    }
+}
+
+impl<Db: Backend> FromSql<Binary, Db> for Bytes
+where
+    Vec<u8>: FromSql<Binary, Db>,
+{
+    #[inline]
+    fn from_sql(bytes: Db::RawValue<'_>) -> deserialize::Result<Self> {
+        <Vec<u8> as FromSql<Binary, Db>>::from_sql(bytes).map(Into::into)
+    }
+}
+
+impl<Db: Backend, St: SingleValue> Queryable<St, Db> for Bytes
+where
+    Self: FromSql<St, Db>,
+{
+    type Row = Self;
+
+    #[inline]
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+macro_rules! impl_as_expression {
+    ($($t:ty),+) => {$(
+        impl AsExpression<Binary> for $t {
+            type Expression = Bound<Binary, Self>;
+
+            #[inline]
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+
+        impl AsExpression<Nullable<Binary>> for $t {
+            type Expression = Bound<Nullable<Binary>, Self>;
+
+            #[inline]
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+    )+};
+}
+
+impl_as_expression!(Bytes, &Bytes, &&Bytes);
+
+#[cfg(test)]
+mod tests {
+    use crate::{diesel_types::tests::roundtrip, Bytes};
+    use diesel::sql_types::Binary;
+
+    #[test]
+    fn binary() {
+        let bytes = Bytes::from_static(&[1, 2, 3, 4]);
+        assert_eq!(roundtrip::<Binary, _>(&bytes), [1, 2, 3, 4]);
+        assert_eq!(roundtrip::<Binary, _>(&Bytes::new()), [0u8; 0]);
+    }
+}
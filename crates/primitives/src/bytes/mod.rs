@@ -1,3 +1,4 @@
+use crate::utils::{fmt_hex, fmt_hex_short, DisplayFn};
 use alloc::{boxed::Box, string::String, vec::Vec};
 use core::{
     borrow::Borrow,
@@ -5,13 +6,47 @@ use core::{
     ops::{Deref, DerefMut, RangeBounds},
 };
 
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "defmt")]
+mod defmt;
+
+#[cfg(feature = "diesel")]
+mod diesel;
+
+#[cfg(feature = "postgres")]
+mod postgres;
+
+#[cfg(feature = "rkyv")]
+mod rkyv;
+
 #[cfg(feature = "rlp")]
 mod rlp;
 
+#[cfg(feature = "scale")]
+mod scale;
+
+#[cfg(feature = "json-schema")]
+mod schemars;
+
 #[cfg(feature = "serde")]
 mod serde;
 
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
 /// Wrapper type around [`bytes::Bytes`] to support "0x" prefixed hex strings.
+///
+/// `Bytes` is a cheaply cloneable, reference-counted view into a contiguous
+/// buffer. Cloning and [slicing](Self::slice) only bump a reference count
+/// and never copy the underlying data; copies happen only when constructing
+/// from borrowed data (e.g. [`copy_from_slice`](Self::copy_from_slice)), or
+/// when converting into an owned container such as [`Vec<u8>`] from a shared
+/// or static buffer.
 #[derive(Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct Bytes(pub bytes::Bytes);
@@ -287,13 +322,63 @@ impl Bytes {
         Self(bytes::Bytes::copy_from_slice(data))
     }
 
+    /// Creates a new `Bytes` that keeps `owner` alive and points to the bytes
+    /// it exposes through [`AsRef<[u8]>`].
+    ///
+    /// The data is not copied. This is useful to wrap buffers managed
+    /// elsewhere, such as memory-mapped files, and share them without
+    /// duplicating their contents. `owner` is dropped once the last `Bytes`
+    /// referencing it is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::Bytes;
+    ///
+    /// let owner = vec![1u8, 2, 3, 4];
+    /// let b = Bytes::from_owner(owner);
+    /// assert_eq!(b.slice(1..3)[..], [2, 3]);
+    /// ```
+    #[inline]
+    pub fn from_owner<T>(owner: T) -> Self
+    where
+        T: AsRef<[u8]> + Send + 'static,
+    {
+        Self(bytes::Bytes::from_owner(owner))
+    }
+
     /// Returns a slice of self for the provided range.
+    ///
+    /// This does not copy: the returned `Bytes` shares the underlying buffer
+    /// with `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::Bytes;
+    ///
+    /// let b = Bytes::from_static(b"hello world");
+    /// let hello = b.slice(..5);
+    /// assert_eq!(&hello[..], b"hello");
+    /// assert_eq!(hello.as_ptr(), b.as_ptr());
+    /// ```
     #[inline]
     pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
         Self(self.0.slice(range))
     }
 
     /// Returns a slice of self that is equivalent to the given `subset`.
+    ///
+    /// This does not copy: the returned `Bytes` shares the underlying buffer
+    /// with `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `subset` is not contained within `self`.
     #[inline]
     pub fn slice_ref(&self, subset: &[u8]) -> Self {
         Self(self.0.slice_ref(subset))
@@ -313,6 +398,54 @@ impl Bytes {
         Self(self.0.split_to(at))
     }
 
+    /// Returns an adapter which displays the bytes as `0x`-prefixed uppercase
+    /// hex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::Bytes;
+    ///
+    /// let b = Bytes::from_static(&[0xde, 0xad]);
+    /// assert_eq!(b.display_upper().to_string(), "0xDEAD");
+    /// ```
+    #[inline]
+    pub fn display_upper(&self) -> impl fmt::Display + '_ {
+        DisplayFn(move |f: &mut fmt::Formatter<'_>| fmt_hex(f, self, true, true))
+    }
+
+    /// Returns an adapter which displays the bytes as lowercase hex, without
+    /// the `0x` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::Bytes;
+    ///
+    /// let b = Bytes::from_static(&[0xde, 0xad]);
+    /// assert_eq!(b.display_unprefixed().to_string(), "dead");
+    /// ```
+    #[inline]
+    pub fn display_unprefixed(&self) -> impl fmt::Display + '_ {
+        DisplayFn(move |f: &mut fmt::Formatter<'_>| fmt_hex(f, self, false, false))
+    }
+
+    /// Returns an adapter which displays the bytes in the truncated
+    /// `0x1234…abcd` form. Values of 4 bytes or less are displayed in full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::Bytes;
+    ///
+    /// let b = Bytes::from_static(&[0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc]);
+    /// assert_eq!(b.display_short().to_string(), "0x1234…9abc");
+    /// ```
+    #[inline]
+    pub fn display_short(&self) -> impl fmt::Display + '_ {
+        DisplayFn(move |f: &mut fmt::Formatter<'_>| fmt_hex_short(f, self))
+    }
+
     #[inline]
     fn hex_encode(&self) -> String {
         hex::encode_prefixed(self.0.as_ref())
@@ -381,6 +514,24 @@ mod tests {
         assert_eq!(format!("{b}"), expected);
     }
 
+    #[test]
+    fn slice_shares_buffer() {
+        let b = Bytes::from(vec![0u8; 64]);
+        let sub = b.slice(16..32);
+        assert_eq!(sub.len(), 16);
+        assert_eq!(sub.as_ptr(), b[16..].as_ptr());
+        assert_eq!(b.slice_ref(&sub).as_ptr(), sub.as_ptr());
+    }
+
+    #[test]
+    fn from_owner() {
+        let owner: Box<[u8]> = Box::new([1, 2, 3]);
+        let ptr = owner.as_ptr();
+        let b = Bytes::from_owner(owner);
+        assert_eq!(b.as_ptr(), ptr);
+        assert_eq!(b, [1u8, 2, 3][..]);
+    }
+
     #[test]
     fn debug() {
         let b = Bytes::from_static(&[1, 35, 69, 103, 137, 171, 205, 239]);
@@ -0,0 +1,73 @@
+use super::Bytes;
+use crate::private::BoxedError;
+use bytes::{BufMut, BytesMut};
+use core::str;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type, WrongType};
+
+/// Converts to Postgres types.
+///
+/// The mapping is selected by the type of the target column:
+/// - `BYTEA` stores the raw bytes;
+/// - `TEXT`, `VARCHAR` and `CHAR(n)` store a `0x`-prefixed lowercase hex
+///   string.
+impl ToSql for Bytes {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxedError> {
+        match *ty {
+            Type::BYTEA => out.put_slice(&self.0),
+            Type::TEXT | Type::VARCHAR | Type::BPCHAR => {
+                out.put_slice(self.hex_encode().as_bytes())
+            }
+            _ => return Err(Box::new(WrongType::new::<Self>(ty.clone()))),
+        }
+        Ok(IsNull::No)
+    }
+
+    accepts!(BYTEA, TEXT, VARCHAR, BPCHAR);
+
+    to_sql_checked!();
+}
+
+/// Converts from Postgres types.
+///
+/// See [`ToSql`](#impl-ToSql-for-Bytes) for the supported mappings. Hex
+/// strings are accepted with or without the `0x` prefix, in any case.
+impl<'a> FromSql<'a> for Bytes {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxedError> {
+        match *ty {
+            Type::BYTEA => Ok(Self::copy_from_slice(raw)),
+            // `CHAR(n)` values are right-padded with spaces.
+            _ => Ok(str::from_utf8(raw)?.trim_end().parse()?),
+        }
+    }
+
+    accepts!(BYTEA, TEXT, VARCHAR, BPCHAR);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let bytes = Bytes::from_static(&[0xde, 0xad, 0xbe, 0xef]);
+        for (ty, expected) in [
+            (Type::BYTEA, &bytes[..]),
+            (Type::TEXT, b"0xdeadbeef"),
+            (Type::VARCHAR, b"0xdeadbeef"),
+            (Type::BPCHAR, b"0xdeadbeef"),
+        ] {
+            let mut out = BytesMut::new();
+            bytes.to_sql_checked(&ty, &mut out).unwrap();
+            assert_eq!(&out[..], expected);
+            assert_eq!(Bytes::from_sql(&ty, &out).unwrap(), bytes);
+        }
+
+        assert_eq!(Bytes::from_sql(&Type::TEXT, b"0x").unwrap(), Bytes::new());
+        assert_eq!(
+            Bytes::from_sql(&Type::BPCHAR, b"0xdeadbeef  ").unwrap(),
+            bytes
+        );
+        assert!(Bytes::from_sql(&Type::TEXT, b"0x123").is_err());
+        assert!(!<Bytes as ToSql>::accepts(&Type::INT4));
+    }
+}
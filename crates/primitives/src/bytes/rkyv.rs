@@ -0,0 +1,47 @@
+use crate::Bytes;
+use rkyv::{
+    rancor::Fallible,
+    ser::{Allocator, Writer},
+    vec::{ArchivedVec, VecResolver},
+    Archive, Deserialize, Place, Serialize,
+};
+
+/// `Bytes` archives as an [`ArchivedVec<u8>`], the same as `Vec<u8>`.
+impl Archive for Bytes {
+    type Archived = ArchivedVec<u8>;
+    type Resolver = VecResolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_len(self.len(), resolver, out);
+    }
+}
+
+impl<S: Fallible + Allocator + Writer + ?Sized> Serialize<S> for Bytes {
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<u8>::serialize_from_slice(self.as_ref(), serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Bytes, D> for ArchivedVec<u8> {
+    #[inline]
+    fn deserialize(&self, _deserializer: &mut D) -> Result<Bytes, D::Error> {
+        Ok(Bytes::copy_from_slice(self.as_slice()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rkyv::rancor::Error;
+
+    #[test]
+    fn roundtrip() {
+        let bytes = Bytes::from_static(&[1, 35, 69, 103, 137, 171, 205, 239]);
+        let archive = rkyv::to_bytes::<Error>(&bytes).unwrap();
+        let archived = rkyv::access::<ArchivedVec<u8>, Error>(&archive).unwrap();
+        assert_eq!(archived.as_slice(), &bytes[..]);
+        assert_eq!(rkyv::deserialize::<Bytes, Error>(archived).unwrap(), bytes);
+    }
+}
@@ -0,0 +1,60 @@
+use super::Bytes;
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode, EncodeLike, Error, Input, Output};
+use scale_info::{Type, TypeInfo};
+
+/// `Bytes` is encoded like `Vec<u8>`: a compact length prefix followed by the
+/// raw bytes.
+impl Encode for Bytes {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.as_ref().size_hint()
+    }
+
+    #[inline]
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.as_ref().encode_to(dest)
+    }
+}
+
+impl EncodeLike for Bytes {}
+
+impl EncodeLike<Vec<u8>> for Bytes {}
+
+impl Decode for Bytes {
+    #[inline]
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        Vec::<u8>::decode(input).map(Into::into)
+    }
+}
+
+impl TypeInfo for Bytes {
+    type Identity = [u8];
+
+    #[inline]
+    fn type_info() -> Type {
+        <[u8]>::type_info()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let bytes = Bytes::from_static(&[1, 2, 3]);
+        let bin = bytes.encode();
+        assert_eq!(bin, vec![1u8, 2, 3].encode());
+        assert_eq!(bin, [3 << 2, 1, 2, 3]);
+        assert_eq!(Bytes::decode(&mut &bin[..]).unwrap(), bytes);
+
+        assert_eq!(Bytes::new().encode(), [0]);
+        assert!(Bytes::decode(&mut &[3 << 2, 1][..]).is_err());
+    }
+
+    #[test]
+    fn type_info() {
+        assert_eq!(Bytes::type_info(), Vec::<u8>::type_info());
+    }
+}
@@ -0,0 +1,42 @@
+use super::Bytes;
+use alloc::borrow::Cow;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+/// `Bytes` is described as a `0x`-prefixed hex string with an even number of
+/// digits, matching its serde representation.
+impl JsonSchema for Bytes {
+    #[inline]
+    fn inline_schema() -> bool {
+        true
+    }
+
+    #[inline]
+    fn schema_name() -> Cow<'static, str> {
+        "Bytes".into()
+    }
+
+    #[inline]
+    fn schema_id() -> Cow<'static, str> {
+        "alloy_primitives::Bytes".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": "^0x([0-9a-fA-F]{2})*$",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use schemars::schema_for;
+
+    #[test]
+    fn schema() {
+        let schema = schema_for!(Bytes);
+        assert_eq!(schema.get("type").unwrap(), "string");
+        assert_eq!(schema.get("pattern").unwrap(), "^0x([0-9a-fA-F]{2})*$");
+    }
+}
@@ -27,7 +27,7 @@ impl<'de> serde::Deserialize<'de> for Bytes {
             }
 
             fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
-                Ok(Bytes::from(v.to_vec()))
+                Ok(Bytes::copy_from_slice(v))
             }
 
             fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
@@ -83,6 +83,17 @@ mod tests {
         assert_eq!(serde_json::from_value::<Bytes>(val).unwrap(), bytes);
     }
 
+    #[test]
+    fn serde_borrowed() {
+        use serde::de::value::{BorrowedBytesDeserializer, BorrowedStrDeserializer, Error};
+
+        let expected = Bytes::from_static(&[0x12, 0x34]);
+        let de = BorrowedStrDeserializer::<Error>::new("0x1234");
+        assert_eq!(Bytes::deserialize(de).unwrap(), expected);
+        let de = BorrowedBytesDeserializer::<Error>::new(&[0x12, 0x34]);
+        assert_eq!(Bytes::deserialize(de).unwrap(), expected);
+    }
+
     #[test]
     fn serde_num_array() {
         let json = serde_json::json! {{"variable": [0,1,2,3,4]}};
@@ -98,6 +109,7 @@ mod tests {
         let bytes = Bytes::from_static(&[1, 35, 69, 103, 137, 171, 205, 239]);
 
         let bin = bincode::serialize(&bytes).unwrap();
+        assert_eq!(bin.len(), 8 + 8);
         assert_eq!(bincode::deserialize::<Bytes>(&bin).unwrap(), bytes);
     }
 }
@@ -0,0 +1,68 @@
+use super::Bytes;
+use alloc::vec::Vec;
+use sqlx_core::{
+    database::Database,
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+
+/// `Bytes` is stored in the database's binary type, like `Vec<u8>`: `BYTEA`
+/// in Postgres, `VARBINARY`/`BLOB` in MySQL and `BLOB` in SQLite.
+impl<DB: Database> Type<DB> for Bytes
+where
+    Vec<u8>: Type<DB>,
+{
+    #[inline]
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as Type<DB>>::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'a, DB: Database> Encode<'a, DB> for Bytes
+where
+    Vec<u8>: Encode<'a, DB>,
+{
+    #[inline]
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'a>) -> Result<IsNull, BoxDynError> {
+        self.to_vec().encode_by_ref(buf)
+    }
+}
+
+impl<'a, DB: Database> Decode<'a, DB> for Bytes
+where
+    &'a [u8]: Decode<'a, DB>,
+{
+    #[inline]
+    fn decode(value: DB::ValueRef<'a>) -> Result<Self, BoxDynError> {
+        <&[u8] as Decode<DB>>::decode(value).map(Self::copy_from_slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx_core::{connection::Connection, query_scalar::query_scalar};
+    use sqlx_sqlite::SqliteConnection;
+
+    #[test]
+    fn blob() {
+        futures_executor::block_on(async {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            for value in [Bytes::from_static(&[1, 2, 3, 4]), Bytes::new()] {
+                let decoded: Bytes = query_scalar("SELECT ?")
+                    .bind(value.clone())
+                    .fetch_one(&mut conn)
+                    .await
+                    .unwrap();
+                assert_eq!(decoded, value);
+            }
+        });
+    }
+}
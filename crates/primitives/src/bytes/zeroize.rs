@@ -0,0 +1,37 @@
+use super::Bytes;
+use zeroize::Zeroize;
+
+/// Wipes the contents of `self` and leaves it empty.
+///
+/// The underlying buffer can only be wiped if `self` is its only handle:
+/// static data and buffers shared with other `Bytes` handles are released
+/// without being overwritten.
+impl Zeroize for Bytes {
+    fn zeroize(&mut self) {
+        if let Ok(mut bytes) = core::mem::take(&mut self.0).try_into_mut() {
+            bytes.as_mut().zeroize();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zeroize() {
+        let mut bytes = Bytes::from(vec![1, 2, 3]);
+        bytes.zeroize();
+        assert!(bytes.is_empty());
+
+        let mut shared = Bytes::from(vec![1, 2, 3]);
+        let other = shared.clone();
+        shared.zeroize();
+        assert!(shared.is_empty());
+        assert_eq!(other, [1, 2, 3][..]);
+
+        let mut bytes = Bytes::from_static(b"static");
+        bytes.zeroize();
+        assert!(bytes.is_empty());
+    }
+}
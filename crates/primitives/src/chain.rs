@@ -0,0 +1,353 @@
+use core::{fmt, num::ParseIntError, str::FromStr};
+
+/// A chain identifier, as introduced in [EIP-155].
+///
+/// This is a thin wrapper around a [`u64`] which prevents chain IDs from being
+/// confused with other integers, such as block numbers or nonces.
+///
+/// Constants are provided for well-known networks. With the `known-chains`
+/// feature, `name` and `from_name` also map between these and their canonical
+/// names.
+///
+/// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::ChainId;
+///
+/// let chain_id = ChainId::MAINNET;
+/// assert_eq!(chain_id, ChainId::new(1));
+/// assert_eq!(u64::from(chain_id), 1);
+/// assert_eq!("10".parse::<ChainId>().unwrap(), ChainId::OPTIMISM);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(derive_arbitrary::Arbitrary))]
+#[repr(transparent)]
+pub struct ChainId(pub u64);
+
+macro_rules! known_chains {
+    ($($(#[$attr:meta])* $konst:ident = $id:literal => $name:literal,)+) => {
+        impl ChainId {
+            $(
+                $(#[$attr])*
+                pub const $konst: Self = Self($id);
+            )+
+
+            /// All the chains with a constant on this type, with their names.
+            #[cfg(feature = "known-chains")]
+            pub const KNOWN: &'static [(Self, &'static str)] = &[$((Self::$konst, $name)),+];
+        }
+    };
+}
+
+known_chains! {
+    /// Ethereum mainnet.
+    MAINNET = 1 => "mainnet",
+    /// The Goerli testnet (deprecated).
+    GOERLI = 5 => "goerli",
+    /// The Sepolia testnet.
+    SEPOLIA = 11155111 => "sepolia",
+    /// The Holesky testnet.
+    HOLESKY = 17000 => "holesky",
+    /// OP Mainnet.
+    OPTIMISM = 10 => "optimism",
+    /// BNB Smart Chain.
+    BSC = 56 => "bsc",
+    /// Gnosis Chain.
+    GNOSIS = 100 => "gnosis",
+    /// Polygon PoS.
+    POLYGON = 137 => "polygon",
+    /// Fantom Opera.
+    FANTOM = 250 => "fantom",
+    /// zkSync Era.
+    ZKSYNC = 324 => "zksync",
+    /// Base.
+    BASE = 8453 => "base",
+    /// Arbitrum One.
+    ARBITRUM = 42161 => "arbitrum",
+    /// Avalanche C-Chain.
+    AVALANCHE = 43114 => "avalanche",
+    /// Linea.
+    LINEA = 59144 => "linea",
+    /// Scroll.
+    SCROLL = 534352 => "scroll",
+}
+
+impl From<u64> for ChainId {
+    #[inline]
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ChainId> for u64 {
+    #[inline]
+    fn from(value: ChainId) -> Self {
+        value.0
+    }
+}
+
+impl PartialEq<u64> for ChainId {
+    #[inline]
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for ChainId {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for ChainId {
+    type Err = ParseIntError;
+
+    /// Parses a chain ID from its decimal representation, or, with the
+    /// `known-chains` feature, from the name of a known chain.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        #[cfg(feature = "known-chains")]
+        if let Some(chain_id) = Self::from_name(s) {
+            return Ok(chain_id)
+        }
+        s.parse().map(Self)
+    }
+}
+
+impl ChainId {
+    /// Creates a new chain ID.
+    #[inline]
+    pub const fn new(id: u64) -> Self {
+        Self(id)
+    }
+
+    /// Returns the chain ID as a `u64`.
+    #[inline]
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the canonical name of the chain, if it is [known](Self::KNOWN).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::ChainId;
+    ///
+    /// assert_eq!(ChainId::MAINNET.name(), Some("mainnet"));
+    /// assert_eq!(ChainId::from_name("sepolia"), Some(ChainId::SEPOLIA));
+    /// assert_eq!("sepolia".parse::<ChainId>().unwrap(), ChainId::SEPOLIA);
+    /// ```
+    #[cfg(feature = "known-chains")]
+    #[inline]
+    pub fn name(self) -> Option<&'static str> {
+        Self::KNOWN
+            .iter()
+            .find(|(id, _)| *id == self)
+            .map(|&(_, name)| name)
+    }
+
+    /// Returns the chain ID of the [known chain](Self::KNOWN) with the given
+    /// name. The comparison is ASCII case-insensitive.
+    #[cfg(feature = "known-chains")]
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::KNOWN
+            .iter()
+            .find(|(_, n)| n.eq_ignore_ascii_case(name))
+            .map(|&(id, _)| id)
+    }
+
+    /// Returns whether the `v` value of a legacy transaction signature is
+    /// replay-protected, i.e. whether it encodes a chain ID as specified in
+    /// [EIP-155].
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::ChainId;
+    ///
+    /// assert!(!ChainId::is_legacy_protected(27));
+    /// assert!(!ChainId::is_legacy_protected(28));
+    /// assert!(ChainId::is_legacy_protected(37));
+    /// ```
+    #[inline]
+    pub const fn is_legacy_protected(v: u64) -> bool {
+        v >= 35
+    }
+
+    /// Extracts the chain ID from the `v` value of a legacy transaction
+    /// signature, as specified in [EIP-155].
+    ///
+    /// Returns `None` if `v` is not
+    /// [replay-protected](Self::is_legacy_protected).
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::ChainId;
+    ///
+    /// assert_eq!(ChainId::from_legacy_v(37), Some(ChainId::MAINNET));
+    /// assert_eq!(ChainId::from_legacy_v(38), Some(ChainId::MAINNET));
+    /// assert_eq!(ChainId::from_legacy_v(27), None);
+    /// ```
+    #[inline]
+    pub const fn from_legacy_v(v: u64) -> Option<Self> {
+        if Self::is_legacy_protected(v) {
+            Some(Self((v - 35) / 2))
+        } else {
+            None
+        }
+    }
+
+    /// Computes the replay-protected `v` value of a legacy transaction
+    /// signature for this chain, as specified in [EIP-155]:
+    /// `chain_id * 2 + 35 + y_parity`.
+    ///
+    /// Returns `None` on overflow.
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::ChainId;
+    ///
+    /// assert_eq!(ChainId::MAINNET.legacy_v(false), Some(37));
+    /// assert_eq!(ChainId::MAINNET.legacy_v(true), Some(38));
+    /// ```
+    #[inline]
+    pub const fn legacy_v(self, y_parity: bool) -> Option<u64> {
+        match self.0.checked_mul(2) {
+            Some(v) => v.checked_add(35 + y_parity as u64),
+            None => None,
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for ChainId {
+    type Parameters = ();
+    type Strategy = proptest::arbitrary::Mapped<u64, Self>;
+
+    #[inline]
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+        proptest::arbitrary::any::<u64>().prop_map(Self)
+    }
+}
+
+#[cfg(feature = "rlp")]
+impl alloy_rlp::Encodable for ChainId {
+    #[inline]
+    fn length(&self) -> usize {
+        self.0.length()
+    }
+
+    #[inline]
+    fn encode(&self, out: &mut dyn bytes::BufMut) {
+        self.0.encode(out);
+    }
+}
+
+#[cfg(feature = "rlp")]
+impl alloy_rlp::Decodable for ChainId {
+    #[inline]
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        u64::decode(buf).map(Self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ChainId {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+/// Accepts JSON numbers as well as hexadecimal and decimal strings, since
+/// JSON-RPC encodes chain IDs as quantities.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ChainId {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            crate::serde_quantity::deserialize(deserializer).map(Self)
+        } else {
+            u64::deserialize(deserializer).map(Self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "known-chains")]
+    #[test]
+    fn known() {
+        for &(id, name) in ChainId::KNOWN {
+            assert_eq!(id.name(), Some(name));
+            assert_eq!(ChainId::from_name(name), Some(id));
+            assert_eq!(name.parse::<ChainId>(), Ok(id));
+            assert_eq!(id.to_string().parse::<ChainId>(), Ok(id));
+        }
+        assert_eq!(ChainId::from_name("MAINNET"), Some(ChainId::MAINNET));
+        assert_eq!(ChainId::new(1337).name(), None);
+        assert!("unknown".parse::<ChainId>().is_err());
+    }
+
+    #[cfg(feature = "known-chains")]
+    #[test]
+    fn known_unique() {
+        for (i, (id, name)) in ChainId::KNOWN.iter().enumerate() {
+            for (other_id, other_name) in &ChainId::KNOWN[i + 1..] {
+                assert_ne!(id, other_id);
+                assert_ne!(name, other_name);
+            }
+        }
+    }
+
+    #[test]
+    fn legacy_v() {
+        for id in [0, 1, 56, 11155111, u64::MAX / 2 - 18] {
+            let chain_id = ChainId::new(id);
+            for y_parity in [false, true] {
+                let v = chain_id.legacy_v(y_parity).unwrap();
+                assert!(ChainId::is_legacy_protected(v));
+                assert_eq!(ChainId::from_legacy_v(v), Some(chain_id));
+            }
+        }
+        assert_eq!(ChainId::new(u64::MAX / 2).legacy_v(false), None);
+        for v in [0, 1, 27, 28] {
+            assert!(!ChainId::is_legacy_protected(v));
+            assert_eq!(ChainId::from_legacy_v(v), None);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        assert_eq!(serde_json::to_string(&ChainId::MAINNET).unwrap(), "1");
+        for s in ["1", r#""1""#, r#""0x1""#] {
+            assert_eq!(
+                serde_json::from_str::<ChainId>(s).unwrap(),
+                ChainId::MAINNET
+            );
+        }
+
+        let bin = bincode::serialize(&ChainId::SEPOLIA).unwrap();
+        assert_eq!(
+            bincode::deserialize::<ChainId>(&bin).unwrap(),
+            ChainId::SEPOLIA
+        );
+    }
+}
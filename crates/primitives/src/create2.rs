@@ -0,0 +1,145 @@
+use crate::{bits::CREATE2_SALT, keccak256, Address, B256};
+use core::borrow::Borrow;
+
+/// Computes the [`CREATE2`](Address::create2) addresses of a contract deployed
+/// by the same deployer with different salts.
+///
+/// The init code is hashed once, and the `CREATE2` preimage is built once per
+/// batch of [`addresses`](Self::addresses), so that only the salt is rewritten
+/// for each address.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{address, b256, Create2Deployer};
+///
+/// let deployer = Create2Deployer::new(
+///     address!("5C69bEe701ef814a2B6a3EDD4B1652CB9cc5aA6f"),
+///     b256!("96e8ac4277198ff8b6f785478aa9a39f403cb768dd02cbee326c3e7da348845f"),
+/// );
+/// let salt = b256!("2b2f5776e38002e0c013d0d89828fdb06fee595ea2d5ed4b194e3883e823e350");
+/// let expected = address!("0d4a11d5EEaaC28EC3F61d100daF4d40471f1852");
+/// assert_eq!(deployer.address(salt), expected);
+///
+/// let salts = [
+///     salt,
+///     b256!("0000000000000000000000000000000000000000000000000000000000000001"),
+/// ];
+/// let addresses: Vec<_> = deployer.addresses(salts).collect();
+/// assert_eq!(addresses[0], (salt, expected));
+/// assert_eq!(addresses[1].1, deployer.address(salts[1]));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Create2Deployer {
+    /// The address of the deployer, such as a factory contract.
+    pub deployer: Address,
+    /// The hash of the init code of the deployed contract.
+    pub init_code_hash: B256,
+}
+
+impl Create2Deployer {
+    /// Creates a new deployer from the hash of the init code.
+    #[inline]
+    pub const fn new(deployer: Address, init_code_hash: B256) -> Self {
+        Self {
+            deployer,
+            init_code_hash,
+        }
+    }
+
+    /// Creates a new deployer from the init code, hashing it.
+    #[inline]
+    pub fn from_code<C: AsRef<[u8]>>(deployer: Address, init_code: C) -> Self {
+        Self::new(deployer, keccak256(init_code))
+    }
+
+    /// Computes the `CREATE2` address of the contract deployed with the given
+    /// salt.
+    #[inline]
+    #[must_use]
+    pub fn address<S: Borrow<[u8; 32]>>(&self, salt: S) -> Address {
+        self.deployer.create2(salt, self.init_code_hash)
+    }
+
+    /// Returns an iterator over the given salts and the `CREATE2` addresses of
+    /// the contracts deployed with them.
+    #[inline]
+    pub fn addresses<I>(&self, salts: I) -> Create2Addresses<I::IntoIter>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<[u8; 32]>,
+    {
+        Create2Addresses {
+            preimage: self.deployer.create2_preimage(&self.init_code_hash),
+            salts: salts.into_iter(),
+        }
+    }
+}
+
+/// An iterator over salts and their `CREATE2` addresses.
+///
+/// Created by [`Create2Deployer::addresses`].
+#[derive(Clone, Debug)]
+pub struct Create2Addresses<I> {
+    preimage: [u8; 85],
+    salts: I,
+}
+
+impl<I> Iterator for Create2Addresses<I>
+where
+    I: Iterator,
+    I::Item: Borrow<[u8; 32]>,
+{
+    type Item = (I::Item, Address);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let salt = self.salts.next()?;
+        self.preimage[CREATE2_SALT].copy_from_slice(salt.borrow());
+        let address = Address::from_word(keccak256(self.preimage));
+        Some((salt, address))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.salts.size_hint()
+    }
+}
+
+impl<I> ExactSizeIterator for Create2Addresses<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Borrow<[u8; 32]>,
+{
+}
+
+impl<I> core::iter::FusedIterator for Create2Addresses<I>
+where
+    I: core::iter::FusedIterator,
+    I::Item: Borrow<[u8; 32]>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::U256;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn create2_deployer() {
+        let deployer = address!("8ba1f109551bD432803012645Ac136ddd64DBA72");
+        let init_code = bytes!("6394198df16000526103ff60206004601c335afa6040516060f3");
+        let create2 = Create2Deployer::from_code(deployer, &init_code);
+        assert_eq!(create2.init_code_hash, keccak256(&init_code));
+
+        let salts: Vec<B256> = (0..100u64).map(|i| U256::from(i).into()).collect();
+        let addresses: Vec<_> = create2.addresses(&salts).collect();
+        assert_eq!(addresses.len(), salts.len());
+        for (salt, address) in addresses {
+            assert_eq!(address, deployer.create2_from_code(salt, &init_code));
+            assert_eq!(address, create2.address(salt));
+        }
+        assert_eq!(create2.addresses(&salts[..3]).len(), 3);
+    }
+}
@@ -0,0 +1,238 @@
+//! Diesel SQL types for types that cannot implement Diesel's own mappings.
+//!
+//! [`Uint`] is defined in `ruint`, so this crate cannot map it to Diesel's
+//! [`Numeric`](diesel::sql_types::Numeric). Use [`Numeric`] from this module
+//! as the column type instead: it is the same Postgres `NUMERIC` type, and
+//! [`Uint`] implements [`ToSql`], [`FromSql`] and [`Queryable`] for it. All
+//! other primitive types map to Diesel's `Binary` directly.
+//!
+//! Diesel's blanket `AsExpression` implementation also prevents this crate
+//! from implementing it for [`Uint`], so values are written with
+//! [`bind`](diesel::query_builder::SqlQuery::bind) rather than `Insertable`.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{diesel_types::Numeric, Address, U256};
+//! use diesel::{pg::Pg, prelude::*, sql_types::Binary};
+//!
+//! diesel::table! {
+//!     use diesel::sql_types::Binary;
+//!     use alloy_primitives::diesel_types::Numeric;
+//!
+//!     balances (address) {
+//!         address -> Binary,
+//!         balance -> Numeric,
+//!     }
+//! }
+//!
+//! #[derive(Queryable, Selectable)]
+//! #[diesel(table_name = balances, check_for_backend(Pg))]
+//! struct Balance {
+//!     address: Address,
+//!     balance: U256,
+//! }
+//!
+//! let insert = diesel::sql_query("INSERT INTO balances VALUES ($1, $2)")
+//!     .bind::<Binary, _>(Address::ZERO)
+//!     .bind::<Numeric, _>(U256::from(100));
+//! ```
+
+use alloc::vec::Vec;
+use diesel::{
+    deserialize::{self, FromSql},
+    pg::{data_types::PgNumeric, Pg, PgValue},
+    query_builder::QueryId,
+    serialize::{self, Output, ToSql},
+    sql_types::SqlType,
+    Queryable,
+};
+use ruint::Uint;
+
+/// The Postgres `NUMERIC` SQL type, which [`Uint`] maps to.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Clone, Copy, Debug, Default, QueryId, SqlType)]
+#[diesel(postgres_type(oid = 1700, array_oid = 1231))]
+pub struct Numeric;
+
+macro_rules! impl_numeric_ops {
+    ($($op:ident),+) => {$(
+        impl diesel::sql_types::ops::$op for Numeric {
+            type Rhs = Self;
+            type Output = Self;
+        }
+    )+};
+}
+
+impl_numeric_ops!(Add, Sub, Mul, Div);
+
+/// Base of the digits of a [`PgNumeric`].
+const NBASE: u64 = 10_000;
+
+impl<const BITS: usize, const LIMBS: usize> ToSql<Numeric, Pg> for Uint<BITS, LIMBS> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let numeric = to_numeric(self)?;
+        ToSql::<diesel::sql_types::Numeric, Pg>::to_sql(&numeric, &mut out.reborrow())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromSql<Numeric, Pg> for Uint<BITS, LIMBS> {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let numeric = <PgNumeric as FromSql<diesel::sql_types::Numeric, Pg>>::from_sql(bytes)?;
+        from_numeric(&numeric)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Queryable<Numeric, Pg> for Uint<BITS, LIMBS> {
+    type Row = Self;
+
+    #[inline]
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+/// Converts an integer to its base-10000 `NUMERIC` representation.
+fn to_numeric<const BITS: usize, const LIMBS: usize>(
+    value: &Uint<BITS, LIMBS>,
+) -> Result<PgNumeric, &'static str> {
+    let mut digits = value
+        .to_base_be(NBASE)
+        .map(|digit| digit as i16)
+        .collect::<Vec<_>>();
+    let weight = i16::try_from(digits.len().saturating_sub(1))
+        .map_err(|_| "value has too many digits for NUMERIC")?;
+    // Postgres omits trailing zero digits; the weight alone places the rest.
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    Ok(PgNumeric::Positive {
+        weight,
+        scale: 0,
+        digits,
+    })
+}
+
+/// Converts a `NUMERIC` value to an integer, rejecting negative, fractional
+/// and out-of-range values.
+fn from_numeric<const BITS: usize, const LIMBS: usize>(
+    numeric: &PgNumeric,
+) -> deserialize::Result<Uint<BITS, LIMBS>> {
+    let (weight, digits) = match numeric {
+        PgNumeric::Positive { weight, digits, .. } => (*weight, digits),
+        PgNumeric::Negative { .. } => {
+            return Err("negative NUMERIC value for unsigned integer".into())
+        }
+        PgNumeric::NaN => return Err("NaN NUMERIC value for unsigned integer".into()),
+    };
+    if digits
+        .iter()
+        .any(|&digit| !(0..NBASE as i16).contains(&digit))
+    {
+        return Err("invalid NUMERIC digit".into());
+    }
+
+    // Digits past the weight are fractional and must all be zero.
+    let integer_len = usize::try_from(i32::from(weight) + 1).unwrap_or(0);
+    if digits.iter().skip(integer_len).any(|&digit| digit != 0) {
+        return Err("fractional NUMERIC value for unsigned integer".into());
+    }
+
+    let padding = integer_len.saturating_sub(digits.len());
+    let integer = digits.iter().take(integer_len).map(|&digit| digit as u64);
+    Ok(Uint::from_base_be(
+        NBASE,
+        integer.chain(core::iter::repeat(0).take(padding)),
+    )?)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{U256, U64};
+    use diesel::{
+        pg::PgMetadataLookup,
+        query_builder::bind_collector::{BindCollector, RawBytesBindCollector},
+        sql_types::HasSqlType,
+    };
+
+    struct NoLookup;
+
+    impl PgMetadataLookup for NoLookup {
+        fn lookup_type(
+            &mut self,
+            _type_name: &str,
+            _schema: Option<&str>,
+        ) -> diesel::pg::PgTypeMetadata {
+            unreachable!()
+        }
+    }
+
+    /// Encodes `value` as a Postgres bind parameter of type `ST`, decodes it
+    /// back, and returns the encoded bytes.
+    pub(crate) fn roundtrip<ST, T>(value: &T) -> Vec<u8>
+    where
+        Pg: HasSqlType<ST>,
+        T: ToSql<ST, Pg> + FromSql<ST, Pg> + PartialEq + core::fmt::Debug,
+    {
+        let mut collector = RawBytesBindCollector::<Pg>::new();
+        collector
+            .push_bound_value::<ST, T>(value, &mut NoLookup)
+            .unwrap();
+        let bytes = collector.binds.pop().unwrap().unwrap();
+        let oid = core::num::NonZeroU32::new(1).unwrap();
+        assert_eq!(T::from_sql(PgValue::new(&bytes, &oid)).unwrap(), *value);
+        bytes
+    }
+
+    #[test]
+    fn numeric() {
+        // ndigits, weight, sign, dscale, digits
+        assert_eq!(
+            roundtrip::<Numeric, _>(&U256::ZERO),
+            [0, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            roundtrip::<Numeric, _>(&U256::from(12_345_678)),
+            [0, 2, 0, 1, 0, 0, 0, 0, 0x04, 0xd2, 0x16, 0x2e]
+        );
+        assert_eq!(
+            roundtrip::<Numeric, _>(&U256::from(100_000_000)),
+            [0, 1, 0, 2, 0, 0, 0, 0, 0, 1]
+        );
+
+        roundtrip::<Numeric, _>(&U256::MAX);
+        roundtrip::<Numeric, _>(&U64::MAX);
+    }
+
+    #[test]
+    fn numeric_invalid() {
+        let from_numeric = from_numeric::<64, 1>;
+        let positive = |weight, digits| PgNumeric::Positive {
+            weight,
+            scale: 0,
+            digits,
+        };
+
+        assert_eq!(
+            from_numeric(&positive(1, vec![1])).unwrap(),
+            U64::from(10_000)
+        );
+        assert_eq!(
+            from_numeric(&positive(0, vec![1, 0])).unwrap(),
+            U64::from(1)
+        );
+        assert!(from_numeric(&positive(0, vec![1, 5])).is_err());
+        assert!(from_numeric(&positive(-1, vec![5])).is_err());
+        assert!(from_numeric(&positive(5, vec![1])).is_err());
+        assert!(from_numeric(&positive(0, vec![10_000])).is_err());
+        assert!(from_numeric(&PgNumeric::Negative {
+            weight: 0,
+            scale: 0,
+            digits: vec![1]
+        })
+        .is_err());
+        assert!(from_numeric(&PgNumeric::NaN).is_err());
+    }
+}
@@ -0,0 +1,369 @@
+//! Explicit-precision conversions between integers and `f64`.
+
+use crate::{Sign, Signed};
+use core::fmt;
+use ruint::Uint;
+
+/// The error type that is returned when a checked float conversion fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FloatConversionError {
+    /// The float is NaN or infinite.
+    NotFinite,
+    /// The value does not fit in the target type.
+    OutOfRange,
+    /// The relative error of the conversion exceeds the given tolerance.
+    PrecisionLoss,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FloatConversionError {}
+
+impl fmt::Display for FloatConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotFinite => "float is not finite",
+            Self::OutOfRange => "value does not fit in the target type",
+            Self::PrecisionLoss => "conversion loses more precision than tolerated",
+        })
+    }
+}
+
+/// Explicit-precision conversions between big integers and [`f64`].
+///
+/// Unlike the conversions provided by [`ruint`], these are available in
+/// `no_std` environments, and spell out how precision is lost:
+///
+/// - [`to_f64_lossy`](Self::to_f64_lossy) rounds to the nearest representable
+///   float (ties to even), like `u128 as f64`;
+/// - [`from_f64_lossy`](Self::from_f64_lossy) truncates towards zero and
+///   saturates at the bounds of the type, like `f64 as u128`;
+/// - the `checked_*` variants error when the relative error of the conversion
+///   exceeds a caller-specified `tolerance`, where `0.0` requires an exact
+///   conversion.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{FloatConversion, FloatConversionError, I256, U256};
+///
+/// let x = U256::from(1u64 << 53) + U256::from(1);
+/// assert_eq!(x.to_f64_lossy(), 9007199254740992.0);
+/// assert_eq!(
+///     x.checked_to_f64(0.0),
+///     Err(FloatConversionError::PrecisionLoss)
+/// );
+/// assert_eq!(x.checked_to_f64(1e-15), Ok(9007199254740992.0));
+///
+/// assert_eq!(U256::from_f64_lossy(-1.5), U256::ZERO);
+/// assert_eq!(U256::from_f64_lossy(f64::INFINITY), U256::MAX);
+/// assert_eq!(I256::from_f64_lossy(-1.5), I256::MINUS_ONE);
+/// assert_eq!(I256::checked_from_f64(-1.5, 0.5), Ok(I256::MINUS_ONE));
+/// assert_eq!(
+///     I256::checked_from_f64(-1.5, 0.1),
+///     Err(FloatConversionError::PrecisionLoss)
+/// );
+/// ```
+pub trait FloatConversion: Sized {
+    /// Converts `self` to the nearest [`f64`], rounding ties to even.
+    ///
+    /// Returns infinity if the value is too large to be represented.
+    fn to_f64_lossy(&self) -> f64;
+
+    /// Converts `self` to an [`f64`], erroring if the relative error of the
+    /// result is greater than `tolerance`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FloatConversionError::OutOfRange`] if the result would be
+    /// infinite, and [`FloatConversionError::PrecisionLoss`] if the relative
+    /// error exceeds `tolerance`.
+    fn checked_to_f64(&self, tolerance: f64) -> Result<f64, FloatConversionError>;
+
+    /// Converts an [`f64`] to an integer, truncating the fractional part.
+    ///
+    /// Values outside of the range of the type saturate to its minimum or
+    /// maximum, and NaN is converted to zero.
+    fn from_f64_lossy(value: f64) -> Self;
+
+    /// Converts an [`f64`] to an integer, truncating the fractional part and
+    /// erroring if the relative error of the result is greater than
+    /// `tolerance`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FloatConversionError::NotFinite`] if `value` is NaN or
+    /// infinite, [`FloatConversionError::OutOfRange`] if the truncated value
+    /// does not fit in the type, and [`FloatConversionError::PrecisionLoss`] if
+    /// the relative error exceeds `tolerance`.
+    fn checked_from_f64(value: f64, tolerance: f64) -> Result<Self, FloatConversionError>;
+}
+
+impl<const BITS: usize, const LIMBS: usize> FloatConversion for Uint<BITS, LIMBS> {
+    fn to_f64_lossy(&self) -> f64 {
+        let bit_len = self.bit_len();
+        if bit_len <= 64 {
+            return self.as_limbs().first().copied().unwrap_or(0) as f64
+        }
+
+        // Keep the 64 most significant bits, and fold all the discarded bits
+        // into a sticky bit so that the final rounding is correct.
+        let shift = bit_len - 64;
+        let top = (*self >> shift).as_limbs()[0];
+        let sticky = (self.trailing_zeros() < shift) as u64;
+        (top | sticky) as f64 * exp2(shift)
+    }
+
+    fn checked_to_f64(&self, tolerance: f64) -> Result<f64, FloatConversionError> {
+        let float = self.to_f64_lossy();
+        if float == f64::INFINITY {
+            return Err(FloatConversionError::OutOfRange)
+        }
+
+        // `float` is integral, so this is only out of range when it was
+        // rounded up to `2^BITS`, in which case the error is `2^BITS - self`.
+        let error = match uint_from_f64::<BITS, LIMBS>(float) {
+            Ok(back) if back >= *self => back - *self,
+            Ok(back) => *self - back,
+            Err(_) => self.wrapping_neg(),
+        };
+        check_tolerance(error.to_f64_lossy(), float, tolerance)?;
+        Ok(float)
+    }
+
+    fn from_f64_lossy(value: f64) -> Self {
+        match uint_from_f64(value) {
+            Ok(x) => x,
+            Err(_) if value > 0.0 => Self::MAX,
+            Err(_) => Self::ZERO,
+        }
+    }
+
+    fn checked_from_f64(value: f64, tolerance: f64) -> Result<Self, FloatConversionError> {
+        let x = uint_from_f64(value)?;
+        check_tolerance(value - x.to_f64_lossy(), value, tolerance)?;
+        Ok(x)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FloatConversion for Signed<BITS, LIMBS> {
+    fn to_f64_lossy(&self) -> f64 {
+        let (sign, abs) = self.into_sign_and_abs();
+        apply_sign(sign, abs.to_f64_lossy())
+    }
+
+    fn checked_to_f64(&self, tolerance: f64) -> Result<f64, FloatConversionError> {
+        let (sign, abs) = self.into_sign_and_abs();
+        abs.checked_to_f64(tolerance)
+            .map(|float| apply_sign(sign, float))
+    }
+
+    fn from_f64_lossy(value: f64) -> Self {
+        match signed_from_f64(value) {
+            Ok(x) => x,
+            Err(FloatConversionError::NotFinite) if value.is_nan() => Self::ZERO,
+            Err(_) if value > 0.0 => Self::MAX,
+            Err(_) => Self::MIN,
+        }
+    }
+
+    fn checked_from_f64(value: f64, tolerance: f64) -> Result<Self, FloatConversionError> {
+        let x = signed_from_f64(value)?;
+        check_tolerance(value - x.to_f64_lossy(), value, tolerance)?;
+        Ok(x)
+    }
+}
+
+#[inline]
+fn apply_sign(sign: Sign, float: f64) -> f64 {
+    match sign {
+        Sign::Positive => float,
+        Sign::Negative => -float,
+    }
+}
+
+/// Converts a finite float to an integer, truncating towards zero.
+fn uint_from_f64<const BITS: usize, const LIMBS: usize>(
+    value: f64,
+) -> Result<Uint<BITS, LIMBS>, FloatConversionError> {
+    if !value.is_finite() {
+        return Err(FloatConversionError::NotFinite)
+    }
+    if value <= -1.0 {
+        return Err(FloatConversionError::OutOfRange)
+    }
+
+    let bits = value.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32 - 1075;
+    let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+    // |value| < 1, including subnormals and -0.0
+    if exponent < -52 {
+        return Ok(Uint::ZERO)
+    }
+
+    let out_of_range = |_| FloatConversionError::OutOfRange;
+    if exponent <= 0 {
+        Uint::try_from(mantissa >> -exponent).map_err(out_of_range)
+    } else {
+        Uint::try_from(mantissa)
+            .map_err(out_of_range)?
+            .checked_shl(exponent as usize)
+            .ok_or(FloatConversionError::OutOfRange)
+    }
+}
+
+/// Converts a finite float to a signed integer, truncating towards zero.
+fn signed_from_f64<const BITS: usize, const LIMBS: usize>(
+    value: f64,
+) -> Result<Signed<BITS, LIMBS>, FloatConversionError> {
+    let (sign, abs) = if value.is_sign_negative() {
+        (Sign::Negative, -value)
+    } else {
+        (Sign::Positive, value)
+    };
+    let abs = uint_from_f64(abs)?;
+    Signed::checked_from_sign_and_abs(sign, abs).ok_or(FloatConversionError::OutOfRange)
+}
+
+/// Checks that `|error| <= tolerance * |expected|`.
+fn check_tolerance(error: f64, expected: f64, tolerance: f64) -> Result<(), FloatConversionError> {
+    let abs = |x: f64| if x < 0.0 { -x } else { x };
+    if error == 0.0 || abs(error) <= tolerance * abs(expected) {
+        Ok(())
+    } else {
+        Err(FloatConversionError::PrecisionLoss)
+    }
+}
+
+/// Returns `2^n` as a float, without using `std`.
+fn exp2(n: usize) -> f64 {
+    if n > 1023 {
+        f64::INFINITY
+    } else {
+        f64::from_bits((n as u64 + 1023) << 52)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::*, I256, U256};
+
+    #[test]
+    fn uint_to_f64() {
+        assert_eq!(U0::ZERO.to_f64_lossy(), 0.0);
+        assert_eq!(U256::ZERO.to_f64_lossy(), 0.0);
+        assert_eq!(U256::from(u64::MAX).to_f64_lossy(), u64::MAX as f64);
+        assert_eq!(U256::from(u128::MAX).to_f64_lossy(), u128::MAX as f64);
+        assert_eq!(U256::MAX.to_f64_lossy(), 2f64.powi(256));
+        assert_eq!(U512::MAX.to_f64_lossy(), 2f64.powi(512));
+        assert_eq!(U2048::MAX.to_f64_lossy(), f64::INFINITY);
+
+        // matches the rounding of `u128 as f64`
+        for x in [
+            (1u128 << 100) + (1 << 47),
+            (1u128 << 100) + (1 << 47) + 1,
+            (1u128 << 100) + (3 << 47),
+            u128::MAX - (1 << 80),
+        ] {
+            assert_eq!(U256::from(x).to_f64_lossy(), x as f64, "{x}");
+            assert_eq!(U128::from(x).to_f64_lossy(), x as f64, "{x}");
+        }
+    }
+
+    #[test]
+    fn uint_from_f64() {
+        assert_eq!(U256::from_f64_lossy(0.0), U256::ZERO);
+        assert_eq!(U256::from_f64_lossy(-0.0), U256::ZERO);
+        assert_eq!(U256::from_f64_lossy(0.99), U256::ZERO);
+        assert_eq!(U256::from_f64_lossy(1.99), U256::from(1));
+        assert_eq!(U256::from_f64_lossy(-1.0), U256::ZERO);
+        assert_eq!(U256::from_f64_lossy(f64::NAN), U256::ZERO);
+        assert_eq!(U256::from_f64_lossy(f64::INFINITY), U256::MAX);
+        assert_eq!(U256::from_f64_lossy(f64::NEG_INFINITY), U256::ZERO);
+        assert_eq!(U256::from_f64_lossy(1e18), U256::from(10u64.pow(18)));
+        assert_eq!(U256::from_f64_lossy(2f64.powi(200)), U256::from(1) << 200);
+        assert_eq!(U256::from_f64_lossy(2f64.powi(256)), U256::MAX);
+        assert_eq!(U8::from_f64_lossy(255.9), U8::MAX);
+        assert_eq!(U8::from_f64_lossy(256.0), U8::MAX);
+
+        for x in [1.5e20, 123456789.0, 3.0e37, f64::MAX] {
+            assert_eq!(U128::from_f64_lossy(x).to::<u128>(), x as u128, "{x}");
+        }
+    }
+
+    #[test]
+    fn uint_checked() {
+        assert_eq!(U256::from(1).checked_to_f64(0.0), Ok(1.0));
+        assert_eq!(
+            U256::MAX.checked_to_f64(0.0),
+            Err(FloatConversionError::PrecisionLoss)
+        );
+        assert_eq!(U256::MAX.checked_to_f64(1e-15), Ok(2f64.powi(256)));
+        assert_eq!(
+            U2048::MAX.checked_to_f64(1.0),
+            Err(FloatConversionError::OutOfRange)
+        );
+
+        assert_eq!(U256::checked_from_f64(2.0, 0.0), Ok(U256::from(2)));
+        assert_eq!(
+            U256::checked_from_f64(2.5, 0.0),
+            Err(FloatConversionError::PrecisionLoss)
+        );
+        assert_eq!(U256::checked_from_f64(2.5, 0.2), Ok(U256::from(2)));
+        assert_eq!(
+            U256::checked_from_f64(0.5, 0.5),
+            Err(FloatConversionError::PrecisionLoss)
+        );
+        assert_eq!(U256::checked_from_f64(0.5, 1.0), Ok(U256::ZERO));
+        assert_eq!(
+            U256::checked_from_f64(-1.0, 1.0),
+            Err(FloatConversionError::OutOfRange)
+        );
+        assert_eq!(
+            U256::checked_from_f64(1e80, 1.0),
+            Err(FloatConversionError::OutOfRange)
+        );
+        assert_eq!(
+            U256::checked_from_f64(f64::NAN, 1.0),
+            Err(FloatConversionError::NotFinite)
+        );
+        assert_eq!(
+            U256::checked_from_f64(f64::INFINITY, 1.0),
+            Err(FloatConversionError::NotFinite)
+        );
+    }
+
+    #[test]
+    fn signed() {
+        assert_eq!(I256::ZERO.to_f64_lossy(), 0.0);
+        assert_eq!(I256::MINUS_ONE.to_f64_lossy(), -1.0);
+        assert_eq!(I256::MIN.to_f64_lossy(), -(2f64.powi(255)));
+        assert_eq!(I256::MAX.to_f64_lossy(), 2f64.powi(255));
+        assert_eq!(I128::MIN.to_f64_lossy(), i128::MIN as f64);
+
+        assert_eq!(I256::from_f64_lossy(-1.99), I256::MINUS_ONE);
+        assert_eq!(I256::from_f64_lossy(-0.5), I256::ZERO);
+        assert_eq!(I256::from_f64_lossy(f64::NAN), I256::ZERO);
+        assert_eq!(I256::from_f64_lossy(f64::INFINITY), I256::MAX);
+        assert_eq!(I256::from_f64_lossy(f64::NEG_INFINITY), I256::MIN);
+        assert_eq!(I256::from_f64_lossy(-(2f64.powi(255))), I256::MIN);
+        assert_eq!(I256::from_f64_lossy(2f64.powi(255)), I256::MAX);
+        assert_eq!(I8::from_f64_lossy(-128.0), I8::MIN);
+        assert_eq!(I8::from_f64_lossy(-129.0), I8::MIN);
+        assert_eq!(I8::from_f64_lossy(127.5), I8::MAX);
+
+        assert_eq!(I256::MIN.checked_to_f64(0.0), Ok(-(2f64.powi(255))));
+        assert_eq!(
+            I256::MAX.checked_to_f64(0.0),
+            Err(FloatConversionError::PrecisionLoss)
+        );
+        assert_eq!(
+            I256::checked_from_f64(-2.0, 0.0),
+            Ok(I256::unchecked_from(-2))
+        );
+        assert_eq!(
+            I256::checked_from_f64(2f64.powi(255), 1.0),
+            Err(FloatConversionError::OutOfRange)
+        );
+    }
+}
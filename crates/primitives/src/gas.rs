@@ -0,0 +1,156 @@
+//! Calldata gas cost calculation.
+//!
+//! [`CalldataCosts`] describes the per-byte gas costs of transaction calldata
+//! for a given fork, including the [EIP-7623] calldata floor when applicable.
+//! These only account for the calldata; the base transaction cost (21000 gas)
+//! and any other intrinsic costs are not included.
+//!
+//! [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::gas::CalldataCosts;
+//!
+//! let data = [0x00, 0x00, 0x01, 0x02];
+//! assert_eq!(CalldataCosts::FRONTIER.gas(&data), 2 * 4 + 2 * 68);
+//! assert_eq!(CalldataCosts::ISTANBUL.gas(&data), 2 * 4 + 2 * 16);
+//!
+//! // Under EIP-7623, calldata-heavy transactions pay at least the floor.
+//! assert_eq!(CalldataCosts::PRAGUE.floor_gas(&data), (2 + 2 * 4) * 10);
+//! assert_eq!(CalldataCosts::PRAGUE.min_gas(&data), 100);
+//! ```
+
+/// The per-byte gas costs of transaction calldata.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CalldataCosts {
+    /// Gas charged per zero byte.
+    pub zero_byte: u64,
+    /// Gas charged per non-zero byte.
+    pub nonzero_byte: u64,
+    /// Floor gas charged per calldata [token](calldata_tokens), as introduced
+    /// in [EIP-7623], or `None` before it.
+    ///
+    /// [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+    pub floor_per_token: Option<u64>,
+}
+
+impl CalldataCosts {
+    /// Costs from Frontier up to Istanbul: 4 gas per zero byte and 68 gas
+    /// per non-zero byte.
+    pub const FRONTIER: Self = Self {
+        zero_byte: 4,
+        nonzero_byte: 68,
+        floor_per_token: None,
+    };
+
+    /// Costs from Istanbul ([EIP-2028]) up to Prague: 4 gas per zero byte and
+    /// 16 gas per non-zero byte.
+    ///
+    /// [EIP-2028]: https://eips.ethereum.org/EIPS/eip-2028
+    pub const ISTANBUL: Self = Self {
+        zero_byte: 4,
+        nonzero_byte: 16,
+        floor_per_token: None,
+    };
+
+    /// Costs from Prague ([EIP-7623]): Istanbul costs, with a floor of 10 gas
+    /// per calldata token.
+    ///
+    /// [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+    pub const PRAGUE: Self = Self {
+        floor_per_token: Some(10),
+        ..Self::ISTANBUL
+    };
+
+    /// Returns the standard gas cost of `data`.
+    #[inline]
+    pub const fn gas(&self, data: &[u8]) -> u64 {
+        let zeros = count_zeros(data);
+        let nonzeros = data.len() as u64 - zeros;
+        zeros * self.zero_byte + nonzeros * self.nonzero_byte
+    }
+
+    /// Returns the [EIP-7623] floor gas cost of `data`, or 0 if these costs do
+    /// not have a floor.
+    ///
+    /// [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+    #[inline]
+    pub const fn floor_gas(&self, data: &[u8]) -> u64 {
+        match self.floor_per_token {
+            Some(floor) => calldata_tokens(data) * floor,
+            None => 0,
+        }
+    }
+
+    /// Returns the minimum gas a transaction pays for `data`: the greater of
+    /// the [standard](Self::gas) and [floor](Self::floor_gas) costs.
+    ///
+    /// The floor applies to the whole transaction, including execution gas,
+    /// so this is a lower bound of the calldata's cost.
+    #[inline]
+    pub const fn min_gas(&self, data: &[u8]) -> u64 {
+        let gas = self.gas(data);
+        let floor = self.floor_gas(data);
+        if gas > floor {
+            gas
+        } else {
+            floor
+        }
+    }
+}
+
+/// Returns the number of calldata tokens in `data`, as defined in [EIP-7623]:
+/// `zero_bytes + 4 * nonzero_bytes`.
+///
+/// [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+#[inline]
+pub const fn calldata_tokens(data: &[u8]) -> u64 {
+    let zeros = count_zeros(data);
+    zeros + (data.len() as u64 - zeros) * 4
+}
+
+const fn count_zeros(data: &[u8]) -> u64 {
+    let mut zeros = 0;
+    let mut i = 0;
+    while i < data.len() {
+        zeros += (data[i] == 0) as u64;
+        i += 1;
+    }
+    zeros
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        for costs in [
+            CalldataCosts::FRONTIER,
+            CalldataCosts::ISTANBUL,
+            CalldataCosts::PRAGUE,
+        ] {
+            assert_eq!(costs.gas(&[]), 0);
+            assert_eq!(costs.floor_gas(&[]), 0);
+            assert_eq!(costs.min_gas(&[]), 0);
+        }
+    }
+
+    #[test]
+    fn costs() {
+        let data = hex_literal::hex!(
+            "a9059cbb000000000000000000000000000000000000000000000000000000000000dead"
+        );
+        assert_eq!(calldata_tokens(&data), 30 + 6 * 4);
+        assert_eq!(CalldataCosts::FRONTIER.gas(&data), 30 * 4 + 6 * 68);
+        assert_eq!(CalldataCosts::ISTANBUL.gas(&data), 30 * 4 + 6 * 16);
+        assert_eq!(CalldataCosts::ISTANBUL.floor_gas(&data), 0);
+        assert_eq!(CalldataCosts::PRAGUE.floor_gas(&data), 54 * 10);
+        assert_eq!(CalldataCosts::PRAGUE.min_gas(&data), 540);
+
+        let data = [0xff; 8];
+        assert_eq!(CalldataCosts::PRAGUE.gas(&data), 128);
+        assert_eq!(CalldataCosts::PRAGUE.min_gas(&data), 320);
+    }
+}
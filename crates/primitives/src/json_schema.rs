@@ -0,0 +1,40 @@
+//! JSON Schema helpers for types that cannot implement [`JsonSchema`].
+//!
+//! [`Uint`] is defined in `ruint`, so this crate cannot implement
+//! [`JsonSchema`] for it. Use [`uint`] with `schema_with` instead. All other
+//! primitive types implement [`JsonSchema`] directly.
+//!
+//! [`JsonSchema`]: schemars::JsonSchema
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{Address, U256};
+//! use schemars::{schema_for, JsonSchema};
+//!
+//! #[derive(JsonSchema)]
+//! struct Transfer {
+//!     to: Address,
+//!     #[schemars(schema_with = "alloy_primitives::json_schema::uint::<256, 4>")]
+//!     value: U256,
+//! }
+//!
+//! let schema = schema_for!(Transfer);
+//! let properties = schema.get("properties").unwrap();
+//! assert_eq!(properties["to"]["pattern"], "^0x[0-9a-fA-F]{40}$");
+//! assert_eq!(properties["value"]["pattern"], "^0x[0-9a-fA-F]{1,64}$");
+//! ```
+
+use alloc::format;
+use ruint::Uint;
+use schemars::{json_schema, Schema, SchemaGenerator};
+
+/// Returns the schema of a [`Uint`]: a `0x`-prefixed hex string without
+/// leading zeros, matching its serde representation.
+pub fn uint<const BITS: usize, const LIMBS: usize>(_: &mut SchemaGenerator) -> Schema {
+    let digits = Uint::<BITS, LIMBS>::BYTES.max(1) * 2;
+    json_schema!({
+        "type": "string",
+        "pattern": format!("^0x[0-9a-fA-F]{{1,{digits}}}$"),
+    })
+}
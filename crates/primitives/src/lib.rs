@@ -20,15 +20,15 @@
 extern crate alloc;
 
 // Used in Serde tests.
-#[cfg(test)]
+#[cfg(all(test, feature = "serde"))]
 use {bincode as _, serde as _, serde_json as _};
 
 pub mod aliases;
 #[doc(no_inline)]
 pub use aliases::{
-    BlockHash, BlockNumber, ChainId, Selector, StorageKey, StorageValue, TxHash, TxIndex, TxNumber,
-    B128, B256, B512, B64, I128, I16, I160, I256, I32, I64, I8, U128, U16, U160, U256, U32, U512,
-    U64, U8,
+    BlockHash, BlockNumber, Selector, StorageKey, StorageValue, TxHash, TxIndex, TxNumber, B128,
+    B256, B512, B64, I1024, I128, I16, I160, I2048, I256, I32, I4096, I512, I64, I8, U1024, U128,
+    U16, U160, U2048, U256, U32, U4096, U512, U64, U8,
 };
 
 #[macro_use]
@@ -38,18 +38,74 @@ pub use bits::{
     BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES,
 };
 
+mod chain;
+pub use chain::ChainId;
+
+mod create2;
+pub use create2::{Create2Addresses, Create2Deployer};
+
 #[path = "bytes/mod.rs"]
 mod bytes_;
 pub use self::bytes_::Bytes;
 
+mod float;
+pub use float::{FloatConversion, FloatConversionError};
+
+pub mod gas;
+
+#[cfg(feature = "diesel")]
+pub mod diesel_types;
+
+#[cfg(feature = "json-schema")]
+pub mod json_schema;
+
 mod log;
 pub use log::Log;
 
+#[cfg(feature = "std")]
+pub mod map;
+
+pub mod merkle;
+
+pub mod scan;
+
+pub mod storage;
+
+#[cfg(feature = "vanity")]
+pub mod vanity;
+
+#[cfg(feature = "arbitrary")]
+pub mod strategies;
+
+#[cfg(feature = "serde")]
+pub mod serde_b64;
+
+#[cfg(feature = "serde")]
+pub mod serde_decimal;
+
+#[cfg(feature = "serde")]
+pub mod serde_hex_opt;
+
+#[cfg(feature = "serde")]
+pub mod serde_quantity;
+
+#[cfg(feature = "serde")]
+mod serde_utils;
+
 mod signed;
+#[cfg(feature = "rkyv")]
+pub use signed::ArchivedSigned;
 pub use signed::{BigIntConversionError, ParseSignedError, Sign, Signed};
 
+#[cfg(feature = "ssz")]
+pub mod uint_tree_hash;
+
+mod units;
+
 mod utils;
-pub use utils::{eip191_hash_message, keccak256};
+#[cfg(feature = "rayon")]
+pub use utils::par_keccak256_batch;
+pub use utils::{eip191_hash_message, keccak256, keccak256_batch};
 
 #[doc(no_inline)]
 pub use ::bytes;
@@ -97,18 +153,55 @@ pub mod private {
     };
     pub use derive_more;
 
+    pub use crate::{bits::parse_address_literal, units::parse_u256};
+
     #[cfg(feature = "getrandom")]
     pub use getrandom;
 
     #[cfg(feature = "rand")]
     pub use rand;
 
+    #[cfg(feature = "rand_core")]
+    pub use rand_core;
+
     #[cfg(feature = "rlp")]
     pub use alloy_rlp;
 
     #[cfg(feature = "serde")]
     pub use serde;
 
+    #[cfg(feature = "borsh")]
+    pub use borsh;
+
+    #[cfg(feature = "rkyv")]
+    pub use rkyv;
+
+    #[cfg(feature = "ssz")]
+    pub use {alloc::vec::Vec, ssz, tree_hash};
+
+    #[cfg(feature = "scale")]
+    pub use {parity_scale_codec, scale_info};
+
+    #[cfg(feature = "postgres")]
+    pub use {bytes::BytesMut, postgres_types};
+    #[cfg(feature = "postgres")]
+    pub type BoxedError = alloc::boxed::Box<dyn std::error::Error + Sync + Send>;
+
+    #[cfg(feature = "sqlx")]
+    pub use sqlx_core;
+
+    #[cfg(feature = "diesel")]
+    pub use diesel;
+
+    #[cfg(feature = "json-schema")]
+    pub use {alloc::borrow::Cow, schemars};
+
+    #[cfg(feature = "zeroize")]
+    pub use zeroize;
+
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+
     #[cfg(feature = "arbitrary")]
     pub use {arbitrary, derive_arbitrary, proptest, proptest_derive};
 }
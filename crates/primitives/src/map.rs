@@ -0,0 +1,143 @@
+//! Hash maps and sets keyed by fixed-size byte arrays.
+//!
+//! Hashes, addresses and other [`FixedBytes`] keys are usually already
+//! uniformly distributed, so hashing them again with the default SipHash is
+//! wasted work. [`FbHasher`] instead uses the last 8 bytes of the key
+//! directly as the hash.
+//!
+//! This hasher is **not** resistant to HashDoS: an attacker who can choose
+//! keys, e.g. by grinding addresses, can force collisions. Use the standard
+//! [`HashMap`] for untrusted keys.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{map::AddressHashMap, Address};
+//!
+//! let mut balances = AddressHashMap::default();
+//! balances.insert(Address::ZERO, 100u64);
+//! assert_eq!(balances[&Address::ZERO], 100);
+//! ```
+
+use crate::{Address, FixedBytes, B256};
+use core::hash::{BuildHasherDefault, Hasher};
+use std::collections::{HashMap, HashSet};
+
+/// A [`Hasher`] specialized for [`FixedBytes<N>`] keys.
+///
+/// Keys of `N >= 8` bytes are hashed by reading their last 8 bytes. Any other
+/// input falls back to a simple multiplicative hash.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FbHasher<const N: usize> {
+    hash: u64,
+}
+
+/// A [`BuildHasher`](core::hash::BuildHasher) for [`FbHasher`].
+pub type FbBuildHasher<const N: usize> = BuildHasherDefault<FbHasher<N>>;
+
+/// A [`HashMap`] keyed by [`FixedBytes<N>`], using [`FbHasher`].
+pub type FbHashMap<const N: usize, V> = HashMap<FixedBytes<N>, V, FbBuildHasher<N>>;
+
+/// A [`HashSet`] of [`FixedBytes<N>`], using [`FbHasher`].
+pub type FbHashSet<const N: usize> = HashSet<FixedBytes<N>, FbBuildHasher<N>>;
+
+/// A [`HashMap`] keyed by [`Address`], using [`FbHasher`].
+pub type AddressHashMap<V> = HashMap<Address, V, FbBuildHasher<20>>;
+
+/// A [`HashSet`] of [`Address`], using [`FbHasher`].
+pub type AddressHashSet = HashSet<Address, FbBuildHasher<20>>;
+
+/// A [`HashMap`] keyed by [`B256`], using [`FbHasher`].
+pub type B256HashMap<V> = HashMap<B256, V, FbBuildHasher<32>>;
+
+/// A [`HashSet`] of [`B256`], using [`FbHasher`].
+pub type B256HashSet = HashSet<B256, FbBuildHasher<32>>;
+
+impl<const N: usize> FbHasher<N> {
+    /// Multiplier from FxHash.
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+    #[inline]
+    fn fold(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(Self::SEED);
+    }
+}
+
+impl<const N: usize> Hasher for FbHasher<N> {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        if N >= 8 && bytes.len() == N {
+            // Big-endian so that keys differing only in their last byte, like
+            // precompile addresses, land in different buckets.
+            let tail = bytes[N - 8..].try_into().unwrap();
+            self.hash ^= u64::from_be_bytes(tail);
+            return
+        }
+
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.fold(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        for &byte in chunks.remainder() {
+            self.fold(byte as u64);
+        }
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        // Skip the length prefix of the key's byte slice.
+        if i != N {
+            self.fold(i as u64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::hash::{BuildHasher, Hash};
+
+    fn hash_with<T: Hash, const N: usize>(value: &T) -> u64 {
+        let mut hasher = FbBuildHasher::<N>::default().build_hasher();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn last_bytes() {
+        let hash = B256::with_last_byte(0x42);
+        assert_eq!(hash_with::<_, 32>(&hash), 0x42);
+        assert_eq!(hash_with::<_, 20>(&Address::with_last_byte(1)), 1);
+        assert_eq!(
+            hash_with::<_, 32>(&FixedBytes::<32>::ZERO),
+            hash_with::<_, 32>(&B256::ZERO)
+        );
+        assert_ne!(
+            hash_with::<_, 4>(&[1u8, 2, 3, 4]),
+            hash_with::<_, 4>(&[1u8, 2, 3, 5])
+        );
+    }
+
+    #[test]
+    fn maps() {
+        let mut map = AddressHashMap::default();
+        for i in 0..=255 {
+            map.insert(Address::with_last_byte(i), i);
+        }
+        assert_eq!(map.len(), 256);
+        assert_eq!(map[&Address::with_last_byte(7)], 7);
+
+        let mut set = B256HashSet::default();
+        assert!(set.insert(B256::repeat_byte(1)));
+        assert!(!set.insert(B256::repeat_byte(1)));
+
+        let mut map = FbHashMap::<4, _>::default();
+        map.insert(FixedBytes([1, 2, 3, 4]), ());
+        assert!(map.contains_key(&FixedBytes([1, 2, 3, 4])));
+    }
+}
@@ -0,0 +1,271 @@
+//! Keccak-256 binary Merkle trees.
+//!
+//! [`MerkleTree`] builds a tree over [`B256`] leaves, and generates
+//! [`MerkleProof`]s which can be verified against its root.
+//!
+//! By default, the two children of a node are sorted before being hashed,
+//! which is what OpenZeppelin's [`MerkleProof`][oz] library expects: proofs
+//! generated by [`MerkleTree::new`] can be passed as-is to
+//! `MerkleProof.verify` (see [`MerkleProof::hashes`]). Use
+//! [`MerkleTree::new_unsorted`] to hash children in tree order instead.
+//!
+//! When a layer has an odd number of nodes, the last node is carried up to the
+//! next layer unchanged. Leaves are used as-is: hashing them, for example
+//! with OpenZeppelin's double `keccak256`, is the responsibility of the
+//! caller.
+//!
+//! [oz]: https://docs.openzeppelin.com/contracts/5.x/api/utils#MerkleProof
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{keccak256, merkle::MerkleTree};
+//!
+//! let leaves = ["alice", "bob", "carol"].map(keccak256);
+//! let tree = MerkleTree::new(leaves.to_vec());
+//!
+//! let proof = tree.proof(1).unwrap();
+//! assert!(proof.verify(tree.root(), leaves[1]));
+//! assert!(!proof.verify(tree.root(), leaves[0]));
+//! ```
+
+use crate::{keccak256, B256};
+use alloc::vec::Vec;
+
+/// A Keccak-256 binary Merkle tree over [`B256`] leaves.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleTree {
+    /// The layers of the tree, from the leaves up to the root.
+    layers: Vec<Vec<B256>>,
+    sort_pairs: bool,
+}
+
+impl MerkleTree {
+    /// Builds a tree over the given leaves, sorting each pair of nodes before
+    /// hashing them, as OpenZeppelin's `MerkleProof` expects.
+    #[inline]
+    pub fn new(leaves: Vec<B256>) -> Self {
+        Self::build(leaves, true)
+    }
+
+    /// Builds a tree over the given leaves, hashing each pair of nodes in tree
+    /// order.
+    #[inline]
+    pub fn new_unsorted(leaves: Vec<B256>) -> Self {
+        Self::build(leaves, false)
+    }
+
+    fn build(leaves: Vec<B256>, sort_pairs: bool) -> Self {
+        let mut layers = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            let layer = layers.last().unwrap();
+            let next = layer
+                .chunks(2)
+                .map(|pair| match *pair {
+                    [left, right] => hash_pair(left, right, sort_pairs),
+                    [node] => node,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+        Self { layers, sort_pairs }
+    }
+
+    /// Returns the root of the tree, or [`B256::ZERO`] if the tree is empty.
+    #[inline]
+    pub fn root(&self) -> B256 {
+        self.layers
+            .last()
+            .and_then(|layer| layer.first())
+            .copied()
+            .unwrap_or(B256::ZERO)
+    }
+
+    /// Returns the leaves of the tree.
+    #[inline]
+    pub fn leaves(&self) -> &[B256] {
+        &self.layers[0]
+    }
+
+    /// Returns whether pairs of nodes are sorted before being hashed.
+    #[inline]
+    pub const fn sort_pairs(&self) -> bool {
+        self.sort_pairs
+    }
+
+    /// Generates the proof for the leaf at `index`, or `None` if it is out of
+    /// bounds.
+    pub fn proof(&self, mut index: usize) -> Option<MerkleProof> {
+        if index >= self.leaves().len() {
+            return None
+        }
+
+        let mut nodes = Vec::with_capacity(self.layers.len() - 1);
+        for layer in &self.layers[..self.layers.len() - 1] {
+            let sibling = index ^ 1;
+            if let Some(&hash) = layer.get(sibling) {
+                nodes.push(if sibling < index {
+                    ProofNode::Left(hash)
+                } else {
+                    ProofNode::Right(hash)
+                });
+            }
+            index /= 2;
+        }
+        Some(MerkleProof {
+            nodes,
+            sort_pairs: self.sort_pairs,
+        })
+    }
+}
+
+/// A node of a [`MerkleProof`]: the sibling hash, and its side.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProofNode {
+    /// The sibling is the left child.
+    Left(B256),
+    /// The sibling is the right child.
+    Right(B256),
+}
+
+impl ProofNode {
+    /// Returns the sibling hash.
+    #[inline]
+    pub const fn hash(&self) -> B256 {
+        match *self {
+            Self::Left(hash) | Self::Right(hash) => hash,
+        }
+    }
+}
+
+/// A proof of inclusion of a leaf in a [`MerkleTree`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleProof {
+    nodes: Vec<ProofNode>,
+    sort_pairs: bool,
+}
+
+impl MerkleProof {
+    /// Returns the nodes of the proof, from the leaf up to the root.
+    #[inline]
+    pub fn nodes(&self) -> &[ProofNode] {
+        &self.nodes
+    }
+
+    /// Returns the sibling hashes of the proof, from the leaf up to the root.
+    ///
+    /// For trees built with [`MerkleTree::new`], this is the `proof` argument
+    /// expected by OpenZeppelin's `MerkleProof.verify`.
+    #[inline]
+    pub fn hashes(&self) -> Vec<B256> {
+        self.nodes.iter().map(ProofNode::hash).collect()
+    }
+
+    /// Computes the root of the tree from the given leaf.
+    pub fn compute_root(&self, leaf: B256) -> B256 {
+        self.nodes
+            .iter()
+            .fold(leaf, |node, sibling| match *sibling {
+                ProofNode::Left(hash) => hash_pair(hash, node, self.sort_pairs),
+                ProofNode::Right(hash) => hash_pair(node, hash, self.sort_pairs),
+            })
+    }
+
+    /// Returns whether `leaf` is included in the tree with the given `root`.
+    #[inline]
+    pub fn verify(&self, root: B256, leaf: B256) -> bool {
+        self.compute_root(leaf) == root
+    }
+}
+
+/// Verifies an OpenZeppelin-compatible proof, where each pair of nodes is
+/// sorted before being hashed.
+///
+/// This is equivalent to OpenZeppelin's `MerkleProof.verify`.
+pub fn verify_sorted(proof: &[B256], root: B256, leaf: B256) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |node, &sibling| hash_pair(node, sibling, true))
+        == root
+}
+
+/// Hashes two nodes together, sorting them first if `sort` is `true`.
+pub fn hash_pair(left: B256, right: B256, sort: bool) -> B256 {
+    let (left, right) = if sort && right < left {
+        (right, left)
+    } else {
+        (left, right)
+    };
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(left.as_slice());
+    buf[32..].copy_from_slice(right.as_slice());
+    keccak256(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(n: usize) -> Vec<B256> {
+        (0..n as u64).map(|i| keccak256(i.to_be_bytes())).collect()
+    }
+
+    #[test]
+    fn empty() {
+        let tree = MerkleTree::new(Vec::new());
+        assert_eq!(tree.root(), B256::ZERO);
+        assert_eq!(tree.proof(0), None);
+    }
+
+    #[test]
+    fn single() {
+        let leaf = keccak256("leaf");
+        let tree = MerkleTree::new(vec![leaf]);
+        assert_eq!(tree.root(), leaf);
+        let proof = tree.proof(0).unwrap();
+        assert!(proof.nodes().is_empty());
+        assert!(proof.verify(leaf, leaf));
+    }
+
+    #[test]
+    fn root() {
+        let l = leaves(3);
+        let expected = hash_pair(hash_pair(l[0], l[1], true), l[2], true);
+        assert_eq!(MerkleTree::new(l.clone()).root(), expected);
+
+        let expected = keccak256([l[0].as_slice(), l[1].as_slice()].concat());
+        assert_eq!(MerkleTree::new_unsorted(l[..2].to_vec()).root(), expected);
+    }
+
+    #[test]
+    fn hash_pair_sorted() {
+        let [a, b] = [keccak256("a"), keccak256("b")];
+        assert_eq!(hash_pair(a, b, true), hash_pair(b, a, true));
+        assert_ne!(hash_pair(a, b, false), hash_pair(b, a, false));
+    }
+
+    #[test]
+    fn proofs() {
+        for n in 1..=17 {
+            let l = leaves(n);
+            for tree in [
+                MerkleTree::new(l.clone()),
+                MerkleTree::new_unsorted(l.clone()),
+            ] {
+                let root = tree.root();
+                for (i, &leaf) in l.iter().enumerate() {
+                    let proof = tree.proof(i).unwrap();
+                    assert!(proof.verify(root, leaf), "n={n} i={i}");
+                    assert!(!proof.verify(root, keccak256(leaf)), "n={n} i={i}");
+                    if tree.sort_pairs() {
+                        assert!(verify_sorted(&proof.hashes(), root, leaf));
+                    }
+                }
+                assert_eq!(tree.proof(n), None);
+            }
+        }
+    }
+}
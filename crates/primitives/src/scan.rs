@@ -0,0 +1,256 @@
+//! Extraction of addresses and hashes from free-form text.
+//!
+//! A [`Scanner`] finds the `0x`-prefixed addresses and 32-byte hashes in
+//! arbitrary text, such as logs, HTML, or chat messages, and returns them
+//! along with their byte ranges in the text.
+//!
+//! A candidate is `0x` followed by exactly 40 (address) or 64 (hash) hex
+//! digits, which is not part of a longer word: hex strings of other lengths,
+//! such as signatures or calldata, and strings like `a0x...` or `0x...g` are
+//! skipped. Hex digits without the `0x` prefix are never matched, as they are
+//! indistinguishable from other identifiers such as commit hashes.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{
+//!     address,
+//!     scan::{Scanned, Scanner},
+//! };
+//!
+//! let text = "sent 1 ETH to 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045 \
+//!             in 0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060";
+//!
+//! let found: Vec<_> = Scanner::new().scan(text).collect();
+//! assert_eq!(found.len(), 2);
+//! assert_eq!(found[0].range, 14..56);
+//! assert_eq!(
+//!     found[0].value,
+//!     Scanned::Address(address!("d8da6bf26964af9d7eed9e03e53415d37aa96045"))
+//! );
+//! assert!(matches!(found[1].value, Scanned::Hash(_)));
+//! ```
+
+use crate::{Address, B256};
+use core::ops::Range;
+
+/// Finds addresses and hashes in free-form text.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Scanner {
+    require_checksum: bool,
+    chain_id: Option<u64>,
+}
+
+/// A value found by a [`Scanner`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Match<T> {
+    /// The byte range of the value in the text, including the `0x` prefix.
+    pub range: Range<usize>,
+    /// The parsed value.
+    pub value: T,
+}
+
+/// An address or hash found by [`Scanner::scan`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Scanned {
+    /// A 20-byte address.
+    Address(Address),
+    /// A 32-byte hash.
+    Hash(B256),
+}
+
+impl Scanner {
+    /// Creates a new scanner, which accepts addresses regardless of their
+    /// checksum.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            require_checksum: false,
+            chain_id: None,
+        }
+    }
+
+    /// Only accepts addresses with a valid [EIP-55] checksum, optionally
+    /// using the [EIP-1191] checksum of the given chain ID.
+    ///
+    /// Addresses which are not checksummed, such as all-lowercase addresses,
+    /// are skipped.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    /// [EIP-1191]: https://eips.ethereum.org/EIPS/eip-1191
+    #[inline]
+    pub const fn require_checksum(mut self, chain_id: Option<u64>) -> Self {
+        self.require_checksum = true;
+        self.chain_id = chain_id;
+        self
+    }
+
+    /// Returns an iterator over the addresses and hashes in `text`, in order.
+    pub fn scan<'a>(&self, text: &'a str) -> impl Iterator<Item = Match<Scanned>> + 'a {
+        let this = *self;
+        candidates(text).filter_map(move |range| {
+            let s = &text[range.clone()];
+            let value = match s.len() {
+                ADDRESS_LEN => Scanned::Address(this.parse_address(s)?),
+                HASH_LEN => Scanned::Hash(s.parse().ok()?),
+                _ => return None,
+            };
+            Some(Match { range, value })
+        })
+    }
+
+    /// Returns an iterator over the addresses in `text`, in order.
+    pub fn addresses<'a>(&self, text: &'a str) -> impl Iterator<Item = Match<Address>> + 'a {
+        self.scan(text).filter_map(|m| match m.value {
+            Scanned::Address(address) => Some(Match {
+                range: m.range,
+                value: address,
+            }),
+            Scanned::Hash(_) => None,
+        })
+    }
+
+    /// Returns an iterator over the hashes in `text`, in order.
+    pub fn hashes<'a>(&self, text: &'a str) -> impl Iterator<Item = Match<B256>> + 'a {
+        self.scan(text).filter_map(|m| match m.value {
+            Scanned::Hash(hash) => Some(Match {
+                range: m.range,
+                value: hash,
+            }),
+            Scanned::Address(_) => None,
+        })
+    }
+
+    fn parse_address(&self, s: &str) -> Option<Address> {
+        if self.require_checksum {
+            Address::parse_checksummed(s, self.chain_id).ok()
+        } else {
+            s.parse().ok()
+        }
+    }
+}
+
+/// The length of a `0x`-prefixed address.
+const ADDRESS_LEN: usize = 2 + 40;
+/// The length of a `0x`-prefixed hash.
+const HASH_LEN: usize = 2 + 64;
+
+/// Returns the ranges of the `0x`-prefixed hex strings in `text` which are not
+/// part of a longer word.
+fn candidates(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    core::iter::from_fn(move || {
+        while i + 1 < bytes.len() {
+            let start = i;
+            i += 1;
+            if bytes[start] != b'0'
+                || bytes[start + 1] != b'x'
+                || (start > 0 && is_word(bytes[start - 1]))
+            {
+                continue
+            }
+
+            let end = start
+                + 2
+                + bytes[start + 2..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_hexdigit())
+                    .count();
+            i = end;
+            if bytes.get(end).map_or(false, |&b| is_word(b)) {
+                continue
+            }
+            return Some(start..end)
+        }
+        None
+    })
+}
+
+#[inline]
+const fn is_word(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    const ADDRESS: &str = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+    const HASH: &str = "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060";
+
+    #[test]
+    fn scan() {
+        let text = format!(
+            "<a href=\"/address/{ADDRESS}\">{}</a>, tx: {HASH}.\n\
+             sig: 0x{:0130}, commit: {}, not: a{ADDRESS} {ADDRESS}g 0X{}",
+            ADDRESS.to_lowercase(),
+            0,
+            &HASH[2..42],
+            &ADDRESS[2..],
+        );
+        let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        let hash = b256!("5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060");
+
+        let found: Vec<_> = Scanner::new().scan(&text).collect();
+        assert_eq!(
+            found.iter().map(|m| m.value).collect::<Vec<_>>(),
+            [
+                Scanned::Address(address),
+                Scanned::Address(address),
+                Scanned::Hash(hash)
+            ]
+        );
+        for m in &found {
+            assert!(text[m.range.clone()].eq_ignore_ascii_case(match m.value {
+                Scanned::Address(_) => ADDRESS,
+                Scanned::Hash(_) => HASH,
+            }));
+        }
+
+        let addresses: Vec<_> = Scanner::new().addresses(&text).collect();
+        assert_eq!(addresses.len(), 2);
+        assert_eq!(addresses[0].range, found[0].range);
+        let hashes: Vec<_> = Scanner::new().hashes(&text).collect();
+        assert_eq!(
+            hashes,
+            [Match {
+                range: found[2].range.clone(),
+                value: hash
+            }]
+        );
+    }
+
+    #[test]
+    fn require_checksum() {
+        let bad = ADDRESS.replace('d', "D");
+        let text = format!("{ADDRESS} {} {bad} {HASH}", ADDRESS.to_lowercase());
+
+        let found: Vec<_> = Scanner::new().require_checksum(None).scan(&text).collect();
+        assert_eq!(found.len(), 2);
+        assert_eq!(&text[found[0].range.clone()], ADDRESS);
+        assert!(matches!(found[1].value, Scanned::Hash(_)));
+
+        assert_eq!(Scanner::new().addresses(&text).count(), 3);
+        assert_eq!(
+            Scanner::new()
+                .require_checksum(Some(1))
+                .addresses(&text)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn edges() {
+        assert_eq!(Scanner::new().scan("").count(), 0);
+        assert_eq!(Scanner::new().scan("0").count(), 0);
+        assert_eq!(Scanner::new().scan("0x").count(), 0);
+        assert_eq!(Scanner::new().scan(ADDRESS).count(), 1);
+        assert_eq!(Scanner::new().scan(&format!("€{ADDRESS}€")).count(), 1);
+        assert_eq!(Scanner::new().scan(&format!("0x{ADDRESS}")).count(), 0);
+    }
+}
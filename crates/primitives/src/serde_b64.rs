@@ -0,0 +1,146 @@
+//! Serde helpers for [`B64`] values such as block nonces.
+//!
+//! By default, [`B64`] is serialized as a zero-padded, `0x`-prefixed
+//! hexadecimal string, but some APIs encode block nonces as JSON numbers. The
+//! functions in this module accept both on deserialization, always
+//! interpreting the value as a big-endian `u64`. Use the [`int`] submodule to
+//! serialize as a JSON number instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{serde_b64, B64};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Header {
+//!     #[serde(with = "serde_b64")]
+//!     nonce: B64,
+//!     #[serde(with = "serde_b64::int")]
+//!     aux_nonce: B64,
+//! }
+//!
+//! let header: Header = serde_json::from_str(r#"{"nonce":66,"aux_nonce":"0x42"}"#).unwrap();
+//! assert_eq!(header.nonce, B64::from(0x42u64));
+//! assert_eq!(header.aux_nonce, B64::from(0x42u64));
+//! assert_eq!(
+//!     serde_json::to_string(&header).unwrap(),
+//!     r#"{"nonce":"0x0000000000000042","aux_nonce":66}"#
+//! );
+//! ```
+
+use crate::{
+    serde_utils::{strip_hex_prefix, IntegerVisitor},
+    B64,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes a [`B64`] as a zero-padded, `0x`-prefixed hexadecimal string.
+pub fn serialize<S: Serializer>(value: &B64, serializer: S) -> Result<S::Ok, S::Error> {
+    value.serialize(serializer)
+}
+
+/// Deserializes a [`B64`] from a hexadecimal string or an integer.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<B64, D::Error> {
+    if deserializer.is_human_readable() {
+        deserialize_any(deserializer)
+    } else {
+        B64::deserialize(deserializer)
+    }
+}
+
+/// (De)serialize a [`B64`] as an integer.
+pub mod int {
+    use super::*;
+
+    /// Serializes a [`B64`] as a big-endian `u64`.
+    pub fn serialize<S: Serializer>(value: &B64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(value.to_u64())
+    }
+
+    /// Deserializes a [`B64`] from an integer or a hexadecimal string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<B64, D::Error> {
+        if deserializer.is_human_readable() {
+            deserialize_any(deserializer)
+        } else {
+            u64::deserialize(deserializer).map(B64::from_u64)
+        }
+    }
+}
+
+fn deserialize_any<'de, D: Deserializer<'de>>(deserializer: D) -> Result<B64, D::Error> {
+    deserializer
+        .deserialize_any(IntegerVisitor {
+            expecting: "a 64-bit unsigned integer or a hexadecimal string of at most 8 bytes",
+            parse: parse_hex_u64,
+        })
+        .map(B64::from_u64)
+}
+
+fn parse_hex_u64(s: &str) -> Option<u64> {
+    let digits = strip_hex_prefix(s)?;
+    if digits.is_empty() || digits.len() > 16 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None
+    }
+    u64::from_str_radix(digits, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        serde_utils::test_utils::{assert_bincode_roundtrip, assert_json_eq, assert_json_err},
+        B64,
+    };
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Hex(#[serde(with = "super")] B64);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Int(#[serde(with = "super::int")] B64);
+
+    #[test]
+    fn deserialize() {
+        let expected = B64::from_u64(0x1b4);
+        let inputs = [r#""0x00000000000001b4""#, r#""0x1b4""#, r#""0X1B4""#, "436"];
+        assert_json_eq(inputs, &Hex(expected));
+        assert_json_eq(inputs, &Int(expected));
+        assert_json_eq([r#""0xffffffffffffffff""#], &Hex(B64::from_u64(u64::MAX)));
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        let inputs = [
+            r#""0x""#,
+            r#""""#,
+            r#""1b4""#,
+            r#""0x+1""#,
+            r#""0x10000000000000000""#,
+            "-1",
+            "1.5",
+            "null",
+        ];
+        assert_json_err::<Hex, _>(inputs);
+        assert_json_err::<Int, _>(inputs);
+    }
+
+    #[test]
+    fn serialize() {
+        let nonce = B64::from_u64(0x1b4);
+        assert_eq!(
+            serde_json::to_string(&Hex(nonce)).unwrap(),
+            r#""0x00000000000001b4""#
+        );
+        assert_eq!(serde_json::to_string(&Int(nonce)).unwrap(), "436");
+    }
+
+    #[test]
+    fn bincode_roundtrip() {
+        let nonce = B64::from_u64(0x1b4);
+        assert_bincode_roundtrip(&Hex(nonce));
+        assert_eq!(
+            assert_bincode_roundtrip(&Int(nonce)),
+            0x1b4u64.to_le_bytes()
+        );
+    }
+}
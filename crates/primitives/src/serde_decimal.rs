@@ -0,0 +1,172 @@
+//! Serde helpers for (de)serializing integers as decimal strings.
+//!
+//! By default, [`Uint`] is serialized as a hexadecimal string. Use this module
+//! with `#[serde(with = "serde_decimal")]` when a JSON API or database
+//! requires decimal strings instead, and JSON numbers would overflow.
+//!
+//! Deserialization accepts decimal strings and JSON numbers, and rejects
+//! hexadecimal strings. Use [`serde_quantity`] to also accept those.
+//!
+//! Supported types are [`u64`], [`u128`], [`Uint`] (e.g. [`U256`]) and
+//! [`Signed`] (e.g. [`I256`]).
+//!
+//! [`serde_quantity`]: crate::serde_quantity
+//! [`U256`]: crate::U256
+//! [`I256`]: crate::I256
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{serde_decimal, I256, U256};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Balance {
+//!     #[serde(with = "serde_decimal")]
+//!     amount: U256,
+//!     #[serde(with = "serde_decimal")]
+//!     delta: I256,
+//! }
+//!
+//! let balance = Balance {
+//!     amount: U256::from(1_000_000),
+//!     delta: I256::MINUS_ONE,
+//! };
+//! let json = serde_json::to_string(&balance).unwrap();
+//! assert_eq!(json, r#"{"amount":"1000000","delta":"-1"}"#);
+//!
+//! let balance: Balance = serde_json::from_str(r#"{"amount":1000000,"delta":"-1"}"#).unwrap();
+//! assert_eq!(balance.amount, U256::from(1_000_000));
+//! assert_eq!(balance.delta, I256::MINUS_ONE);
+//! ```
+
+use crate::{
+    serde_utils::{Integer, IntegerVisitor},
+    Signed,
+};
+use ruint::Uint;
+use serde::{Deserializer, Serializer};
+
+/// An integer type that can be (de)serialized as a decimal string.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Decimal: Integer {
+    #[doc(hidden)]
+    const SIGNED: bool;
+
+    /// Parses a validated string of ASCII digits, optionally prefixed with
+    /// `-` if [`SIGNED`](Self::SIGNED).
+    #[doc(hidden)]
+    fn from_dec_str(s: &str) -> Option<Self>;
+}
+
+macro_rules! impl_decimal_primitive {
+    ($($t:ty),+) => {$(
+        impl Decimal for $t {
+            const SIGNED: bool = false;
+
+            #[inline]
+            fn from_dec_str(s: &str) -> Option<Self> {
+                s.parse().ok()
+            }
+        }
+    )+};
+}
+
+impl_decimal_primitive!(u64, u128);
+
+impl<const BITS: usize, const LIMBS: usize> Decimal for Uint<BITS, LIMBS> {
+    const SIGNED: bool = false;
+
+    #[inline]
+    fn from_dec_str(s: &str) -> Option<Self> {
+        Self::from_str_radix(s, 10).ok()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Decimal for Signed<BITS, LIMBS> {
+    const SIGNED: bool = true;
+
+    #[inline]
+    fn from_dec_str(s: &str) -> Option<Self> {
+        Self::from_dec_str(s).ok()
+    }
+}
+
+/// Serializes an integer as a decimal string.
+pub fn serialize<T: Decimal, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+/// Deserializes an integer from a decimal string or a number.
+pub fn deserialize<'de, T: Decimal, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    deserializer.deserialize_any(IntegerVisitor {
+        expecting: "a decimal integer string",
+        parse: parse_decimal,
+    })
+}
+
+fn parse_decimal<T: Decimal>(s: &str) -> Option<T> {
+    let digits = match s.strip_prefix('-') {
+        Some(digits) if T::SIGNED => digits,
+        _ => s,
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None
+    }
+    T::from_dec_str(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        serde_utils::test_utils::{assert_json_eq, assert_json_err, assert_json_roundtrip},
+        I256, U256,
+    };
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Dec<T: super::Decimal>(#[serde(with = "super")] T);
+
+    #[test]
+    fn roundtrip() {
+        for value in [U256::ZERO, U256::from(1), U256::MAX] {
+            assert_json_roundtrip(&Dec(value), &format!("\"{value}\""));
+        }
+        for value in [I256::ZERO, I256::MINUS_ONE, I256::MIN, I256::MAX] {
+            assert_json_roundtrip(&Dec(value), &format!("\"{value}\""));
+        }
+        for value in [0, 436, u64::MAX] {
+            assert_json_roundtrip(&Dec(value), &format!("\"{value}\""));
+        }
+        assert_json_roundtrip(&Dec(u128::MAX), &format!("\"{}\"", u128::MAX));
+    }
+
+    #[test]
+    fn numbers() {
+        assert_json_eq(["42"], &Dec(U256::from(42)));
+        assert_json_eq(["42"], &Dec(42u64));
+        assert_json_eq(["-42"], &Dec(I256::try_from(-42).unwrap()));
+        assert_json_err::<Dec<U256>, _>(["-42"]);
+        assert_json_err::<Dec<u64>, _>(["-42"]);
+    }
+
+    #[test]
+    fn invalid() {
+        let inputs = [
+            r#""""#,
+            r#""-""#,
+            r#""0x10""#,
+            r#""1_000""#,
+            r#""+1""#,
+            r#"" 1""#,
+            "1.5",
+        ];
+        assert_json_err::<Dec<U256>, _>(inputs);
+        assert_json_err::<Dec<I256>, _>(inputs);
+        assert_json_err::<Dec<u64>, _>(inputs);
+        assert_json_err::<Dec<U256>, _>([r#""-1""#.into(), format!("\"{}0\"", U256::MAX)]);
+        assert_json_err::<Dec<I256>, _>([format!("\"{}\"", U256::MAX)]);
+        assert_json_err::<Dec<u64>, _>([r#""18446744073709551616""#]);
+    }
+}
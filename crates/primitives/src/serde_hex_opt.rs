@@ -0,0 +1,308 @@
+//! Serde helpers for optional hex-encoded values.
+//!
+//! RPC providers are inconsistent in how they encode missing byte strings:
+//! some use `null`, some omit the field, and some return an empty `""` or
+//! `"0x"` string, even for fixed-size values like hashes. The functions in
+//! this module deserialize all of these as `None`, and serialize `None` as
+//! `null`. Use the [`strict`] submodule to only treat `null` as `None`, and
+//! decode empty strings as values, and the [`empty`] submodule to serialize
+//! `None` as `"0x"` instead.
+//!
+//! Supported types are [`Bytes`], [`FixedBytes`] and the types wrapping it,
+//! such as [`Address`] and [`B256`].
+//!
+//! Note that, like any `with` attribute on an `Option` field, these functions
+//! are not called for missing fields, which are an error unless the field is
+//! also annotated with `#[serde(default)]`.
+//!
+//! [`Address`]: crate::Address
+//! [`B256`]: crate::B256
+//! [`Bytes`]: crate::Bytes
+//! [`FixedBytes`]: crate::FixedBytes
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{serde_hex_opt, Bytes, B256};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Transaction {
+//!     #[serde(default, with = "serde_hex_opt")]
+//!     block_hash: Option<B256>,
+//!     #[serde(default, with = "serde_hex_opt::strict")]
+//!     input: Option<Bytes>,
+//!     #[serde(default, with = "serde_hex_opt::empty")]
+//!     data: Option<Bytes>,
+//! }
+//!
+//! let tx: Transaction = serde_json::from_str(r#"{"block_hash":"0x","input":"0x"}"#).unwrap();
+//! assert_eq!(tx.block_hash, None);
+//! assert_eq!(tx.input, Some(Bytes::new()));
+//! assert_eq!(tx.data, None);
+//! assert_eq!(
+//!     serde_json::to_string(&tx).unwrap(),
+//!     r#"{"block_hash":null,"input":"0x","data":"0x"}"#
+//! );
+//! ```
+
+use core::{fmt, marker::PhantomData};
+use hex::FromHex;
+use serde::{
+    de::{
+        self,
+        value::{BytesDeserializer, SeqAccessDeserializer},
+        Visitor,
+    },
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Serializes an optional value as a hexadecimal string, or `null`.
+pub fn serialize<T: Serialize, S: Serializer>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    value.serialize(serializer)
+}
+
+/// Deserializes an optional value from a hexadecimal string, treating `null`,
+/// `""` and `"0x"` as `None`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de> + FromHex,
+    T::Error: fmt::Display,
+    D: Deserializer<'de>,
+{
+    deserialize_opt(deserializer, true)
+}
+
+/// Only treats `null` as `None`, and decodes `""` and `"0x"` as values.
+///
+/// Empty strings are therefore valid for [`Bytes`](crate::Bytes), but not for
+/// fixed-size values.
+pub mod strict {
+    use super::*;
+
+    /// Serializes an optional value as a hexadecimal string, or `null`.
+    pub fn serialize<T: Serialize, S: Serializer>(
+        value: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    /// Deserializes an optional value from a hexadecimal string, treating
+    /// only `null` as `None`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: Deserialize<'de> + FromHex,
+        T::Error: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        deserialize_opt(deserializer, false)
+    }
+}
+
+/// Serializes `None` as `"0x"`, and deserializes like
+/// [`deserialize`].
+pub mod empty {
+    use super::*;
+
+    /// Serializes an optional value as a hexadecimal string, or `"0x"`.
+    ///
+    /// `None` is serialized as such in binary formats.
+    pub fn serialize<T: Serialize, S: Serializer>(
+        value: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            None if serializer.is_human_readable() => serializer.serialize_str("0x"),
+            value => value.serialize(serializer),
+        }
+    }
+
+    /// Deserializes an optional value from a hexadecimal string, treating
+    /// `null`, `""` and `"0x"` as `None`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: Deserialize<'de> + FromHex,
+        T::Error: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        deserialize_opt(deserializer, true)
+    }
+}
+
+fn deserialize_opt<'de, T, D>(deserializer: D, empty_is_none: bool) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de> + FromHex,
+    T::Error: fmt::Display,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_option(OptionVisitor {
+            empty_is_none,
+            _marker: PhantomData,
+        })
+    } else {
+        Option::deserialize(deserializer)
+    }
+}
+
+struct OptionVisitor<T> {
+    empty_is_none: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T> Visitor<'de> for OptionVisitor<T>
+where
+    T: Deserialize<'de> + FromHex,
+    T::Error: fmt::Display,
+{
+    type Value = Option<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a hex string, an array of u8, or null")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        if self.empty_is_none && (v.is_empty() || v == "0x") {
+            return Ok(None)
+        }
+        T::from_hex(v).map(Some).map_err(de::Error::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        T::deserialize(BytesDeserializer::new(v)).map(Some)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        T::deserialize(SeqAccessDeserializer::new(seq)).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        serde_utils::test_utils::{
+            assert_bincode_roundtrip, assert_json_eq, assert_json_err, assert_json_roundtrip,
+        },
+        Address, Bytes, B256,
+    };
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Lenient {
+        #[serde(default, with = "super")]
+        bytes: Option<Bytes>,
+        #[serde(default, with = "super")]
+        hash: Option<B256>,
+    }
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Strict {
+        #[serde(default, with = "super::strict")]
+        bytes: Option<Bytes>,
+        #[serde(default, with = "super::strict")]
+        address: Option<Address>,
+    }
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Empty {
+        #[serde(default, with = "super::empty")]
+        bytes: Option<Bytes>,
+        #[serde(default, with = "super::empty")]
+        hash: Option<B256>,
+    }
+
+    #[test]
+    fn deserialize_none() {
+        let inputs = [
+            r#"{}"#,
+            r#"{"bytes":null,"hash":null}"#,
+            r#"{"bytes":"","hash":""}"#,
+            r#"{"bytes":"0x","hash":"0x"}"#,
+        ];
+        assert_json_eq(inputs, &Lenient::default());
+        assert_json_eq(inputs, &Empty::default());
+        assert_json_eq(
+            [r#"{}"#, r#"{"bytes":null,"address":null}"#],
+            &Strict::default(),
+        );
+    }
+
+    #[test]
+    fn deserialize_some() {
+        let lenient = Lenient {
+            bytes: Some(Bytes::from_static(&[0x12, 0x34])),
+            hash: Some(B256::repeat_byte(0x11)),
+        };
+        let hash = format!("{}", B256::repeat_byte(0x11));
+        assert_json_eq(
+            [
+                format!(r#"{{"bytes":"0x1234","hash":"{hash}"}}"#),
+                format!(r#"{{"bytes":"1234","hash":"{}"}}"#, &hash[2..]),
+                format!(r#"{{"bytes":[18,52],"hash":{:?}}}"#, [0x11u8; 32]),
+            ],
+            &lenient,
+        );
+
+        let strict = Strict {
+            bytes: Some(Bytes::new()),
+            address: None,
+        };
+        assert_json_eq([r#"{"bytes":"0x"}"#, r#"{"bytes":""}"#], &strict);
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        assert_json_err::<Lenient, _>([
+            r#"{"bytes":"0x123"}"#,
+            r#"{"bytes":"zz"}"#,
+            r#"{"hash":"0x1234"}"#,
+            r#"{"bytes":1}"#,
+        ]);
+        assert_json_err::<Strict, _>([r#"{"address":"0x"}"#, r#"{"address":""}"#]);
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&Lenient::default()).unwrap(),
+            r#"{"bytes":null,"hash":null}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Empty::default()).unwrap(),
+            r#"{"bytes":"0x","hash":"0x"}"#
+        );
+        let empty = Empty {
+            bytes: Some(Bytes::from_static(&[0x12, 0x34])),
+            hash: None,
+        };
+        assert_json_roundtrip(&empty, r#"{"bytes":"0x1234","hash":"0x"}"#);
+    }
+
+    #[test]
+    fn bincode_roundtrip() {
+        assert_bincode_roundtrip(&Empty {
+            bytes: Some(Bytes::from_static(&[0x12, 0x34])),
+            hash: None,
+        });
+        assert_bincode_roundtrip(&Lenient {
+            bytes: None,
+            hash: Some(B256::repeat_byte(0x11)),
+        });
+    }
+}
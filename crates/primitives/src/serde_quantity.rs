@@ -0,0 +1,181 @@
+//! Serde helpers for Ethereum JSON-RPC "quantities".
+//!
+//! RPC providers are inconsistent in how they encode integers: most follow the
+//! specification and use `0x`-prefixed hexadecimal strings, but some return
+//! decimal strings or plain JSON numbers. The functions in this module accept
+//! all three on deserialization, and serialize as a `0x`-prefixed hexadecimal
+//! string. Use [`serde_decimal`] to (de)serialize decimal strings instead.
+//!
+//! Supported types are [`u64`], [`u128`] and [`Uint`] (e.g. [`U256`]).
+//!
+//! [`serde_decimal`]: crate::serde_decimal
+//! [`U256`]: crate::U256
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{serde_decimal, serde_quantity, U256};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Block {
+//!     #[serde(with = "serde_quantity")]
+//!     number: u64,
+//!     #[serde(with = "serde_decimal")]
+//!     difficulty: U256,
+//! }
+//!
+//! let block: Block = serde_json::from_str(r#"{"number":"0x1b4","difficulty":16}"#).unwrap();
+//! assert_eq!(block.number, 436);
+//! assert_eq!(block.difficulty, U256::from(16));
+//!
+//! let block: Block = serde_json::from_str(r#"{"number":436,"difficulty":"16"}"#).unwrap();
+//! assert_eq!(block.number, 436);
+//! assert_eq!(
+//!     serde_json::to_string(&block).unwrap(),
+//!     r#"{"number":"0x1b4","difficulty":"16"}"#
+//! );
+//! ```
+
+use crate::serde_utils::{strip_hex_prefix, Integer, IntegerVisitor};
+use core::fmt;
+use ruint::Uint;
+use serde::{Deserializer, Serializer};
+
+/// An unsigned integer type that can be (de)serialized as a quantity.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Quantity: Integer {
+    /// Writes `self` as minimal lowercase hexadecimal, without a prefix.
+    #[doc(hidden)]
+    fn fmt_hex(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    #[doc(hidden)]
+    fn from_str_radix(s: &str, radix: u32) -> Option<Self>;
+}
+
+macro_rules! impl_quantity_primitive {
+    ($($t:ty),+) => {$(
+        impl Quantity for $t {
+            #[inline]
+            fn fmt_hex(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerHex::fmt(self, f)
+            }
+
+            #[inline]
+            fn from_str_radix(s: &str, radix: u32) -> Option<Self> {
+                <$t>::from_str_radix(s, radix).ok()
+            }
+        }
+    )+};
+}
+
+impl_quantity_primitive!(u64, u128);
+
+impl<const BITS: usize, const LIMBS: usize> Quantity for Uint<BITS, LIMBS> {
+    fn fmt_hex(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `Uint`'s `LowerHex` implementation is zero-padded to the full width.
+        let limbs = self.as_limbs();
+        let Some(top) = limbs.iter().rposition(|&limb| limb != 0) else {
+            return f.write_str("0")
+        };
+        write!(f, "{:x}", limbs[top])?;
+        limbs[..top]
+            .iter()
+            .rev()
+            .try_for_each(|limb| write!(f, "{limb:016x}"))
+    }
+
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Option<Self> {
+        Self::from_str_radix(s, radix as u64).ok()
+    }
+}
+
+/// Serializes a quantity as a `0x`-prefixed hexadecimal string.
+pub fn serialize<T: Quantity, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&HexQuantity(value))
+}
+
+/// Deserializes a quantity from a `0x`-prefixed hexadecimal string, a decimal
+/// string, or a number.
+pub fn deserialize<'de, T: Quantity, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    deserializer.deserialize_any(IntegerVisitor {
+        expecting: "a hexadecimal or decimal quantity",
+        parse: parse_quantity,
+    })
+}
+
+fn parse_quantity<T: Quantity>(s: &str) -> Option<T> {
+    let (digits, radix) = match strip_hex_prefix(s) {
+        Some(hex) => (hex, 16),
+        None => (s, 10),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None
+    }
+    T::from_str_radix(digits, radix)
+}
+
+struct HexQuantity<'a, T>(&'a T);
+
+impl<T: Quantity> fmt::Display for HexQuantity<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("0x")?;
+        self.0.fmt_hex(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        serde_utils::test_utils::{assert_json_eq, assert_json_err},
+        U256,
+    };
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Hex<T: super::Quantity>(#[serde(with = "super")] T);
+
+    #[test]
+    fn deserialize() {
+        let inputs = [r#""0x1b4""#, r#""0X1B4""#, r#""436""#, "436"];
+        assert_json_eq(inputs, &Hex(436u64));
+        assert_json_eq(inputs, &Hex(436u128));
+        assert_json_eq(inputs, &Hex(U256::from(436)));
+
+        assert_json_eq([format!("\"{}\"", U256::MAX)], &Hex(U256::MAX));
+        assert_json_eq([format!("\"{:#x}\"", u128::MAX)], &Hex(u128::MAX));
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        let inputs = [
+            r#""0x""#,
+            r#""""#,
+            r#""1b4""#,
+            r#""-1""#,
+            r#""0x+1""#,
+            "-1",
+            "1.5",
+            "null",
+        ];
+        assert_json_err::<Hex<u64>, _>(inputs);
+        assert_json_err::<Hex<U256>, _>(inputs);
+        assert_json_err::<Hex<u64>, _>([r#""0x10000000000000000""#, r#""18446744073709551616""#]);
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(serde_json::to_string(&Hex(0u64)).unwrap(), r#""0x0""#);
+        assert_eq!(serde_json::to_string(&Hex(436u64)).unwrap(), r#""0x1b4""#);
+        assert_eq!(
+            serde_json::to_string(&Hex(u128::MAX)).unwrap(),
+            format!("\"{:#x}\"", u128::MAX)
+        );
+        assert_eq!(
+            serde_json::to_string(&Hex(U256::from(436))).unwrap(),
+            r#""0x1b4""#
+        );
+    }
+}
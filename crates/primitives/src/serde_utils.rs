@@ -0,0 +1,159 @@
+//! Shared internals for the `serde_*` helper modules.
+
+use crate::Signed;
+use core::fmt;
+use ruint::Uint;
+use serde::de::{self, Visitor};
+
+/// An integer type supported by the serde helper modules.
+///
+/// This is the sealing supertrait of
+/// [`Quantity`](crate::serde_quantity::Quantity)
+/// and [`Decimal`](crate::serde_decimal::Decimal): it is public but
+/// unreachable, so they cannot be implemented outside of this crate.
+pub trait Integer: Sized + fmt::Display {
+    #[doc(hidden)]
+    fn from_u128(value: u128) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn from_i128(value: i128) -> Option<Self>;
+}
+
+macro_rules! impl_integer_primitive {
+    ($($t:ty),+) => {$(
+        impl Integer for $t {
+            #[inline]
+            fn from_u128(value: u128) -> Option<Self> {
+                value.try_into().ok()
+            }
+
+            #[inline]
+            fn from_i128(value: i128) -> Option<Self> {
+                value.try_into().ok()
+            }
+        }
+    )+};
+}
+
+impl_integer_primitive!(u64, u128);
+
+impl<const BITS: usize, const LIMBS: usize> Integer for Uint<BITS, LIMBS> {
+    #[inline]
+    fn from_u128(value: u128) -> Option<Self> {
+        value.try_into().ok()
+    }
+
+    #[inline]
+    fn from_i128(value: i128) -> Option<Self> {
+        u128::try_from(value).ok().and_then(Self::from_u128)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Integer for Signed<BITS, LIMBS> {
+    #[inline]
+    fn from_u128(value: u128) -> Option<Self> {
+        value.try_into().ok()
+    }
+
+    #[inline]
+    fn from_i128(value: i128) -> Option<Self> {
+        value.try_into().ok()
+    }
+}
+
+/// Visits an [`Integer`] from a JSON number, or from a string using `parse`.
+pub(crate) struct IntegerVisitor<T> {
+    pub(crate) expecting: &'static str,
+    pub(crate) parse: fn(&str) -> Option<T>,
+}
+
+impl<T: Integer> Visitor<'_> for IntegerVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.visit_u128(v as u128)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        T::from_u128(v).ok_or_else(|| E::custom("integer out of range"))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.visit_i128(v as i128)
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        T::from_i128(v).ok_or_else(|| E::custom("integer out of range"))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        (self.parse)(v).ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+/// Strips a `0x` or `0X` prefix from `s`.
+#[inline]
+pub(crate) fn strip_hex_prefix(s: &str) -> Option<&str> {
+    s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"))
+}
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use alloc::vec::Vec;
+    use core::fmt::Debug;
+    use serde::{de::DeserializeOwned, Serialize};
+
+    /// Asserts that every JSON input deserializes to `expected`.
+    #[track_caller]
+    pub(crate) fn assert_json_eq<T, I>(inputs: I, expected: &T)
+    where
+        T: DeserializeOwned + PartialEq + Debug,
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        for s in inputs {
+            let s = s.as_ref();
+            assert_eq!(serde_json::from_str::<T>(s).unwrap(), *expected, "{s}");
+        }
+    }
+
+    /// Asserts that every JSON input fails to deserialize.
+    #[track_caller]
+    pub(crate) fn assert_json_err<T, I>(inputs: I)
+    where
+        T: DeserializeOwned + Debug,
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        for s in inputs {
+            let s = s.as_ref();
+            assert!(serde_json::from_str::<T>(s).is_err(), "{s}");
+        }
+    }
+
+    /// Asserts that `value` serializes to `json`, and deserializes back.
+    #[track_caller]
+    pub(crate) fn assert_json_roundtrip<T>(value: &T, json: &str)
+    where
+        T: Serialize + DeserializeOwned + PartialEq + Debug,
+    {
+        assert_eq!(serde_json::to_string(value).unwrap(), json);
+        assert_eq!(serde_json::from_str::<T>(json).unwrap(), *value, "{json}");
+    }
+
+    /// Asserts that `value` roundtrips through bincode, and returns the
+    /// encoded bytes.
+    #[track_caller]
+    pub(crate) fn assert_bincode_roundtrip<T>(value: &T) -> Vec<u8>
+    where
+        T: Serialize + DeserializeOwned + PartialEq + Debug,
+    {
+        let bin = bincode::serialize(value).unwrap();
+        assert_eq!(bincode::deserialize::<T>(&bin).unwrap(), *value);
+        bin
+    }
+}
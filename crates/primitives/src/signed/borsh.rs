@@ -0,0 +1,36 @@
+use super::Signed;
+use borsh::{io, BorshDeserialize, BorshSerialize};
+use ruint::Uint;
+
+/// Serializes the two's complement representation in the same format as
+/// [`Uint`]: `BYTES` little-endian bytes.
+impl<const BITS: usize, const LIMBS: usize> BorshSerialize for Signed<BITS, LIMBS> {
+    #[inline]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> BorshDeserialize for Signed<BITS, LIMBS> {
+    #[inline]
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Uint::deserialize_reader(reader).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{I256, U256};
+    use borsh::BorshDeserialize;
+
+    #[test]
+    fn roundtrip() {
+        for value in [I256::ZERO, I256::MINUS_ONE, I256::MIN, I256::MAX] {
+            let bin = borsh::to_vec(&value).unwrap();
+            assert_eq!(bin.len(), 32);
+            assert_eq!(bin, borsh::to_vec(&value.into_raw()).unwrap());
+            assert_eq!(I256::try_from_slice(&bin).unwrap(), value);
+        }
+        assert_eq!(borsh::to_vec(&U256::from(1)).unwrap()[0], 1);
+    }
+}
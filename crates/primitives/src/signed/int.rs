@@ -13,9 +13,9 @@ use ruint::Uint;
 ///
 /// ## Aliases
 ///
-/// We provide aliases for every bit-width divisble by 8, from 8 to 256. These
-/// are located in [`crate::aliases`] and are named `I256`, `I248` etc. Most
-/// users will want [`crate::I256`].
+/// We provide aliases for common bit-widths, from 8 to 4096. These are located
+/// in [`crate::aliases`] and are named `I256`, `I512` etc. Most users will want
+/// [`crate::I256`].
 ///
 /// # Usage
 ///
@@ -75,32 +75,31 @@ impl<const BITS: usize, const LIMBS: usize> fmt::Display for Signed<BITS, LIMBS>
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> fmt::Binary for Signed<BITS, LIMBS> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
-    }
-}
-
-impl<const BITS: usize, const LIMBS: usize> fmt::Octal for Signed<BITS, LIMBS> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
-    }
-}
-
-impl<const BITS: usize, const LIMBS: usize> fmt::LowerHex for Signed<BITS, LIMBS> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
-    }
+macro_rules! impl_fmt_radix {
+    ($($trait:ident => $fmt:literal, $alt:literal;)+) => {$(
+        impl<const BITS: usize, const LIMBS: usize> fmt::$trait for Signed<BITS, LIMBS> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                // the two's complement representation has no sign, so ignore
+                // the `sign_plus` flag, which `Uint` would otherwise print
+                if f.sign_plus() {
+                    if f.alternate() {
+                        write!(f, $alt, self.0)
+                    } else {
+                        write!(f, $fmt, self.0)
+                    }
+                } else {
+                    fmt::$trait::fmt(&self.0, f)
+                }
+            }
+        }
+    )+};
 }
 
-impl<const BITS: usize, const LIMBS: usize> fmt::UpperHex for Signed<BITS, LIMBS> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
-    }
+impl_fmt_radix! {
+    Binary => "{:b}", "{:#b}";
+    Octal => "{:o}", "{:#o}";
+    LowerHex => "{:x}", "{:#x}";
+    UpperHex => "{:X}", "{:#X}";
 }
 
 impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
@@ -140,6 +139,54 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         Self(val)
     }
 
+    /// Reinterprets the bits of an unsigned integer as a signed one, wrapping
+    /// values greater than [`MAX`](Self::MAX) around to negative numbers.
+    ///
+    /// This mirrors an explicit `int256(x)` cast from `uint256` in Solidity
+    /// and is equivalent to [`from_raw`](Self::from_raw). Use
+    /// [`TryFrom`] for a conversion that rejects values that do not fit.
+    #[inline]
+    pub const fn wrapping_from_uint(val: Uint<BITS, LIMBS>) -> Self {
+        Self(val)
+    }
+
+    /// Reinterprets the bits of `self` as an unsigned integer, wrapping
+    /// negative values around to `2^BITS + self`.
+    ///
+    /// This mirrors an explicit `uint256(x)` cast from `int256` in Solidity
+    /// and is equivalent to [`into_raw`](Self::into_raw). Use [`TryFrom`] for
+    /// a conversion that rejects negative values, or
+    /// [`unsigned_abs`](Self::unsigned_abs) for the magnitude.
+    #[inline]
+    pub const fn wrapping_into_uint(self) -> Uint<BITS, LIMBS> {
+        self.0
+    }
+
+    /// Creates a new signed integer with the given random number generator.
+    #[cfg(feature = "rand_core")]
+    #[inline]
+    #[doc(alias = "random_using")]
+    pub fn random_with<R: rand_core::RngCore + ?Sized>(rng: &mut R) -> Self {
+        let mut this = Self::ZERO;
+        this.randomize_with(rng);
+        this
+    }
+
+    /// Fills this signed integer with the given random number generator.
+    #[cfg(feature = "rand_core")]
+    #[inline]
+    #[doc(alias = "randomize_using")]
+    pub fn randomize_with<R: rand_core::RngCore + ?Sized>(&mut self, rng: &mut R) {
+        // Implemented on `rand_core` directly, since `Uint`'s own random
+        // constructors require the `rand` crate.
+        let mut limbs = [0u64; LIMBS];
+        limbs.iter_mut().for_each(|limb| *limb = rng.next_u64());
+        if let Some(top) = limbs.last_mut() {
+            *top &= Uint::<BITS, LIMBS>::MASK;
+        }
+        self.0 = Uint::from_limbs(limbs);
+    }
+
     /// Shortcut for `val.try_into().unwrap()`.
     ///
     /// # Panics
@@ -231,20 +278,20 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
 
     /// Returns the number of ones in the binary representation of `self`.
     #[inline]
-    pub fn count_ones(&self) -> usize {
+    pub const fn count_ones(&self) -> usize {
         self.0.count_ones()
     }
 
     /// Returns the number of zeros in the binary representation of `self`.
     #[inline]
-    pub fn count_zeros(&self) -> usize {
+    pub const fn count_zeros(&self) -> usize {
         self.0.count_zeros()
     }
 
     /// Returns the number of leading zeros in the binary representation of
     /// `self`.
     #[inline]
-    pub fn leading_zeros(&self) -> usize {
+    pub const fn leading_zeros(&self) -> usize {
         self.0.leading_zeros()
     }
 
@@ -498,6 +545,66 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     pub const fn from_limbs(limbs: [u64; LIMBS]) -> Self {
         Self(Uint::from_limbs(limbs))
     }
+
+    /// Converts `self` to a signed integer of a different bit-width,
+    /// returning `None` if the value does not fit.
+    ///
+    /// Widening conversions, e.g. from [`I256`](crate::I256) to
+    /// [`I512`](crate::I512), always succeed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{I256, I512};
+    ///
+    /// let a = I256::MIN.checked_resize::<512, 8>().unwrap();
+    /// assert_eq!(a.to_string(), I256::MIN.to_string());
+    ///
+    /// let b = a * I512::try_from(2).unwrap();
+    /// assert_eq!(b.checked_resize::<256, 4>(), None);
+    /// ```
+    #[inline]
+    pub fn checked_resize<const BITS_DST: usize, const LIMBS_DST: usize>(
+        self,
+    ) -> Option<Signed<BITS_DST, LIMBS_DST>> {
+        // Negative values are resized through their bitwise complement, which
+        // is non-negative and sign-extends to the complement of the result.
+        if BITS_DST == 0 {
+            return self.is_zero().then_some(Signed::ZERO);
+        }
+        let negative = self.is_negative();
+        let bits = if negative { !self.0 } else { self.0 };
+        let resized = Uint::<BITS_DST, LIMBS_DST>::checked_from_limbs_slice(bits.as_limbs())?;
+        if resized.bit(BITS_DST - 1) {
+            return None
+        }
+        Some(Signed(if negative { !resized } else { resized }))
+    }
+
+    /// Converts `self` to a signed integer of a different bit-width,
+    /// truncating the most significant bits if the value does not fit.
+    ///
+    /// This mirrors an explicit cast between signed integer types in
+    /// Solidity, e.g. `int128(x)` from `int256`.
+    #[inline]
+    pub fn wrapping_resize<const BITS_DST: usize, const LIMBS_DST: usize>(
+        self,
+    ) -> Signed<BITS_DST, LIMBS_DST> {
+        let negative = self.is_negative();
+        let bits = if negative { !self.0 } else { self.0 };
+        let resized = Uint::<BITS_DST, LIMBS_DST>::wrapping_from_limbs_slice(bits.as_limbs());
+        Signed(if negative { !resized } else { resized })
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<const BITS: usize, const LIMBS: usize> rand::distributions::Distribution<Signed<BITS, LIMBS>>
+    for rand::distributions::Standard
+{
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Signed<BITS, LIMBS> {
+        Signed::random_with(rng)
+    }
 }
 
 #[cfg(test)]
@@ -551,6 +658,64 @@ mod tests {
             "57896044618658097711785492504343953926634992332820282019728792003956564819967",
             "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
         );
+        test_identities!(
+            I512,
+            "6703903964971298549787012499102923063739682910296196688861780721860882015036773488400937149083451713845015929093243025426876941405973284973216824503042047",
+            "-6703903964971298549787012499102923063739682910296196688861780721860882015036773488400937149083451713845015929093243025426876941405973284973216824503042048"
+        );
+    }
+
+    #[test]
+    fn uint_conversions() {
+        macro_rules! run_test {
+            ($i_struct:ty, $u_struct:ty) => {
+                let m1 = <$i_struct>::MINUS_ONE;
+
+                // wrapping casts round-trip all bit patterns
+                assert_eq!(<$i_struct>::wrapping_from_uint(<$u_struct>::MAX), m1);
+                assert_eq!(m1.wrapping_into_uint(), <$u_struct>::MAX);
+                assert_eq!(
+                    <$i_struct>::wrapping_from_uint(<$i_struct>::MIN.wrapping_into_uint()),
+                    <$i_struct>::MIN
+                );
+                assert_eq!(
+                    <$i_struct>::wrapping_from_uint(<$u_struct>::from(42)),
+                    <$i_struct>::try_from(42).unwrap()
+                );
+
+                // lossless conversions reject what does not fit
+                assert!(<$i_struct>::try_from(<$u_struct>::MAX).is_err());
+                assert!(<$u_struct>::try_from(m1).is_err());
+                assert_eq!(
+                    <$u_struct>::try_from(<$i_struct>::MAX).unwrap(),
+                    <$u_struct>::MAX >> 1
+                );
+
+                // sign and magnitude
+                assert_eq!(m1.twos_complement(), <$u_struct>::from(1));
+                assert_eq!(
+                    <$i_struct>::checked_from_sign_and_abs(
+                        Sign::Negative,
+                        <$i_struct>::MIN.unsigned_abs()
+                    ),
+                    Some(<$i_struct>::MIN)
+                );
+                assert_eq!(
+                    <$i_struct>::checked_from_sign_and_abs(
+                        Sign::Positive,
+                        <$i_struct>::MIN.unsigned_abs()
+                    ),
+                    None
+                );
+            };
+        }
+
+        run_test!(I96, U96);
+        run_test!(I128, U128);
+        run_test!(I160, U160);
+        run_test!(I192, U192);
+        run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -605,6 +770,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -658,6 +824,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -782,6 +949,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -827,6 +995,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -861,6 +1030,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -893,6 +1063,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -924,6 +1095,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -948,6 +1120,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -1152,6 +1325,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -1214,6 +1388,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -1281,6 +1456,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -1338,6 +1514,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -1359,6 +1536,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -1405,6 +1583,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -1467,6 +1646,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -1497,6 +1677,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -1515,6 +1696,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -1537,6 +1719,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -1589,6 +1772,81 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
+    }
+
+    #[test]
+    fn min_max_edge_cases() {
+        macro_rules! run_test {
+            ($i_struct:ty, $u_struct:ty) => {
+                let min = <$i_struct>::MIN;
+                let max = <$i_struct>::MAX;
+                let one = <$i_struct>::ONE;
+                let m1 = <$i_struct>::MINUS_ONE;
+
+                // abs
+                assert_eq!(min.checked_abs(), None);
+                assert_eq!(min.saturating_abs(), max);
+                assert_eq!(min.wrapping_abs(), min);
+                assert_eq!(
+                    min.unsigned_abs(),
+                    <$u_struct>::from(1) << (<$u_struct>::BITS - 1)
+                );
+                assert_eq!(max.checked_abs(), Some(max));
+                assert_eq!(max.unsigned_abs(), max.into_raw());
+
+                // add / sub
+                assert_eq!(max.checked_add(one), None);
+                assert_eq!(max.saturating_add(one), max);
+                assert_eq!(max.wrapping_add(one), min);
+                assert_eq!(min.checked_sub(one), None);
+                assert_eq!(min.saturating_sub(one), min);
+                assert_eq!(min.wrapping_sub(one), max);
+
+                // mul
+                assert_eq!(min.checked_mul(m1), None);
+                assert_eq!(min.saturating_mul(m1), max);
+                assert_eq!(max.saturating_mul(m1), min + one);
+                assert_eq!(max.saturating_mul(max), max);
+                assert_eq!(min.saturating_mul(max), min);
+
+                // div / rem
+                assert_eq!(min.saturating_div(m1), max);
+                assert_eq!(min.saturating_rem(m1), <$i_struct>::ZERO);
+                assert_eq!(min.saturating_div_euclid(m1), max);
+                assert_eq!(min.saturating_rem_euclid(m1), <$i_struct>::ZERO);
+                assert_eq!(min.checked_div(min), Some(one));
+                assert_eq!(min.checked_rem(max), Some(m1));
+                assert_eq!(min.div_euclid(max), -<$i_struct>::try_from(2).unwrap());
+                assert_eq!(min.rem_euclid(max), max - one);
+
+                // shifts
+                assert_eq!(one.checked_shl(<$i_struct>::BITS), None);
+                assert_eq!(one.checked_shl(<$i_struct>::BITS - 1), Some(min));
+                assert_eq!(min.checked_shr(<$i_struct>::BITS), None);
+                assert_eq!(min.checked_asr(<$i_struct>::BITS - 1), Some(m1));
+                assert_eq!(
+                    max.checked_asr(<$i_struct>::BITS - 1),
+                    Some(<$i_struct>::ZERO)
+                );
+                assert_eq!(min.checked_asr(<$i_struct>::BITS), None);
+
+                // abs_diff / signum
+                assert_eq!(max.abs_diff(min), <$u_struct>::MAX);
+                assert_eq!(min.abs_diff(max), <$u_struct>::MAX);
+                assert_eq!(m1.abs_diff(one), <$u_struct>::from(2));
+                assert_eq!(min.signum(), m1);
+                assert_eq!(max.signum(), one);
+                assert_eq!(<$i_struct>::ZERO.signum(), <$i_struct>::ZERO);
+            };
+        }
+
+        run_test!(I96, U96);
+        run_test!(I128, U128);
+        run_test!(I160, U160);
+        run_test!(I192, U192);
+        run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -1635,6 +1893,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -1670,6 +1929,7 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
     }
 
     #[test]
@@ -1729,5 +1989,65 @@ mod tests {
         run_test!(I160, U160);
         run_test!(I192, U192);
         run_test!(I256, U256);
+        run_test!(I512, U512);
+    }
+
+    #[test]
+    fn resize() {
+        for value in [I256::ZERO, I256::ONE, I256::MINUS_ONE, I256::MAX, I256::MIN] {
+            let wide = value.checked_resize::<512, 8>().unwrap();
+            assert_eq!(wide.to_string(), value.to_string());
+            assert_eq!(wide.wrapping_resize::<256, 4>(), value);
+            assert_eq!(wide.checked_resize::<256, 4>(), Some(value));
+            assert_eq!(value.wrapping_resize::<512, 8>(), wide);
+        }
+
+        let two = I512::try_from(2).unwrap();
+        let big = I256::MAX.checked_resize::<512, 8>().unwrap() * two;
+        assert_eq!(big.checked_resize::<256, 4>(), None);
+        assert_eq!(big.wrapping_resize::<256, 4>(), I256::try_from(-2).unwrap());
+        let small = I256::MIN.checked_resize::<512, 8>().unwrap() - I512::ONE;
+        assert_eq!(small.checked_resize::<256, 4>(), None);
+        assert_eq!(small.wrapping_resize::<256, 4>(), I256::MAX);
+
+        assert_eq!(
+            I512::MAX.checked_resize::<4096, 64>().unwrap().to_string(),
+            I512::MAX.to_string()
+        );
+        assert_eq!(
+            I512::MIN.checked_resize::<4096, 64>().unwrap().to_string(),
+            I512::MIN.to_string()
+        );
+
+        assert_eq!(I256::ZERO.checked_resize::<0, 0>(), Some(I0::ZERO));
+        assert_eq!(I256::ONE.checked_resize::<0, 0>(), None);
+        assert_eq!(
+            I256::MINUS_ONE.checked_resize::<1, 1>(),
+            Some(I1::MINUS_ONE)
+        );
+        assert_eq!(I256::ONE.checked_resize::<1, 1>(), None);
+        assert_eq!(
+            I1::MINUS_ONE.checked_resize::<256, 4>(),
+            Some(I256::MINUS_ONE)
+        );
+    }
+
+    #[test]
+    fn wide_arithmetic() {
+        // 2^2047 - 1, as an RSA-style modulus, squared in 4096 bits.
+        let modulus = U2048::MAX >> 1usize;
+        let wide = U4096::from_limbs_slice(modulus.as_limbs());
+        let square = wide * wide;
+        assert_eq!(square % wide, U4096::ZERO);
+        assert_eq!(
+            U2048::from_limbs_slice(&square.as_limbs()[..32]),
+            U2048::from(1)
+        );
+
+        let a = I512::MIN + I512::ONE;
+        assert_eq!(-a, I512::MAX);
+        assert_eq!(a / I512::MINUS_ONE, I512::MAX);
+        assert_eq!(I512::MAX.checked_add(I512::ONE), None);
+        assert_eq!(I512::MIN.overflowing_neg(), (I512::MIN, true));
     }
 }
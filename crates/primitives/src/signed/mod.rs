@@ -1,5 +1,9 @@
 //! This module contains a 256-bit signed integer implementation.
 
+/// Borsh support.
+#[cfg(feature = "borsh")]
+mod borsh;
+
 /// Conversion implementations.
 mod conversions;
 
@@ -14,6 +18,20 @@ pub use int::Signed;
 /// Operation implementations.
 mod ops;
 
+/// Rkyv support.
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "rkyv")]
+pub use self::rkyv::ArchivedSigned;
+
+/// Parity SCALE codec support.
+#[cfg(feature = "scale")]
+mod scale;
+
+/// JSON Schema support.
+#[cfg(feature = "json-schema")]
+mod schemars;
+
 /// A simple [`Sign`] enum, for dealing with integer signs.
 mod sign;
 pub use sign::Sign;
@@ -22,5 +40,9 @@ pub use sign::Sign;
 #[cfg(feature = "serde")]
 mod serde;
 
+/// Zeroize support.
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
 /// Utility functions used in the signed integer implementation.
 pub(crate) mod utils;
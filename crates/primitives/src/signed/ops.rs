@@ -78,6 +78,37 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         self.into_sign_and_abs().1
     }
 
+    /// Computes the absolute difference between `self` and `other`.
+    ///
+    /// This function always returns the correct answer without overflow or
+    /// panics by returning an unsigned integer.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff(self, other: Self) -> Uint<BITS, LIMBS> {
+        if self < other {
+            other.0.wrapping_sub(self.0)
+        } else {
+            self.0.wrapping_sub(other.0)
+        }
+    }
+
+    /// Returns a number representing the sign of `self`:
+    ///
+    /// - `0` if the number is zero
+    /// - `1` if the number is positive
+    /// - `-1` if the number is negative
+    #[inline]
+    #[must_use]
+    pub const fn signum(self) -> Self {
+        if self.is_negative() {
+            Self::MINUS_ONE
+        } else if self.is_zero() {
+            Self::ZERO
+        } else {
+            Self::ONE
+        }
+    }
+
     /// Negates self, overflowing if this is equal to the minimum value.
     ///
     /// Returns a tuple of the negated version of self along with a boolean
@@ -405,6 +436,22 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         }
     }
 
+    /// Saturating integer remainder. Computes `self % rhs`, saturating at the
+    /// numeric bounds instead of overflowing.
+    ///
+    /// The only case where the remainder overflows is `MIN % -1`; in that case
+    /// this function returns `0`, the mathematically correct result.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is 0.
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn saturating_rem(self, rhs: Self) -> Self {
+        self.overflowing_rem(rhs).0
+    }
+
     /// Wrapping (modular) remainder. Computes `self % rhs`, wrapping around at
     /// the boundary of the type.
     ///
@@ -484,6 +531,23 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         }
     }
 
+    /// Saturating Euclidean division. Computes `self.div_euclid(rhs)`,
+    /// saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is 0.
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn saturating_div_euclid(self, rhs: Self) -> Self {
+        match self.overflowing_div_euclid(rhs) {
+            (value, false) => value,
+            // MIN / -1 is the only possible saturating overflow
+            _ => Self::MAX,
+        }
+    }
+
     /// Wrapping Euclidean division. Computes `self.div_euclid(rhs)`,
     /// wrapping around at the boundary of the type.
     ///
@@ -576,6 +640,19 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         }
     }
 
+    /// Saturating Euclidean remainder. Computes `self.rem_euclid(rhs)`,
+    /// returning `0` for `MIN % -1` instead of overflowing.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is 0.
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn saturating_rem_euclid(self, rhs: Self) -> Self {
+        self.overflowing_rem_euclid(rhs).0
+    }
+
     /// Returns the sign of `self` to the exponent `exp`.
     ///
     /// Note that this method does not actually try to compute the `self` to the
@@ -684,7 +761,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     #[inline]
     #[must_use]
     pub fn overflowing_shl(self, rhs: usize) -> (Self, bool) {
-        if rhs >= 256 {
+        if rhs >= BITS {
             (Self::ZERO, true)
         } else {
             (Self(self.0 << rhs), false)
@@ -718,7 +795,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     #[inline]
     #[must_use]
     pub fn overflowing_shr(self, rhs: usize) -> (Self, bool) {
-        if rhs >= 256 {
+        if rhs >= BITS {
             (Self::ZERO, true)
         } else {
             (Self(self.0 >> rhs), false)
@@ -779,6 +856,19 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         }
     }
 
+    /// Checked arithmetic shift right. Computes `self.asr(rhs)`, returning
+    /// `None` if `rhs` is larger than or equal to the number of bits in
+    /// `self`.
+    #[inline]
+    #[must_use]
+    pub fn checked_asr(self, rhs: usize) -> Option<Self> {
+        if rhs >= BITS {
+            None
+        } else {
+            Some(self.asr(rhs))
+        }
+    }
+
     /// Arithmetic shift left operation. Computes `self << rhs`, checking for
     /// overflow on the final result.
     ///
@@ -0,0 +1,119 @@
+use super::Signed;
+use core::fmt;
+use rkyv::{
+    bytecheck::CheckBytes,
+    rancor::{Fallible, Trace},
+    Archive, Archived, Deserialize, Place, Portable, Serialize,
+};
+use ruint::Uint;
+
+/// An archived [`Signed`].
+///
+/// Stores the two's complement representation in the same format as an
+/// archived [`Uint`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ArchivedSigned<const BITS: usize, const LIMBS: usize>(Archived<Uint<BITS, LIMBS>>);
+
+impl<const BITS: usize, const LIMBS: usize> From<&ArchivedSigned<BITS, LIMBS>>
+    for Signed<BITS, LIMBS>
+{
+    #[inline]
+    fn from(archived: &ArchivedSigned<BITS, LIMBS>) -> Self {
+        Self(<Uint<BITS, LIMBS> as From<_>>::from(&archived.0))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<ArchivedSigned<BITS, LIMBS>>
+    for Signed<BITS, LIMBS>
+{
+    #[inline]
+    fn from(archived: ArchivedSigned<BITS, LIMBS>) -> Self {
+        Self::from(&archived)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> fmt::Display for ArchivedSigned<BITS, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Signed::from(self), f)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> fmt::Debug for ArchivedSigned<BITS, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&Signed::from(self), f)
+    }
+}
+
+// SAFETY: `ArchivedSigned` is `repr(transparent)` for `ArchivedUint`, which is
+// `Portable`.
+unsafe impl<const BITS: usize, const LIMBS: usize> Portable for ArchivedSigned<BITS, LIMBS> {}
+
+// SAFETY: `ArchivedSigned` is `repr(transparent)` for `ArchivedUint`.
+unsafe impl<C: Fallible + ?Sized, const BITS: usize, const LIMBS: usize> CheckBytes<C>
+    for ArchivedSigned<BITS, LIMBS>
+where
+    C::Error: Trace,
+{
+    #[inline]
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        Archived::<Uint<BITS, LIMBS>>::check_bytes(value.cast(), context)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Archive for Signed<BITS, LIMBS> {
+    type Archived = ArchivedSigned<BITS, LIMBS>;
+    type Resolver = <Uint<BITS, LIMBS> as Archive>::Resolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        // SAFETY: `ArchivedSigned` is `repr(transparent)` for `ArchivedUint`.
+        self.0.resolve(resolver, unsafe { out.cast_unchecked() });
+    }
+}
+
+impl<S: Fallible + ?Sized, const BITS: usize, const LIMBS: usize> Serialize<S>
+    for Signed<BITS, LIMBS>
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized, const BITS: usize, const LIMBS: usize>
+    Deserialize<Signed<BITS, LIMBS>, D> for ArchivedSigned<BITS, LIMBS>
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut D) -> Result<Signed<BITS, LIMBS>, D::Error> {
+        self.0.deserialize(deserializer).map(Signed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{I256, U256};
+    use alloc::string::ToString;
+    use rkyv::rancor::Error;
+
+    #[test]
+    fn roundtrip() {
+        for value in [I256::ZERO, I256::MINUS_ONE, I256::MIN, I256::MAX] {
+            let bytes = rkyv::to_bytes::<Error>(&value).unwrap();
+            assert_eq!(bytes.len(), 32);
+            assert_eq!(
+                &bytes[..],
+                &rkyv::to_bytes::<Error>(&value.into_raw()).unwrap()[..]
+            );
+
+            let archived = rkyv::access::<ArchivedSigned<256, 4>, Error>(&bytes).unwrap();
+            assert_eq!(I256::from(archived), value);
+            assert_eq!(archived.to_string(), value.to_string());
+            assert_eq!(rkyv::deserialize::<I256, Error>(archived).unwrap(), value);
+        }
+
+        let bytes = rkyv::to_bytes::<Error>(&U256::from(1)).unwrap();
+        assert_eq!(bytes[0], 1);
+    }
+}
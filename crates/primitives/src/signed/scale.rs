@@ -0,0 +1,110 @@
+use super::Signed;
+use alloc::vec::Vec;
+use parity_scale_codec::{
+    Compact, CompactLen, Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen,
+};
+use ruint::Uint;
+use scale_info::{build::Fields, meta_type, Path, Type, TypeInfo, TypeParameter};
+
+/// Encodes the two's complement representation in the same format as
+/// [`Uint`]: a compact length prefix followed by `BYTES` little-endian bytes.
+impl<const BITS: usize, const LIMBS: usize> Encode for Signed<BITS, LIMBS> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.0.size_hint()
+    }
+
+    #[inline]
+    fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+        self.0.using_encoded(f)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> EncodeLike for Signed<BITS, LIMBS> {}
+
+impl<const BITS: usize, const LIMBS: usize> Decode for Signed<BITS, LIMBS> {
+    #[inline]
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        Uint::decode(input).map(Self)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> MaxEncodedLen for Signed<BITS, LIMBS> {
+    #[inline]
+    fn max_encoded_len() -> usize {
+        Compact::<u32>::compact_len(&(Self::BYTES as u32)) + Self::BYTES
+    }
+}
+
+/// `Uint` does not implement `TypeInfo`, so the inner value is described by
+/// its encoding, a `Vec<u8>`.
+///
+/// Like [`FixedBytes`](crate::FixedBytes), the const parameter `BITS` is
+/// described as a type parameter bound to `[u8; BITS]`, and widths with an
+/// [alias](crate::aliases) are named after it, so that each width is a
+/// distinct type.
+impl<const BITS: usize, const LIMBS: usize> TypeInfo for Signed<BITS, LIMBS> {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        let ident = match BITS {
+            0 => "I0",
+            1 => "I1",
+            8 => "I8",
+            16 => "I16",
+            32 => "I32",
+            64 => "I64",
+            128 => "I128",
+            160 => "I160",
+            192 => "I192",
+            256 => "I256",
+            512 => "I512",
+            1024 => "I1024",
+            2048 => "I2048",
+            4096 => "I4096",
+            _ => "Signed",
+        };
+        Type::builder()
+            .path(Path::new(ident, module_path!()))
+            .type_params([TypeParameter::new("BITS", Some(meta_type::<[u8; BITS]>()))])
+            .composite(
+                Fields::unnamed().field(|f| f.ty::<Vec<u8>>().type_name("Uint<BITS, LIMBS>")),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::I128, I256, U256};
+    use scale_info::TypeDef;
+
+    #[test]
+    fn roundtrip() {
+        for value in [I256::ZERO, I256::MINUS_ONE, I256::MIN, I256::MAX] {
+            let bin = value.encode();
+            assert_eq!(bin.len(), I256::max_encoded_len());
+            assert_eq!(bin, value.into_raw().encode());
+            assert_eq!(I256::decode(&mut &bin[..]).unwrap(), value);
+        }
+        assert_eq!(U256::from(1).encode()[..2], [32 << 2, 1]);
+        assert_eq!(I256::max_encoded_len(), 33);
+    }
+
+    #[test]
+    fn type_info() {
+        let info = I256::type_info();
+        assert_eq!(info.path.ident(), Some("I256"));
+        assert_eq!(info.type_params.len(), 1);
+        assert_eq!(info.type_params[0].name, "BITS");
+        let param = info.type_params[0].ty.unwrap().type_info();
+        assert!(matches!(param.type_def, TypeDef::Array(ref array) if array.len == 256));
+
+        assert_eq!(I128::type_info().path.ident(), Some("I128"));
+        assert_eq!(Signed::<24, 1>::type_info().path.ident(), Some("Signed"));
+        assert_ne!(
+            Signed::<24, 1>::type_info().type_params,
+            Signed::<40, 1>::type_info().type_params
+        );
+    }
+}
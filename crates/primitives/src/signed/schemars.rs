@@ -0,0 +1,42 @@
+use super::Signed;
+use alloc::{borrow::Cow, format};
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+/// `Signed` is described as a decimal integer string, matching its serde
+/// representation.
+impl<const BITS: usize, const LIMBS: usize> JsonSchema for Signed<BITS, LIMBS> {
+    #[inline]
+    fn inline_schema() -> bool {
+        true
+    }
+
+    #[inline]
+    fn schema_name() -> Cow<'static, str> {
+        format!("Int{BITS}").into()
+    }
+
+    #[inline]
+    fn schema_id() -> Cow<'static, str> {
+        format!("alloy_primitives::Signed<{BITS}>").into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": "^-?[0-9]+$",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::I256;
+    use schemars::schema_for;
+
+    #[test]
+    fn schema() {
+        let schema = schema_for!(I256);
+        assert_eq!(schema.get("type").unwrap(), "string");
+        assert_eq!(schema.get("pattern").unwrap(), "^-?[0-9]+$");
+    }
+}
@@ -6,6 +6,7 @@ use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
+/// Serializes a [`Signed`] value as a decimal string, in all formats.
 impl<const BITS: usize, const LIMBS: usize> Serialize for Signed<BITS, LIMBS> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         serializer.collect_str(self)
@@ -48,8 +49,43 @@ impl<'de, const BITS: usize, const LIMBS: usize> Deserialize<'de> for Signed<BIT
             }
         }
 
-        deserializer.deserialize_any(SignedVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SignedVisitor)
+        } else {
+            // Binary formats are not self-describing; values are always strings.
+            deserializer.deserialize_str(SignedVisitor)
+        }
     }
 }
 
-// TODO: Tests
+#[cfg(test)]
+mod tests {
+    use crate::aliases::I256;
+    use alloc::string::ToString;
+
+    #[test]
+    fn serde_json() {
+        for (value, s) in [
+            (I256::ZERO, "\"0\""),
+            (I256::MINUS_ONE, "\"-1\""),
+            (I256::try_from(42).unwrap(), "\"42\""),
+        ] {
+            assert_eq!(serde_json::to_string(&value).unwrap(), s);
+            assert_eq!(serde_json::from_str::<I256>(s).unwrap(), value);
+        }
+        assert_eq!(
+            serde_json::from_str::<I256>("-42").unwrap(),
+            I256::try_from(-42).unwrap()
+        );
+    }
+
+    #[test]
+    fn bincode_roundtrip() {
+        for value in [I256::ZERO, I256::MINUS_ONE, I256::MIN, I256::MAX] {
+            let bin = bincode::serialize(&value).unwrap();
+            // the wire format is unchanged: the decimal string
+            assert_eq!(bin, bincode::serialize(&value.to_string()).unwrap());
+            assert_eq!(bincode::deserialize::<I256>(&bin).unwrap(), value);
+        }
+    }
+}
@@ -5,6 +5,7 @@ use core::{
 
 /// Enum to represent the sign of a 256-bit signed integer.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(derive_arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum Sign {
     /// Less than zero.
@@ -13,6 +14,17 @@ pub enum Sign {
     Positive = 1,
 }
 
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Sign {
+    type Parameters = ();
+    type Strategy = proptest::sample::Select<Self>;
+
+    #[inline]
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(&[Self::Negative, Self::Positive][..])
+    }
+}
+
 impl ops::Mul for Sign {
     type Output = Self;
 
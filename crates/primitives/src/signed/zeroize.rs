@@ -0,0 +1,22 @@
+use super::Signed;
+use zeroize::Zeroize;
+
+impl<const BITS: usize, const LIMBS: usize> Zeroize for Signed<BITS, LIMBS> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::I256;
+
+    #[test]
+    fn zeroize() {
+        let mut value = I256::MINUS_ONE;
+        value.zeroize();
+        assert_eq!(value, I256::ZERO);
+    }
+}
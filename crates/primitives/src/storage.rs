@@ -0,0 +1,228 @@
+//! Storage slot computation, following the [Solidity storage layout].
+//!
+//! These functions compute the slots at which mapping values, dynamic array
+//! elements, and long `bytes` / `string` data are stored, e.g. to read
+//! contract state with `eth_getStorageAt`.
+//!
+//! [Solidity storage layout]: https://docs.soliditylang.org/en/latest/internals/layout_in_storage.html
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{address, storage, U256};
+//!
+//! // `mapping(address => mapping(address => uint256)) allowance` at slot 1
+//! let owner = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+//! let spender = address!("0000000000000000000000000000000000000001");
+//! let slot =
+//!     storage::nested_mapping_slot([owner.into_word(), spender.into_word()], U256::from(1));
+//! assert_eq!(
+//!     slot,
+//!     storage::mapping_slot(
+//!         spender.into_word(),
+//!         storage::mapping_slot(owner.into_word(), U256::from(1))
+//!     )
+//! );
+//! ```
+
+use crate::{keccak256, B256, U256};
+
+/// Computes the slot of the value at `key` in a mapping stored at `base_slot`:
+/// `keccak256(key . base_slot)`.
+///
+/// `key` must be the key encoded as a 32-byte word, as for value types: e.g.
+/// [`Address::into_word`](crate::Address::into_word) or
+/// [`U256::to_be_bytes`]. Use [`mapping_slot_bytes`] for `bytes` and
+/// `string` keys.
+#[inline]
+pub fn mapping_slot(key: B256, base_slot: U256) -> U256 {
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(key.as_slice());
+    buf[32..].copy_from_slice(&base_slot.to_be_bytes::<32>());
+    keccak256(buf).into()
+}
+
+/// Computes the slot of the value at a `bytes` or `string` `key` in a mapping
+/// stored at `base_slot`: `keccak256(key . base_slot)`, where `key` is not
+/// padded.
+#[inline]
+pub fn mapping_slot_bytes(key: &[u8], base_slot: U256) -> U256 {
+    let mut buf = alloc::vec::Vec::with_capacity(key.len() + 32);
+    buf.extend_from_slice(key);
+    buf.extend_from_slice(&base_slot.to_be_bytes::<32>());
+    keccak256(buf).into()
+}
+
+/// Computes the slot of a value in nested mappings stored at `base_slot`,
+/// applying [`mapping_slot`] for each key, from the outermost mapping inwards.
+///
+/// For example, the slot of `m[a][b]` is
+/// `nested_mapping_slot([a, b], base_slot)`.
+#[inline]
+pub fn nested_mapping_slot<I>(keys: I, base_slot: U256) -> U256
+where
+    I: IntoIterator<Item = B256>,
+{
+    keys.into_iter()
+        .fold(base_slot, |slot, key| mapping_slot(key, slot))
+}
+
+/// Computes the slot at which the elements of a dynamic array stored at
+/// `base_slot` start: `keccak256(base_slot)`.
+///
+/// `base_slot` itself holds the length of the array.
+#[inline]
+pub fn dynamic_array_slot(base_slot: U256) -> U256 {
+    keccak256(base_slot.to_be_bytes::<32>()).into()
+}
+
+/// Computes the slot of the element at `index` in a dynamic array stored at
+/// `base_slot`, where each element occupies `element_slots` slots.
+///
+/// Elements of 16 bytes or less are packed several per slot by Solidity;
+/// for those, pass the index of the slot instead, i.e.
+/// `index / (32 / element_size)`, with `element_slots` set to 1.
+#[inline]
+pub fn dynamic_array_element_slot(base_slot: U256, index: U256, element_slots: U256) -> U256 {
+    dynamic_array_slot(base_slot).wrapping_add(index.wrapping_mul(element_slots))
+}
+
+/// The storage layout of a `bytes` or `string` value, as decoded from the
+/// word stored at its slot by [`bytes_layout`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BytesLayout {
+    /// The value is at most 31 bytes long, and is stored left-aligned in the
+    /// slot itself.
+    Short {
+        /// The length of the value, in bytes.
+        len: usize,
+    },
+    /// The value is at least 32 bytes long, and is stored in consecutive
+    /// slots starting at `data_slot`.
+    Long {
+        /// The length of the value, in bytes.
+        len: U256,
+        /// The first slot holding the value, `keccak256(base_slot)`.
+        data_slot: U256,
+    },
+}
+
+impl BytesLayout {
+    /// Returns the length of the value, in bytes.
+    #[inline]
+    pub fn len(&self) -> U256 {
+        match *self {
+            Self::Short { len } => U256::from(len),
+            Self::Long { len, .. } => len,
+        }
+    }
+
+    /// Returns whether the value is empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        matches!(self, Self::Short { len: 0 })
+    }
+}
+
+/// Decodes the layout of a `bytes` or `string` value stored at `base_slot`,
+/// given the `word` read from that slot.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{
+///     b256,
+///     storage::{bytes_layout, BytesLayout},
+///     U256,
+/// };
+///
+/// // "abc", stored inline: data followed by `len * 2` in the lowest byte
+/// let word = b256!("6162630000000000000000000000000000000000000000000000000000000006");
+/// assert_eq!(
+///     bytes_layout(U256::ZERO, word),
+///     BytesLayout::Short { len: 3 }
+/// );
+///
+/// // 64 bytes, stored out of line: `len * 2 + 1`
+/// let word = b256!("0000000000000000000000000000000000000000000000000000000000000081");
+/// assert_eq!(
+///     bytes_layout(U256::ZERO, word),
+///     BytesLayout::Long {
+///         len: U256::from(64),
+///         data_slot: alloy_primitives::storage::dynamic_array_slot(U256::ZERO)
+///     }
+/// );
+/// ```
+#[inline]
+pub fn bytes_layout(base_slot: U256, word: B256) -> BytesLayout {
+    let last = word[31];
+    if last & 1 == 0 {
+        BytesLayout::Short {
+            len: (last / 2) as usize,
+        }
+    } else {
+        BytesLayout::Long {
+            len: U256::from_be_bytes(word.0) >> 1,
+            data_slot: dynamic_array_slot(base_slot),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array_slots() {
+        let slot0: U256 =
+            b256!("290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563").into();
+        let slot1: U256 =
+            b256!("b10e2d527612073b26eecdfd717e6a320cf44b4afac2b0732d9fcbe2b7fa0cf6").into();
+        assert_eq!(dynamic_array_slot(U256::ZERO), slot0);
+        assert_eq!(dynamic_array_slot(U256::from(1)), slot1);
+        assert_eq!(
+            dynamic_array_element_slot(U256::ZERO, U256::from(3), U256::from(2)),
+            slot0 + U256::from(6)
+        );
+    }
+
+    #[test]
+    fn mapping_slots() {
+        let expected: U256 =
+            b256!("ad3228b676f7d3cd4284a5443f17f1962b36e491b30a40b2405849e597ba5fb5").into();
+        assert_eq!(mapping_slot(B256::ZERO, U256::ZERO), expected);
+        assert_eq!(mapping_slot_bytes(&[0; 32], U256::ZERO), expected);
+        assert_eq!(nested_mapping_slot([B256::ZERO], U256::ZERO), expected);
+        assert_eq!(nested_mapping_slot([], U256::from(7)), U256::from(7));
+        assert_eq!(
+            nested_mapping_slot([B256::ZERO, B256::ZERO], U256::ZERO),
+            mapping_slot(B256::ZERO, expected)
+        );
+    }
+
+    #[test]
+    fn bytes_layouts() {
+        assert_eq!(
+            bytes_layout(U256::ZERO, B256::ZERO),
+            BytesLayout::Short { len: 0 }
+        );
+        assert!(bytes_layout(U256::ZERO, B256::ZERO).is_empty());
+
+        let mut word = B256::repeat_byte(0x61);
+        word[31] = 62;
+        let layout = bytes_layout(U256::ZERO, word);
+        assert_eq!(layout, BytesLayout::Short { len: 31 });
+        assert_eq!(layout.len(), U256::from(31));
+
+        let word = B256::from(U256::from(32 * 2 + 1));
+        let layout = bytes_layout(U256::from(5), word);
+        assert_eq!(
+            layout,
+            BytesLayout::Long {
+                len: U256::from(32),
+                data_slot: dynamic_array_slot(U256::from(5))
+            }
+        );
+        assert!(!layout.is_empty());
+    }
+}
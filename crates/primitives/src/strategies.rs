@@ -0,0 +1,158 @@
+//! [`proptest`] strategies for primitive types.
+//!
+//! Every primitive type implements
+//! [`Arbitrary`](proptest::arbitrary::Arbitrary), so `any::<T>()` produces
+//! uniformly random values. The strategies in this module shape the generated
+//! values instead: they bias towards edge cases that uniform sampling almost
+//! never hits, such as the zero address, or constrain values to a range.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{strategies::*, U256};
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(to in address_strategy(), value in u256_in_range(U256::ZERO..U256::from(1000)))| {
+//!     prop_assert!(value < U256::from(1000));
+//!     let _ = to;
+//! });
+//! ```
+
+use crate::{Address, Bytes, FixedBytes, I256, U256};
+use core::ops::{Bound, RangeBounds};
+use proptest::{
+    arbitrary::any,
+    collection::{vec, SizeRange},
+    prop_oneof,
+    strategy::{Just, Strategy},
+};
+
+/// Returns a strategy for addresses that favors the zero address and the
+/// precompiles (`0x01` to `0x0a`) alongside uniformly random addresses.
+pub fn address_strategy() -> impl Strategy<Value = Address> {
+    prop_oneof![
+        1 => Just(Address::ZERO),
+        1 => (1u8..=10).prop_map(Address::with_last_byte),
+        8 => any::<Address>(),
+    ]
+}
+
+/// Returns a strategy for fixed-size byte arrays that favors all-zero and
+/// all-`0xff` values alongside uniformly random values.
+pub fn fixed_bytes_strategy<const N: usize>() -> impl Strategy<Value = FixedBytes<N>> {
+    prop_oneof![
+        1 => Just(FixedBytes::ZERO),
+        1 => Just(FixedBytes::repeat_byte(0xff)),
+        8 => any::<FixedBytes<N>>(),
+    ]
+}
+
+/// Returns a strategy for [`Bytes`] with a length in `size`.
+pub fn bytes_strategy(size: impl Into<SizeRange>) -> impl Strategy<Value = Bytes> {
+    vec(any::<u8>(), size).prop_map(Bytes::from)
+}
+
+/// Returns a strategy for [`U256`] values in `range`, favoring the bounds of
+/// the range.
+///
+/// # Panics
+///
+/// Panics if the range is empty.
+pub fn u256_in_range(range: impl RangeBounds<U256>) -> impl Strategy<Value = U256> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(U256::from(1)).expect("empty range"),
+        Bound::Unbounded => U256::ZERO,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end,
+        Bound::Excluded(&end) => end.checked_sub(U256::from(1)).expect("empty range"),
+        Bound::Unbounded => U256::MAX,
+    };
+    assert!(start <= end, "empty range");
+
+    // Width of the range minus one, so that the full range does not overflow.
+    let span = end - start;
+    prop_oneof![
+        1 => Just(start),
+        1 => Just(end),
+        8 => any::<U256>().prop_map(move |x| {
+            if span == U256::MAX {
+                x
+            } else {
+                start + x % (span + U256::from(1))
+            }
+        }),
+    ]
+}
+
+/// Returns a strategy for [`I256`] values that favors zero, `-1`, and the
+/// minimum and maximum values alongside uniformly random values.
+pub fn i256_strategy() -> impl Strategy<Value = I256> {
+    prop_oneof![
+        1 => Just(I256::ZERO),
+        1 => Just(I256::MINUS_ONE),
+        1 => Just(I256::MIN),
+        1 => Just(I256::MAX),
+        6 => any::<I256>(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bloom, Function, Log, Sign, B256, I8, U8};
+    use proptest::{prop_assert, proptest};
+
+    fn assert_arbitrary<T>()
+    where
+        T: for<'a> arbitrary::Arbitrary<'a> + proptest::arbitrary::Arbitrary,
+    {
+    }
+
+    #[test]
+    fn arbitrary_impls() {
+        assert_arbitrary::<Address>();
+        assert_arbitrary::<B256>();
+        assert_arbitrary::<Bloom>();
+        assert_arbitrary::<Bytes>();
+        assert_arbitrary::<FixedBytes<0>>();
+        assert_arbitrary::<Function>();
+        assert_arbitrary::<I8>();
+        assert_arbitrary::<I256>();
+        assert_arbitrary::<Log>();
+        assert_arbitrary::<Sign>();
+        assert_arbitrary::<U8>();
+        assert_arbitrary::<U256>();
+    }
+
+    proptest! {
+        #[test]
+        fn ranges(
+            a in u256_in_range(U256::from(10)..U256::from(20)),
+            b in u256_in_range(U256::from(10)..=U256::from(10)),
+            c in u256_in_range(..U256::from(3)),
+            d in u256_in_range(U256::MAX - U256::from(1)..),
+            _e in u256_in_range(..),
+        ) {
+            prop_assert!((U256::from(10)..U256::from(20)).contains(&a));
+            prop_assert!(b == U256::from(10));
+            prop_assert!(c < U256::from(3));
+            prop_assert!(d >= U256::MAX - U256::from(1));
+        }
+
+        #[test]
+        fn bytes_len(bytes in bytes_strategy(4..8)) {
+            prop_assert!((4..8).contains(&bytes.len()));
+        }
+
+        #[test]
+        fn shaped(_a in address_strategy(), _b in fixed_bytes_strategy::<32>(), _i in i256_strategy()) {}
+    }
+
+    #[test]
+    #[should_panic = "empty range"]
+    fn empty_range() {
+        let _ = u256_in_range(U256::from(1)..U256::from(1));
+    }
+}
@@ -0,0 +1,88 @@
+//! SSZ tree hashing for [`U256`], which cannot implement [`TreeHash`].
+//!
+//! [`Uint`](ruint::Uint) is defined in `ruint`, so this crate cannot implement
+//! [`TreeHash`] for it. These functions provide the SSZ `uint256` tree hash
+//! instead, for use in hand-written [`TreeHash`] implementations of types that
+//! contain a [`U256`]. All other primitive types implement [`TreeHash`]
+//! directly.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{uint_tree_hash, Address, U256};
+//! use tree_hash::{merkle_root, Hash256, PackedEncoding, TreeHash, TreeHashType};
+//!
+//! struct Deposit {
+//!     to: Address,
+//!     amount: U256,
+//! }
+//!
+//! impl TreeHash for Deposit {
+//!     fn tree_hash_type() -> TreeHashType {
+//!         TreeHashType::Container
+//!     }
+//!
+//!     fn tree_hash_packed_encoding(&self) -> PackedEncoding {
+//!         unreachable!("containers are never packed")
+//!     }
+//!
+//!     fn tree_hash_packing_factor() -> usize {
+//!         unreachable!("containers are never packed")
+//!     }
+//!
+//!     fn tree_hash_root(&self) -> Hash256 {
+//!         let mut leaves = Vec::with_capacity(64);
+//!         leaves.extend_from_slice(self.to.tree_hash_root().as_bytes());
+//!         leaves.extend_from_slice(uint_tree_hash::tree_hash_root(&self.amount).as_bytes());
+//!         merkle_root(&leaves, 0)
+//!     }
+//! }
+//! ```
+//!
+//! [`TreeHash`]: tree_hash::TreeHash
+//! [`U256`]: crate::U256
+
+use crate::U256;
+use tree_hash::{Hash256, PackedEncoding, TreeHashType};
+
+/// Returns the [`TreeHashType`] of a `uint256`, which is a basic type.
+#[inline]
+pub const fn tree_hash_type() -> TreeHashType {
+    TreeHashType::Basic
+}
+
+/// Returns the packed encoding of a `uint256`: its 32 little-endian bytes.
+#[inline]
+pub fn tree_hash_packed_encoding(value: &U256) -> PackedEncoding {
+    PackedEncoding::from_slice(&value.to_le_bytes::<32>())
+}
+
+/// Returns the number of `uint256` values packed into a chunk, which is 1.
+#[inline]
+pub const fn tree_hash_packing_factor() -> usize {
+    1
+}
+
+/// Returns the tree hash root of a `uint256`: its 32 little-endian bytes.
+#[inline]
+pub fn tree_hash_root(value: &U256) -> Hash256 {
+    Hash256::from(value.to_le_bytes::<32>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tree_hash() {
+        let value = U256::from(0x0102);
+        let mut expected = [0u8; 32];
+        expected[..2].copy_from_slice(&[0x02, 0x01]);
+        assert_eq!(tree_hash_root(&value).as_bytes(), &expected);
+        assert_eq!(&tree_hash_packed_encoding(&value)[..], &expected);
+
+        assert_eq!(tree_hash_root(&U256::ZERO), Hash256::zero());
+        assert_eq!(tree_hash_root(&U256::MAX).as_bytes(), &[0xff; 32]);
+        assert_eq!(tree_hash_root(&(U256::from(1) << 255)).as_bytes()[31], 0x80);
+    }
+}
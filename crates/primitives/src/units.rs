@@ -0,0 +1,258 @@
+//! Compile-time parsing of numeric literals with Ethereum units.
+
+use crate::U256;
+
+/// Converts a numeric literal, optionally followed by an Ethereum unit, into a
+/// [`U256`] at compile time.
+///
+/// Accepts:
+/// - integer literals, with optional underscores and `0x`, `0o` or `0b`
+///   prefixes: `1_000_000`, `0xff`;
+/// - decimal and scientific notation: `1.5`, `1e18`, `2.5e-3`;
+/// - a trailing unit, one of `wei`, `kwei`, `mwei`, `gwei`, `szabo`, `finney`
+///   or `ether`: `5 gwei`, `2.5 ether`, `1e-9 ether`.
+///
+/// The resulting value must be a whole number of wei that fits in a
+/// [`U256`], otherwise compilation fails.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{u256, U256};
+///
+/// const ONE_ETHER: U256 = u256!(1 ether);
+/// assert_eq!(ONE_ETHER, U256::from(10).pow(U256::from(18)));
+/// assert_eq!(u256!(1e18), ONE_ETHER);
+/// assert_eq!(u256!(1_000_000_000_000_000_000), ONE_ETHER);
+/// assert_eq!(u256!(1000 finney), ONE_ETHER);
+///
+/// assert_eq!(u256!(2.5 ether), U256::from(2_500_000_000_000_000_000u128));
+/// assert_eq!(u256!(5 gwei), U256::from(5_000_000_000u64));
+/// assert_eq!(u256!(0xff), U256::from(255));
+/// ```
+///
+/// Values that are not a whole number of wei are rejected at compile time:
+///
+/// ```compile_fail
+/// # use alloy_primitives::{u256, U256};
+/// const HALF_WEI: U256 = u256!(0.5 wei);
+/// ```
+#[macro_export]
+macro_rules! u256 {
+    ($value:literal) => {{
+        const VALUE: $crate::U256 = $crate::private::parse_u256(stringify!($value), "wei");
+        VALUE
+    }};
+
+    ($value:literal $unit:ident) => {{
+        const VALUE: $crate::U256 =
+            $crate::private::parse_u256(stringify!($value), stringify!($unit));
+        VALUE
+    }};
+}
+
+/// Parses a stringified numeric literal with a unit. Not public API.
+///
+/// Panics on invalid input, which results in a compilation error when called
+/// in a const context.
+#[doc(hidden)]
+pub const fn parse_u256(s: &str, unit: &str) -> U256 {
+    let s = s.as_bytes();
+    let decimals = unit_decimals(unit.as_bytes());
+
+    let (radix, start) = match s {
+        [b'0', b'x' | b'X', ..] => (16, 2),
+        [b'0', b'o' | b'O', ..] => (8, 2),
+        [b'0', b'b' | b'B', ..] => (2, 2),
+        _ => (10, 0),
+    };
+
+    let mut limbs = [0u64; 4];
+    let mut any_digits = false;
+    let mut fraction_digits = 0i64;
+    let mut in_fraction = false;
+    let mut i = start;
+    while i < s.len() {
+        let c = s[i];
+        if c == b'_' {
+            i += 1;
+            continue
+        }
+        if radix == 10 && c == b'.' && !in_fraction {
+            in_fraction = true;
+            i += 1;
+            continue
+        }
+        if radix == 10 && (c == b'e' || c == b'E') {
+            break
+        }
+
+        let digit = match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            b'A'..=b'F' => c - b'A' + 10,
+            _ => panic!("invalid character in numeric literal"),
+        };
+        if digit as u64 >= radix {
+            panic!("invalid digit for the literal's radix")
+        }
+        limbs = mul_add(limbs, radix, digit as u64);
+        any_digits = true;
+        if in_fraction {
+            fraction_digits += 1;
+        }
+        i += 1;
+    }
+    if !any_digits {
+        panic!("numeric literal has no digits")
+    }
+
+    let mut exponent = 0i64;
+    if i < s.len() {
+        // skip `e`
+        i += 1;
+        let negative = i < s.len() && s[i] == b'-';
+        if i < s.len() && (s[i] == b'-' || s[i] == b'+') {
+            i += 1;
+        }
+        if i == s.len() {
+            panic!("missing exponent in numeric literal")
+        }
+        while i < s.len() {
+            let c = s[i];
+            match c {
+                b'0'..=b'9' => exponent = exponent * 10 + (c - b'0') as i64,
+                b'_' => {}
+                _ => panic!("invalid character in exponent"),
+            }
+            if exponent > 1000 {
+                panic!("exponent is too large")
+            }
+            i += 1;
+        }
+        if negative {
+            exponent = -exponent;
+        }
+    }
+
+    let mut scale = exponent + decimals - fraction_digits;
+    while scale > 0 {
+        limbs = mul_add(limbs, 10, 0);
+        scale -= 1;
+    }
+    while scale < 0 {
+        limbs = div_exact_10(limbs);
+        scale += 1;
+    }
+    U256::from_limbs(limbs)
+}
+
+const fn unit_decimals(unit: &[u8]) -> i64 {
+    match unit {
+        b"wei" => 0,
+        b"kwei" => 3,
+        b"mwei" => 6,
+        b"gwei" => 9,
+        b"szabo" => 12,
+        b"finney" => 15,
+        b"ether" => 18,
+        _ => panic!("unknown unit; expected one of wei, kwei, mwei, gwei, szabo, finney, ether"),
+    }
+}
+
+/// `limbs * mul + add`, panicking on overflow.
+const fn mul_add(mut limbs: [u64; 4], mul: u64, add: u64) -> [u64; 4] {
+    let mut carry = add as u128;
+    let mut i = 0;
+    while i < 4 {
+        let x = limbs[i] as u128 * mul as u128 + carry;
+        limbs[i] = x as u64;
+        carry = x >> 64;
+        i += 1;
+    }
+    if carry != 0 {
+        panic!("numeric literal does not fit in a U256")
+    }
+    limbs
+}
+
+/// `limbs / 10`, panicking if there is a remainder.
+const fn div_exact_10(mut limbs: [u64; 4]) -> [u64; 4] {
+    let mut rem = 0u128;
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
+        let x = (rem << 64) | limbs[i] as u128;
+        limbs[i] = (x / 10) as u64;
+        rem = x % 10;
+    }
+    if rem != 0 {
+        panic!("numeric literal is not a whole number of wei")
+    }
+    limbs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literals() {
+        assert_eq!(u256!(0), U256::ZERO);
+        assert_eq!(u256!(1_000_000), U256::from(1_000_000));
+        assert_eq!(u256!(0xdead_beef), U256::from(0xdead_beef_u64));
+        assert_eq!(u256!(0o777), U256::from(0o777));
+        assert_eq!(u256!(0b1010), U256::from(10));
+        assert_eq!(u256!(1e18), U256::from(10u64.pow(18)));
+        assert_eq!(u256!(1E+3), U256::from(1000));
+        assert_eq!(u256!(1.5e3), U256::from(1500));
+        assert_eq!(u256!(25e-1 gwei), U256::from(2_500_000_000u64));
+        assert_eq!(u256!(1_000.000), U256::from(1000));
+        assert_eq!(
+            u256!(115792089237316195423570985008687907853269984665640564039457584007913129639935),
+            U256::MAX
+        );
+        assert_eq!(
+            u256!(0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff),
+            U256::MAX
+        );
+    }
+
+    #[test]
+    fn units() {
+        assert_eq!(u256!(1 wei), U256::from(1));
+        assert_eq!(u256!(1 kwei), U256::from(1_000));
+        assert_eq!(u256!(1 mwei), U256::from(1_000_000));
+        assert_eq!(u256!(5 gwei), U256::from(5_000_000_000u64));
+        assert_eq!(u256!(1 szabo), U256::from(10u64.pow(12)));
+        assert_eq!(u256!(1 finney), U256::from(10u64.pow(15)));
+        assert_eq!(u256!(2.5 ether), U256::from(25 * 10u64.pow(17)));
+        assert_eq!(u256!(1e-9 ether), u256!(1 gwei));
+        assert_eq!(u256!(0.000000001 ether), u256!(1 gwei));
+        assert_eq!(u256!(1e6 ether), U256::from(10u128.pow(24)));
+    }
+
+    #[test]
+    #[should_panic = "not a whole number of wei"]
+    fn fractional_wei() {
+        parse_u256("1.5", "wei");
+    }
+
+    #[test]
+    #[should_panic = "does not fit in a U256"]
+    fn overflow() {
+        parse_u256("1e78", "wei");
+    }
+
+    #[test]
+    #[should_panic = "unknown unit"]
+    fn unknown_unit() {
+        parse_u256("1", "eth");
+    }
+
+    #[test]
+    #[should_panic = "invalid character"]
+    fn suffix() {
+        parse_u256("1u64", "wei");
+    }
+}
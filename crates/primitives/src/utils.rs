@@ -1,5 +1,7 @@
 use crate::{bits::FixedBytes, B256};
 use alloc::{string::ToString, vec::Vec};
+use core::{fmt, str};
+
 /// The prefix used for hashing messages according to EIP-191.
 const EIP191_PREFIX: &str = "\x19Ethereum Signed Message:\n";
 
@@ -77,10 +79,152 @@ pub fn keccak256<T: AsRef<[u8]>>(bytes: T) -> FixedBytes<32> {
     keccak256(bytes.as_ref())
 }
 
+/// Hashes each of the inputs with [`keccak256`].
+///
+/// Unlike mapping [`keccak256`] over the inputs, this initializes the hasher
+/// once and copies its initial state for each input, and allocates the output
+/// only once when the number of inputs is known.
+///
+/// See `par_keccak256_batch` for a parallel version, with the `rayon` feature.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{keccak256, keccak256_batch};
+///
+/// let hashes = keccak256_batch(["a", "b"]);
+/// assert_eq!(hashes, [keccak256("a"), keccak256("b")]);
+/// ```
+pub fn keccak256_batch<I>(inputs: I) -> Vec<B256>
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    let inputs = inputs.into_iter();
+    let mut hashes = Vec::with_capacity(inputs.size_hint().0);
+    cfg_if::cfg_if! {
+        if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak")))] {
+            // the host keeps no hasher state between calls
+            hashes.extend(inputs.map(keccak256));
+        } else {
+            use tiny_keccak::{Hasher, Keccak};
+
+            let initial = Keccak::v256();
+            hashes.extend(inputs.map(|input| {
+                let mut hasher = initial.clone();
+                hasher.update(input.as_ref());
+                let mut output = B256::ZERO;
+                hasher.finalize(&mut output.0);
+                output
+            }));
+        }
+    }
+    hashes
+}
+
+/// Hashes each of the inputs with [`keccak256`] in parallel, using [`rayon`].
+///
+/// The inputs are split into chunks, each of which is hashed on a single
+/// thread with [`keccak256_batch`]. Short batches are hashed on the current
+/// thread, as the cost of spreading them across threads would exceed that of
+/// hashing them.
+#[cfg(feature = "rayon")]
+pub fn par_keccak256_batch<T: AsRef<[u8]> + Sync>(inputs: &[T]) -> Vec<B256> {
+    use rayon::prelude::*;
+
+    if inputs.len() < PAR_KECCAK_MIN_LEN {
+        return keccak256_batch(inputs)
+    }
+    inputs
+        .par_chunks(PAR_KECCAK_MIN_LEN)
+        .flat_map_iter(keccak256_batch)
+        .collect()
+}
+
+/// The minimum number of inputs to hash them in parallel, and the number of
+/// inputs hashed by each parallel task.
+#[cfg(feature = "rayon")]
+const PAR_KECCAK_MIN_LEN: usize = 1 << 10;
+
+/// A [`Display`](fmt::Display) implementation backed by a closure.
+pub(crate) struct DisplayFn<F>(pub(crate) F);
+
+impl<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result> fmt::Display for DisplayFn<F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+/// Writes `bytes` as hex to the formatter without allocating.
+pub(crate) fn fmt_hex(
+    f: &mut fmt::Formatter<'_>,
+    bytes: &[u8],
+    upper: bool,
+    prefix: bool,
+) -> fmt::Result {
+    const CHUNK: usize = 64;
+
+    if prefix {
+        f.write_str("0x")?;
+    }
+    let mut buf = [0u8; CHUNK * 2];
+    for chunk in bytes.chunks(CHUNK) {
+        let out = &mut buf[..chunk.len() * 2];
+        hex::encode_to_slice(chunk, out).unwrap();
+        if upper {
+            out.make_ascii_uppercase();
+        }
+        // SAFETY: hex is always valid ASCII
+        f.write_str(unsafe { str::from_utf8_unchecked(out) })?;
+    }
+    Ok(())
+}
+
+/// Writes `bytes` as `0x1234…abcd`, keeping the first and last two bytes.
+/// Inputs of 4 bytes or less are written in full.
+pub(crate) fn fmt_hex_short(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    let len = bytes.len();
+    if len <= 4 {
+        return fmt_hex(f, bytes, false, true)
+    }
+    fmt_hex(f, &bytes[..2], false, true)?;
+    f.write_str("…")?;
+    fmt_hex(f, &bytes[len - 2..], false, false)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn keccak256_batches() {
+        let inputs: Vec<Vec<u8>> = (0..2000u32)
+            .map(|i| i.to_be_bytes().repeat(i as usize % 50))
+            .collect();
+        let expected: Vec<_> = inputs.iter().map(keccak256).collect();
+        assert_eq!(keccak256_batch(&inputs), expected);
+        assert!(keccak256_batch(Vec::<&[u8]>::new()).is_empty());
+        #[cfg(feature = "rayon")]
+        {
+            assert_eq!(par_keccak256_batch(&inputs), expected);
+            assert_eq!(par_keccak256_batch(&inputs[..10]), expected[..10]);
+        }
+    }
+
+    #[test]
+    fn hex_formatting() {
+        let bytes: Vec<u8> = (0..=200).collect();
+        let upper = DisplayFn(|f: &mut fmt::Formatter<'_>| fmt_hex(f, &bytes, true, true));
+        assert_eq!(upper.to_string(), hex::encode_upper_prefixed(&bytes));
+        let lower = DisplayFn(|f: &mut fmt::Formatter<'_>| fmt_hex(f, &bytes, false, false));
+        assert_eq!(lower.to_string(), hex::encode(&bytes));
+        let short = DisplayFn(|f: &mut fmt::Formatter<'_>| fmt_hex_short(f, &bytes));
+        assert_eq!(short.to_string(), "0x0001…c7c8");
+        let short = DisplayFn(|f: &mut fmt::Formatter<'_>| fmt_hex_short(f, &bytes[..4]));
+        assert_eq!(short.to_string(), "0x00010203");
+    }
+
     // test vector taken from:
     // https://web3js.readthedocs.io/en/v1.2.2/web3-eth-accounts.html#hashmessage
     #[test]
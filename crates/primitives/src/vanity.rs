@@ -0,0 +1,316 @@
+//! Multi-threaded search for vanity [`CREATE2`](Address::create2) addresses.
+//!
+//! A [`Create2Search`] tries salts for a [`Create2Deployer`] on several threads
+//! until the resulting address matches a predicate, such as an
+//! [`AddressPattern`] of prefix, suffix or masked nibbles.
+//!
+//! The salts tried are the search's base salt with its last 8 bytes replaced
+//! by a big-endian counter, so that the first 24 bytes can be fixed, as
+//! required by some deployers which check that the salt starts with the
+//! caller's address.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{
+//!     address, b256,
+//!     vanity::{AddressPattern, Create2Search},
+//!     Create2Deployer,
+//! };
+//!
+//! let deployer = Create2Deployer::new(
+//!     address!("4e59b44847b379578588920cA78FbF26c0B4956C"),
+//!     b256!("96e8ac4277198ff8b6f785478aa9a39f403cb768dd02cbee326c3e7da348845f"),
+//! );
+//! let pattern = AddressPattern::new().with_prefix("00")?.with_suffix("f")?;
+//!
+//! let (salt, address) = Create2Search::new(deployer)
+//!     .find(|address| pattern.is_match(address))
+//!     .unwrap();
+//! assert!(address.to_string().starts_with("0x00"));
+//! assert_eq!(deployer.address(salt), address);
+//! # Ok::<_, alloy_primitives::hex::FromHexError>(())
+//! ```
+
+use crate::{bits::CREATE2_SALT, keccak256, Address, Create2Deployer, B256};
+use core::num::NonZeroUsize;
+use hex::FromHexError;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+/// A pattern of hex nibbles which an address must match.
+///
+/// The default pattern matches any address.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AddressPattern {
+    mask: Address,
+    value: Address,
+}
+
+impl AddressPattern {
+    /// Creates a new pattern which matches any address.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            mask: Address::ZERO,
+            value: Address::ZERO,
+        }
+    }
+
+    /// Creates a new pattern which matches the addresses whose bits set in
+    /// `mask` are equal to those of `value`.
+    #[inline]
+    pub fn masked(mask: Address, value: Address) -> Self {
+        Self {
+            mask,
+            value: value & mask,
+        }
+    }
+
+    /// Requires the address to start with the given hex nibbles, with an
+    /// optional `0x` prefix. Letters are matched case-insensitively.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `prefix` contains a non-hex character, or if it is
+    /// longer than an address.
+    pub fn with_prefix(self, prefix: &str) -> Result<Self, FromHexError> {
+        self.with_nibbles(prefix, 0)
+    }
+
+    /// Requires the address to end with the given hex nibbles, with an
+    /// optional `0x` prefix. Letters are matched case-insensitively.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `suffix` contains a non-hex character, or if it is
+    /// longer than an address.
+    pub fn with_suffix(self, suffix: &str) -> Result<Self, FromHexError> {
+        let len = suffix.strip_prefix("0x").unwrap_or(suffix).len();
+        self.with_nibbles(
+            suffix,
+            NIBBLES
+                .checked_sub(len)
+                .ok_or(FromHexError::InvalidStringLength)?,
+        )
+    }
+
+    /// Returns `true` if the address matches this pattern.
+    #[inline]
+    pub fn is_match(&self, address: &Address) -> bool {
+        address
+            .iter()
+            .zip(self.mask.iter().zip(self.value.iter()))
+            .all(|(a, (m, v))| a & m == *v)
+    }
+
+    fn with_nibbles(mut self, s: &str, start: usize) -> Result<Self, FromHexError> {
+        let (s, offset) = match s.strip_prefix("0x") {
+            Some(s) => (s, 2),
+            None => (s, 0),
+        };
+        if start + s.len() > NIBBLES {
+            return Err(FromHexError::InvalidStringLength)
+        }
+        for (i, c) in s.chars().enumerate() {
+            let nibble = c.to_digit(16).ok_or(FromHexError::InvalidHexCharacter {
+                c,
+                index: offset + i,
+            })? as u8;
+            let i = start + i;
+            let shift = if i % 2 == 0 { 4 } else { 0 };
+            self.mask[i / 2] |= 0xf << shift;
+            self.value[i / 2] = (self.value[i / 2] & !(0xf << shift)) | nibble << shift;
+        }
+        Ok(self)
+    }
+}
+
+/// The number of hex nibbles in an address.
+const NIBBLES: usize = 40;
+
+/// The range of the counter in the salts tried by a [`Create2Search`].
+const COUNTER: core::ops::Range<usize> = 24..32;
+
+/// Searches salts for a [`CREATE2`](Address::create2) address matching a
+/// predicate on several threads.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Clone, Copy, Debug)]
+pub struct Create2Search {
+    deployer: Create2Deployer,
+    salt: B256,
+    threads: Option<NonZeroUsize>,
+    max_attempts: Option<u64>,
+}
+
+impl Create2Search {
+    /// Creates a new search with a zero base salt, using all available threads
+    /// and no limit on the number of attempts.
+    #[inline]
+    pub const fn new(deployer: Create2Deployer) -> Self {
+        Self {
+            deployer,
+            salt: B256::ZERO,
+            threads: None,
+            max_attempts: None,
+        }
+    }
+
+    /// Sets the base salt, whose last 8 bytes are replaced by the counter.
+    #[inline]
+    pub const fn salt(mut self, salt: B256) -> Self {
+        self.salt = salt;
+        self
+    }
+
+    /// Sets the number of threads to search on. Defaults to
+    /// [`available_parallelism`](thread::available_parallelism).
+    #[inline]
+    pub const fn threads(mut self, threads: NonZeroUsize) -> Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    /// Sets the maximum number of salts to try, across all threads.
+    #[inline]
+    pub const fn max_attempts(mut self, max_attempts: u64) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Searches for a salt whose address matches `predicate`, returning the
+    /// first one found along with its address, or `None` if the maximum
+    /// number of attempts was reached.
+    ///
+    /// With more than one thread, the salt found is not necessarily the one
+    /// with the lowest counter.
+    pub fn find<F>(&self, predicate: F) -> Option<(B256, Address)>
+    where
+        F: Fn(&Address) -> bool + Sync,
+    {
+        let threads = self
+            .threads
+            .or_else(|| thread::available_parallelism().ok())
+            .map_or(1, NonZeroUsize::get) as u64;
+        let max_attempts = self.max_attempts.unwrap_or(u64::MAX);
+
+        let mut preimage = self
+            .deployer
+            .deployer
+            .create2_preimage(&self.deployer.init_code_hash);
+        preimage[CREATE2_SALT].copy_from_slice(self.salt.as_slice());
+
+        let found = AtomicBool::new(false);
+        let result = Mutex::new(None);
+        let search = |start: u64| {
+            let mut preimage = preimage;
+            let mut counter = start;
+            while counter < max_attempts && !found.load(Ordering::Relaxed) {
+                preimage[CREATE2_SALT][COUNTER].copy_from_slice(&counter.to_be_bytes());
+                let address = Address::from_word(keccak256(preimage));
+                if predicate(&address) {
+                    if !found.swap(true, Ordering::Relaxed) {
+                        let salt = B256::from_slice(&preimage[CREATE2_SALT]);
+                        *result.lock().unwrap() = Some((salt, address));
+                    }
+                    break
+                }
+                counter = match counter.checked_add(threads) {
+                    Some(counter) => counter,
+                    None => break,
+                };
+            }
+        };
+        thread::scope(|s| {
+            for start in 1..threads {
+                s.spawn(move || search(start));
+            }
+            search(0);
+        });
+        result.into_inner().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern() {
+        let address = address!("00c0FFEe254729296a45a3885639ac7E10F9d549");
+        assert!(AddressPattern::new().is_match(&address));
+        for prefix in [
+            "0",
+            "00c",
+            "0x00C0FFEE",
+            "00c0ffee254729296a45a3885639ac7e10f9d549",
+        ] {
+            let pattern = AddressPattern::new().with_prefix(prefix).unwrap();
+            assert!(pattern.is_match(&address), "{prefix}");
+        }
+        for suffix in ["9", "0xd549", "F9D549"] {
+            let pattern = AddressPattern::new().with_suffix(suffix).unwrap();
+            assert!(pattern.is_match(&address), "{suffix}");
+        }
+        let pattern = AddressPattern::new()
+            .with_prefix("00c0")
+            .unwrap()
+            .with_suffix("d549")
+            .unwrap();
+        assert!(pattern.is_match(&address));
+        assert!(!pattern.is_match(&Address::ZERO));
+        assert!(!AddressPattern::new()
+            .with_prefix("01")
+            .unwrap()
+            .is_match(&address));
+        assert!(!AddressPattern::new()
+            .with_suffix("8")
+            .unwrap()
+            .is_match(&address));
+
+        let masked =
+            AddressPattern::masked(Address::with_last_byte(0xf0), Address::repeat_byte(0x4f));
+        assert!(masked.is_match(&address));
+        assert!(!masked.is_match(&Address::ZERO));
+
+        assert_eq!(
+            AddressPattern::new().with_prefix("0x0g"),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 3 })
+        );
+        assert_eq!(
+            AddressPattern::new().with_suffix(&"0".repeat(41)),
+            Err(FromHexError::InvalidStringLength)
+        );
+        assert_eq!(
+            AddressPattern::new().with_prefix(&"0".repeat(41)),
+            Err(FromHexError::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn create2_search() {
+        let deployer = Create2Deployer::from_code(Address::repeat_byte(0x11), [0x60, 0x00]);
+        let pattern = AddressPattern::new().with_prefix("abc").unwrap();
+
+        for threads in [1, 4] {
+            let search = Create2Search::new(deployer)
+                .salt(B256::repeat_byte(0xff))
+                .threads(NonZeroUsize::new(threads).unwrap());
+            let (salt, address) = search.find(|a| pattern.is_match(a)).unwrap();
+            assert!(pattern.is_match(&address));
+            assert_eq!(deployer.address(salt), address);
+            assert_eq!(salt[..24], [0xff; 24]);
+        }
+
+        let search = Create2Search::new(deployer).max_attempts(10);
+        assert_eq!(search.find(|_| false), None);
+        let (salt, _) = search.find(|_| true).unwrap();
+        assert!(u64::from_be_bytes(salt[24..].try_into().unwrap()) < 10);
+    }
+}
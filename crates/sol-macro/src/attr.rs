@@ -51,6 +51,45 @@ pub fn docs_str(attrs: &[Attribute]) -> String {
     doc
 }
 
+/// Returns the text of the first `@<tag>` NatSpec tag in the doc comments, up
+/// to the next tag, if present. The text is empty if the tag has none.
+pub fn natspec_tag(attrs: &[Attribute], tag: &str) -> Option<String> {
+    let docs = docs_str(attrs);
+    let mut text: Option<String> = None;
+    for line in docs.lines() {
+        // strip the leading `*` of `/** */` comments
+        let line = line.trim_start().trim_start_matches('*').trim();
+        if let Some(text) = &mut text {
+            if line.starts_with('@') {
+                break
+            }
+            if !line.is_empty() {
+                if !text.is_empty() {
+                    text.push(' ');
+                }
+                text.push_str(line);
+            }
+        } else if let Some(rest) = line.strip_prefix('@').and_then(|l| l.strip_prefix(tag)) {
+            if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+                text = Some(rest.trim().to_string());
+            }
+        }
+    }
+    text
+}
+
+/// Returns a `#[deprecated]` attribute if the doc comments contain the given
+/// NatSpec deprecation tag, defaulting to `custom:deprecated`.
+pub fn mk_deprecated(attrs: &[Attribute], tag: Option<&LitStr>) -> Option<TokenStream> {
+    let tag = tag.map_or_else(|| "custom:deprecated".to_string(), LitStr::value);
+    let note = natspec_tag(attrs, tag.strip_prefix('@').unwrap_or(&tag))?;
+    Some(if note.is_empty() {
+        quote!(#[deprecated])
+    } else {
+        quote!(#[deprecated(note = #note)])
+    })
+}
+
 pub fn derives(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
     attrs.iter().filter(|a| is_derive(a))
 }
@@ -76,6 +115,10 @@ pub struct SolAttrs {
     pub all_derives: Option<bool>,
     pub extra_methods: Option<bool>,
     pub docs: Option<bool>,
+    pub arbitrary: Option<bool>,
+    pub struct_conversions: Option<bool>,
+    pub borrowed_events: Option<bool>,
+    pub selectors_only: Option<bool>,
 
     // TODO: Implement
     pub rename: Option<LitStr>,
@@ -84,6 +127,10 @@ pub struct SolAttrs {
 
     pub bytecode: Option<LitStr>,
     pub deployed_bytecode: Option<LitStr>,
+
+    pub debug_output: Option<LitStr>,
+    pub deprecation_tag: Option<LitStr>,
+    pub include_paths: Option<Vec<LitStr>>,
 }
 
 impl SolAttrs {
@@ -130,6 +177,15 @@ impl SolAttrs {
                 // `path = "<str>"`
                 let lit = || meta.value()?.parse::<LitStr>();
 
+                // `path = ["<str>", ...]`
+                let lits = || {
+                    let input = meta.value()?;
+                    let content;
+                    syn::bracketed!(content in input);
+                    let lits = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+                    Ok::<_, Error>(lits.into_iter().collect())
+                };
+
                 // `path = "0x<hex>"`
                 let bytes = || {
                     let lit = lit()?;
@@ -148,12 +204,20 @@ impl SolAttrs {
                     all_derives => bool()?,
                     extra_methods => bool()?,
                     docs => bool()?,
+                    arbitrary => bool()?,
+                    struct_conversions => bool()?,
+                    borrowed_events => bool()?,
+                    selectors_only => bool()?,
 
                     rename => lit()?,
                     rename_all => CasingStyle::from_lit(&lit()?)?,
 
                     bytecode => bytes()?,
                     deployed_bytecode => bytes()?,
+
+                    debug_output => lit()?,
+                    deprecation_tag => lit()?,
+                    include_paths => lits()?,
                 };
                 Ok(())
             })?;
@@ -258,6 +322,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn natspec_tags() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[doc = " @notice Does things."]),
+            parse_quote!(#[doc = " @custom:deprecated Use"]),
+            parse_quote!(#[doc = " `other` instead."]),
+            parse_quote!(#[doc = " @param x The input."]),
+        ];
+        assert_eq!(
+            natspec_tag(&attrs, "custom:deprecated").as_deref(),
+            Some("Use `other` instead.")
+        );
+        assert_eq!(
+            natspec_tag(&attrs, "notice").as_deref(),
+            Some("Does things.")
+        );
+        assert_eq!(natspec_tag(&attrs, "custom:dep"), None);
+        assert_eq!(natspec_tag(&attrs, "dev"), None);
+
+        let block: Attribute = parse_quote!(#[doc = "\n * @custom:deprecated\n "]);
+        assert_eq!(
+            natspec_tag(&[block], "custom:deprecated").as_deref(),
+            Some("")
+        );
+    }
+
     fn run_test(
         attrs_s: &'static [&'static str],
         expected: std::result::Result<SolAttrs, &'static str>,
@@ -309,6 +399,22 @@ mod tests {
             #[sol(docs)] => Ok(sol_attrs! { docs: true }),
             #[sol(docs = true)] => Ok(sol_attrs! { docs: true }),
             #[sol(docs = false)] => Ok(sol_attrs! { docs: false }),
+
+            #[sol(arbitrary)] => Ok(sol_attrs! { arbitrary: true }),
+            #[sol(arbitrary = false)] => Ok(sol_attrs! { arbitrary: false }),
+            #[sol(arbitrary)] #[sol(arbitrary)] => Err(DUPLICATE_ERROR),
+
+            #[sol(struct_conversions)] => Ok(sol_attrs! { struct_conversions: true }),
+            #[sol(struct_conversions = false)] => Ok(sol_attrs! { struct_conversions: false }),
+            #[sol(struct_conversions)] #[sol(struct_conversions)] => Err(DUPLICATE_ERROR),
+
+            #[sol(borrowed_events)] => Ok(sol_attrs! { borrowed_events: true }),
+            #[sol(borrowed_events = false)] => Ok(sol_attrs! { borrowed_events: false }),
+            #[sol(borrowed_events)] #[sol(borrowed_events)] => Err(DUPLICATE_ERROR),
+
+            #[sol(selectors_only)] => Ok(sol_attrs! { selectors_only: true }),
+            #[sol(selectors_only = false)] => Ok(sol_attrs! { selectors_only: false }),
+            #[sol(selectors_only)] #[sol(selectors_only)] => Err(DUPLICATE_ERROR),
         }
 
         rename {
@@ -328,5 +434,23 @@ mod tests {
             #[sol(bytecode = "xyz")] => Err("expected hex literal"),
             #[sol(bytecode = "123")] => Err("expected even number of hex digits"),
         }
+
+        debug_output {
+            #[sol(debug_output = "expanded.rs")] => Ok(sol_attrs! { debug_output: parse_quote!("expanded.rs") }),
+            #[sol(debug_output)] => Err("expected `=`"),
+            #[sol(debug_output = true)] => Err("expected string literal"),
+        }
+
+        deprecation_tag {
+            #[sol(deprecation_tag = "custom:retired")] => Ok(sol_attrs! { deprecation_tag: parse_quote!("custom:retired") }),
+            #[sol(deprecation_tag)] => Err("expected `=`"),
+        }
+
+        include_paths {
+            #[sol(include_paths = [])] => Ok(sol_attrs! { include_paths: vec![] }),
+            #[sol(include_paths = ["lib", "node_modules",])] => Ok(sol_attrs! { include_paths: vec![parse_quote!("lib"), parse_quote!("node_modules")] }),
+            #[sol(include_paths = "lib")] => Err("expected square brackets"),
+            #[sol(include_paths = [lib])] => Err("expected string literal"),
+        }
     }
 }
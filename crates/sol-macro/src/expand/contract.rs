@@ -1,8 +1,11 @@
 //! [`ItemContract`] expansion.
 
 use super::{ty, ExpCtxt};
-use crate::{attr, utils::ExprArray};
-use ast::{Item, ItemContract, ItemError, ItemEvent, ItemFunction, SolIdent};
+use crate::{
+    attr,
+    utils::{self, ExprArray},
+};
+use ast::{Item, ItemContract, ItemError, ItemEvent, ItemFunction, SolIdent, Spanned};
 use heck::ToSnakeCase;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
@@ -38,6 +41,10 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
         .or(cx.attrs.extra_methods)
         .unwrap_or(false);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
+    let selectors_only = sol_attrs
+        .selectors_only
+        .or(cx.attrs.selectors_only)
+        .unwrap_or(false);
 
     let bytecode = sol_attrs.bytecode.map(|lit| {
         let name = Ident::new("BYTECODE", lit.span());
@@ -54,6 +61,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
         }
     });
 
+    check_selector_collisions(cx, body);
+
     let mut functions = Vec::with_capacity(contract.body.len());
     let mut errors = Vec::with_capacity(contract.body.len());
     let mut events = Vec::with_capacity(contract.body.len());
@@ -68,6 +77,25 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
             .cloned(),
     );
 
+    if selectors_only {
+        for item in body {
+            item_tokens.extend(super::selectors::expand(cx, item)?);
+        }
+        let mod_descr_doc = (docs && attr::docs_str(&mod_attrs).trim().is_empty())
+            .then(|| attr::mk_doc("Module containing a contract's selectors."));
+        return Ok(quote! {
+            #mod_descr_doc
+            #(#mod_attrs)*
+            #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+            pub mod #name {
+                #bytecode
+                #deployed_bytecode
+
+                #item_tokens
+            }
+        })
+    }
+
     for item in body {
         match item {
             Item::Function(function) if function.name.is_some() => functions.push(function),
@@ -116,7 +144,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
         #mod_descr_doc
         #(#mod_attrs)*
         #mod_iface_doc
-        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        #[allow(non_camel_case_types, non_snake_case, clippy::style, deprecated)]
         pub mod #name {
             use super::*;
 
@@ -133,6 +161,49 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
     Ok(tokens)
 }
 
+/// Emits an error for each pair of functions, or of errors, in the contract
+/// whose selectors collide, as the interface enums could not tell them apart.
+///
+/// Items with unresolved types are skipped, as they are reported when expanded.
+fn check_selector_collisions(cx: &ExpCtxt<'_>, body: &[Item]) {
+    let mut functions = Vec::new();
+    let mut errors = Vec::new();
+    for item in body {
+        match item {
+            Item::Function(function) if cx.is_resolved(&function.arguments) => {
+                if let Some(name) = &function.name {
+                    functions.push((cx.function_signature(function), name.span()));
+                }
+            }
+            Item::Error(error) if cx.is_resolved(&error.parameters) => {
+                errors.push((cx.error_signature(error), error.name.span()))
+            }
+            _ => {}
+        }
+    }
+
+    for (desc, items) in [("function", functions), ("error", errors)] {
+        let selectors: Vec<_> = items
+            .iter()
+            .map(|(signature, _)| utils::selector(signature).array)
+            .collect();
+        for (i, (a, a_span)) in items.iter().enumerate() {
+            for (j, (b, b_span)) in items.iter().enumerate().skip(i + 1) {
+                // same signatures are reported as duplicate overloads
+                if selectors[i] == selectors[j] && a != b {
+                    emit_error!(
+                        *b_span,
+                        "{desc} selector collision: `{b}` and `{a}` have the same selector `{}`",
+                        hex::encode_prefixed(selectors[i]);
+
+                        note = *a_span => "other declaration is here";
+                    );
+                }
+            }
+        }
+    }
+}
+
 // note that item impls generated here do not need to be wrapped in an anonymous
 // constant (`const _: () = { ... };`) because they are in one already
 
@@ -347,7 +418,52 @@ impl<'a> CallLikeExpander<'a> {
 
     fn expand_event(self, attrs: Vec<Attribute>, extra_methods: bool) -> TokenStream {
         // TODO: SolInterface for events
-        self.generate_enum(attrs, extra_methods)
+        let def = self.generate_enum(attrs, extra_methods);
+        let Self { name, variants, .. } = &self;
+        let name_s = name.to_string();
+        quote! {
+            #def
+
+            #[automatically_derived]
+            impl ::core::convert::TryFrom<(&[::alloy_sol_types::private::B256], &[u8])> for #name {
+                type Error = ::alloy_sol_types::Error;
+
+                /// Decodes a log of a non-anonymous event, based on its first
+                /// topic.
+                fn try_from(
+                    (topics, data): (&[::alloy_sol_types::private::B256], &[u8]),
+                ) -> ::alloy_sol_types::Result<Self> {
+                    let Some(&signature_hash) = topics.first() else {
+                        return Err(::alloy_sol_types::Error::TopicLengthMismatch {
+                            expected: 1,
+                            actual: 0,
+                        })
+                    };
+                    #(
+                        if !<#variants as ::alloy_sol_types::SolEvent>::ANONYMOUS
+                            && signature_hash == <#variants as ::alloy_sol_types::SolEvent>::SIGNATURE_HASH
+                        {
+                            return <#variants as ::alloy_sol_types::SolEvent>::decode_raw_log(topics, data, true)
+                                .map(Self::#variants)
+                        }
+                    )*
+                    Err(::alloy_sol_types::Error::UnknownEventSignature {
+                        name: #name_s,
+                        signature_hash,
+                    })
+                }
+            }
+
+            #[automatically_derived]
+            impl ::core::convert::TryFrom<&::alloy_sol_types::private::Log> for #name {
+                type Error = ::alloy_sol_types::Error;
+
+                #[inline]
+                fn try_from(log: &::alloy_sol_types::private::Log) -> ::alloy_sol_types::Result<Self> {
+                    Self::try_from((log.topics(), &log.data[..]))
+                }
+            }
+        }
     }
 
     fn generate_enum(&self, mut attrs: Vec<Attribute>, extra_methods: bool) -> TokenStream {
@@ -1,6 +1,6 @@
 //! [`ItemEnum`] expansion.
 
-use super::ExpCtxt;
+use super::{derive_debug, ExpCtxt};
 use crate::attr;
 use ast::{ItemEnum, Spanned};
 use proc_macro2::TokenStream;
@@ -70,6 +70,41 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
         quote! { expect("unreachable") }
     };
 
+    // only generate valid variants
+    let arbitrary = sol_attrs
+        .arbitrary
+        .or(cx.attrs.arbitrary)
+        .unwrap_or(false)
+        .then(|| {
+            derive_debug(&mut attrs);
+            quote! {
+                #[automatically_derived]
+                impl<'a> ::alloy_sol_types::private::arbitrary::Arbitrary<'a> for #name {
+                    fn arbitrary(
+                        u: &mut ::alloy_sol_types::private::arbitrary::Unstructured<'a>,
+                    ) -> ::alloy_sol_types::private::arbitrary::Result<Self> {
+                        let v = u.int_in_range(0..=#max)?;
+                        ::core::result::Result::Ok(Self::try_from(v).expect("unreachable"))
+                    }
+                }
+
+                #[automatically_derived]
+                impl ::alloy_sol_types::private::proptest::arbitrary::Arbitrary for #name {
+                    type Parameters = ();
+                    type Strategy = ::alloy_sol_types::private::proptest::strategy::BoxedStrategy<Self>;
+
+                    fn arbitrary_with((): ()) -> Self::Strategy {
+                        ::alloy_sol_types::private::proptest::strategy::Strategy::boxed(
+                            ::alloy_sol_types::private::proptest::strategy::Strategy::prop_map(
+                                0..=#max,
+                                |v: u8| Self::try_from(v).expect("unreachable"),
+                            ),
+                        )
+                    }
+                }
+            }
+        });
+
     let uint8 = quote!(::alloy_sol_types::sol_data::Uint<8>);
     let uint8_st = quote!(<#uint8 as ::alloy_sol_types::SolType>);
 
@@ -87,6 +122,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
 
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
+            #arbitrary
+
             #[automatically_derived]
             impl ::core::convert::From<#name> for u8 {
                 #[inline]
@@ -135,6 +172,11 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
                 fn stv_abi_encode_packed_to(&self, out: &mut ::alloy_sol_types::private::Vec<u8>) {
                     out.push(*self as u8);
                 }
+
+                #[inline]
+                fn stv_abi_encode_to(&self, out: &mut ::alloy_sol_types::private::Vec<u8>) {
+                    out.extend_from_slice(::alloy_sol_types::Word::with_last_byte(*self as u8).as_slice());
+                }
             }
 
             #[automatically_derived]
@@ -1,6 +1,9 @@
 //! [`ItemError`] expansion.
 
-use super::{expand_fields, expand_from_into_tuples, expand_tokenize, ExpCtxt};
+use super::{
+    check_field_attrs, expand_abi_encode, expand_arbitrary, expand_fields, expand_from_into_tuples,
+    expand_tokenize, ExpCtxt,
+};
 use crate::attr;
 use ast::ItemError;
 use proc_macro2::TokenStream;
@@ -26,12 +29,20 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
         ..
     } = error;
     cx.assert_resolved(params)?;
+    check_field_attrs(params.iter().flat_map(|p| &p.attrs))?;
+    cx.assert_no_cfg_structs(params.types())?;
 
     let (sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
     cx.derives(&mut attrs, params, true);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
+    let arbitrary = sol_attrs
+        .arbitrary
+        .or(cx.attrs.arbitrary)
+        .unwrap_or(false)
+        .then(|| expand_arbitrary(&mut attrs, &name.0, params));
 
     let tokenize_impl = expand_tokenize(params);
+    let (encoded_size_impl, encode_impl) = expand_abi_encode(params);
 
     let signature = cx.error_signature(error);
     let selector = crate::utils::selector(&signature);
@@ -58,6 +69,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
         const _: () = {
             #converts
 
+            #arbitrary
+
             #[automatically_derived]
             impl ::alloy_sol_types::SolError for #name {
                 type Parameters<'a> = UnderlyingSolTuple<'a>;
@@ -75,6 +88,19 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
                 fn tokenize(&self) -> Self::Token<'_> {
                     #tokenize_impl
                 }
+
+                #[inline]
+                fn abi_encoded_size(&self) -> usize {
+                    if let Some(size) = <Self::Parameters<'_> as ::alloy_sol_types::SolType>::ENCODED_SIZE {
+                        return size
+                    }
+                    #encoded_size_impl
+                }
+
+                #[inline]
+                fn abi_encode_raw(&self, out: &mut ::alloy_sol_types::private::Vec<u8>) {
+                    #encode_impl
+                }
             }
         };
     };
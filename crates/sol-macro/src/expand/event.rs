@@ -1,11 +1,14 @@
 //! [`ItemEvent`] expansion.
 
-use super::{anon_name, expand_event_tokenize, expand_tuple_types, expand_type, ty, ExpCtxt};
+use super::{
+    anon_name, check_field_attrs, expand_event_abi_encode, expand_event_tokenize,
+    expand_tuple_types, expand_type, ty, ExpCtxt,
+};
 use crate::attr;
 use ast::{EventParameter, ItemEvent, SolIdent, Spanned};
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
-use syn::Result;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{Attribute, Result};
 
 /// Expands an [`ItemEvent`]:
 ///
@@ -17,6 +20,12 @@ use syn::Result;
 /// impl SolEvent for #name {
 ///     ...
 /// }
+///
+/// impl TryFrom<&Log> for #name { ... }
+/// impl TryFrom<(&[B256], &[u8])> for #name { ... }
+///
+/// // with `#[sol(borrowed_events)]`
+/// pub struct #{name}Ref<'a> { ... }
 /// ```
 pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream> {
     let ItemEvent { attrs, .. } = event;
@@ -25,8 +34,15 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
     let (sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
     cx.derives(&mut attrs, &params, true);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
+    let deprecation_tag = sol_attrs
+        .deprecation_tag
+        .as_ref()
+        .or(cx.attrs.deprecation_tag.as_ref());
+    let deprecated = attr::mk_deprecated(&attrs, deprecation_tag);
 
     cx.assert_resolved(&params)?;
+    check_field_attrs(event.parameters.iter().flat_map(|p| &p.attrs))?;
+    cx.assert_no_cfg_structs(event.parameters.iter().map(|p| &p.ty))?;
     event.assert_valid()?;
 
     let name = cx.overloaded_name(event.into());
@@ -99,6 +115,14 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
         .map(|(i, p)| expand_event_topic_field(i, p, p.name.as_ref()));
 
     let tokenize_body_impl = expand_event_tokenize(&event.parameters);
+    let (encoded_size_impl, encode_data_impl) = expand_event_abi_encode(&event.parameters);
+
+    let borrowed = sol_attrs
+        .borrowed_events
+        .or(cx.attrs.borrowed_events)
+        .unwrap_or(false)
+        .then(|| expand_borrowed(event, &name, &attrs, docs, deprecated.as_ref()))
+        .flatten();
 
     let encode_topics_impl = encode_first_topic
         .into_iter()
@@ -116,12 +140,13 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
     let tokens = quote! {
         #(#attrs)*
         #doc
+        #deprecated
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         pub struct #name {
-            #(pub #fields,)*
+            #(#fields,)*
         }
 
-        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        #[allow(non_camel_case_types, non_snake_case, clippy::style, deprecated)]
         const _: () = {
             #[automatically_derived]
             impl ::alloy_sol_types::SolEvent for #name {
@@ -152,6 +177,19 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
                     #tokenize_body_impl
                 }
 
+                #[inline]
+                fn abi_encoded_size(&self) -> usize {
+                    if let Some(size) = <Self::DataTuple<'_> as ::alloy_sol_types::SolType>::ENCODED_SIZE {
+                        return size
+                    }
+                    #encoded_size_impl
+                }
+
+                #[inline]
+                fn encode_data_to(&self, out: &mut ::alloy_sol_types::private::Vec<u8>) {
+                    #encode_data_impl
+                }
+
                 #[inline]
                 fn topics(&self) -> <Self::TopicList as ::alloy_sol_types::SolType>::RustType {
                     #topics_impl
@@ -163,17 +201,174 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
                     out: &mut [::alloy_sol_types::abi::token::WordToken],
                 ) -> ::alloy_sol_types::Result<()> {
                     if out.len() < <Self::TopicList as ::alloy_sol_types::TopicList>::COUNT {
-                        return Err(::alloy_sol_types::Error::Overrun);
+                        return Err(::alloy_sol_types::Error::Overrun { offset: out.len() });
                     }
                     #(#encode_topics_impl)*
                     Ok(())
                 }
             }
+
+            #[automatically_derived]
+            impl ::core::convert::TryFrom<&::alloy_sol_types::private::Log> for #name {
+                type Error = ::alloy_sol_types::Error;
+
+                #[inline]
+                fn try_from(log: &::alloy_sol_types::private::Log) -> ::alloy_sol_types::Result<Self> {
+                    <Self as ::alloy_sol_types::SolEvent>::decode_raw_log(log.topics(), &log.data, true)
+                }
+            }
+
+            #[automatically_derived]
+            impl ::core::convert::TryFrom<(&[::alloy_sol_types::private::B256], &[u8])> for #name {
+                type Error = ::alloy_sol_types::Error;
+
+                #[inline]
+                fn try_from(
+                    (topics, data): (&[::alloy_sol_types::private::B256], &[u8]),
+                ) -> ::alloy_sol_types::Result<Self> {
+                    <Self as ::alloy_sol_types::SolEvent>::decode_raw_log(topics, data, true)
+                }
+            }
         };
+
+        #borrowed
     };
     Ok(tokens)
 }
 
+/// Expands the `#{name}Ref<'a>` struct of an event with
+/// `#[sol(borrowed_events)]`, if it has any non-indexed `string` or `bytes`
+/// parameters.
+fn expand_borrowed(
+    event: &ItemEvent,
+    name: &SolIdent,
+    attrs: &[Attribute],
+    docs: bool,
+    deprecated: Option<&TokenStream>,
+) -> Option<TokenStream> {
+    if !event
+        .non_indexed_params()
+        .any(|p| matches!(p.ty, ast::Type::String(_) | ast::Type::Bytes(_)))
+    {
+        return None
+    }
+
+    let ref_name = format_ident!("{}Ref", name);
+    let derives = attrs.iter().filter(|attr| attr.path().is_ident("derive"));
+
+    let mut fields = Vec::with_capacity(event.parameters.len());
+    let mut decode = Vec::with_capacity(event.parameters.len());
+    let mut into_owned = Vec::with_capacity(event.parameters.len());
+    let mut topic_i = !event.is_anonymous() as usize;
+    let mut data_i = 0usize;
+    for (i, p) in event.parameters.iter().enumerate() {
+        let field_name = anon_name((i, p.name.as_ref()));
+        let param_attrs = &p.attrs;
+        if p.is_indexed() {
+            fields.push(expand_event_topic_field(i, p, p.name.as_ref()));
+            let i = syn::Index::from(topic_i);
+            topic_i += 1;
+            decode.push(quote!(#field_name: topics.#i));
+            into_owned.push(quote!(#field_name: self.#field_name));
+            continue
+        }
+
+        let i = syn::Index::from(data_i);
+        data_i += 1;
+        let (ty, value, owned) = match p.ty {
+            ast::Type::String(span) => (
+                quote_spanned!(span=> ::alloy_sol_types::private::Cow<'a, str>),
+                quote!(::alloy_sol_types::private::String::from_utf8_lossy(data.#i.0)),
+                quote!(self.#field_name.into_owned()),
+            ),
+            ast::Type::Bytes(span) => (
+                quote_spanned!(span=> &'a [u8]),
+                quote!(data.#i.0),
+                quote!(self.#field_name.to_vec()),
+            ),
+            _ => {
+                let sol_ty = expand_type(&p.ty);
+                (
+                    ty::expand_rust_type(&p.ty),
+                    quote!(<#sol_ty as ::alloy_sol_types::SolType>::detokenize(data.#i)),
+                    quote!(self.#field_name),
+                )
+            }
+        };
+        fields.push(quote! {
+            #(#param_attrs)*
+            pub #field_name: #ty
+        });
+        decode.push(quote!(#field_name: #value));
+        into_owned.push(quote!(#field_name: #owned));
+    }
+
+    let doc = docs.then(|| {
+        attr::mk_doc(format!(
+            "Borrowed version of [`{name}`], whose non-indexed `string` and `bytes` parameters \
+             borrow from the log data."
+        ))
+    });
+    Some(quote! {
+        #(#derives)*
+        #doc
+        #deprecated
+        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        pub struct #ref_name<'a> {
+            #(#fields,)*
+        }
+
+        #[allow(non_camel_case_types, non_snake_case, clippy::style, deprecated)]
+        const _: () = {
+            #[automatically_derived]
+            impl<'a> #ref_name<'a> {
+                /// Decodes the event from the given log topics and data,
+                /// checking that they belong to this event.
+                ///
+                /// See [`SolEvent::decode_raw_log`](::alloy_sol_types::SolEvent::decode_raw_log)
+                /// for more details.
+                #[inline]
+                pub fn decode_raw_log(
+                    topics: &[::alloy_sol_types::private::B256],
+                    data: &'a [u8],
+                    validate: bool,
+                ) -> ::alloy_sol_types::Result<Self> {
+                    <#name as ::alloy_sol_types::SolEvent>::check_topics(topics)?;
+                    let topics = <#name as ::alloy_sol_types::SolEvent>::decode_topics(topics.iter().copied())?;
+                    let data = ::alloy_sol_types::abi::decode_sequence::<
+                        <#name as ::alloy_sol_types::SolEvent>::DataToken<'a>,
+                    >(data, validate)?;
+                    if validate {
+                        <<#name as ::alloy_sol_types::SolEvent>::DataTuple<'a> as ::alloy_sol_types::SolType>::type_check(&data)?;
+                    }
+                    Ok(Self {
+                        #(#decode,)*
+                    })
+                }
+
+                /// Decodes the event from the given log object, checking that
+                /// it belongs to this event.
+                #[inline]
+                pub fn decode_log(
+                    log: &'a ::alloy_sol_types::private::Log,
+                    validate: bool,
+                ) -> ::alloy_sol_types::Result<Self> {
+                    Self::decode_raw_log(log.topics(), &log.data, validate)
+                }
+
+                /// Converts this into the owned event, copying the borrowed
+                /// parameters.
+                #[inline]
+                pub fn into_owned(self) -> #name {
+                    #name {
+                        #(#into_owned,)*
+                    }
+                }
+            }
+        };
+    })
+}
+
 fn expand_event_topic_type(param: &EventParameter) -> TokenStream {
     assert!(param.is_indexed());
     if param.is_abi_dynamic() {
@@ -197,5 +392,9 @@ fn expand_event_topic_field(
     } else {
         ty::expand_rust_type(&param.ty)
     };
-    quote!(#name: #ty)
+    let attrs = &param.attrs;
+    quote! {
+        #(#attrs)*
+        pub #name: #ty
+    }
 }
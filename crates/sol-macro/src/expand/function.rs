@@ -1,6 +1,9 @@
 //! [`ItemFunction`] expansion.
 
-use super::{expand_fields, expand_from_into_tuples, expand_tokenize, expand_tuple_types, ExpCtxt};
+use super::{
+    check_field_attrs, expand_abi_encode, expand_arbitrary, expand_fields, expand_from_into_tuples,
+    expand_tokenize, expand_tuple_types, ExpCtxt,
+};
 use crate::attr;
 use ast::ItemFunction;
 use proc_macro2::TokenStream;
@@ -41,6 +44,13 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
     if !returns.is_empty() {
         cx.assert_resolved(returns)?;
     }
+    check_field_attrs(
+        arguments
+            .iter()
+            .chain(returns.iter())
+            .flat_map(|p| &p.attrs),
+    )?;
+    cx.assert_no_cfg_structs(arguments.types())?;
 
     let (sol_attrs, mut call_attrs) = crate::attr::SolAttrs::parse(attrs)?;
     let mut return_attrs = call_attrs.clone();
@@ -49,10 +59,25 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
         cx.derives(&mut return_attrs, returns, true);
     }
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
+    let deprecation_tag = sol_attrs
+        .deprecation_tag
+        .as_ref()
+        .or(cx.attrs.deprecation_tag.as_ref());
+    let deprecated = attr::mk_deprecated(attrs, deprecation_tag);
 
     let call_name = cx.call_name(function);
     let return_name = cx.return_name(function);
 
+    let arbitrary = sol_attrs
+        .arbitrary
+        .or(cx.attrs.arbitrary)
+        .unwrap_or(false)
+        .then(|| {
+            let call = expand_arbitrary(&mut call_attrs, &call_name, arguments);
+            let ret = expand_arbitrary(&mut return_attrs, &return_name, returns);
+            quote!(#call #ret)
+        });
+
     let call_fields = expand_fields(arguments);
     let return_fields = expand_fields(returns);
 
@@ -65,6 +90,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
     let signature = cx.function_signature(function);
     let selector = crate::utils::selector(&signature);
     let tokenize_impl = expand_tokenize(arguments);
+    let (encoded_size_impl, encode_impl) = expand_abi_encode(arguments);
 
     let call_doc = docs.then(|| {
         let selector = hex::encode_prefixed(selector.array);
@@ -82,6 +108,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
     let tokens = quote! {
         #(#call_attrs)*
         #call_doc
+        #deprecated
         #[allow(non_camel_case_types, non_snake_case)]
         #[derive(Clone)]
         pub struct #call_name {
@@ -96,11 +123,13 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
             #(#return_fields),*
         }
 
-        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        #[allow(non_camel_case_types, non_snake_case, clippy::style, deprecated)]
         const _: () = {
             { #converts }
             { #return_converts }
 
+            #arbitrary
+
             #[automatically_derived]
             impl ::alloy_sol_types::SolCall for #call_name {
                 type Arguments<'a> = #call_tuple;
@@ -122,6 +151,19 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
                     #tokenize_impl
                 }
 
+                #[inline]
+                fn abi_encoded_size(&self) -> usize {
+                    if let Some(size) = <Self::Arguments<'_> as ::alloy_sol_types::SolType>::ENCODED_SIZE {
+                        return size
+                    }
+                    #encoded_size_impl
+                }
+
+                #[inline]
+                fn abi_encode_raw(&self, out: &mut ::alloy_sol_types::private::Vec<u8>) {
+                    #encode_impl
+                }
+
                 fn abi_decode_returns(data: &[u8], validate: bool) -> ::alloy_sol_types::Result<Self::Return> {
                     <Self::ReturnTuple<'_> as ::alloy_sol_types::SolType>::abi_decode_sequence(data, validate).map(Into::into)
                 }
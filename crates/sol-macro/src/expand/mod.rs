@@ -6,13 +6,13 @@ use crate::{
     utils::{self, ExprArray},
 };
 use ast::{
-    EventParameter, File, Item, ItemError, ItemEvent, ItemFunction, Parameters, SolIdent, SolPath,
-    Spanned, Type, VariableDeclaration, Visit,
+    EventParameter, File, Item, ItemContract, ItemError, ItemEvent, ItemFunction, Parameters,
+    SolIdent, SolPath, Spanned, Type, VariableDeclaration, Visit,
 };
 use indexmap::IndexMap;
 use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use quote::{format_ident, quote, TokenStreamExt};
-use std::{borrow::Borrow, fmt::Write};
+use std::{borrow::Borrow, cell::RefCell, collections::HashSet, fmt::Write};
 use syn::{parse_quote, Attribute, Error, Result};
 
 mod ty;
@@ -23,6 +23,7 @@ mod r#enum;
 mod error;
 mod event;
 mod function;
+mod selectors;
 mod r#struct;
 mod udt;
 mod var_def;
@@ -31,8 +32,13 @@ mod var_def;
 const RESOLVE_LIMIT: usize = 32;
 
 /// The [`sol!`](crate::sol!) expansion implementation.
-pub fn expand(ast: File) -> Result<TokenStream> {
-    ExpCtxt::new(&ast).expand()
+///
+/// `imported_contracts` holds the names of the contracts declared in the files
+/// imported by `ast`, whose `import` directives have been resolved.
+pub fn expand(ast: File, imported_contracts: HashSet<String>) -> Result<TokenStream> {
+    let mut cx = ExpCtxt::new(&ast);
+    cx.imported_contracts = imported_contracts;
+    cx.expand()
 }
 
 /// The expansion context.
@@ -40,10 +46,15 @@ struct ExpCtxt<'ast> {
     all_items: Vec<&'ast Item>,
     custom_types: IndexMap<SolIdent, Type>,
 
-    /// `name => item`
-    overloaded_items: IndexMap<String, Vec<OverloadedItem<'ast>>>,
-    /// `signature => new_name`
-    overloads: IndexMap<String, String>,
+    /// `(scope, name) => item`
+    overloaded_items: IndexMap<(Option<String>, String), Vec<OverloadedItem<'ast>>>,
+    /// The overload scope of the contract currently being visited or expanded.
+    /// See [`overload_scope`](Self::overload_scope).
+    current_scope: RefCell<Option<String>>,
+    /// `(scope, signature) => new_name`
+    overloads: IndexMap<(Option<String>, String), String>,
+    /// The names of the contracts declared in imported files.
+    imported_contracts: HashSet<String>,
 
     attrs: SolAttrs,
     ast: &'ast File,
@@ -56,7 +67,9 @@ impl<'ast> ExpCtxt<'ast> {
             all_items: Vec::new(),
             custom_types: IndexMap::new(),
             overloaded_items: IndexMap::new(),
+            current_scope: RefCell::new(None),
             overloads: IndexMap::new(),
+            imported_contracts: HashSet::new(),
             attrs: SolAttrs::default(),
             ast,
         }
@@ -72,6 +85,10 @@ impl<'ast> ExpCtxt<'ast> {
 
         self.visit_file(self.ast);
 
+        if let Err(e) = self.check_item_attributes() {
+            tokens.extend(e.into_compile_error());
+        }
+
         if self.all_items.len() > 1 {
             self.resolve_custom_types();
             if self.mk_overloads_map().is_err() {
@@ -91,12 +108,21 @@ impl<'ast> ExpCtxt<'ast> {
             };
             tokens.extend(t);
         }
+        tokens.extend(r#struct::expand_conversions(&self));
         Ok(tokens)
     }
 
     fn expand_item(&self, item: &Item) -> Result<TokenStream> {
+        if self.attrs.selectors_only == Some(true) && !matches!(item, Item::Contract(_)) {
+            return selectors::expand(self, item)
+        }
         match item {
-            Item::Contract(contract) => contract::expand(self, contract),
+            Item::Contract(contract) => {
+                let prev = self.current_scope.replace(self.overload_scope(contract));
+                let res = contract::expand(self, contract);
+                self.current_scope.replace(prev);
+                res
+            }
             Item::Enum(enumm) => r#enum::expand(self, enumm),
             Item::Error(error) => error::expand(self, error),
             Item::Event(event) => event::expand(self, event),
@@ -121,6 +147,21 @@ impl<'ast> ExpCtxt<'ast> {
         utils::combine_errors(errs)
     }
 
+    /// Rejects `#[sol(...)]` attributes that only apply to the whole input.
+    fn check_item_attributes(&self) -> Result<()> {
+        let errs = self.all_items.iter().filter_map(|item| {
+            // other errors are reported when the item is expanded
+            let (attrs, _) = SolAttrs::parse(item.attrs()?).ok()?;
+            attrs.debug_output.map(|path| {
+                Error::new(
+                    path.span(),
+                    "`debug_output` is only allowed as an inner attribute",
+                )
+            })
+        });
+        utils::combine_errors(errs)
+    }
+
     fn mk_types_map(&mut self) {
         let mut map = std::mem::take(&mut self.custom_types);
         map.reserve(self.all_items.len());
@@ -179,7 +220,8 @@ impl<'ast> ExpCtxt<'ast> {
 
         let mut failed = false;
 
-        for functions in self.overloaded_items.values().filter(|fs| fs.len() >= 2) {
+        for ((scope, _), functions) in self.overloaded_items.iter().filter(|(_, fs)| fs.len() >= 2)
+        {
             // check for same parameters
             for (i, &a) in functions.iter().enumerate() {
                 for &b in functions.iter().skip(i + 1) {
@@ -213,7 +255,7 @@ impl<'ast> ExpCtxt<'ast> {
                     )
                 }
 
-                overloads_map.insert(item.signature(self), new_name);
+                overloads_map.insert((scope.clone(), item.signature(self)), new_name);
             }
         }
 
@@ -232,10 +274,16 @@ impl<'ast> Visit<'ast> for ExpCtxt<'ast> {
         ast::visit::visit_item(self, item);
     }
 
+    fn visit_item_contract(&mut self, contract: &'ast ItemContract) {
+        let prev = self.current_scope.replace(self.overload_scope(contract));
+        ast::visit::visit_item_contract(self, contract);
+        self.current_scope.replace(prev);
+    }
+
     fn visit_item_function(&mut self, function: &'ast ItemFunction) {
         if let Some(name) = &function.name {
             self.overloaded_items
-                .entry(name.as_string())
+                .entry((self.current_scope.borrow().clone(), name.as_string()))
                 .or_default()
                 .push(OverloadedItem::Function(function));
         }
@@ -244,7 +292,7 @@ impl<'ast> Visit<'ast> for ExpCtxt<'ast> {
 
     fn visit_item_event(&mut self, event: &'ast ItemEvent) {
         self.overloaded_items
-            .entry(event.name.as_string())
+            .entry((self.current_scope.borrow().clone(), event.name.as_string()))
             .or_default()
             .push(OverloadedItem::Event(event));
         ast::visit::visit_item_event(self, event);
@@ -346,13 +394,25 @@ impl<'ast> ExpCtxt<'ast> {
     /// Use `.into()` to convert from `&ItemFunction` or `&ItemEvent`.
     fn overloaded_name(&self, item: OverloadedItem<'ast>) -> SolIdent {
         let original_ident = item.name().expect("item has no name");
-        let sig = item.signature(self);
-        match self.overloads.get(&sig) {
+        let key = (self.current_scope.borrow().clone(), item.signature(self));
+        match self.overloads.get(&key) {
             Some(name) => SolIdent::new_spanned(name, original_ident.span()),
             None => original_ident.clone(),
         }
     }
 
+    /// Returns the scope in which the items of the given contract are
+    /// overloaded.
+    ///
+    /// Items of contracts declared in imported files are only overloaded
+    /// within the same contract, so that an imported interface and its
+    /// implementation can declare the same function. All other items share a
+    /// single scope, which keeps the generated names of inline inputs stable.
+    fn overload_scope(&self, contract: &ItemContract) -> Option<String> {
+        let name = contract.name.as_string();
+        self.imported_contracts.contains(&name).then_some(name)
+    }
+
     /// Returns the name of the function's call Rust struct.
     fn call_name(&self, function: &ItemFunction) -> Ident {
         let function_name = self.function_name(function);
@@ -402,12 +462,10 @@ impl<'ast> ExpCtxt<'ast> {
         utils::selector(self.error_signature(error))
     }
 
-    #[allow(dead_code)]
     fn event_signature(&self, event: &ItemEvent) -> String {
         self.signature(event.name.as_string(), &event.params())
     }
 
-    #[allow(dead_code)]
     fn event_selector(&self, event: &ItemEvent) -> ExprArray<u8, 32> {
         utils::event_selector(self.event_signature(event))
     }
@@ -510,10 +568,97 @@ impl<'ast> ExpCtxt<'ast> {
         }
         Ok(())
     }
+
+    /// Rejects structs with `#[cfg]` fields in the parameters of a function,
+    /// error, or event, whose signature is computed from all of the fields.
+    fn assert_no_cfg_structs<'a, I>(&self, params: I) -> Result<()>
+    where
+        I: IntoIterator<Item = &'a Type>,
+    {
+        let mut errs = Vec::new();
+        for ty in params {
+            ty.visit(|ty| {
+                if let Type::Custom(name) = ty {
+                    if self.has_cfg_fields(name, 0) {
+                        let msg = format!(
+                            "struct `{name}` has `cfg` fields, so it cannot be used in a signature"
+                        );
+                        errs.push(Error::new(name.span(), msg));
+                    }
+                }
+            });
+        }
+        utils::combine_errors(errs)
+    }
+
+    /// Returns `true` if `name` is a struct that has `#[cfg]` fields, directly
+    /// or in one of its struct fields.
+    fn has_cfg_fields(&self, name: &SolPath, depth: usize) -> bool {
+        let Some(Item::Struct(s)) = self.try_item(name) else {
+            return false
+        };
+        if depth >= RESOLVE_LIMIT {
+            return false
+        }
+        s.fields.iter().any(|field| {
+            let mut has = cfg_predicates(&field.attrs).next().is_some();
+            field.ty.visit(|ty| {
+                if let Type::Custom(name) = ty {
+                    has = has || self.has_cfg_fields(name, depth + 1);
+                }
+            });
+            has
+        })
+    }
+
+    /// Returns `true` if all of the types in the parameters are resolved.
+    fn is_resolved<'a, I>(&self, params: I) -> bool
+    where
+        I: IntoIterator<Item = &'a VariableDeclaration>,
+    {
+        let mut resolved = true;
+        for param in params {
+            param.ty.visit(|ty| {
+                if let Type::Custom(name) = ty {
+                    resolved &= self.custom_types.contains_key(name.last());
+                }
+            });
+        }
+        resolved
+    }
 }
 
 // helper functions
 
+/// Rejects `#[cfg]` attributes on parameters, which would remove them from the
+/// generated Rust type but not from the item's signature.
+///
+/// All other attributes, including `#[cfg_attr]`, are forwarded as-is.
+fn check_field_attrs<'a>(attrs: impl IntoIterator<Item = &'a Attribute>) -> Result<()> {
+    utils::combine_errors(
+        attrs
+            .into_iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .map(|attr| {
+                Error::new_spanned(
+                    attr,
+                    "`cfg` attributes are only supported on struct fields, as parameters cannot be \
+                     removed from the signature; use `cfg_attr` to conditionally apply other \
+                     attributes instead",
+                )
+            }),
+    )
+}
+
+/// Returns the predicates of the `#[cfg]` attributes of a struct field.
+fn cfg_predicates(attrs: &[Attribute]) -> impl Iterator<Item = TokenStream> + '_ {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .filter_map(|attr| attr.meta.require_list().ok())
+        .map(|list| list.tokens.clone())
+}
+
 /// Expands a list of parameters into a list of struct fields.
 fn expand_fields<P>(params: &Parameters<P>) -> impl Iterator<Item = TokenStream> + '_ {
     params.iter().enumerate().map(|(i, var)| {
@@ -595,6 +740,69 @@ fn expand_tuple_types<'a, I: IntoIterator<Item = &'a Type>>(
     (wrap_in_parens(sol), wrap_in_parens(rust))
 }
 
+/// Adds `#[derive(Debug)]` to `attrs` if it is not already derived.
+fn derive_debug(attrs: &mut Vec<Attribute>) {
+    let has_debug = attr::derives_mapped(attrs)
+        .any(|path| path.segments.last().map_or(false, |s| s.ident == "Debug"));
+    if !has_debug {
+        attrs.push(parse_quote!(#[derive(Debug)]));
+    }
+}
+
+/// Expands the `#[sol(arbitrary)]` implementations of a struct-like type.
+///
+/// `proptest` strategies require `Debug`, so it is derived if it is not
+/// already.
+fn expand_arbitrary<P>(
+    attrs: &mut Vec<Attribute>,
+    name: &Ident,
+    fields: &Parameters<P>,
+) -> TokenStream {
+    derive_debug(attrs);
+
+    let names: Vec<_> = fields.names().enumerate().map(anon_name).collect();
+    let types: Vec<_> = fields.types().map(expand_rust_type).collect();
+
+    // nest the strategies as `(a, (b, (c, ())))` to support any number of fields
+    let strategy = types.iter().rev().fold(
+        quote!(::alloy_sol_types::private::proptest::strategy::Just(())),
+        |acc, ty| quote!((::alloy_sol_types::private::proptest::arbitrary::any::<#ty>(), #acc)),
+    );
+    let pattern = names
+        .iter()
+        .rev()
+        .fold(quote!(()), |acc, name| quote!((#name, #acc)));
+
+    quote! {
+        #[automatically_derived]
+        impl<'a> ::alloy_sol_types::private::arbitrary::Arbitrary<'a> for #name {
+            #[allow(unused_variables)]
+            fn arbitrary(
+                u: &mut ::alloy_sol_types::private::arbitrary::Unstructured<'a>,
+            ) -> ::alloy_sol_types::private::arbitrary::Result<Self> {
+                ::core::result::Result::Ok(Self {
+                    #(#names: u.arbitrary()?,)*
+                })
+            }
+        }
+
+        #[automatically_derived]
+        impl ::alloy_sol_types::private::proptest::arbitrary::Arbitrary for #name {
+            type Parameters = ();
+            type Strategy = ::alloy_sol_types::private::proptest::strategy::BoxedStrategy<Self>;
+
+            fn arbitrary_with((): ()) -> Self::Strategy {
+                ::alloy_sol_types::private::proptest::strategy::Strategy::boxed(
+                    ::alloy_sol_types::private::proptest::strategy::Strategy::prop_map(
+                        #strategy,
+                        |#pattern| Self { #(#names,)* },
+                    ),
+                )
+            }
+        }
+    }
+}
+
 /// Expand the body of a `tokenize` function.
 fn expand_tokenize<P>(params: &Parameters<P>) -> TokenStream {
     tokenize_(
@@ -630,3 +838,59 @@ fn tokenize_<'a>(
         (#(#statements,)*)
     }
 }
+
+/// Expand the bodies of an `abi_encoded_size` function and of a function which
+/// ABI-encodes the parameters as a sequence into `out`, without tokenizing.
+fn expand_abi_encode<P>(params: &Parameters<P>) -> (TokenStream, TokenStream) {
+    abi_encode_(
+        params
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (i, &p.ty, p.name.as_ref())),
+    )
+}
+
+/// Expand the bodies of an `abi_encoded_size` function and of an
+/// `encode_data_to` function, without tokenizing.
+fn expand_event_abi_encode<'a>(
+    params: impl IntoIterator<Item = &'a EventParameter>,
+) -> (TokenStream, TokenStream) {
+    abi_encode_(
+        params
+            .into_iter()
+            .enumerate()
+            .filter(|(_, p)| !p.is_indexed())
+            .map(|(i, p)| (i, &p.ty, p.name.as_ref())),
+    )
+}
+
+fn abi_encode_<'a>(
+    iter: impl Iterator<Item = (usize, &'a Type, Option<&'a SolIdent>)>,
+) -> (TokenStream, TokenStream) {
+    let (tys, names): (Vec<_>, Vec<_>) = iter
+        .map(|(i, ty, name)| {
+            let name = name.cloned().unwrap_or_else(|| generate_name(i).into());
+            (expand_type(ty), name)
+        })
+        .unzip();
+    if tys.is_empty() {
+        return (quote!(0usize), quote!(let _ = out;))
+    }
+
+    let size = quote! {
+        0usize
+        #(
+            + <#tys as ::alloy_sol_types::SolType>::abi_encoded_size(&self.#names)
+            + 32 * (<#tys as ::alloy_sol_types::SolType>::DYNAMIC as usize)
+        )*
+    };
+    let encode = quote! {
+        let mut enc = ::alloy_sol_types::private::SeqEncoder::new(
+            out,
+            0usize #( + ::alloy_sol_types::private::head_size::<#tys>() )*,
+        );
+        #( enc.head::<#tys, _>(&self.#names); )*
+        #( enc.tail::<#tys, _>(&self.#names); )*
+    };
+    (size, encode)
+}
@@ -0,0 +1,93 @@
+//! `#[sol(selectors_only)]` expansion.
+
+use super::ExpCtxt;
+use crate::attr;
+use ast::{Item, ItemContract};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Result;
+
+/// Expands an item in `selectors_only` mode, emitting only the constants that
+/// identify it:
+///
+/// ```ignore (pseudo-code)
+/// pub mod #{name}Call {
+///     pub const SIGNATURE: &str = "...";
+///     pub const SELECTOR: [u8; 4] = [...];
+/// }
+///
+/// pub mod #error_name {
+///     pub const SIGNATURE: &str = "...";
+///     pub const SELECTOR: [u8; 4] = [...];
+/// }
+///
+/// pub mod #event_name {
+///     pub const SIGNATURE: &str = "...";
+///     pub const SIGNATURE_HASH: B256 = ...;
+/// }
+/// ```
+///
+/// The modules are named like the types of the full expansion, so that
+/// `#{name}Call::SELECTOR` resolves in both modes. All other items are omitted.
+pub(super) fn expand(cx: &ExpCtxt<'_>, item: &Item) -> Result<TokenStream> {
+    let (name, signature, selector, attrs) = match item {
+        Item::Function(function) if function.name.is_some() => {
+            cx.assert_resolved(&function.arguments)?;
+            let selector = cx.function_selector(function);
+            (
+                cx.call_name(function),
+                cx.function_signature(function),
+                quote!(pub const SELECTOR: [u8; 4] = #selector;),
+                &function.attrs,
+            )
+        }
+        Item::Error(error) => {
+            cx.assert_resolved(&error.parameters)?;
+            let selector = cx.error_selector(error);
+            (
+                error.name.0.clone(),
+                cx.error_signature(error),
+                quote!(pub const SELECTOR: [u8; 4] = #selector;),
+                &error.attrs,
+            )
+        }
+        Item::Event(event) => {
+            cx.assert_resolved(&event.params())?;
+            let selector = cx.event_selector(event);
+            (
+                cx.overloaded_name(event.into()).0,
+                cx.event_signature(event),
+                quote! {
+                    pub const SIGNATURE_HASH: ::alloy_sol_types::private::B256 =
+                        ::alloy_sol_types::private::B256::new(#selector);
+                },
+                &event.attrs,
+            )
+        }
+        _ => return Ok(TokenStream::new()),
+    };
+
+    let (sol_attrs, attrs) = attr::SolAttrs::parse(attrs)?;
+    let attrs = attrs.iter().filter(|a| !a.path().is_ident("derive"));
+    let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
+    let doc = docs.then(|| attr::mk_doc(format!("Selector constants of `{signature}`.")));
+    Ok(quote! {
+        #(#attrs)*
+        #doc
+        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        pub mod #name {
+            /// The Solidity signature.
+            pub const SIGNATURE: &str = #signature;
+            #selector
+        }
+    })
+}
+
+/// Returns whether the given contract is expanded in `selectors_only` mode.
+pub(super) fn enabled(cx: &ExpCtxt<'_>, contract: &ItemContract) -> bool {
+    attr::SolAttrs::parse(&contract.attrs)
+        .ok()
+        .and_then(|(attrs, _)| attrs.selectors_only)
+        .or(cx.attrs.selectors_only)
+        .unwrap_or(false)
+}
@@ -1,7 +1,11 @@
 //! [`ItemStruct`] expansion.
 
-use super::{attr, expand_fields, expand_from_into_tuples, expand_tokenize, expand_type, ExpCtxt};
-use ast::{Item, ItemStruct, Spanned, Type};
+use super::{
+    attr, cfg_predicates, expand_abi_encode, expand_arbitrary, expand_fields,
+    expand_from_into_tuples, expand_tokenize, expand_type, ExpCtxt,
+};
+use ast::{Item, ItemContract, ItemStruct, Parameters, SolIdent, SolPath, Spanned, Type};
+use indexmap::IndexMap;
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::num::NonZeroU16;
@@ -23,6 +27,10 @@ use syn::Result;
 ///     ...
 /// }
 /// ```
+///
+/// Fields with `#[cfg]` attributes are kept in the struct definition, and the
+/// implementations are expanded once for each combination of the `cfg`
+/// predicates, with only the fields that are enabled in it.
 pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
     let ItemStruct {
         name,
@@ -34,7 +42,85 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
     let (sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
     cx.derives(&mut attrs, fields, true);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
+    let arbitrary = sol_attrs.arbitrary.or(cx.attrs.arbitrary).unwrap_or(false);
+
+    let mut predicates = IndexMap::new();
+    for predicate in fields.iter().flat_map(|f| cfg_predicates(&f.attrs)) {
+        predicates.entry(predicate.to_string()).or_insert(predicate);
+    }
+    if predicates.len() > MAX_CFG_PREDICATES {
+        let msg = format!(
+            "structs can have at most {MAX_CFG_PREDICATES} distinct `cfg` predicates on their fields"
+        );
+        return Err(syn::Error::new(name.span(), msg))
+    }
 
+    let mut impls = TokenStream::new();
+    for enabled in 0..1usize << predicates.len() {
+        let is_enabled = |predicate: &TokenStream| {
+            let i = predicates.get_index_of(&predicate.to_string()).unwrap();
+            enabled & (1 << i) != 0
+        };
+        let fields: Parameters<syn::token::Semi> = fields
+            .iter()
+            .filter(|f| cfg_predicates(&f.attrs).all(|p| is_enabled(&p)))
+            .cloned()
+            .collect();
+        let body = if fields.is_empty() {
+            let msg = format!("struct `{name}` has no fields in this configuration");
+            quote!(::core::compile_error!(#msg);)
+        } else {
+            let arbitrary = arbitrary.then(|| expand_arbitrary(&mut attrs, &name.0, &fields));
+            expand_impls(cx, name, &fields, arbitrary)
+        };
+        let cfg = (!predicates.is_empty()).then(|| {
+            let all = predicates.values().enumerate().map(|(i, p)| {
+                if enabled & (1 << i) != 0 {
+                    quote!(#p)
+                } else {
+                    quote!(not(#p))
+                }
+            });
+            quote!(#[cfg(all(#(#all),*))])
+        });
+        impls.extend(quote! {
+            #cfg
+            #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+            const _: () = {
+                #body
+            };
+        });
+    }
+
+    let attrs = attrs.iter();
+    let fields = expand_fields(fields);
+
+    let doc = docs.then(|| attr::mk_doc(format!("```solidity\n{s}\n```")));
+    let tokens = quote! {
+        #(#attrs)*
+        #doc
+        #[allow(non_camel_case_types, non_snake_case)]
+        #[derive(Clone)]
+        pub struct #name {
+            #(#fields),*
+        }
+
+        #impls
+    };
+    Ok(tokens)
+}
+
+/// The maximum number of distinct `cfg` predicates on the fields of a struct,
+/// since the implementations are expanded once for each of their combinations.
+const MAX_CFG_PREDICATES: usize = 4;
+
+/// Expands the implementations of a struct with the given fields.
+fn expand_impls(
+    cx: &ExpCtxt<'_>,
+    name: &SolIdent,
+    fields: &Parameters<syn::token::Semi>,
+    arbitrary: Option<TokenStream>,
+) -> TokenStream {
     let (field_types, field_names): (Vec<_>, Vec<_>) = fields
         .iter()
         .map(|f| (expand_type(&f.ty), f.name.as_ref().unwrap()))
@@ -43,6 +129,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
     let eip712_encode_type_fns = expand_encode_type_fns(cx, fields, name);
 
     let tokenize_impl = expand_tokenize(fields);
+    let (encoded_size_impl, encode_impl) = expand_abi_encode(fields);
 
     let encode_data_impl = match fields.len() {
         0 => unreachable!("struct with zero fields"),
@@ -58,121 +145,129 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
         },
     };
 
-    let attrs = attrs.iter();
     let convert = expand_from_into_tuples(&name.0, fields);
     let name_s = name.to_string();
-    let fields = expand_fields(fields);
 
-    let doc = docs.then(|| attr::mk_doc(format!("```solidity\n{s}\n```")));
-    let tokens = quote! {
-        #(#attrs)*
-        #doc
-        #[allow(non_camel_case_types, non_snake_case)]
-        #[derive(Clone)]
-        pub struct #name {
-            #(#fields),*
-        }
+    quote! {
+        #convert
 
-        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
-        const _: () = {
-            #convert
+        #arbitrary
 
-            #[automatically_derived]
-            impl ::alloy_sol_types::SolValue for #name {
-                type SolType = Self;
+        #[automatically_derived]
+        impl ::alloy_sol_types::SolValue for #name {
+            type SolType = Self;
+        }
+
+        #[automatically_derived]
+        impl ::alloy_sol_types::private::SolTypeValue<Self> for #name {
+            fn stv_to_tokens(&self) -> <Self as ::alloy_sol_types::SolType>::TokenType<'_> {
+                #tokenize_impl
             }
 
-            #[automatically_derived]
-            impl ::alloy_sol_types::private::SolTypeValue<Self> for #name {
-                fn stv_to_tokens(&self) -> <Self as ::alloy_sol_types::SolType>::TokenType<'_> {
-                    #tokenize_impl
+            #[inline]
+            fn stv_abi_encoded_size(&self) -> usize {
+                if let Some(size) = <Self as ::alloy_sol_types::SolType>::ENCODED_SIZE {
+                    return size
                 }
+                #encoded_size_impl
+            }
 
-                #[inline]
-                fn stv_abi_encoded_size(&self) -> usize {
-                    // TODO: Avoid cloning
-                    let tuple = <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                    <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::abi_encoded_size(&tuple)
-                }
+            #[inline]
+            fn stv_abi_encode_to(&self, out: &mut ::alloy_sol_types::private::Vec<u8>) {
+                #encode_impl
+            }
 
-                #[inline]
-                fn stv_eip712_data_word(&self) -> ::alloy_sol_types::Word {
-                    <Self as ::alloy_sol_types::SolStruct>::eip712_hash_struct(self)
-                }
+            #[inline]
+            fn stv_eip712_data_word(&self) -> ::alloy_sol_types::Word {
+                <Self as ::alloy_sol_types::SolStruct>::eip712_hash_struct(self)
+            }
 
-                #[inline]
-                fn stv_abi_encode_packed_to(&self, out: &mut ::alloy_sol_types::private::Vec<u8>) {
-                    // TODO: Avoid cloning
-                    let tuple = <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                    <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::abi_encode_packed_to(&tuple, out)
-                }
+            #[inline]
+            fn stv_abi_encode_packed_to(&self, out: &mut ::alloy_sol_types::private::Vec<u8>) {
+                // TODO: Avoid cloning
+                let tuple = <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
+                <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::abi_encode_packed_to(&tuple, out)
             }
+        }
 
-            #[automatically_derived]
-            impl ::alloy_sol_types::SolType for #name {
-                type RustType = Self;
-                type TokenType<'a> = <UnderlyingSolTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+        #[automatically_derived]
+        impl ::alloy_sol_types::SolType for #name {
+            type RustType = Self;
+            type TokenType<'a> = <UnderlyingSolTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
 
-                #[inline]
-                fn sol_type_name() -> ::alloy_sol_types::private::Cow<'static, str> {
-                    ::alloy_sol_types::private::Cow::Borrowed(
-                        <Self as ::alloy_sol_types::SolStruct>::NAME
-                    )
-                }
+            const ENCODED_SIZE: Option<usize> =
+                <UnderlyingSolTuple<'static> as ::alloy_sol_types::SolType>::ENCODED_SIZE;
 
-                #[inline]
-                fn valid_token(token: &Self::TokenType<'_>) -> bool {
-                    <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::valid_token(token)
-                }
+            #[inline]
+            fn sol_type_name() -> ::alloy_sol_types::private::Cow<'static, str> {
+                ::alloy_sol_types::private::Cow::Borrowed(
+                    <Self as ::alloy_sol_types::SolStruct>::NAME
+                )
+            }
 
-                #[inline]
-                fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
-                    let tuple = <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::detokenize(token);
-                    <Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from(tuple)
-                }
+            #[inline]
+            fn valid_token(token: &Self::TokenType<'_>) -> bool {
+                <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::valid_token(token)
             }
 
-            #[automatically_derived]
-            impl ::alloy_sol_types::SolStruct for #name {
-                const NAME: &'static str = #name_s;
+            #[inline]
+            fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
+                let tuple = <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::detokenize(token);
+                <Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from(tuple)
+            }
 
-                #eip712_encode_type_fns
+            #[inline]
+            fn abi_decode_from(dec: &mut ::alloy_sol_types::abi::Decoder<'_>) -> ::alloy_sol_types::Result<Self::RustType> {
+                <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::abi_decode_from(dec)
+                    .map(<Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from)
+            }
 
-                fn eip712_encode_data(&self) -> Vec<u8> {
-                    #encode_data_impl
-                }
+            #[inline]
+            fn abi_decode_sequence_from(dec: &mut ::alloy_sol_types::abi::Decoder<'_>) -> ::alloy_sol_types::Result<Self::RustType> {
+                <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::abi_decode_sequence_from(dec)
+                    .map(<Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from)
             }
+        }
 
-            #[automatically_derived]
-            impl ::alloy_sol_types::EventTopic for #name {
-                #[inline]
-                fn topic_preimage_length(rust: &Self::RustType) -> usize {
-                    0usize
-                    #(
-                        + <#field_types as ::alloy_sol_types::EventTopic>::topic_preimage_length(&rust.#field_names)
-                    )*
-                }
+        #[automatically_derived]
+        impl ::alloy_sol_types::SolStruct for #name {
+            const NAME: &'static str = #name_s;
 
-                #[inline]
-                fn encode_topic_preimage(rust: &Self::RustType, out: &mut ::alloy_sol_types::private::Vec<u8>) {
-                    out.reserve(<Self as ::alloy_sol_types::EventTopic>::topic_preimage_length(rust));
-                    #(
-                        <#field_types as ::alloy_sol_types::EventTopic>::encode_topic_preimage(&rust.#field_names, out);
-                    )*
-                }
+            #eip712_encode_type_fns
 
-                #[inline]
-                fn encode_topic(rust: &Self::RustType) -> ::alloy_sol_types::abi::token::WordToken {
-                    let mut out = ::alloy_sol_types::private::Vec::new();
-                    <Self as ::alloy_sol_types::EventTopic>::encode_topic_preimage(rust, &mut out);
-                    ::alloy_sol_types::abi::token::WordToken(
-                        ::alloy_sol_types::private::keccak256(out)
-                    )
-                }
+            fn eip712_encode_data(&self) -> ::alloy_sol_types::private::Vec<u8> {
+                #encode_data_impl
             }
-        };
-    };
-    Ok(tokens)
+        }
+
+        #[automatically_derived]
+        impl ::alloy_sol_types::EventTopic for #name {
+            #[inline]
+            fn topic_preimage_length(rust: &Self::RustType) -> usize {
+                0usize
+                #(
+                    + <#field_types as ::alloy_sol_types::EventTopic>::topic_preimage_length(&rust.#field_names)
+                )*
+            }
+
+            #[inline]
+            fn encode_topic_preimage(rust: &Self::RustType, out: &mut ::alloy_sol_types::private::Vec<u8>) {
+                out.reserve(<Self as ::alloy_sol_types::EventTopic>::topic_preimage_length(rust));
+                #(
+                    <#field_types as ::alloy_sol_types::EventTopic>::encode_topic_preimage(&rust.#field_names, out);
+                )*
+            }
+
+            #[inline]
+            fn encode_topic(rust: &Self::RustType) -> ::alloy_sol_types::abi::token::WordToken {
+                let mut out = ::alloy_sol_types::private::Vec::new();
+                <Self as ::alloy_sol_types::EventTopic>::encode_topic_preimage(rust, &mut out);
+                ::alloy_sol_types::abi::token::WordToken(
+                    ::alloy_sol_types::private::keccak256(out)
+                )
+            }
+        }
+    }
 }
 
 fn expand_encode_type_fns(
@@ -249,3 +344,202 @@ fn expand_encode_type_fns(
         #encode_type_impl_opt
     }
 }
+
+/// A struct, along with the contract it is defined in, if any.
+type ScopedStruct<'a> = (Option<&'a ItemContract>, &'a ItemStruct);
+
+/// Expands `From` conversions between the structurally identical structs of
+/// the input: structs with the same field names and types, which are defined
+/// in different contracts or under different names.
+///
+/// Fields with struct types are converted with `into`, so they only need to
+/// be structurally identical themselves.
+pub(super) fn expand_conversions(cx: &ExpCtxt<'_>) -> TokenStream {
+    // no structs are generated in `selectors_only` mode
+    if cx.attrs.selectors_only == Some(true) {
+        return TokenStream::new()
+    }
+
+    let structs: Vec<ScopedStruct<'_>> = cx
+        .ast
+        .items
+        .iter()
+        .flat_map(|item| match item {
+            Item::Struct(s) => vec![(None, s)],
+            Item::Contract(c) if !super::selectors::enabled(cx, c) => c
+                .body
+                .iter()
+                .filter_map(|item| match item {
+                    Item::Struct(s) => Some((Some(c), s)),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        })
+        .collect();
+
+    let enabled = |s: &ItemStruct| {
+        crate::attr::SolAttrs::parse(&s.attrs)
+            .ok()
+            .and_then(|(attrs, _)| attrs.struct_conversions)
+            .or(cx.attrs.struct_conversions)
+            .unwrap_or(false)
+    };
+
+    let conversions = Conversions { ast: cx.ast };
+    let mut tokens = TokenStream::new();
+    for &from in &structs {
+        for &to in &structs {
+            if std::ptr::eq(from.1, to.1)
+                || !(enabled(from.1) || enabled(to.1))
+                || !conversions.convertible(from, to, 0)
+            {
+                continue
+            }
+            tokens.extend(conversions.expand(from, to));
+        }
+    }
+
+    if tokens.is_empty() {
+        return tokens
+    }
+    quote! {
+        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        const _: () = {
+            #tokens
+        };
+    }
+}
+
+struct Conversions<'a> {
+    ast: &'a ast::File,
+}
+
+impl<'a> Conversions<'a> {
+    fn expand(&self, from: ScopedStruct<'a>, to: ScopedStruct<'a>) -> TokenStream {
+        let fields = from.1.fields.iter().zip(to.1.fields.iter()).map(|(a, b)| {
+            let name = a.name.as_ref().unwrap();
+            if self.same_type(from.0, &a.ty, to.0, &b.ty) {
+                quote!(#name: value.#name)
+            } else {
+                quote!(#name: ::core::convert::Into::into(value.#name))
+            }
+        });
+        let from = struct_path(from);
+        let to = struct_path(to);
+        quote! {
+            #[automatically_derived]
+            impl ::core::convert::From<#from> for #to {
+                #[inline]
+                fn from(value: #from) -> Self {
+                    Self {
+                        #(#fields,)*
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if `a` can be converted into `b` field by field.
+    ///
+    /// Structs with `#[cfg]` fields are never convertible, since their fields
+    /// depend on the configuration.
+    fn convertible(&self, a: ScopedStruct<'a>, b: ScopedStruct<'a>, depth: usize) -> bool {
+        if depth >= super::RESOLVE_LIMIT || a.1.fields.len() != b.1.fields.len() {
+            return false
+        }
+        let has_cfg = |s: &ItemStruct| {
+            s.fields
+                .iter()
+                .any(|f| cfg_predicates(&f.attrs).next().is_some())
+        };
+        if has_cfg(a.1) || has_cfg(b.1) {
+            return false
+        }
+        a.1.fields.iter().zip(b.1.fields.iter()).all(|(fa, fb)| {
+            if fa.name != fb.name {
+                return false
+            }
+            if self.same_type(a.0, &fa.ty, b.0, &fb.ty) {
+                return true
+            }
+            let (Type::Custom(pa), Type::Custom(pb)) = (&fa.ty, &fb.ty) else {
+                return false
+            };
+            match (self.resolve(a.0, pa), self.resolve(b.0, pb)) {
+                (Some((sa, Item::Struct(ia))), Some((sb, Item::Struct(ib)))) => {
+                    self.convertible((sa, ia), (sb, ib), depth + 1)
+                }
+                _ => false,
+            }
+        })
+    }
+
+    /// Returns `true` if `a` in `scope_a` and `b` in `scope_b` expand to the
+    /// same Rust type.
+    fn same_type(
+        &self,
+        scope_a: Option<&'a ItemContract>,
+        a: &Type,
+        scope_b: Option<&'a ItemContract>,
+        b: &Type,
+    ) -> bool {
+        match (a, b) {
+            (Type::Custom(pa), Type::Custom(pb)) => {
+                match (self.resolve(scope_a, pa), self.resolve(scope_b, pb)) {
+                    (Some((_, ia)), Some((_, ib))) => std::ptr::eq(ia, ib),
+                    _ => false,
+                }
+            }
+            (Type::Array(a), Type::Array(b)) => {
+                a.size() == b.size() && self.same_type(scope_a, &a.ty, scope_b, &b.ty)
+            }
+            (Type::Tuple(a), Type::Tuple(b)) => {
+                a.types.len() == b.types.len()
+                    && a.types
+                        .iter()
+                        .zip(b.types.iter())
+                        .all(|(a, b)| self.same_type(scope_a, a, scope_b, b))
+            }
+            _ => a == b,
+        }
+    }
+
+    /// Resolves a custom type like Rust does in the generated code: in the
+    /// given contract first, then at the top level of the input.
+    fn resolve(
+        &self,
+        scope: Option<&'a ItemContract>,
+        path: &SolPath,
+    ) -> Option<(Option<&'a ItemContract>, &'a Item)> {
+        let find = |scope: Option<&'a ItemContract>| {
+            let items = scope.map_or(&self.ast.items, |c| &c.body);
+            items
+                .iter()
+                .find(|item| item.name() == Some(path.last()))
+                .map(|item| (scope, item))
+        };
+        let contract = |name: &SolIdent| {
+            self.ast.items.iter().find_map(|item| match item {
+                Item::Contract(c) if c.name == *name => Some(c),
+                _ => None,
+            })
+        };
+        match path.len() {
+            1 => scope.and_then(|c| find(Some(c))).or_else(|| find(None)),
+            2 => find(Some(contract(path.first())?)),
+            _ => None,
+        }
+    }
+}
+
+fn struct_path((contract, s): ScopedStruct<'_>) -> TokenStream {
+    let name = &s.name;
+    match contract {
+        Some(contract) => {
+            let contract = &contract.name;
+            quote!(#contract::#name)
+        }
+        None => quote!(#name),
+    }
+}
@@ -0,0 +1,119 @@
+//! Resolution of `import` directives in Solidity files.
+
+use crate::input::manifest_path;
+use ast::{File, ImportPath, Item, Spanned};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+use syn::{Error, LitStr, Result};
+
+/// Replaces the `import` directives of the Solidity file at `path` with the
+/// items of the imported files, recursively.
+///
+/// Paths starting with `./` or `../` are resolved relative to the importing
+/// file. Other paths are resolved against each of the `include_paths` in
+/// order, and then against the manifest directory, like `solc`'s include and
+/// base paths.
+///
+/// Every file is only included once, even if it is imported several times.
+/// Aliased imports are not supported, since all items are expanded in the same
+/// scope.
+///
+/// Returns the paths of all the imported files, and the names of the contracts
+/// declared in them.
+pub fn resolve(
+    file: &mut File,
+    path: &Path,
+    include_paths: &[LitStr],
+) -> Result<(Vec<PathBuf>, HashSet<String>)> {
+    let include_paths = include_paths
+        .iter()
+        .map(|lit| manifest_path(lit.value(), lit.span()))
+        .collect::<Result<Vec<_>>>()?;
+    let mut resolver = Resolver {
+        include_paths,
+        seen: HashSet::from([path.to_path_buf()]),
+        files: Vec::new(),
+        contracts: HashSet::new(),
+    };
+    let items = std::mem::take(&mut file.items);
+    file.items = resolver.resolve_items(items, path)?;
+    Ok((resolver.files, resolver.contracts))
+}
+
+struct Resolver {
+    include_paths: Vec<PathBuf>,
+    seen: HashSet<PathBuf>,
+    files: Vec<PathBuf>,
+    contracts: HashSet<String>,
+}
+
+impl Resolver {
+    fn resolve_items(&mut self, items: Vec<Item>, path: &Path) -> Result<Vec<Item>> {
+        let mut resolved = Vec::with_capacity(items.len());
+        for item in items {
+            let Item::Import(import) = item else {
+                resolved.push(item);
+                continue
+            };
+
+            let lit = import.path.path();
+            let alias = match &import.path {
+                ImportPath::Plain(plain) => plain.alias.as_ref().map(|alias| alias.span()),
+                ImportPath::Aliases(aliases) => aliases
+                    .imports
+                    .iter()
+                    .find_map(|(_, alias)| alias.as_ref().map(|alias| alias.span())),
+                ImportPath::Glob(glob) => glob.alias.as_ref().map(|alias| alias.span()),
+            };
+            if let Some(span) = alias {
+                return Err(Error::new(span, "aliased imports are not supported"))
+            }
+
+            let imported = self.resolve_path(lit, path)?;
+            if !self.seen.insert(imported.clone()) {
+                continue
+            }
+            let contents = std::fs::read_to_string(&imported).map_err(|e| {
+                let msg = format!("failed to read imported file {}: {e}", imported.display());
+                Error::new(lit.span(), msg)
+            })?;
+            let file = syn::parse_str::<File>(&contents).map_err(|e| {
+                let msg = format!("failed to parse imported file {}: {e}", imported.display());
+                Error::new(lit.span(), msg)
+            })?;
+            self.files.push(imported.clone());
+            self.contracts
+                .extend(file.items.iter().filter_map(|item| match item {
+                    Item::Contract(contract) => Some(contract.name.as_string()),
+                    _ => None,
+                }));
+            resolved.extend(self.resolve_items(file.items, &imported)?);
+        }
+        Ok(resolved)
+    }
+
+    fn resolve_path(&self, lit: &ast::LitStr, importer: &Path) -> Result<PathBuf> {
+        let import = lit.value();
+        let candidates = if import.starts_with("./") || import.starts_with("../") {
+            let dir = importer.parent().unwrap_or(Path::new(""));
+            vec![dir.join(&import)]
+        } else {
+            let mut candidates = self
+                .include_paths
+                .iter()
+                .map(|dir| dir.join(&import))
+                .collect::<Vec<_>>();
+            candidates.push(manifest_path(import.clone(), lit.span())?);
+            candidates
+        };
+        candidates
+            .iter()
+            .find_map(|candidate| dunce::canonicalize(candidate).ok())
+            .ok_or_else(|| {
+                let msg = format!("failed to resolve import {import:?}");
+                Error::new(lit.span(), msg)
+            })
+    }
+}
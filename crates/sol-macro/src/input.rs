@@ -1,7 +1,8 @@
+use crate::attr::SolAttrs;
 use ast::Spanned;
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use std::path::PathBuf;
+use std::{collections::HashSet, path::PathBuf};
 use syn::{
     parse::{discouraged::Speculative, Parse, ParseStream},
     Attribute, Error, Ident, LitStr, Result, Token,
@@ -12,7 +13,7 @@ pub enum SolInputKind {
     Sol(ast::File),
     Type(ast::Type),
     #[cfg(feature = "json")]
-    Json(Ident, alloy_json_abi::ContractObject),
+    Json(Ident, Box<alloy_json_abi::ContractObject>),
 }
 
 // doesn't parse Json
@@ -83,13 +84,7 @@ impl SolInput {
                 || (s.starts_with('[') && s.ends_with(']')))
         };
         if is_path {
-            let mut p = PathBuf::from(value);
-            if p.is_relative() {
-                let dir = std::env::var_os("CARGO_MANIFEST_DIR")
-                    .map(PathBuf::from)
-                    .ok_or_else(|| Error::new(span, "failed to get manifest dir"))?;
-                p = dir.join(p);
-            }
+            let mut p = manifest_path(value, span)?;
             p = dunce::canonicalize(&p)
                 .map_err(|e| Error::new(span, format!("failed to canonicalize path: {e}")))?;
             value = std::fs::read_to_string(&p)
@@ -140,15 +135,22 @@ impl SolInput {
     pub fn expand(self) -> Result<TokenStream> {
         let Self { attrs, path, kind } = self;
 
-        let include = path.map(|p| {
-            let p = p.to_str().unwrap();
-            quote! { const _: &'static [u8] = ::core::include_bytes!(#p); }
-        });
-
+        let mut paths: Vec<PathBuf> = path.iter().cloned().collect();
+        let mut debug_output = None;
         let tokens = match kind {
             SolInputKind::Sol(mut file) => {
                 file.attrs.extend(attrs);
-                crate::expand::expand(file)
+                let sol_attrs = SolAttrs::parse(&file.attrs)?.0;
+                debug_output = sol_attrs.debug_output;
+                let mut imported_contracts = HashSet::new();
+                if let Some(path) = &path {
+                    let include_paths = sol_attrs.include_paths.unwrap_or_default();
+                    let (files, contracts) =
+                        crate::import::resolve(&mut file, path, &include_paths)?;
+                    paths.extend(files);
+                    imported_contracts = contracts;
+                }
+                crate::expand::expand(file, imported_contracts)
             }
             SolInputKind::Type(ty) => {
                 if attrs.is_empty() {
@@ -161,12 +163,130 @@ impl SolInput {
                 }
             }
             #[cfg(feature = "json")]
-            SolInputKind::Json(name, json) => crate::json::expand(name, json, attrs),
+            SolInputKind::Json(name, json) => {
+                debug_output = SolAttrs::parse(&attrs)?.0.debug_output;
+                // the attributes are applied to the generated contract
+                let attrs = strip_debug_output(attrs)?;
+                crate::json::expand(name, *json, attrs)
+            }
         }?;
 
-        Ok(quote! {
-            #include
+        let include = paths.iter().map(|p| {
+            let p = p.to_str().unwrap();
+            quote! { const _: &'static [u8] = ::core::include_bytes!(#p); }
+        });
+        let tokens = quote! {
+            #(#include)*
             #tokens
-        })
+        };
+        if let Some(path) = debug_output {
+            write_debug_output(&path, &tokens)?;
+        }
+        Ok(tokens)
+    }
+}
+
+/// Resolves a path relative to the `CARGO_MANIFEST_DIR` environment variable.
+pub fn manifest_path(path: String, span: Span) -> Result<PathBuf> {
+    let path = PathBuf::from(path);
+    if path.is_relative() {
+        let dir = std::env::var_os("CARGO_MANIFEST_DIR")
+            .map(PathBuf::from)
+            .ok_or_else(|| Error::new(span, "failed to get manifest dir"))?;
+        Ok(dir.join(path))
+    } else {
+        Ok(path)
+    }
+}
+
+/// Removes `debug_output` from `#[sol(...)]` attributes, since it is not
+/// allowed on items.
+#[cfg(feature = "json")]
+fn strip_debug_output(attrs: Vec<Attribute>) -> Result<Vec<Attribute>> {
+    let mut stripped = Vec::with_capacity(attrs.len());
+    for attr in attrs {
+        if !attr.path().is_ident("sol") {
+            stripped.push(attr);
+            continue
+        }
+        let metas = attr.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Meta, Token![,]>::parse_terminated,
+        )?;
+        let metas = metas
+            .into_iter()
+            .filter(|meta| !meta.path().is_ident("debug_output"));
+        stripped.push(syn::parse_quote!(#[sol(#(#metas),*)]));
+    }
+    Ok(stripped)
+}
+
+/// Writes the expanded tokens to `path` for `#[sol(debug_output)]`.
+///
+/// The output is formatted with `rustfmt` on a best-effort basis, since it is
+/// not necessarily installed.
+fn write_debug_output(path: &LitStr, tokens: &TokenStream) -> Result<()> {
+    let span = path.span();
+    let path = manifest_path(path.value(), span)?;
+    std::fs::write(&path, tokens.to_string())
+        .map_err(|e| Error::new(span, format!("failed to write debug output: {e}")))?;
+    let rustfmt = std::env::var_os("RUSTFMT").unwrap_or_else(|| "rustfmt".into());
+    let _ = std::process::Command::new(rustfmt)
+        .arg("--edition=2021")
+        .arg(&path)
+        .output();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore = "no fs")]
+    fn debug_output() {
+        let path = std::env::temp_dir().join(format!("sol-debug-output-{}.rs", std::process::id()));
+        let lit = LitStr::new(path.to_str().unwrap(), Span::call_site());
+        let input: SolInput = syn::parse_quote! {
+            #![sol(debug_output = #lit)]
+            struct Foo { uint256 a; }
+        };
+        input.expand().unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        // the file may or may not have been formatted
+        let written: String = written.split_whitespace().collect();
+        assert!(written.contains("pubstructFoo{"), "{written}");
+        assert!(
+            written.contains("impl::alloy_sol_types::SolStructforFoo{"),
+            "{written}"
+        );
+    }
+
+    #[test]
+    fn debug_output_on_item() {
+        let input: SolInput = syn::parse_quote! {
+            #[sol(debug_output = "expanded.rs")]
+            struct Foo { uint256 a; }
+        };
+        let tokens = input.expand().unwrap().to_string();
+        assert!(
+            tokens.contains("`debug_output` is only allowed as an inner attribute"),
+            "{tokens}"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn strip_debug_output() {
+        let attrs: Vec<Attribute> = vec![
+            syn::parse_quote!(#[sol(all_derives, debug_output = "expanded.rs")]),
+            syn::parse_quote!(#[derive(Default)]),
+        ];
+        let expected: Vec<Attribute> = vec![
+            syn::parse_quote!(#[sol(all_derives)]),
+            syn::parse_quote!(#[derive(Default)]),
+        ];
+        assert_eq!(super::strip_debug_output(attrs).unwrap(), expected);
     }
 }
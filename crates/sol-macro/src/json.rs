@@ -8,6 +8,7 @@ pub fn expand(name: Ident, json: ContractObject, attrs: Vec<Attribute>) -> Resul
         abi,
         bytecode,
         deployed_bytecode,
+        ..
     } = json;
 
     let mut abi = abi.ok_or_else(|| syn::Error::new(name.span(), "ABI not found in JSON"))?;
@@ -49,7 +50,7 @@ Generated by the following Solidity interface...
         );
         syn::Error::new(name.span(), msg)
     })?;
-    crate::expand::expand(ast)
+    crate::expand::expand(ast, Default::default())
 }
 
 fn abi_to_sol(name: &Ident, abi: &mut JsonAbi) -> String {
@@ -30,6 +30,7 @@ use syn::parse_macro_input;
 
 mod attr;
 mod expand;
+mod import;
 mod input;
 #[cfg(feature = "json")]
 mod json;
@@ -79,6 +80,15 @@ mod utils;
 /// - a [Solidity type name][sol-types], which simply expands to the
 ///   corresponding Rust type.
 ///
+/// When given a path to a Solidity file, such as
+/// `sol!("contracts/Token.sol")`, its `import` directives are resolved and the
+/// items of the imported files are expanded together with its own, so that
+/// sources can be used as-is. Paths starting with `./` or `../` are relative to
+/// the importing file, and other paths are resolved against the
+/// `include_paths` attribute and then the manifest directory. Each contract
+/// generates its own module. Aliased imports, like `import "a.sol" as A;`, are
+/// not supported. Imports in inline Solidity input are ignored.
+///
 /// [sol-item]: https://docs.soliditylang.org/en/latest/grammar.html#a4.SolidityParser.sourceUnit
 /// [sol-types]: https://docs.soliditylang.org/en/latest/types.html
 ///
@@ -92,6 +102,13 @@ mod utils;
 /// structs, enums, etc. These can be any Rust attribute, and they will be added
 /// to every Rust item generated from the Solidity item.
 ///
+/// Outer attributes are also parsed on struct fields and on function, error,
+/// and event parameters, and they are added to the corresponding generated
+/// fields. `cfg` is only allowed on struct fields, which are then also removed
+/// from the struct's ABI when disabled; such structs cannot be used in
+/// function, error, or event parameters, whose signatures are fixed. `cfg_attr`
+/// is allowed everywhere.
+///
 /// This macro provides the `sol` attribute, which can be used to customize the
 /// generated code. Note that unused attributes are currently silently ignored,
 /// but this may change in the future.
@@ -108,11 +125,46 @@ mod utils;
 ///   [`abigen`][abigen]
 /// - `docs [ = <bool = true>]`: adds doc comments to all generated types. This
 ///   is the default behaviour of [`abigen`][abigen]
+/// - `arbitrary [ = <bool = false>]`: implements the `arbitrary` and `proptest`
+///   crates' `Arbitrary` traits for all generated structs, function calls and
+///   returns, errors, and enums, deriving `Debug` if it is not already. Enums
+///   only generate their valid variants. Requires the `arbitrary` feature of
+///   `alloy-sol-types`
+/// - `struct_conversions [ = <bool = false>]`: implements `From` between the
+///   structurally identical structs of the input, that is structs with the same
+///   field names and types, such as the same struct redefined in several
+///   contracts. Applies to the whole input as an inner attribute, or to the
+///   structs it annotates otherwise
+/// - `borrowed_events [ = <bool = false>]`: generates an `<Event>Ref<'a>`
+///   struct for each event with non-indexed `string` or `bytes` parameters,
+///   which borrows them from the log data instead of allocating them, as
+///   `Cow<'a, str>` and `&'a [u8]`. It is decoded with its `decode_raw_log` and
+///   `decode_log` methods, and converted to the event with `into_owned`.
+///   Applies to the whole input as an inner attribute, or to the events it
+///   annotates otherwise
+/// - `selectors_only [ = <bool = false>]`: only emits the signatures and
+///   selectors of functions, errors, and events, as `SIGNATURE` and `SELECTOR`
+///   or `SIGNATURE_HASH` constants in a module named like the type they would
+///   otherwise generate, such as `transferCall::SELECTOR`. No types or encoding
+///   and decoding code are generated. Useful for matching on selectors with
+///   minimal code generation. Applies to the whole input as an inner attribute,
+///   or to the contracts it annotates otherwise
 /// - `bytecode = <hex string literal>`: specifies the creation/init bytecode of
 ///   a contract. This will emit a `static` item with the specified bytes.
 /// - `deployed_bytecode = <hex string literal>`: specifies the deployed
 ///   bytecode of a contract. This will emit a `static` item with the specified
 ///   bytes.
+/// - `debug_output = <path string literal>`: writes the generated Rust code of
+///   the whole input to the given file, formatted with `rustfmt` if it is
+///   available. Only applies as an inner attribute. Useful for debugging errors
+///   in the generated code.
+/// - `deprecation_tag = <string literal = "custom:deprecated">`: the NatSpec
+///   tag which marks functions and events as deprecated. Their generated call
+///   and event structs are annotated with `#[deprecated]`, with the text of the
+///   tag as the note, so that using them emits a compiler warning
+/// - `include_paths = [<path string literal>, ...]`: the directories against
+///   which non-relative `import` paths are resolved, in order, before the
+///   manifest directory. Only applies to [Solidity file paths](#solidity).
 ///
 /// ### Structs and enums
 ///
@@ -144,13 +196,21 @@ mod utils;
 /// E.g. if there are two functions named `foo`, the generated types will be
 /// `foo_0Call` and `foo_1Call`, each of which will implement `SolCall`
 /// with their respective signatures.
+///
+/// Functions and events are overloaded across all the contracts of the input,
+/// except for those of contracts declared in imported files, which are only
+/// overloaded within the same contract. This allows an imported interface and
+/// its implementation to declare the same function.
 /// ```ignore
 #[cfg_attr(doc, doc = include_str!("../doctests/function_like.rs"))]
 /// ```
 /// 
 /// ### Events
 ///
-/// Events generate a struct that implements `SolEvent`.
+/// Events generate a struct that implements `SolEvent`, and which can be
+/// decoded from a `Log`, or from its topics and data, with `TryFrom`. These
+/// conversions check that the topics belong to the event, and validate the
+/// data.
 ///
 /// Note that events have special encoding rules in Solidity. For example,
 /// `string indexed` will be encoded in the topics as its `bytes32` Keccak-256
@@ -168,6 +228,13 @@ mod utils;
 /// - functions: `<contract_name>Calls`
 /// - errors: `<contract_name>Errors`
 /// - events: `<contract_name>Events`
+///
+/// The events enum can be decoded from a `Log`, or from its topics and data,
+/// with `TryFrom`, based on the first topic. Anonymous events are never
+/// matched.
+///
+/// Two functions, or two errors, of the same contract whose selectors collide
+/// are a compile error, as their container enum could not tell them apart.
 /// ```ignore
 #[cfg_attr(doc, doc = include_str!("../doctests/contracts.rs"))]
 /// ```
@@ -28,6 +28,12 @@ pub struct Decoder<'de> {
     buf: &'de [u8],
     // The current offset in the buffer.
     offset: usize,
+    // The offset of the buffer from the start of the data being decoded.
+    base: usize,
+    // The number of indirections followed to reach this decoder.
+    depth: u8,
+    // The maximum number of indirections to follow.
+    recursion_limit: u8,
     // Whether to validate type correctness and blob re-encoding.
     validate: bool,
 }
@@ -44,6 +50,9 @@ impl fmt::Debug for Decoder<'_> {
         f.debug_struct("Decoder")
             .field("buf", &body)
             .field("offset", &self.offset)
+            .field("base", &self.base)
+            .field("depth", &self.depth)
+            .field("recursion_limit", &self.recursion_limit)
             .field("validate", &self.validate)
             .finish()
     }
@@ -71,6 +80,16 @@ impl fmt::Display for Decoder<'_> {
 }
 
 impl<'de> Decoder<'de> {
+    /// The default maximum number of nested indirections, after which
+    /// [`take_indirection`](Self::take_indirection) fails with
+    /// [`Error::RecursionLimitExceeded`].
+    ///
+    /// Only dynamic values, such as dynamic arrays, `bytes`, `string` and
+    /// tuples containing them, are encoded behind an indirection, so this
+    /// bounds the nesting depth of dynamic types that can be decoded. Use
+    /// [`set_recursion_limit`](Self::set_recursion_limit) to change it.
+    pub const RECURSION_LIMIT: u8 = 128;
+
     /// Instantiate a new decoder from a byte slice and a validation flag.
     ///
     /// If `validate` is true, the decoder will check that the bytes conform to
@@ -81,6 +100,9 @@ impl<'de> Decoder<'de> {
         Self {
             buf,
             offset: 0,
+            base: 0,
+            depth: 0,
+            recursion_limit: Self::RECURSION_LIMIT,
             validate,
         }
     }
@@ -91,6 +113,13 @@ impl<'de> Decoder<'de> {
         self.offset
     }
 
+    /// Returns the number of whole words left in the buffer after the current
+    /// offset.
+    #[inline]
+    pub const fn remaining_words(&self) -> usize {
+        self.buf.len().saturating_sub(self.offset) / Word::len_bytes()
+    }
+
     /// Returns `true` if this decoder is validating type correctness.
     #[inline]
     pub const fn validate(&self) -> bool {
@@ -103,6 +132,21 @@ impl<'de> Decoder<'de> {
         self.validate = validate;
     }
 
+    /// Returns the maximum number of nested indirections this decoder follows.
+    ///
+    /// Defaults to [`RECURSION_LIMIT`](Self::RECURSION_LIMIT).
+    #[inline]
+    pub const fn recursion_limit(&self) -> u8 {
+        self.recursion_limit
+    }
+
+    /// Set the maximum number of nested indirections to follow. This is
+    /// inherited by child decoders.
+    #[inline]
+    pub fn set_recursion_limit(&mut self, limit: u8) {
+        self.recursion_limit = limit;
+    }
+
     /// Create a child decoder, starting at `offset` bytes from the current
     /// decoder's offset.
     ///
@@ -115,16 +159,28 @@ impl<'de> Decoder<'de> {
 
     /// Create a child decoder, starting at `offset` bytes from the current
     /// decoder's offset.
-    /// The child decoder shares the buffer and validation flag.
+    /// The child decoder shares the buffer, validation flag and recursion
+    /// limit.
     #[inline]
     pub fn child(&self, offset: usize) -> Result<Decoder<'de>, Error> {
         match self.buf.get(offset..) {
             Some(buf) => Ok(Decoder {
                 buf,
                 offset: 0,
+                base: self.base.saturating_add(offset),
+                depth: self.depth,
+                recursion_limit: self.recursion_limit,
                 validate: self.validate,
             }),
-            None => Err(Error::Overrun),
+            None => Err(self.overrun_at(offset)),
+        }
+    }
+
+    /// Returns an [`Error::Overrun`] for a read at `offset` in the buffer.
+    #[cold]
+    const fn overrun_at(&self, offset: usize) -> Error {
+        Error::Overrun {
+            offset: self.base.saturating_add(offset),
         }
     }
 
@@ -137,14 +193,19 @@ impl<'de> Decoder<'de> {
     /// Peek into the buffer.
     #[inline]
     pub fn peek<I: SliceIndex<[u8]>>(&self, index: I) -> Result<&'de I::Output, Error> {
-        self.buf.get(index).ok_or(Error::Overrun)
+        self.buf
+            .get(index)
+            .ok_or_else(|| self.overrun_at(self.offset))
     }
 
     /// Peek a slice of size `len` from the buffer at a specific offset, without
     /// advancing the offset.
     #[inline]
     pub fn peek_len_at(&self, offset: usize, len: usize) -> Result<&'de [u8], Error> {
-        self.peek(offset..offset + len)
+        match utils::checked_end(offset, len, self.buf.len()) {
+            Some(end) => Ok(&self.buf[offset..end]),
+            None => Err(self.overrun_at(offset)),
+        }
     }
 
     /// Peek a slice of size `len` from the buffer without advancing the offset.
@@ -192,9 +253,18 @@ impl<'de> Decoder<'de> {
 
     /// Return a child decoder by consuming a word, interpreting it as a
     /// pointer, and following it.
+    ///
+    /// Returns [`Error::RecursionLimitExceeded`] if
+    /// [`recursion_limit`](Self::recursion_limit) indirections have already
+    /// been followed to reach this decoder.
     #[inline]
     pub fn take_indirection(&mut self) -> Result<Decoder<'de>, Error> {
-        self.take_offset().and_then(|offset| self.child(offset))
+        if self.depth >= self.recursion_limit {
+            return Err(Error::RecursionLimitExceeded(self.recursion_limit))
+        }
+        let mut child = self.take_offset().and_then(|offset| self.child(offset))?;
+        child.depth += 1;
+        Ok(child)
     }
 
     /// Take a u32 from the buffer by consuming a word.
@@ -208,11 +278,10 @@ impl<'de> Decoder<'de> {
     /// word boundary.
     pub fn take_slice(&mut self, len: usize) -> Result<&'de [u8]> {
         if self.validate {
-            let padded_len = utils::next_multiple_of_32(len);
-            if self.offset + padded_len > self.buf.len() {
-                return Err(Error::Overrun)
-            }
-            if !utils::check_zeroes(self.peek(self.offset + len..self.offset + padded_len)?) {
+            let end = utils::checked_next_multiple_of_32(len)
+                .and_then(|padded_len| utils::checked_end(self.offset, padded_len, self.buf.len()))
+                .ok_or_else(|| self.overrun_at(self.offset))?;
+            if !utils::check_zeroes(&self.buf[self.offset + len..end]) {
                 return Err(Error::Other(Cow::Borrowed(
                     "non-empty bytes after packed array",
                 )))
@@ -297,8 +366,125 @@ pub fn decode_params<'de, T: TokenSeq<'de>>(data: &'de [u8], validate: bool) ->
 /// See the [`abi`](super) module for more information.
 #[inline]
 pub fn decode_sequence<'de, T: TokenSeq<'de>>(data: &'de [u8], validate: bool) -> Result<T> {
+    decode_sequence_impl(data, validate, false)
+}
+
+/// ABI-decodes a token by wrapping it in a single-element tuple, erroring if
+/// the data is longer than the encoding of the token.
+///
+/// See [`decode_sequence_exact`] for more information.
+#[inline(always)]
+pub fn decode_exact<'de, T: TokenType<'de>>(data: &'de [u8], validate: bool) -> Result<T> {
+    decode_sequence_exact::<(T,)>(data, validate).map(|(t,)| t)
+}
+
+/// ABI-decodes top-level function args, erroring if the data is longer than
+/// the encoding of the args.
+///
+/// See [`decode_sequence_exact`] for more information.
+#[inline(always)]
+pub fn decode_params_exact<'de, T: TokenSeq<'de>>(data: &'de [u8], validate: bool) -> Result<T> {
+    if T::IS_TUPLE {
+        decode_sequence_exact(data, validate)
+    } else {
+        decode_exact(data, validate)
+    }
+}
+
+/// ABI-decodes a token sequence, erroring if the data is longer than the
+/// encoding of the sequence.
+///
+/// [`decode_sequence`] ignores any bytes trailing the encoded sequence, which
+/// allows several encodings of the same values. This returns
+/// [`Error::BufferNotEmpty`] instead, even if `validate` is `false`.
+///
+/// Note that this only detects trailing bytes: unused bytes in the middle of
+/// the data, for example between the tails of dynamic values, are only
+/// detected by re-encoding the result, which is done when `validate` is
+/// `true`.
+///
+/// See the [`abi`](super) module for more information.
+#[inline]
+pub fn decode_sequence_exact<'de, T: TokenSeq<'de>>(data: &'de [u8], validate: bool) -> Result<T> {
+    decode_sequence_impl(data, validate, true)
+}
+
+/// ABI-decodes a token by wrapping it in a single-element tuple, returning
+/// the bytes trailing its encoding.
+///
+/// See [`decode_sequence_with_trailing`] for more information.
+#[inline(always)]
+pub fn decode_with_trailing<'de, T: TokenType<'de>>(
+    data: &'de [u8],
+    validate: bool,
+) -> Result<(T, &'de [u8])> {
+    decode_sequence_with_trailing::<(T,)>(data, validate).map(|((t,), trailing)| (t, trailing))
+}
+
+/// ABI-decodes top-level function args, returning the bytes trailing the
+/// encoding of the args.
+///
+/// See [`decode_sequence_with_trailing`] for more information.
+#[inline(always)]
+pub fn decode_params_with_trailing<'de, T: TokenSeq<'de>>(
+    data: &'de [u8],
+    validate: bool,
+) -> Result<(T, &'de [u8])> {
+    if T::IS_TUPLE {
+        decode_sequence_with_trailing(data, validate)
+    } else {
+        decode_with_trailing(data, validate)
+    }
+}
+
+/// ABI-decodes a token sequence, returning the bytes trailing the encoding of
+/// the sequence.
+///
+/// This is useful for data which has extra bytes appended to an ABI encoding,
+/// such as calls relayed by an [ERC-2771] forwarder, which appends the address
+/// of the original sender to the calldata.
+///
+/// Unlike [`decode_sequence`], this does not return
+/// [`Error::ReserMismatch`] when `validate` is `true` and the data has
+/// trailing bytes: only the bytes preceding them must match the re-encoded
+/// sequence.
+///
+/// The trailing bytes are those following the canonical length of the
+/// encoding, i.e. the sequence's [`total_words`](TokenType::total_words) times
+/// 32. The decoder does not track how far into the data it actually read, so
+/// when `validate` is `false` and the data is not canonically encoded, for
+/// example because its tails are preceded by unused bytes, the returned
+/// trailing bytes may overlap the bytes that were decoded. Set `validate` to
+/// `true` to reject such data with [`Error::ReserMismatch`] instead.
+///
+/// See the [`abi`](super) module for more information.
+///
+/// [ERC-2771]: https://eips.ethereum.org/EIPS/eip-2771
+#[inline]
+pub fn decode_sequence_with_trailing<'de, T: TokenSeq<'de>>(
+    data: &'de [u8],
+    validate: bool,
+) -> Result<(T, &'de [u8])> {
     let mut decoder = Decoder::new(data, validate);
     let result = decoder.decode_sequence::<T>()?;
+    let len = data.len().min(result.total_words() * Word::len_bytes());
+    let (data, trailing) = data.split_at(len);
+    if validate && encode_sequence(&result) != data {
+        return Err(Error::ReserMismatch)
+    }
+    Ok((result, trailing))
+}
+
+fn decode_sequence_impl<'de, T: TokenSeq<'de>>(
+    data: &'de [u8],
+    validate: bool,
+    exact: bool,
+) -> Result<T> {
+    let mut decoder = Decoder::new(data, validate);
+    let result = decoder.decode_sequence::<T>()?;
+    if exact && data.len() > result.total_words() * Word::len_bytes() {
+        return Err(Error::BufferNotEmpty)
+    }
     if validate && encode_sequence(&result) != data {
         return Err(Error::ReserMismatch)
     }
@@ -307,8 +493,13 @@ pub fn decode_sequence<'de, T: TokenSeq<'de>>(data: &'de [u8], validate: bool) -
 
 #[cfg(test)]
 mod tests {
-    use crate::{sol_data, utils::pad_usize, SolType};
-    use alloc::string::ToString;
+    use crate::{
+        abi::{Decoder, TokenType},
+        sol_data,
+        utils::pad_usize,
+        Error, SolType,
+    };
+    use alloc::{string::ToString, vec};
     use alloy_primitives::{address, hex, Address, B256, U256};
 
     #[test]
@@ -537,6 +728,29 @@ mod tests {
         assert_eq!(decoded, expected);
     }
 
+    #[test]
+    fn decode_params_containing_static_array() {
+        type MyTy = (
+            sol_data::FixedArray<sol_data::Address, 2>,
+            sol_data::Address,
+        );
+
+        let encoded = hex!(
+            "
+    		0000000000000000000000001111111111111111111111111111111111111111
+    		0000000000000000000000002222222222222222222222222222222222222222
+    		0000000000000000000000003333333333333333333333333333333333333333
+    	"
+        );
+        let expected = (
+            [Address::repeat_byte(0x11), Address::repeat_byte(0x22)],
+            Address::repeat_byte(0x33),
+        );
+
+        let decoded = MyTy::abi_decode_params(&encoded, true).unwrap();
+        assert_eq!(decoded, expected);
+    }
+
     #[test]
     fn decode_data_with_size_that_is_not_a_multiple_of_32() {
         type MyTy = (
@@ -669,6 +883,157 @@ mod tests {
         assert!(MyTy2::abi_decode_params(&input, true).is_ok());
     }
 
+    #[test]
+    fn decode_with_trailing_bytes() {
+        type MyTy = (sol_data::Uint<256>, sol_data::String);
+
+        let value = (U256::from(1), "hello".to_string());
+        let encoded = MyTy::abi_encode_params(&value);
+        let (decoded, trailing) = MyTy::abi_decode_params_with_trailing(&encoded, true).unwrap();
+        assert_eq!(decoded, value);
+        assert!(trailing.is_empty());
+
+        // ERC-2771: the sender is appended to the calldata
+        let sender = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        let forwarded = [&encoded[..], sender.as_slice()].concat();
+        for validate in [false, true] {
+            let (decoded, trailing) =
+                MyTy::abi_decode_params_with_trailing(&forwarded, validate).unwrap();
+            assert_eq!(decoded, value);
+            assert_eq!(Address::from_slice(trailing), sender);
+        }
+
+        // Trailing bytes don't hide invalid padding before them
+        let mut dirty = forwarded.clone();
+        dirty[encoded.len() - 1] = 1;
+        assert!(MyTy::abi_decode_params_with_trailing(&dirty, false).is_ok());
+        assert_eq!(
+            MyTy::abi_decode_params_with_trailing(&dirty, true),
+            Err(Error::ReserMismatch)
+        );
+
+        let encoded = sol_data::Bool::abi_encode(&true);
+        assert_eq!(
+            sol_data::Bool::abi_decode_with_trailing(&[&encoded[..], &[1, 2]].concat(), true),
+            Ok((true, &[1, 2][..]))
+        );
+
+        // The split is at the canonical length, even if the tail lies past it
+        let gapped = hex!(
+            "
+            0000000000000000000000000000000000000000000000000000000000000001
+            0000000000000000000000000000000000000000000000000000000000000060
+            0000000000000000000000000000000000000000000000000000000000000000
+            0000000000000000000000000000000000000000000000000000000000000005
+            68656c6c6f000000000000000000000000000000000000000000000000000000
+        "
+        );
+        let (decoded, trailing) = MyTy::abi_decode_params_with_trailing(&gapped, false).unwrap();
+        assert_eq!(decoded, value);
+        assert_eq!(trailing, &gapped[128..]);
+        assert_eq!(
+            MyTy::abi_decode_params_with_trailing(&gapped, true),
+            Err(Error::ReserMismatch)
+        );
+    }
+
+    #[test]
+    fn decode_exact_trailing_bytes() {
+        type MyTy = (sol_data::Uint<256>, sol_data::String);
+
+        let value = (U256::from(1), "hello".to_string());
+        let encoded = MyTy::abi_encode_params(&value);
+        assert_eq!(
+            MyTy::abi_decode_params_exact(&encoded, true).unwrap(),
+            value
+        );
+        assert_eq!(
+            MyTy::abi_decode_params_exact(&encoded, false).unwrap(),
+            value
+        );
+
+        let mut trailing = encoded.clone();
+        trailing.extend_from_slice(&[0; 32]);
+        assert_eq!(MyTy::abi_decode_params(&trailing, false).unwrap(), value);
+        assert_eq!(
+            MyTy::abi_decode_params(&trailing, true),
+            Err(Error::ReserMismatch)
+        );
+        assert_eq!(
+            MyTy::abi_decode_params_exact(&trailing, false),
+            Err(Error::BufferNotEmpty)
+        );
+        assert_eq!(
+            MyTy::abi_decode_params_exact(&trailing, true),
+            Err(Error::BufferNotEmpty)
+        );
+
+        let encoded = sol_data::Bool::abi_encode(&true);
+        assert!(sol_data::Bool::abi_decode_exact(&encoded, true).unwrap());
+        assert_eq!(
+            sol_data::Bool::abi_decode_exact(&[encoded, vec![0]].concat(), false),
+            Err(Error::BufferNotEmpty)
+        );
+    }
+
+    #[test]
+    fn decode_overrun_offset() {
+        type MyTy = (sol_data::Uint<256>, sol_data::String);
+
+        let encoded = MyTy::abi_encode_params(&(U256::from(1), "hello".to_string()));
+        for validate in [false, true] {
+            // the string's contents start after the head and the length words
+            assert_eq!(
+                MyTy::abi_decode_params(&encoded[..96], validate),
+                Err(Error::Overrun { offset: 96 })
+            );
+            assert_eq!(
+                MyTy::abi_decode_params(&encoded[..40], validate),
+                Err(Error::Overrun { offset: 32 })
+            );
+        }
+    }
+
+    #[test]
+    fn decode_recursion_limit() {
+        macro_rules! nested {
+            (@ty $w:ident $t:ty;) => { $t };
+            (@ty array $t:ty; $_x:tt $($rest:tt)*) => { nested!(@ty array sol_data::Array<$t>; $($rest)*) };
+            (@ty tuple $t:ty; $_x:tt $($rest:tt)*) => { nested!(@ty tuple ($t,); $($rest)*) };
+            (@val $w:ident $v:expr;) => { $v };
+            (@val array $v:expr; $_x:tt $($rest:tt)*) => { nested!(@val array vec![$v]; $($rest)*) };
+            (@val tuple $v:expr; $_x:tt $($rest:tt)*) => { nested!(@val tuple ($v,); $($rest)*) };
+        }
+
+        // static values are decoded in place and do not count towards the limit
+        type Static = nested!(@ty tuple sol_data::Bool; x x x x x x x x x x x x x x x x x);
+        let value = nested!(@val tuple true; x x x x x x x x x x x x x x x x x);
+        assert_eq!(
+            Static::abi_decode(&Static::abi_encode(&value), true).unwrap(),
+            value
+        );
+
+        // each dynamic array is a single indirection
+        type Shallow = nested!(@ty array sol_data::Bool; x x x x x x x x x x x x x x x x);
+        let value = nested!(@val array true; x x x x x x x x x x x x x x x x);
+        assert_eq!(
+            Shallow::abi_decode(&Shallow::abi_encode(&value), true).unwrap(),
+            value
+        );
+
+        type Deep = nested!(@ty array sol_data::Bool; x x x x x x x x x x x x x x x x x);
+        let value = nested!(@val array true; x x x x x x x x x x x x x x x x x);
+        let encoded = Deep::abi_encode(&value);
+        assert_eq!(Deep::abi_decode(&encoded, true).unwrap(), value);
+
+        let mut decoder = Decoder::new(&encoded, false);
+        decoder.set_recursion_limit(16);
+        assert!(matches!(
+            <Deep as SolType>::TokenType::decode_from(&mut decoder),
+            Err(Error::RecursionLimitExceeded(16))
+        ));
+    }
+
     #[test]
     fn signed_int_dirty_high_bytes() {
         type MyTy = sol_data::Int<8>;
@@ -8,7 +8,7 @@
 // except according to those terms.
 
 use crate::{
-    abi::{TokenSeq, TokenType},
+    abi::{ErasedToken, TokenSeq, TokenType},
     utils, Word,
 };
 use alloc::vec::Vec;
@@ -134,6 +134,22 @@ impl Encoder {
         token.encode_sequence(self);
     }
 
+    /// Appends a sequence of heterogeneous tokens, encoded as a tuple.
+    pub fn append_tokens(&mut self, tokens: &[&dyn ErasedToken]) {
+        let head_words = tokens.iter().map(|t| t.dyn_head_words()).sum::<usize>();
+        self.push_offset(head_words);
+
+        for inner in tokens {
+            inner.dyn_head_append(self);
+            self.bump_offset(inner.dyn_tail_words());
+        }
+        for inner in tokens {
+            inner.dyn_tail_append(self);
+        }
+
+        self.pop_offset();
+    }
+
     /// Append a sequence of bytes, padding to the next word.
     #[inline(always)]
     fn append_bytes(&mut self, bytes: &[u8]) {
@@ -203,6 +219,43 @@ pub fn encode_sequence<'a, T: TokenSeq<'a>>(token: &T) -> Vec<u8> {
     enc.into_bytes()
 }
 
+/// ABI-encodes a sequence of heterogeneous tokens.
+///
+/// This is equivalent to [`encode_sequence`] with a tuple of the same tokens,
+/// but the sequence can be composed at runtime rather than being known at
+/// compile time. Use this, for example, to encode a list of [`TokenType`]s
+/// whose types differ without going through `alloy-dyn-abi`'s value model.
+///
+/// See the [`abi`](super) module for more information.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::U256;
+/// use alloy_sol_types::{
+///     abi::{self, ErasedToken},
+///     sol_data, SolType,
+/// };
+///
+/// let a = sol_data::Uint::<256>::tokenize(&U256::from(1));
+/// let s = String::from("hello");
+/// let b = sol_data::String::tokenize(&s);
+///
+/// let mut tokens: Vec<&dyn ErasedToken> = vec![&a];
+/// tokens.push(&b);
+/// assert_eq!(abi::encode_tokens(tokens), abi::encode_sequence(&(a, b)));
+/// ```
+#[inline]
+pub fn encode_tokens<'t, I>(tokens: I) -> Vec<u8>
+where
+    I: IntoIterator<Item = &'t dyn ErasedToken>,
+{
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let mut enc = Encoder::with_capacity(tokens.iter().map(|t| t.dyn_total_words()).sum());
+    enc.append_tokens(&tokens);
+    enc.into_bytes()
+}
+
 /// Converts a reference to `T` into a reference to a tuple of length 1 (without
 /// copying).
 ///
@@ -219,6 +272,35 @@ mod tests {
     use alloc::{borrow::ToOwned, string::ToString, vec::Vec};
     use alloy_primitives::{hex, Address, U256};
 
+    #[test]
+    fn encode_tokens() {
+        use crate::abi::{self, ErasedToken};
+
+        assert_eq!(abi::encode_tokens([]), Vec::<u8>::new());
+
+        let address = Address::from([0x11u8; 20]);
+        let bytes = vec![0x22u8; 33];
+        let addresses = vec![address; 2];
+        let a = sol_data::Address::tokenize(&address);
+        let b = sol_data::Bytes::tokenize(&bytes);
+        let c = sol_data::Array::<sol_data::Address>::tokenize(&addresses);
+        let d = sol_data::Uint::<256>::tokenize(&U256::from(3));
+
+        let tokens: [&dyn ErasedToken; 4] = [&a, &b, &c, &d];
+        let expected = abi::encode_sequence(&(a, b, c.clone(), d));
+        let encoded = abi::encode_tokens(tokens);
+        assert_eq!(encoded, expected);
+        assert_eq!(
+            encoded,
+            <(
+                sol_data::Address,
+                sol_data::Bytes,
+                sol_data::Array<sol_data::Address>,
+                sol_data::Uint<256>
+            )>::abi_encode_sequence(&(address, bytes, addresses, U256::from(3)))
+        );
+    }
+
     #[test]
     fn encode_address() {
         let address = Address::from([0x11u8; 20]);
@@ -1152,4 +1234,63 @@ mod tests {
         assert_eq!(encoded_params.len() + 32, encoded.len());
         assert_eq!(encoded_params.len(), MyTy::abi_encoded_size(&data));
     }
+
+    #[test]
+    fn encode_params_following_dynamic_fixed_array() {
+        type MyTy = (sol_data::FixedArray<sol_data::String, 2>, sol_data::String);
+
+        let data = (["a".to_string(), "b".to_string()], "c".to_string());
+
+        let encoded_params = MyTy::abi_encode_params(&data);
+        let expected = hex!(
+            "
+    		0000000000000000000000000000000000000000000000000000000000000040
+    		0000000000000000000000000000000000000000000000000000000000000100
+    		0000000000000000000000000000000000000000000000000000000000000040
+    		0000000000000000000000000000000000000000000000000000000000000080
+    		0000000000000000000000000000000000000000000000000000000000000001
+    		6100000000000000000000000000000000000000000000000000000000000000
+    		0000000000000000000000000000000000000000000000000000000000000001
+    		6200000000000000000000000000000000000000000000000000000000000000
+    		0000000000000000000000000000000000000000000000000000000000000001
+    		6300000000000000000000000000000000000000000000000000000000000000
+    	"
+        )
+        .to_vec();
+        assert_eq!(encoded_params, expected);
+        assert_eq!(encoded_params.len(), MyTy::abi_encoded_size(&data));
+        assert_eq!(
+            MyTy::abi_decode_params(&encoded_params, true).unwrap(),
+            data
+        );
+    }
+
+    #[test]
+    fn encode_params_following_array_of_dynamic_tuples() {
+        type MyTy = (sol_data::Array<(sol_data::String,)>, sol_data::String);
+
+        let data = (vec![("a".to_string(),)], "c".to_string());
+
+        let encoded_params = MyTy::abi_encode_params(&data);
+        let expected = hex!(
+            "
+    		0000000000000000000000000000000000000000000000000000000000000040
+    		00000000000000000000000000000000000000000000000000000000000000e0
+    		0000000000000000000000000000000000000000000000000000000000000001
+    		0000000000000000000000000000000000000000000000000000000000000020
+    		0000000000000000000000000000000000000000000000000000000000000020
+    		0000000000000000000000000000000000000000000000000000000000000001
+    		6100000000000000000000000000000000000000000000000000000000000000
+    		0000000000000000000000000000000000000000000000000000000000000001
+    		6300000000000000000000000000000000000000000000000000000000000000
+    	"
+        )
+        .to_vec();
+        assert_eq!(encoded_params, expected);
+        assert_eq!(encoded_params.len(), MyTy::abi_encoded_size(&data));
+        assert_eq!(
+            MyTy::abi_decode_params(&encoded_params, true).unwrap(),
+            data
+        );
+    }
 }
@@ -35,12 +35,34 @@
 //! inferred not to be function parameters.
 //!
 //! This is the least useful one. Most users will not need it.
+//!
+//! [`encode_tokens`] is the equivalent for sequences of heterogeneous tokens
+//! composed at runtime, using the object-safe [`ErasedToken`] trait.
+//!
+//! ### `decode*_exact`
+//!
+//! [`decode_exact`], [`decode_params_exact`] and [`decode_sequence_exact`]
+//! behave like their counterparts, but return an error if the blob has bytes
+//! trailing the decoded tokens.
+//!
+//! ### `decode*_with_trailing`
+//!
+//! [`decode_with_trailing`], [`decode_params_with_trailing`] and
+//! [`decode_sequence_with_trailing`] behave like their counterparts, but
+//! return the bytes trailing the decoded tokens alongside them.
 
 mod encoder;
-pub use encoder::{encode, encode_params, encode_sequence, Encoder};
+pub use encoder::{encode, encode_params, encode_sequence, encode_tokens, Encoder};
 
 mod decoder;
-pub use decoder::{decode, decode_params, decode_sequence, Decoder};
+pub use decoder::{
+    decode, decode_exact, decode_params, decode_params_exact, decode_params_with_trailing,
+    decode_sequence, decode_sequence_exact, decode_sequence_with_trailing, decode_with_trailing,
+    Decoder,
+};
 
 pub mod token;
-pub use token::{TokenSeq, TokenType};
+pub use token::{ErasedToken, TokenSeq, TokenType};
+
+pub mod visitor;
+pub use visitor::{SeqKind, TokenVisitor};
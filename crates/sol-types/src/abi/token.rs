@@ -12,7 +12,7 @@
 //! See [`TokenType`] for more details.
 
 use crate::{
-    abi::{Decoder, Encoder},
+    abi::{Decoder, Encoder, SeqKind, TokenVisitor},
     Result, Word,
 };
 use alloc::vec::Vec;
@@ -74,6 +74,11 @@ pub trait TokenType<'de>: Sealed + Sized {
 
     /// Append tail words to the encoder.
     fn tail_append(&self, enc: &mut Encoder);
+
+    /// Walk the structure of the token with a visitor.
+    ///
+    /// See [`TokenVisitor`] for more details.
+    fn visit<V: TokenVisitor + ?Sized>(&self, visitor: &mut V);
 }
 
 /// A token composed of a sequence of other tokens.
@@ -92,6 +97,65 @@ pub trait TokenSeq<'a>: TokenType<'a> {
     fn decode_sequence(dec: &mut Decoder<'a>) -> Result<Self>;
 }
 
+/// An object-safe version of the encoding half of [`TokenType`].
+///
+/// This allows encoding sequences of heterogeneous tokens composed at runtime,
+/// such as `&[&dyn ErasedToken]`, with
+/// [`encode_tokens`](crate::abi::encode_tokens).
+///
+/// This trait is implemented for all [`TokenType`]s. The methods are prefixed
+/// with `dyn_` to avoid ambiguity with [`TokenType`]'s methods when both traits
+/// are in scope.
+pub trait ErasedToken {
+    /// Calculate the number of head words.
+    fn dyn_head_words(&self) -> usize;
+
+    /// Calculate the number of tail words.
+    fn dyn_tail_words(&self) -> usize;
+
+    /// Calculate the total number of head and tail words.
+    #[inline]
+    fn dyn_total_words(&self) -> usize {
+        self.dyn_head_words() + self.dyn_tail_words()
+    }
+
+    /// Append head words to the encoder.
+    fn dyn_head_append(&self, enc: &mut Encoder);
+
+    /// Append tail words to the encoder.
+    fn dyn_tail_append(&self, enc: &mut Encoder);
+
+    /// Walk the structure of the token with a visitor.
+    fn dyn_visit(&self, visitor: &mut dyn TokenVisitor);
+}
+
+impl<'a, T: TokenType<'a>> ErasedToken for T {
+    #[inline]
+    fn dyn_head_words(&self) -> usize {
+        self.head_words()
+    }
+
+    #[inline]
+    fn dyn_tail_words(&self) -> usize {
+        self.tail_words()
+    }
+
+    #[inline]
+    fn dyn_head_append(&self, enc: &mut Encoder) {
+        self.head_append(enc)
+    }
+
+    #[inline]
+    fn dyn_tail_append(&self, enc: &mut Encoder) {
+        self.tail_append(enc)
+    }
+
+    #[inline]
+    fn dyn_visit(&self, visitor: &mut dyn TokenVisitor) {
+        self.visit(visitor)
+    }
+}
+
 /// A single EVM word - T for any value type.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct WordToken(pub Word);
@@ -177,6 +241,11 @@ impl<'a> TokenType<'a> for WordToken {
 
     #[inline]
     fn tail_append(&self, _enc: &mut Encoder) {}
+
+    #[inline]
+    fn visit<V: TokenVisitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_word(&self.0);
+    }
 }
 
 impl WordToken {
@@ -231,7 +300,13 @@ impl<'de, T: TokenType<'de>, const N: usize> TokenType<'de> for FixedSeqToken<T,
             dec.raw_child()
         };
 
-        Self::decode_sequence(&mut child)
+        let res = Self::decode_sequence(&mut child)?;
+
+        if !Self::DYNAMIC {
+            dec.take_offset_from(&child);
+        }
+
+        Ok(res)
     }
 
     #[inline]
@@ -246,7 +321,7 @@ impl<'de, T: TokenType<'de>, const N: usize> TokenType<'de> for FixedSeqToken<T,
     #[inline]
     fn tail_words(&self) -> usize {
         if Self::DYNAMIC {
-            N
+            self.0.iter().map(|t| t.head_words() + t.tail_words()).sum()
         } else {
             0
         }
@@ -269,6 +344,15 @@ impl<'de, T: TokenType<'de>, const N: usize> TokenType<'de> for FixedSeqToken<T,
             self.encode_sequence(enc);
         }
     }
+
+    #[inline]
+    fn visit<V: TokenVisitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.enter_seq(SeqKind::Fixed, N, Self::DYNAMIC);
+        for inner in &self.0 {
+            inner.visit(visitor);
+        }
+        visitor.leave_seq(SeqKind::Fixed);
+    }
 }
 
 impl<'de, T: TokenType<'de>, const N: usize> TokenSeq<'de> for FixedSeqToken<T, N> {
@@ -359,7 +443,12 @@ impl<'de, T: TokenType<'de>> TokenType<'de> for DynSeqToken<T> {
 
     #[inline]
     fn tail_words(&self) -> usize {
-        1 + self.0.iter().map(TokenType::total_words).sum::<usize>()
+        // not `total_words`, which for dynamic tuples excludes their offset
+        1 + self
+            .0
+            .iter()
+            .map(|t| t.head_words() + t.tail_words())
+            .sum::<usize>()
     }
 
     #[inline]
@@ -372,6 +461,15 @@ impl<'de, T: TokenType<'de>> TokenType<'de> for DynSeqToken<T> {
         enc.append_seq_len(self.0.len());
         self.encode_sequence(enc);
     }
+
+    #[inline]
+    fn visit<V: TokenVisitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.enter_seq(SeqKind::Dynamic, self.0.len(), true);
+        for inner in &self.0 {
+            inner.visit(visitor);
+        }
+        visitor.leave_seq(SeqKind::Dynamic);
+    }
 }
 
 impl<'de, T: TokenType<'de>> TokenSeq<'de> for DynSeqToken<T> {
@@ -466,6 +564,11 @@ impl<'de: 'a, 'a> TokenType<'de> for PackedSeqToken<'a> {
     fn tail_append(&self, enc: &mut Encoder) {
         enc.append_packed_seq(self.0);
     }
+
+    #[inline]
+    fn visit<V: TokenVisitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_packed_seq(self.0);
+    }
 }
 
 impl PackedSeqToken<'_> {
@@ -565,6 +668,16 @@ macro_rules! tuple_impls {
                     enc.pop_offset();
                 }
             }
+
+            #[inline]
+            fn visit<V: TokenVisitor + ?Sized>(&self, visitor: &mut V) {
+                let ($($ty,)+) = self;
+                visitor.enter_seq(SeqKind::Tuple, $count, Self::DYNAMIC);
+                $(
+                    $ty.visit(visitor);
+                )+
+                visitor.leave_seq(SeqKind::Tuple);
+            }
         }
 
         #[allow(non_snake_case)]
@@ -619,6 +732,12 @@ impl<'de> TokenType<'de> for () {
 
     #[inline]
     fn tail_append(&self, _enc: &mut Encoder) {}
+
+    #[inline]
+    fn visit<V: TokenVisitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.enter_seq(SeqKind::Tuple, 0, false);
+        visitor.leave_seq(SeqKind::Tuple);
+    }
 }
 
 impl<'de> TokenSeq<'de> for () {
@@ -639,6 +758,7 @@ all_the_tuples!(tuple_impls);
 mod tests {
     use super::*;
     use crate::{sol_data, SolType};
+    use alloc::string::{String, ToString};
     use alloy_primitives::B256;
 
     macro_rules! assert_type_check {
@@ -700,4 +820,61 @@ mod tests {
             ]),
         );
     }
+
+    #[derive(Default)]
+    struct Events(Vec<String>);
+
+    impl TokenVisitor for Events {
+        fn visit_word(&mut self, word: &Word) {
+            self.0.push(format!("word {}", word[31]));
+        }
+
+        fn visit_packed_seq(&mut self, bytes: &[u8]) {
+            self.0.push(format!("packed {}", bytes.len()));
+        }
+
+        fn enter_seq(&mut self, kind: SeqKind, len: usize, dynamic: bool) {
+            self.0.push(format!("enter {kind:?} {len} {dynamic}"));
+        }
+
+        fn leave_seq(&mut self, kind: SeqKind) {
+            self.0.push(format!("leave {kind:?}"));
+        }
+    }
+
+    #[test]
+    fn visit() {
+        type MyTy = (
+            sol_data::Uint<8>,
+            sol_data::FixedArray<sol_data::Bool, 2>,
+            sol_data::Array<sol_data::String>,
+        );
+        let value = (1u8, [false, true], vec!["ab".to_string(), "c".to_string()]);
+        let token = MyTy::tokenize(&value);
+
+        let mut events = Events::default();
+        token.visit(&mut events);
+        let expected = [
+            "enter Tuple 3 true",
+            "word 1",
+            "enter Fixed 2 false",
+            "word 0",
+            "word 1",
+            "leave Fixed",
+            "enter Dynamic 2 true",
+            "packed 2",
+            "packed 1",
+            "leave Dynamic",
+            "leave Tuple",
+        ];
+        assert_eq!(events.0, expected);
+
+        let mut erased = Events::default();
+        token.dyn_visit(&mut erased);
+        assert_eq!(erased.0, expected);
+
+        let mut events = Events::default();
+        ().visit(&mut events);
+        assert_eq!(events.0, ["enter Tuple 0 false", "leave Tuple"]);
+    }
 }
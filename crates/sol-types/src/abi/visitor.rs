@@ -0,0 +1,89 @@
+//! Token visitors.
+//!
+//! [`TokenVisitor`] walks the structure of a token in encoding order, without
+//! encoding it. This allows writing generic algorithms over tokens once,
+//! rather than once per [`TokenType`](crate::abi::TokenType) implementation.
+//!
+//! # Examples
+//!
+//! Counting the number of words and bytes in a token:
+//!
+//! ```
+//! use alloy_primitives::{Address, U256};
+//! use alloy_sol_types::{
+//!     abi::{SeqKind, TokenType, TokenVisitor},
+//!     sol_data, SolType, Word,
+//! };
+//!
+//! #[derive(Default)]
+//! struct Counter {
+//!     words: usize,
+//!     bytes: usize,
+//! }
+//!
+//! impl TokenVisitor for Counter {
+//!     fn visit_word(&mut self, _word: &Word) {
+//!         self.words += 1;
+//!     }
+//!
+//!     fn visit_packed_seq(&mut self, bytes: &[u8]) {
+//!         self.bytes += bytes.len();
+//!     }
+//! }
+//!
+//! type MyTy = (sol_data::Address, sol_data::Array<sol_data::Bytes>);
+//! let value = (Address::ZERO, vec![vec![1, 2, 3], vec![4]]);
+//!
+//! let mut counter = Counter::default();
+//! MyTy::tokenize(&value).visit(&mut counter);
+//! assert_eq!(counter.words, 1);
+//! assert_eq!(counter.bytes, 4);
+//! ```
+
+use crate::Word;
+
+/// The kind of a sequence token, as passed to [`TokenVisitor::enter_seq`] and
+/// [`TokenVisitor::leave_seq`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SeqKind {
+    /// A fixed-length sequence, `T[N]`:
+    /// [`FixedSeqToken`](crate::abi::token::FixedSeqToken).
+    Fixed,
+    /// A dynamic-length sequence, `T[]`:
+    /// [`DynSeqToken`](crate::abi::token::DynSeqToken).
+    Dynamic,
+    /// A tuple, `(T, U, ...)`.
+    Tuple,
+}
+
+/// A visitor over the structure of a token.
+///
+/// Tokens are visited depth-first, in encoding order, by
+/// [`TokenType::visit`](crate::abi::TokenType::visit). Sequences are
+/// delimited by calls to [`enter_seq`](Self::enter_seq) and
+/// [`leave_seq`](Self::leave_seq), between which their elements are visited.
+///
+/// All methods do nothing by default.
+///
+/// See the [module-level documentation](self) for an example.
+pub trait TokenVisitor {
+    /// Visits a single word: [`WordToken`](crate::abi::token::WordToken).
+    #[inline]
+    fn visit_word(&mut self, _word: &Word) {}
+
+    /// Visits a dynamic-length byte array, `bytes` or `string`:
+    /// [`PackedSeqToken`](crate::abi::token::PackedSeqToken).
+    #[inline]
+    fn visit_packed_seq(&mut self, _bytes: &[u8]) {}
+
+    /// Called before visiting the `len` elements of a sequence.
+    ///
+    /// `dynamic` is `true` if the sequence is dynamically-sized, i.e. if it is
+    /// encoded in the tail rather than in the head.
+    #[inline]
+    fn enter_seq(&mut self, _kind: SeqKind, _len: usize, _dynamic: bool) {}
+
+    /// Called after visiting all the elements of a sequence.
+    #[inline]
+    fn leave_seq(&mut self, _kind: SeqKind) {}
+}
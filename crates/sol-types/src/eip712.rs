@@ -1,5 +1,5 @@
 use crate::SolValue;
-use alloc::{borrow::Cow, string::String, vec::Vec};
+use alloc::{borrow::Cow, collections::BTreeMap, string::String, vec::Vec};
 use alloy_primitives::{keccak256, Address, FixedBytes, B256, U256};
 
 /// EIP-712 domain attributes used in determining the domain separator.
@@ -190,6 +190,172 @@ impl Eip712Domain {
     }
 }
 
+/// A registry of [EIP-712 domains](Eip712Domain), indexed by the chain ID and
+/// address of their verifying contract.
+///
+/// Domains are registered under an explicit key, so that domains which omit
+/// the `chainId` or `verifyingContract` fields can also be looked up.
+///
+/// With the `eip712-serde` feature, the registry can be loaded from any
+/// self-describing format, such as JSON or TOML, as a map of chain IDs to maps
+/// of contract addresses to domains.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::address;
+/// use alloy_sol_types::{eip712_domain, Eip712DomainRegistry};
+///
+/// let permit2 = address!("000000000022D473030F116dDEE9F6B43aC78BA3");
+/// let mut registry = Eip712DomainRegistry::new();
+/// registry.insert(
+///     1,
+///     permit2,
+///     eip712_domain! {
+///         name: "Permit2",
+///         chain_id: 1,
+///         verifying_contract: permit2,
+///     },
+/// );
+///
+/// assert_eq!(
+///     registry.get(1, permit2).unwrap().name.as_deref(),
+///     Some("Permit2")
+/// );
+/// assert!(registry.get(10, permit2).is_none());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Eip712DomainRegistry {
+    domains: BTreeMap<(u64, Address), Eip712Domain>,
+}
+
+impl Eip712DomainRegistry {
+    /// Creates a new, empty registry.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of registered domains.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.domains.len()
+    }
+
+    /// Returns `true` if no domains are registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.domains.is_empty()
+    }
+
+    /// Registers the domain of the given verifying contract on the given
+    /// chain, returning the previously registered domain, if any.
+    #[inline]
+    pub fn insert(
+        &mut self,
+        chain_id: u64,
+        verifying_contract: Address,
+        domain: Eip712Domain,
+    ) -> Option<Eip712Domain> {
+        self.domains.insert((chain_id, verifying_contract), domain)
+    }
+
+    /// Returns the domain of the given verifying contract on the given chain.
+    #[inline]
+    pub fn get(&self, chain_id: u64, verifying_contract: Address) -> Option<&Eip712Domain> {
+        self.domains.get(&(chain_id, verifying_contract))
+    }
+
+    /// Removes and returns the domain of the given verifying contract on the
+    /// given chain.
+    #[inline]
+    pub fn remove(&mut self, chain_id: u64, verifying_contract: Address) -> Option<Eip712Domain> {
+        self.domains.remove(&(chain_id, verifying_contract))
+    }
+
+    /// Returns an iterator over the registered domains with their chain ID and
+    /// verifying contract, ordered by chain ID and then by address.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (u64, Address, &Eip712Domain)> + '_ {
+        self.domains
+            .iter()
+            .map(|(&(chain_id, address), domain)| (chain_id, address, domain))
+    }
+
+    /// Returns an iterator over the domains registered on the given chain,
+    /// with their verifying contract.
+    #[inline]
+    pub fn chain(&self, chain_id: u64) -> impl Iterator<Item = (Address, &Eip712Domain)> + '_ {
+        self.domains
+            .range((chain_id, Address::ZERO)..=(chain_id, Address::repeat_byte(0xff)))
+            .map(|(&(_, address), domain)| (address, domain))
+    }
+}
+
+impl Extend<(u64, Address, Eip712Domain)> for Eip712DomainRegistry {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (u64, Address, Eip712Domain)>>(&mut self, iter: I) {
+        self.domains.extend(
+            iter.into_iter()
+                .map(|(chain_id, address, domain)| ((chain_id, address), domain)),
+        );
+    }
+}
+
+impl FromIterator<(u64, Address, Eip712Domain)> for Eip712DomainRegistry {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (u64, Address, Eip712Domain)>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        registry.extend(iter);
+        registry
+    }
+}
+
+#[cfg(feature = "eip712-serde")]
+impl serde::Serialize for Eip712DomainRegistry {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use alloc::string::ToString;
+
+        struct Chain<'a>(&'a Eip712DomainRegistry, u64);
+
+        impl serde::Serialize for Chain<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_map(self.0.chain(self.1))
+            }
+        }
+
+        // Chain IDs are serialized as strings, as most formats only allow
+        // string keys.
+        let mut chain_ids: Vec<u64> = self.domains.keys().map(|&(id, _)| id).collect();
+        chain_ids.dedup();
+        serializer.collect_map(
+            chain_ids
+                .into_iter()
+                .map(|id| (id.to_string(), Chain(self, id))),
+        )
+    }
+}
+
+#[cfg(feature = "eip712-serde")]
+impl<'de> serde::Deserialize<'de> for Eip712DomainRegistry {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let chains =
+            BTreeMap::<String, BTreeMap<Address, Eip712Domain>>::deserialize(deserializer)?;
+        let mut registry = Self::new();
+        for (chain_id, domains) in chains {
+            let chain_id = chain_id.parse::<u64>().map_err(|e| {
+                serde::de::Error::custom(format_args!("invalid chain ID {chain_id:?}: {e}"))
+            })?;
+            registry.extend(
+                domains
+                    .into_iter()
+                    .map(|(address, domain)| (chain_id, address, domain)),
+            );
+        }
+        Ok(registry)
+    }
+}
+
 /// Convenience macro to instantiate an [EIP-712 domain](Eip712Domain).
 ///
 /// This macro allows you to instantiate an [EIP-712 domain](Eip712Domain)
@@ -347,4 +513,93 @@ mod tests {
             version: my_cow.into_owned(),
         };
     }
+
+    #[test]
+    fn registry() {
+        let a = Address::repeat_byte(0xaa);
+        let b = Address::repeat_byte(0xbb);
+        let domain = |chain_id: u64| eip712_domain! { name: "abcd", chain_id: chain_id, };
+
+        let mut registry: Eip712DomainRegistry =
+            [(10, b, domain(10)), (1, b, domain(1)), (1, a, domain(1))]
+                .into_iter()
+                .collect();
+        assert_eq!(registry.len(), 3);
+        assert_eq!(registry.get(10, b), Some(&domain(10)));
+        assert_eq!(registry.get(10, a), None);
+        assert_eq!(
+            registry
+                .iter()
+                .map(|(id, address, _)| (id, address))
+                .collect::<Vec<_>>(),
+            [(1, a), (1, b), (10, b)]
+        );
+        assert_eq!(registry.chain(1).count(), 2);
+        assert_eq!(registry.chain(5).count(), 0);
+
+        assert_eq!(
+            registry.insert(10, b, eip712_domain! { name: "efgh", }),
+            Some(domain(10))
+        );
+        assert_eq!(
+            registry.remove(10, b),
+            Some(eip712_domain! { name: "efgh", })
+        );
+        assert_eq!(registry.remove(10, b), None);
+        assert_eq!(registry.len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "eip712-serde")]
+    fn registry_serde() {
+        let a = Address::repeat_byte(0xaa);
+        let b = Address::repeat_byte(0xbb);
+        let registry: Eip712DomainRegistry = [
+            (
+                1,
+                a,
+                eip712_domain! { name: "A", version: "1", chain_id: 1, verifying_contract: a, },
+            ),
+            (1, b, eip712_domain! { name: "B", }),
+            (137, a, eip712_domain! { name: "A", version: "2", }),
+        ]
+        .into_iter()
+        .collect();
+
+        let json = serde_json::to_string(&registry).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Eip712DomainRegistry>(&json).unwrap(),
+            registry
+        );
+        let json =
+            r#"{"137":{"0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa":{"name":"A","version":"2"}}}"#;
+        let parsed: Eip712DomainRegistry = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed.get(137, a), registry.get(137, a));
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+
+        let toml = r#"
+            [1.0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa]
+            name = "A"
+            version = "1"
+            chainId = "0x1"
+            verifyingContract = "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+
+            [1.0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb]
+            name = "B"
+
+            [137.0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa]
+            name = "A"
+            version = "2"
+        "#;
+        assert_eq!(
+            toml::from_str::<Eip712DomainRegistry>(toml).unwrap(),
+            registry
+        );
+        assert_eq!(
+            toml::from_str::<Eip712DomainRegistry>(&toml::to_string(&registry).unwrap()).unwrap(),
+            registry
+        );
+
+        assert!(serde_json::from_str::<Eip712DomainRegistry>(r#"{"mainnet":{}}"#).is_err());
+    }
 }
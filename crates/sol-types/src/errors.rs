@@ -26,7 +26,11 @@ pub enum Error {
     },
 
     /// Overran deserialization buffer.
-    Overrun,
+    Overrun {
+        /// The offset of the read that overran the buffer, from the start of
+        /// the data being decoded.
+        offset: usize,
+    },
 
     /// Trailing bytes in deserialization buffer.
     BufferNotEmpty,
@@ -34,6 +38,9 @@ pub enum Error {
     /// Validation reserialization did not match input.
     ReserMismatch,
 
+    /// Exceeded the maximum nesting depth of dynamic values while decoding.
+    RecursionLimitExceeded(u8),
+
     /// Invalid enum value.
     InvalidEnumValue {
         /// The name of the enum.
@@ -52,6 +59,39 @@ pub enum Error {
         selector: alloy_primitives::FixedBytes<4>,
     },
 
+    /// The data does not start with the expected selector.
+    SelectorMismatch {
+        /// The expected selector.
+        expected: alloy_primitives::FixedBytes<4>,
+        /// The actual selector.
+        actual: alloy_primitives::FixedBytes<4>,
+    },
+
+    /// The number of topics of a log does not match the expected event's.
+    TopicLengthMismatch {
+        /// The expected number of topics.
+        expected: usize,
+        /// The actual number of topics.
+        actual: usize,
+    },
+
+    /// The first topic of a log is not the signature hash of the expected
+    /// event.
+    EventSignatureMismatch {
+        /// The expected signature hash.
+        expected: alloy_primitives::B256,
+        /// The actual first topic.
+        actual: alloy_primitives::B256,
+    },
+
+    /// Unknown event signature hash.
+    UnknownEventSignature {
+        /// The type name.
+        name: &'static str,
+        /// The unknown signature hash.
+        signature_hash: alloy_primitives::B256,
+    },
+
     /// Hex error.
     FromHexError(hex::FromHexError),
 
@@ -79,9 +119,14 @@ impl fmt::Display for Error {
                 f,
                 "type check failed for \"{expected_type}\" with data: {data}",
             ),
-            Self::Overrun => f.write_str("buffer overrun while deserializing"),
+            Self::Overrun { offset } => {
+                write!(f, "buffer overrun while deserializing at offset {offset}")
+            }
             Self::BufferNotEmpty => f.write_str("buffer not empty after deserialization"),
             Self::ReserMismatch => f.write_str("reserialization did not match original"),
+            Self::RecursionLimitExceeded(limit) => {
+                write!(f, "recursion limit of {limit} exceeded during decoding")
+            }
             Self::InvalidEnumValue { name, value, max } => write!(
                 f,
                 "`{value}` is not a valid {name} enum value (max: `{max}`)"
@@ -89,6 +134,27 @@ impl fmt::Display for Error {
             Self::UnknownSelector { name, selector } => {
                 write!(f, "unknown selector `{selector}` for {name}")
             }
+            Self::SelectorMismatch { expected, actual } => {
+                write!(f, "selector mismatch: expected {expected}, got {actual}")
+            }
+            Self::TopicLengthMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "topic list length mismatch: expected {expected} topics, got {actual}"
+                )
+            }
+            Self::EventSignatureMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "event signature mismatch: expected {expected}, got {actual}"
+                )
+            }
+            Self::UnknownEventSignature {
+                name,
+                signature_hash,
+            } => {
+                write!(f, "unknown event signature `{signature_hash}` for {name}")
+            }
             Self::FromHexError(e) => e.fmt(f),
             Self::Other(e) => f.write_str(e),
         }
@@ -127,6 +193,19 @@ impl Error {
         }
     }
 
+    /// Instantiates a new [`Error::SelectorMismatch`] with the provided data,
+    /// or an [`Error::Overrun`] if it is shorter than a selector.
+    #[cold]
+    pub fn selector_mismatch(expected: [u8; 4], data: &[u8]) -> Self {
+        match data.get(..4) {
+            Some(actual) => Self::SelectorMismatch {
+                expected: expected.into(),
+                actual: alloy_primitives::FixedBytes::from_slice(actual),
+            },
+            None => Self::Overrun { offset: 0 },
+        }
+    }
+
     /// Instantiates a new [`Error::UnknownSelector`] with the provided data.
     #[cold]
     pub fn unknown_selector(name: &'static str, selector: [u8; 4]) -> Self {
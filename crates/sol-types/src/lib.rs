@@ -178,17 +178,25 @@ pub use errors::{Error, Result};
 
 mod impl_core;
 
+#[cfg(feature = "multicall")]
+pub mod multicall;
+
+#[cfg(feature = "standards")]
+pub mod standards;
+
+pub mod precompiles;
+
 mod types;
 pub use types::{
     data_type as sol_data, decode_revert_reason, ContractError, EventTopic, GenericContractError,
-    Panic, PanicKind, Revert, Selectors, SolCall, SolEnum, SolError, SolEvent, SolInterface,
-    SolStruct, SolType, SolValue, TopicList,
+    Panic, PanicKind, Revert, SelectorCollisions, Selectors, SolCall, SolEnum, SolError, SolEvent,
+    SolInterface, SolStruct, SolType, SolValue, TopicList,
 };
 
 pub mod utils;
 
 mod eip712;
-pub use eip712::Eip712Domain;
+pub use eip712::{Eip712Domain, Eip712DomainRegistry};
 
 /// The ABI word type.
 pub type Word = alloy_primitives::B256;
@@ -206,10 +214,15 @@ pub mod private {
         vec::Vec,
     };
     pub use alloy_primitives::{
-        bytes, keccak256, Address, Bytes, FixedBytes, Function, Signed, Uint, B256, I256, U256,
+        bytes, keccak256, Address, Bytes, FixedBytes, Function, Log, Signed, Uint, B256, I256, U256,
     };
     pub use core::{convert::From, default::Default, option::Option, result::Result};
 
+    #[cfg(feature = "arbitrary")]
+    pub use arbitrary;
+    #[cfg(feature = "arbitrary")]
+    pub use proptest;
+
     pub use Option::{None, Some};
     pub use Result::{Err, Ok};
 
@@ -233,6 +246,87 @@ pub mod private {
         }
         fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>);
         fn stv_eip712_data_word(&self) -> super::Word;
+
+        /// Appends the ABI encoding of the value to `out`, without the offset
+        /// pointing to it if it is dynamic. This is `stv_abi_encoded_size`
+        /// bytes long.
+        ///
+        /// Defaults to encoding the value's tokens.
+        #[inline]
+        fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+            use super::abi::TokenType;
+
+            let token = self.stv_to_tokens();
+            let mut enc = super::abi::Encoder::with_capacity(token.total_words());
+            if T::DYNAMIC {
+                token.tail_append(&mut enc);
+            } else {
+                token.head_append(&mut enc);
+            }
+            out.extend_from_slice(&enc.into_bytes());
+        }
+    }
+
+    /// Encodes a sequence of values, such as the fields of a struct or the
+    /// elements of an array, directly into a buffer: the heads of all the
+    /// values must be written with [`head`](Self::head), and then their tails
+    /// with [`tail`](Self::tail), in the same order.
+    #[derive(Debug)]
+    pub struct SeqEncoder<'a> {
+        out: &'a mut Vec<u8>,
+        offset: usize,
+    }
+
+    impl<'a> SeqEncoder<'a> {
+        /// Creates a new encoder for a sequence whose heads are `head_size`
+        /// bytes long.
+        #[inline]
+        pub fn new(out: &'a mut Vec<u8>, head_size: usize) -> Self {
+            Self {
+                out,
+                offset: head_size,
+            }
+        }
+
+        /// Writes the head of a value: its encoding if it is static, or the
+        /// offset of its tail otherwise.
+        #[inline]
+        pub fn head<T: super::SolType, V: ?Sized + SolTypeValue<T>>(&mut self, value: &V) {
+            if T::DYNAMIC {
+                self.out
+                    .extend_from_slice(super::utils::pad_usize(self.offset).as_slice());
+                self.offset += value.stv_abi_encoded_size();
+            } else {
+                value.stv_abi_encode_to(self.out);
+            }
+        }
+
+        /// Writes the tail of a value, if it is dynamic.
+        #[inline]
+        pub fn tail<T: super::SolType, V: ?Sized + SolTypeValue<T>>(&mut self, value: &V) {
+            if T::DYNAMIC {
+                value.stv_abi_encode_to(self.out);
+            }
+        }
+    }
+
+    /// Returns the size of the head of a value of type `T` in a sequence.
+    #[inline(always)]
+    pub const fn head_size<T: super::SolType>() -> usize {
+        match T::ENCODED_SIZE {
+            Some(size) => size,
+            None => 32,
+        }
+    }
+
+    /// Appends a length-prefixed, padded byte string to `out`.
+    #[inline]
+    pub fn encode_packed_seq_to(bytes: &[u8], out: &mut Vec<u8>) {
+        let padded_len = super::utils::next_multiple_of_32(bytes.len());
+        out.reserve(32 + padded_len);
+        out.extend_from_slice(super::utils::pad_usize(bytes.len()).as_slice());
+        out.extend_from_slice(bytes);
+        out.resize(out.len() + padded_len - bytes.len(), 0);
     }
 
     #[inline(always)]
@@ -0,0 +1,293 @@
+//! [Multicall3] interface and call-building helpers.
+//!
+//! [Multicall3] is deployed at the same [address](MULTICALL3_ADDRESS) on most
+//! EVM chains, and aggregates several calls into a single one. The full
+//! interface is available as [`IMulticall3`], and [`Aggregate3`] builds
+//! `aggregate3` calls out of typed calls and decodes their typed results.
+//!
+//! [Multicall3]: https://github.com/mds1/multicall
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{Address, U256};
+//! use alloy_sol_types::{
+//!     multicall::{Aggregate3, IMulticall3},
+//!     sol, SolCall, SolType,
+//! };
+//!
+//! sol! {
+//!     function balanceOf(address owner) returns (uint256);
+//! }
+//!
+//! let token = Address::repeat_byte(0x11);
+//! let owners = [Address::repeat_byte(0x22), Address::repeat_byte(0x33)];
+//! let multicall =
+//!     Aggregate3::new(owners.map(|owner| (token, balanceOfCall { owner }))).allow_failure(true);
+//!
+//! // send `multicall.calldata()` to `MULTICALL3_ADDRESS`...
+//! let calldata = multicall.calldata();
+//! assert_eq!(calldata[..4], IMulticall3::aggregate3Call::SELECTOR);
+//!
+//! // ... and decode the typed results
+//! let results = vec![
+//!     IMulticall3::Result {
+//!         success: true,
+//!         returnData: balanceOfCall::abi_encode_returns(&(U256::from(100),)),
+//!     },
+//!     IMulticall3::Result {
+//!         success: false,
+//!         returnData: vec![],
+//!     },
+//! ];
+//! let return_data = IMulticall3::aggregate3Call::abi_encode_returns(&(results,));
+//! let returns = multicall.decode_returns(&return_data, true).unwrap();
+//! assert_eq!(returns[0].as_ref().unwrap()._0, U256::from(100));
+//! assert!(returns[1].is_err());
+//! ```
+
+use crate::{sol, Result, SolCall};
+use alloc::vec::Vec;
+use alloy_primitives::{address, Address};
+use core::marker::PhantomData;
+
+/// The address at which [Multicall3](self) is deployed on most chains.
+pub const MULTICALL3_ADDRESS: Address = address!("cA11bde05977b3631167028862bE2a173976CA11");
+
+sol! {
+    /// The [Multicall3](https://github.com/mds1/multicall) interface.
+    #[allow(missing_docs)]
+    #[derive(Debug, PartialEq, Eq)]
+    interface IMulticall3 {
+        struct Call {
+            address target;
+            bytes callData;
+        }
+
+        struct Call3 {
+            address target;
+            bool allowFailure;
+            bytes callData;
+        }
+
+        struct Call3Value {
+            address target;
+            bool allowFailure;
+            uint256 value;
+            bytes callData;
+        }
+
+        struct Result {
+            bool success;
+            bytes returnData;
+        }
+
+        function aggregate(Call[] calldata calls) external payable returns (uint256 blockNumber, bytes[] memory returnData);
+        function aggregate3(Call3[] calldata calls) external payable returns (Result[] memory returnData);
+        function aggregate3Value(Call3Value[] calldata calls) external payable returns (Result[] memory returnData);
+        function blockAndAggregate(Call[] calldata calls) external payable returns (uint256 blockNumber, bytes32 blockHash, Result[] memory returnData);
+        function tryAggregate(bool requireSuccess, Call[] calldata calls) external payable returns (Result[] memory returnData);
+        function tryBlockAndAggregate(bool requireSuccess, Call[] calldata calls) external payable returns (uint256 blockNumber, bytes32 blockHash, Result[] memory returnData);
+
+        function getBasefee() external view returns (uint256 basefee);
+        function getBlockHash(uint256 blockNumber) external view returns (bytes32 blockHash);
+        function getBlockNumber() external view returns (uint256 blockNumber);
+        function getChainId() external view returns (uint256 chainid);
+        function getCurrentBlockCoinbase() external view returns (address coinbase);
+        function getCurrentBlockDifficulty() external view returns (uint256 difficulty);
+        function getCurrentBlockGasLimit() external view returns (uint256 gaslimit);
+        function getCurrentBlockTimestamp() external view returns (uint256 timestamp);
+        function getEthBalance(address addr) external view returns (uint256 balance);
+        function getLastBlockHash() external view returns (bytes32 blockHash);
+    }
+}
+
+/// Builds an `aggregate3` call to [Multicall3](self) out of calls of type `C`,
+/// and decodes their results.
+///
+/// Calls of different types can be aggregated with a [`SolInterface`]
+/// enum, such as the `Calls` enum generated for a contract by [`sol!`], by
+/// building the [`IMulticall3::Call3`]s manually.
+///
+/// See the [module-level documentation](self) for an example.
+///
+/// [`SolInterface`]: crate::SolInterface
+pub struct Aggregate3<C> {
+    calls: Vec<IMulticall3::Call3>,
+    _marker: PhantomData<fn() -> C>,
+}
+
+impl<C> Clone for Aggregate3<C> {
+    fn clone(&self) -> Self {
+        Self {
+            calls: self.calls.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C> core::fmt::Debug for Aggregate3<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Aggregate3")
+            .field("calls", &self.calls)
+            .finish()
+    }
+}
+
+impl<C: SolCall> Aggregate3<C> {
+    /// Creates a new `aggregate3` call out of `(target, call)` pairs.
+    ///
+    /// Calls are not allowed to fail by default: the whole multicall reverts if
+    /// any of them does. See [`allow_failure`](Self::allow_failure).
+    pub fn new<I: IntoIterator<Item = (Address, C)>>(calls: I) -> Self {
+        let calls = calls
+            .into_iter()
+            .map(|(target, call)| IMulticall3::Call3 {
+                target,
+                allowFailure: false,
+                callData: call.abi_encode(),
+            })
+            .collect();
+        Self {
+            calls,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Sets whether the calls are allowed to fail without reverting the
+    /// whole multicall.
+    pub fn allow_failure(mut self, allow_failure: bool) -> Self {
+        for call in &mut self.calls {
+            call.allowFailure = allow_failure;
+        }
+        self
+    }
+
+    /// Returns the aggregated calls.
+    #[inline]
+    pub fn calls(&self) -> &[IMulticall3::Call3] {
+        &self.calls
+    }
+
+    /// Returns the number of aggregated calls.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Returns `true` if there are no aggregated calls.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Returns the `aggregate3` call.
+    #[inline]
+    pub fn to_call(&self) -> IMulticall3::aggregate3Call {
+        IMulticall3::aggregate3Call {
+            calls: self.calls.clone(),
+        }
+    }
+
+    /// Returns the ABI-encoded `aggregate3` calldata, including the selector.
+    #[inline]
+    pub fn calldata(&self) -> Vec<u8> {
+        IMulticall3::aggregate3Call::abi_encode(&self.to_call())
+    }
+
+    /// Decodes the return data of the `aggregate3` call into the results of
+    /// the individual calls, in order.
+    ///
+    /// Each result is either the decoded return of a successful call, or the
+    /// revert data of a failed one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the return data cannot be decoded, if the number
+    /// of results does not match the number of calls, or if the return data
+    /// of a successful call cannot be decoded.
+    pub fn decode_returns(
+        &self,
+        data: &[u8],
+        validate: bool,
+    ) -> Result<Vec<core::result::Result<C::Return, Vec<u8>>>> {
+        let results = IMulticall3::aggregate3Call::abi_decode_returns(data, validate)?.returnData;
+        if results.len() != self.calls.len() {
+            return Err(crate::Error::custom(format!(
+                "expected {} multicall results, got {}",
+                self.calls.len(),
+                results.len()
+            )))
+        }
+        results
+            .into_iter()
+            .map(|result| {
+                if result.success {
+                    C::abi_decode_returns(&result.returnData, validate).map(Ok)
+                } else {
+                    Ok(Err(result.returnData))
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{hex, U256};
+
+    sol! {
+        function getUint(uint256 x) returns (uint256);
+    }
+
+    #[test]
+    fn selectors() {
+        assert_eq!(IMulticall3::aggregate3Call::SELECTOR, hex!("82ad56cb"));
+        assert_eq!(IMulticall3::aggregateCall::SELECTOR, hex!("252dba42"));
+        assert_eq!(IMulticall3::tryAggregateCall::SELECTOR, hex!("bce38bd7"));
+        assert_eq!(IMulticall3::aggregate3ValueCall::SELECTOR, hex!("174dea71"));
+    }
+
+    #[test]
+    fn roundtrip() {
+        let target = Address::repeat_byte(0x42);
+        let multicall =
+            Aggregate3::new((0..3u64).map(|i| (target, getUintCall { x: U256::from(i) })));
+        assert_eq!(multicall.len(), 3);
+        assert!(multicall.calls().iter().all(|c| !c.allowFailure));
+
+        let multicall = multicall.allow_failure(true);
+        let calldata = multicall.calldata();
+        let decoded = IMulticall3::aggregate3Call::abi_decode(&calldata, true).unwrap();
+        assert_eq!(decoded.calls, multicall.calls());
+        assert_eq!(
+            decoded.calls[2].callData,
+            getUintCall { x: U256::from(2) }.abi_encode()
+        );
+
+        let results = vec![
+            IMulticall3::Result {
+                success: true,
+                returnData: getUintCall::abi_encode_returns(&(U256::from(7),)),
+            },
+            IMulticall3::Result {
+                success: false,
+                returnData: vec![1, 2, 3],
+            },
+            IMulticall3::Result {
+                success: true,
+                returnData: getUintCall::abi_encode_returns(&(U256::from(9),)),
+            },
+        ];
+        let data = IMulticall3::aggregate3Call::abi_encode_returns(&(results.clone(),));
+        let returns = multicall.decode_returns(&data, true).unwrap();
+        assert_eq!(returns.len(), 3);
+        assert_eq!(returns[0].as_ref().unwrap()._0, U256::from(7));
+        assert_eq!(returns[1].as_ref().err().unwrap(), &[1, 2, 3]);
+        assert_eq!(returns[2].as_ref().unwrap()._0, U256::from(9));
+
+        let data = IMulticall3::aggregate3Call::abi_encode_returns(&(results[..2].to_vec(),));
+        assert!(multicall.decode_returns(&data, true).is_err());
+    }
+}
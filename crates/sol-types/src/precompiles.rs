@@ -0,0 +1,496 @@
+//! Input and output codecs for the standard precompiles.
+//!
+//! Precompiles do not use the ABI encoding, but their own fixed or
+//! length-prefixed layouts. The input types of this module implement
+//! [`Precompile`] to encode and decode these layouts.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{Address, B256};
+//! use alloy_sol_types::precompiles::{EcRecoverInput, Precompile};
+//!
+//! let input = EcRecoverInput {
+//!     hash: B256::repeat_byte(0x11),
+//!     v: 27,
+//!     r: B256::repeat_byte(0x22),
+//!     s: B256::repeat_byte(0x33),
+//! };
+//! let data = input.encode_input()?;
+//! assert_eq!(data.len(), 128);
+//! assert_eq!(data[63], 27);
+//! assert_eq!(EcRecoverInput::decode_input(&data).unwrap(), input);
+//!
+//! let output = EcRecoverInput::encode_output(&Some(Address::repeat_byte(0x44)));
+//! assert_eq!(
+//!     EcRecoverInput::decode_output(&output).unwrap(),
+//!     Some(Address::repeat_byte(0x44))
+//! );
+//! # Ok::<_, alloy_sol_types::Error>(())
+//! ```
+
+use crate::{Error, Result, Word};
+use alloc::vec::Vec;
+use alloy_primitives::{Address, B256, U256};
+
+/// The address of the `ecrecover` precompile.
+pub const ECRECOVER: Address = Address::with_last_byte(0x01);
+
+/// The address of the identity precompile.
+pub const IDENTITY: Address = Address::with_last_byte(0x04);
+
+/// The address of the `modexp` precompile.
+pub const MODEXP: Address = Address::with_last_byte(0x05);
+
+/// The address of the `blake2f` precompile.
+pub const BLAKE2F: Address = Address::with_last_byte(0x09);
+
+/// The maximum length of each `modexp` operand since the Osaka hard fork, as
+/// set by [EIP-7823](https://eips.ethereum.org/EIPS/eip-7823).
+///
+/// This is enforced by [`ModExpInput::validate`] and
+/// [`ModExpInput::encode_input`], but not when decoding, since longer operands
+/// are valid before Osaka.
+pub const MODEXP_MAX_LEN: usize = 1024;
+
+/// The input of a precompile, along with its output type.
+pub trait Precompile: Sized {
+    /// The address of the precompile.
+    const ADDRESS: Address;
+
+    /// The decoded output of the precompile.
+    type Output;
+
+    /// Encodes the input of the precompile.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input exceeds the limits of the precompile.
+    fn encode_input(&self) -> Result<Vec<u8>>;
+
+    /// Decodes the input of the precompile.
+    fn decode_input(data: &[u8]) -> Result<Self>;
+
+    /// Encodes the output of the precompile.
+    fn encode_output(output: &Self::Output) -> Vec<u8>;
+
+    /// Decodes the output of the precompile.
+    fn decode_output(data: &[u8]) -> Result<Self::Output>;
+}
+
+/// The input of the `ecrecover` precompile: a message hash and a signature.
+///
+/// The output is the signer, or `None` if the signature is invalid, in which
+/// case the precompile returns no data.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EcRecoverInput {
+    /// The message hash.
+    pub hash: B256,
+    /// The recovery ID of the signature, either 27 or 28 for valid
+    /// signatures.
+    pub v: u8,
+    /// The `r` value of the signature.
+    pub r: B256,
+    /// The `s` value of the signature.
+    pub s: B256,
+}
+
+impl Precompile for EcRecoverInput {
+    const ADDRESS: Address = ECRECOVER;
+
+    type Output = Option<Address>;
+
+    fn encode_input(&self) -> Result<Vec<u8>> {
+        let mut data = Vec::with_capacity(128);
+        data.extend_from_slice(self.hash.as_slice());
+        data.extend_from_slice(Word::with_last_byte(self.v).as_slice());
+        data.extend_from_slice(self.r.as_slice());
+        data.extend_from_slice(self.s.as_slice());
+        Ok(data)
+    }
+
+    /// Decodes the input of the `ecrecover` precompile.
+    ///
+    /// Like the precompile, missing bytes are treated as zeros and extra bytes
+    /// are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `v` does not fit in a byte.
+    fn decode_input(data: &[u8]) -> Result<Self> {
+        let word = |i: usize| B256::from_slice(&padded(data, i * 32, 32));
+        let v = word(1);
+        if v[..31] != [0; 31] {
+            return Err(Error::type_check_fail(v.as_slice(), "uint8"))
+        }
+        Ok(Self {
+            hash: word(0),
+            v: v[31],
+            r: word(2),
+            s: word(3),
+        })
+    }
+
+    fn encode_output(output: &Self::Output) -> Vec<u8> {
+        output.map_or_else(Vec::new, |address| address.into_word().to_vec())
+    }
+
+    fn decode_output(data: &[u8]) -> Result<Self::Output> {
+        match data.len() {
+            0 => Ok(None),
+            32 if data[..12] == [0; 12] => Ok(Some(Address::from_slice(&data[12..]))),
+            _ => Err(Error::type_check_fail(data, "address")),
+        }
+    }
+}
+
+/// The input of the `modexp` precompile: computes
+/// `base ** exponent % modulus`.
+///
+/// The operands are big-endian, and the output has the length of the modulus.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ModExpInput {
+    /// The base.
+    pub base: Vec<u8>,
+    /// The exponent.
+    pub exponent: Vec<u8>,
+    /// The modulus.
+    pub modulus: Vec<u8>,
+}
+
+impl ModExpInput {
+    /// Checks that every operand is at most [`MODEXP_MAX_LEN`] bytes long, as
+    /// required since the Osaka hard fork.
+    pub fn validate(&self) -> Result<()> {
+        let operands = [&self.base, &self.exponent, &self.modulus];
+        if operands.iter().any(|op| op.len() > MODEXP_MAX_LEN) {
+            return Err(Error::custom("modexp operand is too long"))
+        }
+        Ok(())
+    }
+}
+
+impl Precompile for ModExpInput {
+    const ADDRESS: Address = MODEXP;
+
+    type Output = Vec<u8>;
+
+    /// Encodes the input of the `modexp` precompile.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an operand is longer than [`MODEXP_MAX_LEN`].
+    fn encode_input(&self) -> Result<Vec<u8>> {
+        self.validate()?;
+        let operands = [&self.base, &self.exponent, &self.modulus];
+        let len = 96 + operands.iter().map(|op| op.len()).sum::<usize>();
+        let mut data = Vec::with_capacity(len);
+        for op in operands {
+            data.extend_from_slice(&U256::from(op.len()).to_be_bytes::<32>());
+        }
+        for op in operands {
+            data.extend_from_slice(op);
+        }
+        Ok(data)
+    }
+
+    /// Decodes the input of the `modexp` precompile.
+    ///
+    /// Like the precompile, missing bytes are treated as zeros and extra bytes
+    /// are ignored.
+    ///
+    /// Operand lengths are not limited to [`MODEXP_MAX_LEN`], and each operand
+    /// is allocated with its declared length. Use [`validate`](Self::validate)
+    /// to check the result against the Osaka limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the operand lengths overflow `usize`.
+    fn decode_input(data: &[u8]) -> Result<Self> {
+        let mut lens = [0; 3];
+        for (i, len) in lens.iter_mut().enumerate() {
+            let word = U256::from_be_slice(&padded(data, i * 32, 32));
+            *len = usize::try_from(word)
+                .map_err(|_| Error::custom("modexp operand length overflows usize"))?;
+        }
+        let [base_len, exponent_len, modulus_len] = lens;
+        let exponent_offset = base_len.checked_add(96);
+        let modulus_offset = exponent_offset.and_then(|offset| offset.checked_add(exponent_len));
+        let (Some(exponent_offset), Some(modulus_offset)) = (exponent_offset, modulus_offset)
+        else {
+            return Err(Error::custom("modexp operand length overflows usize"))
+        };
+        Ok(Self {
+            base: padded(data, 96, base_len),
+            exponent: padded(data, exponent_offset, exponent_len),
+            modulus: padded(data, modulus_offset, modulus_len),
+        })
+    }
+
+    #[inline]
+    fn encode_output(output: &Self::Output) -> Vec<u8> {
+        output.clone()
+    }
+
+    #[inline]
+    fn decode_output(data: &[u8]) -> Result<Self::Output> {
+        Ok(data.to_vec())
+    }
+}
+
+/// The input of the identity precompile, which returns its input unchanged.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IdentityInput {
+    /// The data.
+    pub data: Vec<u8>,
+}
+
+impl Precompile for IdentityInput {
+    const ADDRESS: Address = IDENTITY;
+
+    type Output = Vec<u8>;
+
+    #[inline]
+    fn encode_input(&self) -> Result<Vec<u8>> {
+        Ok(self.data.clone())
+    }
+
+    #[inline]
+    fn decode_input(data: &[u8]) -> Result<Self> {
+        Ok(Self {
+            data: data.to_vec(),
+        })
+    }
+
+    #[inline]
+    fn encode_output(output: &Self::Output) -> Vec<u8> {
+        output.clone()
+    }
+
+    #[inline]
+    fn decode_output(data: &[u8]) -> Result<Self::Output> {
+        Ok(data.to_vec())
+    }
+}
+
+/// The input of the `blake2f` precompile: the BLAKE2b compression function
+/// `F`, as specified by [EIP-152](https://eips.ethereum.org/EIPS/eip-152).
+///
+/// The output is the new state vector.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Blake2fInput {
+    /// The number of rounds.
+    pub rounds: u32,
+    /// The state vector.
+    pub h: [u64; 8],
+    /// The message block vector.
+    pub m: [u64; 16],
+    /// The offset counters.
+    pub t: [u64; 2],
+    /// Whether this is the final block.
+    pub f: bool,
+}
+
+impl Blake2fInput {
+    /// The length of the encoded input.
+    pub const INPUT_LEN: usize = 213;
+
+    /// The length of the encoded output.
+    pub const OUTPUT_LEN: usize = 64;
+}
+
+impl Precompile for Blake2fInput {
+    const ADDRESS: Address = BLAKE2F;
+
+    type Output = [u64; 8];
+
+    fn encode_input(&self) -> Result<Vec<u8>> {
+        let mut data = Vec::with_capacity(Self::INPUT_LEN);
+        data.extend_from_slice(&self.rounds.to_be_bytes());
+        for x in self.h.iter().chain(&self.m).chain(&self.t) {
+            data.extend_from_slice(&x.to_le_bytes());
+        }
+        data.push(self.f as u8);
+        Ok(data)
+    }
+
+    /// Decodes the input of the `blake2f` precompile.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not exactly
+    /// [`INPUT_LEN`](Self::INPUT_LEN) bytes long, or if the final block flag is
+    /// not 0 or 1, as the precompile does.
+    fn decode_input(data: &[u8]) -> Result<Self> {
+        if data.len() != Self::INPUT_LEN {
+            return Err(Error::custom("invalid blake2f input length"))
+        }
+        let f = match data[Self::INPUT_LEN - 1] {
+            0 => false,
+            1 => true,
+            _ => return Err(Error::custom("invalid blake2f final block flag")),
+        };
+        let mut words = data[4..Self::INPUT_LEN - 1]
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()));
+        let mut this = Self {
+            rounds: u32::from_be_bytes(data[..4].try_into().unwrap()),
+            f,
+            ..Default::default()
+        };
+        for x in this.h.iter_mut().chain(&mut this.m).chain(&mut this.t) {
+            *x = words.next().unwrap();
+        }
+        Ok(this)
+    }
+
+    fn encode_output(output: &Self::Output) -> Vec<u8> {
+        output.iter().flat_map(|x| x.to_le_bytes()).collect()
+    }
+
+    fn decode_output(data: &[u8]) -> Result<Self::Output> {
+        if data.len() != Self::OUTPUT_LEN {
+            return Err(Error::custom("invalid blake2f output length"))
+        }
+        let mut output = [0; 8];
+        for (x, chunk) in output.iter_mut().zip(data.chunks_exact(8)) {
+            *x = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Ok(output)
+    }
+}
+
+/// Returns `len` bytes of `data` starting at `offset`, padded with zeros.
+fn padded(data: &[u8], offset: usize, len: usize) -> Vec<u8> {
+    let mut out = vec![0; len];
+    if let Some(data) = data.get(offset..) {
+        let n = data.len().min(len);
+        out[..n].copy_from_slice(&data[..n]);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{b256, hex};
+
+    #[test]
+    fn ecrecover() {
+        let input = EcRecoverInput {
+            hash: B256::repeat_byte(1),
+            v: 28,
+            r: B256::repeat_byte(2),
+            s: B256::repeat_byte(3),
+        };
+        let data = input.encode_input().unwrap();
+        assert_eq!(EcRecoverInput::decode_input(&data).unwrap(), input);
+        assert_eq!(
+            EcRecoverInput::decode_input(&data[..100]).unwrap(),
+            EcRecoverInput {
+                s: b256!("0303030300000000000000000000000000000000000000000000000000000000"),
+                ..input
+            }
+        );
+
+        let mut invalid = data;
+        invalid[62] = 1;
+        assert!(EcRecoverInput::decode_input(&invalid).is_err());
+
+        assert_eq!(EcRecoverInput::decode_output(&[]).unwrap(), None);
+        assert!(EcRecoverInput::encode_output(&None).is_empty());
+        assert!(EcRecoverInput::decode_output(&[1; 32]).is_err());
+    }
+
+    #[test]
+    fn modexp() {
+        // 3 ** 0xffff % 2 ** 255 - 19, from the EIP-198 example
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "03"
+            "ffff"
+            "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+        );
+        let input = ModExpInput::decode_input(&data).unwrap();
+        assert_eq!(input.base, [3]);
+        assert_eq!(input.exponent, [0xff, 0xff]);
+        assert_eq!(input.modulus.len(), 32);
+        assert_eq!(input.encode_input().unwrap(), data);
+
+        let truncated = ModExpInput::decode_input(&data[..99]).unwrap();
+        assert_eq!(truncated.modulus, [0; 32]);
+
+        // Operands longer than the Osaka limit are valid before Osaka.
+        let mut long = data;
+        long[94] = 0x04;
+        let long = ModExpInput::decode_input(&long).unwrap();
+        assert_eq!(long.modulus.len(), MODEXP_MAX_LEN + 32);
+        assert!(long.validate().is_err());
+        assert!(long.encode_input().is_err());
+        assert!(ModExpInput::decode_input(&[0xff; 96]).is_err());
+
+        let max = ModExpInput {
+            modulus: vec![1; MODEXP_MAX_LEN],
+            ..input
+        };
+        assert!(max.validate().is_ok());
+        assert_eq!(
+            ModExpInput::decode_input(&max.encode_input().unwrap()).unwrap(),
+            max
+        );
+        let over = ModExpInput {
+            base: vec![1; MODEXP_MAX_LEN + 1],
+            ..max
+        };
+        assert!(over.encode_input().is_err());
+    }
+
+    #[test]
+    fn identity() {
+        let input = IdentityInput {
+            data: vec![1, 2, 3],
+        };
+        assert_eq!(input.encode_input().unwrap(), [1, 2, 3]);
+        assert_eq!(IdentityInput::decode_input(&[1, 2, 3]).unwrap(), input);
+        assert_eq!(IdentityInput::ADDRESS, IDENTITY);
+    }
+
+    #[test]
+    fn blake2f() {
+        // EIP-152 test vector 5
+        let data = hex!(
+            "0000000c"
+            "48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5"
+            "d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e1319cde05b"
+            "6162630000000000000000000000000000000000000000000000000000000000"
+            "0000000000000000000000000000000000000000000000000000000000000000"
+            "0000000000000000000000000000000000000000000000000000000000000000"
+            "0000000000000000000000000000000000000000000000000000000000000000"
+            "0300000000000000"
+            "0000000000000000"
+            "01"
+        );
+        let input = Blake2fInput::decode_input(&data).unwrap();
+        assert_eq!(input.rounds, 12);
+        assert_eq!(input.h[0], 0x6a09e667f2bdc948);
+        assert_eq!(input.m[0], 0x636261);
+        assert_eq!(input.t, [3, 0]);
+        assert!(input.f);
+        assert_eq!(input.encode_input().unwrap(), data);
+
+        let output = hex!(
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1"
+            "7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        );
+        let state = Blake2fInput::decode_output(&output).unwrap();
+        assert_eq!(Blake2fInput::encode_output(&state), output);
+
+        let mut invalid = data;
+        invalid[212] = 2;
+        assert!(Blake2fInput::decode_input(&invalid).is_err());
+        assert!(Blake2fInput::decode_input(&data[..212]).is_err());
+        assert!(Blake2fInput::decode_output(&output[..63]).is_err());
+    }
+}
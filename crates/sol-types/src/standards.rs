@@ -0,0 +1,243 @@
+//! Bindings for the most common token standards.
+//!
+//! Each interface includes the functions, events, and errors defined by the
+//! standard and its common extensions, so that they can be shared instead of
+//! being generated by every downstream crate:
+//! - [`IERC20`] for [ERC-20](https://eips.ethereum.org/EIPS/eip-20) with the
+//!   metadata extension
+//! - [`IERC721`] for [ERC-721](https://eips.ethereum.org/EIPS/eip-721) with the
+//!   metadata and [ERC-165](https://eips.ethereum.org/EIPS/eip-165) extensions
+//! - [`IERC1155`] for [ERC-1155](https://eips.ethereum.org/EIPS/eip-1155) with
+//!   the metadata URI and ERC-165 extensions
+//! - [`IERC4626`] for [ERC-4626](https://eips.ethereum.org/EIPS/eip-4626)
+//!   including the ERC-20 functions of the share token
+//!
+//! The errors are the ones defined in
+//! [ERC-6093](https://eips.ethereum.org/EIPS/eip-6093) and by OpenZeppelin's
+//! implementation of ERC-4626.
+//!
+//! Overloaded functions are suffixed with their index, as usual: for example
+//! `safeTransferFrom(address,address,uint256)` and
+//! `safeTransferFrom(address,address,uint256,bytes)` are respectively
+//! [`IERC721::safeTransferFrom_0Call`] and
+//! [`IERC721::safeTransferFrom_1Call`].
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{Address, U256};
+//! use alloy_sol_types::{standards::IERC20, SolCall, SolInterface};
+//!
+//! let call = IERC20::transferCall {
+//!     to: Address::repeat_byte(0x11),
+//!     amount: U256::from(100),
+//! };
+//! let calldata = call.abi_encode();
+//! assert_eq!(calldata[..4], [0xa9, 0x05, 0x9c, 0xbb]);
+//!
+//! let decoded = IERC20::IERC20Calls::abi_decode(&calldata, true).unwrap();
+//! assert_eq!(decoded, IERC20::IERC20Calls::transfer(call));
+//! ```
+
+use crate::sol;
+
+sol! {
+    /// The [ERC-20](https://eips.ethereum.org/EIPS/eip-20) token standard.
+    #[allow(missing_docs)]
+    #[derive(Debug, PartialEq, Eq)]
+    interface IERC20 {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Approval(address indexed owner, address indexed spender, uint256 value);
+
+        error ERC20InsufficientBalance(address sender, uint256 balance, uint256 needed);
+        error ERC20InvalidSender(address sender);
+        error ERC20InvalidReceiver(address receiver);
+        error ERC20InsufficientAllowance(address spender, uint256 allowance, uint256 needed);
+        error ERC20InvalidApprover(address approver);
+        error ERC20InvalidSpender(address spender);
+
+        function name() external view returns (string memory);
+        function symbol() external view returns (string memory);
+        function decimals() external view returns (uint8);
+        function totalSupply() external view returns (uint256);
+        function balanceOf(address account) external view returns (uint256);
+        function transfer(address to, uint256 amount) external returns (bool);
+        function allowance(address owner, address spender) external view returns (uint256);
+        function approve(address spender, uint256 amount) external returns (bool);
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+    }
+}
+
+sol! {
+    /// The [ERC-721](https://eips.ethereum.org/EIPS/eip-721) non-fungible
+    /// token standard.
+    #[allow(missing_docs)]
+    #[derive(Debug, PartialEq, Eq)]
+    interface IERC721 {
+        event Transfer(address indexed from, address indexed to, uint256 indexed tokenId);
+        event Approval(address indexed owner, address indexed approved, uint256 indexed tokenId);
+        event ApprovalForAll(address indexed owner, address indexed operator, bool approved);
+
+        error ERC721InvalidOwner(address owner);
+        error ERC721NonexistentToken(uint256 tokenId);
+        error ERC721IncorrectOwner(address sender, uint256 tokenId, address owner);
+        error ERC721InvalidSender(address sender);
+        error ERC721InvalidReceiver(address receiver);
+        error ERC721InsufficientApproval(address operator, uint256 tokenId);
+        error ERC721InvalidApprover(address approver);
+        error ERC721InvalidOperator(address operator);
+
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+        function name() external view returns (string memory);
+        function symbol() external view returns (string memory);
+        function tokenURI(uint256 tokenId) external view returns (string memory);
+        function balanceOf(address owner) external view returns (uint256 balance);
+        function ownerOf(uint256 tokenId) external view returns (address owner);
+        function safeTransferFrom(address from, address to, uint256 tokenId) external;
+        function safeTransferFrom(address from, address to, uint256 tokenId, bytes calldata data) external;
+        function transferFrom(address from, address to, uint256 tokenId) external;
+        function approve(address to, uint256 tokenId) external;
+        function setApprovalForAll(address operator, bool approved) external;
+        function getApproved(uint256 tokenId) external view returns (address operator);
+        function isApprovedForAll(address owner, address operator) external view returns (bool);
+    }
+}
+
+sol! {
+    /// The [ERC-1155](https://eips.ethereum.org/EIPS/eip-1155) multi token
+    /// standard.
+    #[allow(missing_docs)]
+    #[derive(Debug, PartialEq, Eq)]
+    interface IERC1155 {
+        event TransferSingle(address indexed operator, address indexed from, address indexed to, uint256 id, uint256 value);
+        event TransferBatch(address indexed operator, address indexed from, address indexed to, uint256[] ids, uint256[] values);
+        event ApprovalForAll(address indexed account, address indexed operator, bool approved);
+        event URI(string value, uint256 indexed id);
+
+        error ERC1155InsufficientBalance(address sender, uint256 balance, uint256 needed, uint256 tokenId);
+        error ERC1155InvalidSender(address sender);
+        error ERC1155InvalidReceiver(address receiver);
+        error ERC1155MissingApprovalForAll(address operator, address owner);
+        error ERC1155InvalidApprover(address approver);
+        error ERC1155InvalidOperator(address operator);
+        error ERC1155InvalidArrayLength(uint256 idsLength, uint256 valuesLength);
+
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+        function uri(uint256 id) external view returns (string memory);
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+        function balanceOfBatch(address[] calldata accounts, uint256[] calldata ids) external view returns (uint256[] memory);
+        function setApprovalForAll(address operator, bool approved) external;
+        function isApprovedForAll(address account, address operator) external view returns (bool);
+        function safeTransferFrom(address from, address to, uint256 id, uint256 value, bytes calldata data) external;
+        function safeBatchTransferFrom(address from, address to, uint256[] calldata ids, uint256[] calldata values, bytes calldata data) external;
+    }
+}
+
+sol! {
+    /// The [ERC-4626](https://eips.ethereum.org/EIPS/eip-4626) tokenized vault
+    /// standard.
+    #[allow(missing_docs)]
+    #[derive(Debug, PartialEq, Eq)]
+    interface IERC4626 {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Approval(address indexed owner, address indexed spender, uint256 value);
+        event Deposit(address indexed sender, address indexed owner, uint256 assets, uint256 shares);
+        event Withdraw(address indexed sender, address indexed receiver, address indexed owner, uint256 assets, uint256 shares);
+
+        error ERC4626ExceededMaxDeposit(address receiver, uint256 assets, uint256 max);
+        error ERC4626ExceededMaxMint(address receiver, uint256 shares, uint256 max);
+        error ERC4626ExceededMaxWithdraw(address owner, uint256 assets, uint256 max);
+        error ERC4626ExceededMaxRedeem(address owner, uint256 shares, uint256 max);
+
+        function name() external view returns (string memory);
+        function symbol() external view returns (string memory);
+        function decimals() external view returns (uint8);
+        function totalSupply() external view returns (uint256);
+        function balanceOf(address account) external view returns (uint256);
+        function transfer(address to, uint256 amount) external returns (bool);
+        function allowance(address owner, address spender) external view returns (uint256);
+        function approve(address spender, uint256 amount) external returns (bool);
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+
+        function asset() external view returns (address assetTokenAddress);
+        function totalAssets() external view returns (uint256 totalManagedAssets);
+        function convertToShares(uint256 assets) external view returns (uint256 shares);
+        function convertToAssets(uint256 shares) external view returns (uint256 assets);
+        function maxDeposit(address receiver) external view returns (uint256 maxAssets);
+        function previewDeposit(uint256 assets) external view returns (uint256 shares);
+        function deposit(uint256 assets, address receiver) external returns (uint256 shares);
+        function maxMint(address receiver) external view returns (uint256 maxShares);
+        function previewMint(uint256 shares) external view returns (uint256 assets);
+        function mint(uint256 shares, address receiver) external returns (uint256 assets);
+        function maxWithdraw(address owner) external view returns (uint256 maxAssets);
+        function previewWithdraw(uint256 assets) external view returns (uint256 shares);
+        function withdraw(uint256 assets, address receiver, address owner) external returns (uint256 shares);
+        function maxRedeem(address owner) external view returns (uint256 maxShares);
+        function previewRedeem(uint256 shares) external view returns (uint256 assets);
+        function redeem(uint256 shares, address receiver, address owner) external returns (uint256 assets);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SolCall, SolError, SolEvent};
+    use alloy_primitives::{b256, hex};
+
+    #[test]
+    fn erc20() {
+        assert_eq!(IERC20::transferCall::SELECTOR, hex!("a9059cbb"));
+        assert_eq!(IERC20::approveCall::SELECTOR, hex!("095ea7b3"));
+        assert_eq!(IERC20::transferFromCall::SELECTOR, hex!("23b872dd"));
+        assert_eq!(IERC20::balanceOfCall::SELECTOR, hex!("70a08231"));
+        assert_eq!(
+            IERC20::Transfer::SIGNATURE_HASH,
+            b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+        );
+        assert_eq!(IERC20::ERC20InsufficientBalance::SELECTOR, hex!("e450d38c"));
+    }
+
+    #[test]
+    fn erc721() {
+        assert_eq!(IERC721::safeTransferFrom_0Call::SELECTOR, hex!("42842e0e"));
+        assert_eq!(IERC721::safeTransferFrom_1Call::SELECTOR, hex!("b88d4fde"));
+        assert_eq!(IERC721::ownerOfCall::SELECTOR, hex!("6352211e"));
+        assert_eq!(
+            IERC721::Transfer::SIGNATURE_HASH,
+            IERC20::Transfer::SIGNATURE_HASH
+        );
+        assert_eq!(
+            IERC721::ApprovalForAll::SIGNATURE_HASH,
+            b256!("17307eab39ab6107e8899845ad3d59bd9653f200f220920489ca2b5937696c31")
+        );
+    }
+
+    #[test]
+    fn erc1155() {
+        assert_eq!(IERC1155::safeTransferFromCall::SELECTOR, hex!("f242432a"));
+        assert_eq!(
+            IERC1155::safeBatchTransferFromCall::SELECTOR,
+            hex!("2eb2c2d6")
+        );
+        assert_eq!(IERC1155::balanceOfBatchCall::SELECTOR, hex!("4e1273f4"));
+        assert_eq!(
+            IERC1155::TransferSingle::SIGNATURE_HASH,
+            b256!("c3d58168c5ae7397731d063d5bbf3d657854427343f4c083240f7aacaa2d0f62")
+        );
+    }
+
+    #[test]
+    fn erc4626() {
+        assert_eq!(IERC4626::depositCall::SELECTOR, hex!("6e553f65"));
+        assert_eq!(IERC4626::redeemCall::SELECTOR, hex!("ba087652"));
+        assert_eq!(IERC4626::assetCall::SELECTOR, hex!("38d52e0f"));
+        assert_eq!(
+            IERC4626::transferCall::SELECTOR,
+            IERC20::transferCall::SELECTOR
+        );
+        assert_eq!(
+            IERC4626::Deposit::SIGNATURE_HASH,
+            b256!("dcbc1c05240f31ff3ad067ef1ee35ce4997762752e3a095284754544f4c709d7")
+        );
+    }
+}
@@ -8,7 +8,11 @@
 
 #![allow(missing_copy_implementations, missing_debug_implementations)]
 
-use crate::{abi::token::*, private::SolTypeValue, utils, SolType, Word};
+use crate::{
+    abi::{token::*, Decoder},
+    private::{head_size, SeqEncoder, SolTypeValue},
+    utils, SolType, Word,
+};
 use alloc::{borrow::Cow, string::String as RustString, vec::Vec};
 use alloy_primitives::{
     keccak256, Address as RustAddress, FixedBytes as RustFixedBytes, Function as RustFunction,
@@ -37,6 +41,11 @@ impl SolTypeValue<Bool> for bool {
     fn stv_eip712_data_word(&self) -> Word {
         SolTypeValue::<Bool>::stv_to_tokens(self).0
     }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(SolTypeValue::<Bool>::stv_to_tokens(self).as_slice());
+    }
 }
 
 impl SolType for Bool {
@@ -50,7 +59,7 @@ impl SolType for Bool {
 
     #[inline]
     fn valid_token(token: &Self::TokenType<'_>) -> bool {
-        utils::check_zeroes(&token.0[..31])
+        utils::check_zeroes(&token.0[..31]) && token.0[31] <= 1
     }
 
     #[inline]
@@ -81,6 +90,11 @@ where
     fn stv_eip712_data_word(&self) -> Word {
         SolTypeValue::<Int<BITS>>::stv_to_tokens(self).0
     }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(SolTypeValue::<Int<BITS>>::stv_to_tokens(self).as_slice());
+    }
 }
 
 impl<const BITS: usize> SolType for Int<BITS>
@@ -138,6 +152,11 @@ where
     fn stv_eip712_data_word(&self) -> Word {
         SolTypeValue::<Uint<BITS>>::stv_to_tokens(self).0
     }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(SolTypeValue::<Uint<BITS>>::stv_to_tokens(self).as_slice());
+    }
 }
 
 impl<const BITS: usize> SolType for Uint<BITS>
@@ -181,6 +200,11 @@ impl<T: Borrow<[u8; 20]>> SolTypeValue<Address> for T {
     fn stv_eip712_data_word(&self) -> Word {
         SolTypeValue::<Address>::stv_to_tokens(self).0
     }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(SolTypeValue::<Address>::stv_to_tokens(self).as_slice());
+    }
 }
 
 impl SolType for Address {
@@ -221,6 +245,11 @@ impl<T: Borrow<[u8; 24]>> SolTypeValue<Function> for T {
     fn stv_eip712_data_word(&self) -> Word {
         SolTypeValue::<Function>::stv_to_tokens(self).0
     }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(SolTypeValue::<Function>::stv_to_tokens(self).as_slice());
+    }
 }
 
 impl SolType for Function {
@@ -266,6 +295,11 @@ impl<T: ?Sized + AsRef<[u8]>> SolTypeValue<Bytes> for T {
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         out.extend_from_slice(self.as_ref());
     }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        crate::private::encode_packed_seq_to(self.as_ref(), out);
+    }
 }
 
 impl SolType for Bytes {
@@ -324,6 +358,12 @@ where
             T::stv_abi_encode_packed_to(item, out);
         }
     }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(utils::pad_usize(self.len()).as_slice());
+        encode_seq_to::<T, U>(self, out);
+    }
 }
 
 impl<T, U> SolTypeValue<Array<U>> for &[T]
@@ -350,6 +390,11 @@ where
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         (**self).stv_abi_encode_packed_to(out)
     }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        (**self).stv_abi_encode_to(out)
+    }
 }
 
 impl<T, U> SolTypeValue<Array<U>> for &mut [T]
@@ -376,6 +421,11 @@ where
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         (**self).stv_abi_encode_packed_to(out)
     }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        (**self).stv_abi_encode_to(out)
+    }
 }
 
 impl<T, U> SolTypeValue<Array<U>> for Vec<T>
@@ -402,6 +452,107 @@ where
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         (**self).stv_abi_encode_packed_to(out)
     }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        (**self).stv_abi_encode_to(out)
+    }
+}
+
+impl<T, U> SolTypeValue<Array<U>> for Cow<'_, [T]>
+where
+    T: Clone + SolTypeValue<U>,
+    U: SolType,
+{
+    #[inline]
+    fn stv_to_tokens(&self) -> DynSeqToken<U::TokenType<'_>> {
+        <[T] as SolTypeValue<Array<U>>>::stv_to_tokens(self)
+    }
+
+    #[inline]
+    fn stv_abi_encoded_size(&self) -> usize {
+        (**self).stv_abi_encoded_size()
+    }
+
+    #[inline]
+    fn stv_eip712_data_word(&self) -> Word {
+        (**self).stv_eip712_data_word()
+    }
+
+    #[inline]
+    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+        (**self).stv_abi_encode_packed_to(out)
+    }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        (**self).stv_abi_encode_to(out)
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A, U> SolTypeValue<Array<U>> for smallvec::SmallVec<A>
+where
+    A: smallvec::Array,
+    A::Item: SolTypeValue<U>,
+    U: SolType,
+{
+    #[inline]
+    fn stv_to_tokens(&self) -> DynSeqToken<U::TokenType<'_>> {
+        <[A::Item] as SolTypeValue<Array<U>>>::stv_to_tokens(self)
+    }
+
+    #[inline]
+    fn stv_abi_encoded_size(&self) -> usize {
+        (**self).stv_abi_encoded_size()
+    }
+
+    #[inline]
+    fn stv_eip712_data_word(&self) -> Word {
+        (**self).stv_eip712_data_word()
+    }
+
+    #[inline]
+    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+        (**self).stv_abi_encode_packed_to(out)
+    }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        (**self).stv_abi_encode_to(out)
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, U, const CAP: usize> SolTypeValue<Array<U>> for arrayvec::ArrayVec<T, CAP>
+where
+    T: SolTypeValue<U>,
+    U: SolType,
+{
+    #[inline]
+    fn stv_to_tokens(&self) -> DynSeqToken<U::TokenType<'_>> {
+        <[T] as SolTypeValue<Array<U>>>::stv_to_tokens(self)
+    }
+
+    #[inline]
+    fn stv_abi_encoded_size(&self) -> usize {
+        (**self).stv_abi_encoded_size()
+    }
+
+    #[inline]
+    fn stv_eip712_data_word(&self) -> Word {
+        (**self).stv_eip712_data_word()
+    }
+
+    #[inline]
+    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+        (**self).stv_abi_encode_packed_to(out)
+    }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        (**self).stv_abi_encode_to(out)
+    }
 }
 
 impl<T: SolType> SolType for Array<T> {
@@ -424,6 +575,33 @@ impl<T: SolType> SolType for Array<T> {
     fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
         token.0.into_iter().map(T::detokenize).collect()
     }
+
+    fn abi_decode_from(dec: &mut Decoder<'_>) -> crate::Result<Self::RustType> {
+        let mut child = dec.take_indirection()?;
+        let len = child.take_offset()?;
+        // offsets are relative to the word after the length, see
+        // `DynSeqToken::decode_from`
+        let mut child = child.raw_child();
+        // every element takes at least one word, so don't trust `len` to
+        // preallocate more than the data can hold
+        let mut items = Vec::with_capacity(len.min(child.remaining_words()));
+        for _ in 0..len {
+            items.push(T::abi_decode_from(&mut child)?);
+        }
+        Ok(items)
+    }
+}
+
+/// Encodes the elements of an array directly into `out`, without its length.
+#[inline]
+fn encode_seq_to<T: SolTypeValue<U>, U: SolType>(items: &[T], out: &mut Vec<u8>) {
+    let mut enc = SeqEncoder::new(out, items.len() * head_size::<U>());
+    for item in items {
+        enc.head::<U, T>(item);
+    }
+    for item in items {
+        enc.tail::<U, T>(item);
+    }
 }
 
 /// String - `string`
@@ -449,6 +627,11 @@ impl<T: ?Sized + AsRef<str>> SolTypeValue<String> for T {
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         out.extend_from_slice(self.as_ref().as_ref());
     }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        crate::private::encode_packed_seq_to(self.as_ref().as_bytes(), out);
+    }
 }
 
 impl SolType for String {
@@ -497,6 +680,11 @@ where
         SolTypeValue::<FixedBytes<N>>::stv_to_tokens(self).0
     }
 
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(SolTypeValue::<FixedBytes<N>>::stv_to_tokens(self).as_slice());
+    }
+
     #[inline]
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         out.extend_from_slice(self.borrow().as_slice());
@@ -565,6 +753,11 @@ where
             T::stv_abi_encode_packed_to(item, out);
         }
     }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        encode_seq_to::<T, U>(self, out);
+    }
 }
 
 impl<T, U, const N: usize> SolTypeValue<FixedArray<U, N>> for &[T; N]
@@ -591,6 +784,11 @@ where
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         SolTypeValue::<FixedArray<U, N>>::stv_abi_encode_packed_to(&**self, out)
     }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        SolTypeValue::<FixedArray<U, N>>::stv_abi_encode_to(&**self, out)
+    }
 }
 
 impl<T, U, const N: usize> SolTypeValue<FixedArray<U, N>> for &mut [T; N]
@@ -617,6 +815,11 @@ where
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         SolTypeValue::<FixedArray<U, N>>::stv_abi_encode_packed_to(&**self, out)
     }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        SolTypeValue::<FixedArray<U, N>>::stv_abi_encode_to(&**self, out)
+    }
 }
 
 impl<T: SolType, const N: usize> SolType for FixedArray<T, N> {
@@ -644,6 +847,24 @@ impl<T: SolType, const N: usize> SolType for FixedArray<T, N> {
     fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
         token.0.map(T::detokenize)
     }
+
+    fn abi_decode_from(dec: &mut Decoder<'_>) -> crate::Result<Self::RustType> {
+        let mut child = if Self::DYNAMIC {
+            dec.take_indirection()?
+        } else {
+            dec.raw_child()
+        };
+        let res = Self::abi_decode_sequence_from(&mut child)?;
+        if !Self::DYNAMIC {
+            dec.take_offset_from(&child);
+        }
+        Ok(res)
+    }
+
+    #[inline]
+    fn abi_decode_sequence_from(dec: &mut Decoder<'_>) -> crate::Result<Self::RustType> {
+        crate::impl_core::try_from_fn(|_| T::abi_decode_from(dec))
+    }
 }
 
 macro_rules! tuple_encodable_impls {
@@ -678,6 +899,17 @@ macro_rules! tuple_encodable_impls {
                 )+
             }
 
+            fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+                let ($($ty,)+) = self;
+                let mut enc = SeqEncoder::new(out, 0 $( + head_size::<$uty>() )+);
+                $(
+                    enc.head::<$uty, $ty>($ty);
+                )+
+                $(
+                    enc.tail::<$uty, $ty>($ty);
+                )+
+            }
+
             fn stv_eip712_data_word(&self) -> Word {
                 let ($($ty,)+) = self;
                 let encoding: [[u8; 32]; $count] = [$(
@@ -740,6 +972,26 @@ macro_rules! tuple_impls {
                     <$ty as SolType>::detokenize($ty),
                 )+)
             }
+
+            fn abi_decode_from(dec: &mut Decoder<'_>) -> crate::Result<Self::RustType> {
+                let mut child = if Self::DYNAMIC {
+                    dec.take_indirection()?
+                } else {
+                    dec.raw_child()
+                };
+                let res = Self::abi_decode_sequence_from(&mut child)?;
+                if !Self::DYNAMIC {
+                    dec.take_offset_from(&child);
+                }
+                Ok(res)
+            }
+
+            #[inline]
+            fn abi_decode_sequence_from(dec: &mut Decoder<'_>) -> crate::Result<Self::RustType> {
+                Ok(($(
+                    <$ty as SolType>::abi_decode_from(dec)?,
+                )+))
+            }
         }
     };
 }
@@ -755,6 +1007,9 @@ impl SolTypeValue<()> for () {
 
     #[inline]
     fn stv_abi_encode_packed_to(&self, _out: &mut Vec<u8>) {}
+
+    #[inline]
+    fn stv_abi_encode_to(&self, _out: &mut Vec<u8>) {}
 }
 
 all_the_tuples!(@double tuple_encodable_impls);
@@ -1057,6 +1312,166 @@ supported_int!(
     256 => I256, U256;
 );
 
+/// Uuid - `bytes16` holding a [`uuid::Uuid`]
+#[cfg(feature = "uuid")]
+pub struct Uuid;
+
+#[cfg(feature = "uuid")]
+impl SolTypeValue<Uuid> for uuid::Uuid {
+    #[inline]
+    fn stv_to_tokens(&self) -> WordToken {
+        SolTypeValue::<FixedBytes<16>>::stv_to_tokens(self.as_bytes())
+    }
+
+    #[inline]
+    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.as_bytes());
+    }
+
+    #[inline]
+    fn stv_eip712_data_word(&self) -> Word {
+        SolTypeValue::<Uuid>::stv_to_tokens(self).0
+    }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(SolTypeValue::<Uuid>::stv_to_tokens(self).as_slice());
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl SolType for Uuid {
+    type RustType = uuid::Uuid;
+    type TokenType<'a> = WordToken;
+
+    #[inline]
+    fn sol_type_name() -> Cow<'static, str> {
+        "bytes16".into()
+    }
+
+    #[inline]
+    fn valid_token(token: &Self::TokenType<'_>) -> bool {
+        FixedBytes::<16>::valid_token(token)
+    }
+
+    #[inline]
+    fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
+        uuid::Uuid::from_bytes(FixedBytes::<16>::detokenize(token).0)
+    }
+}
+
+/// Timestamp - `uint256` holding a number of seconds since the Unix epoch,
+/// represented as a Rust time type `T`.
+///
+/// Times before the epoch are encoded as `0`, and sub-second precision is
+/// truncated. Decoding with validation rejects timestamps greater than
+/// [`UnixTimestamp::MAX_SECONDS`]; without validation, they saturate to it.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub struct Timestamp<T>(PhantomData<T>);
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+impl<T: UnixTimestamp> SolTypeValue<Timestamp<T>> for T {
+    #[inline]
+    fn stv_to_tokens(&self) -> WordToken {
+        IntBitCount::<256>::tokenize_uint(U256::from(self.unix_seconds()))
+    }
+
+    #[inline]
+    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+        IntBitCount::<256>::encode_packed_to_uint(U256::from(self.unix_seconds()), out);
+    }
+
+    #[inline]
+    fn stv_eip712_data_word(&self) -> Word {
+        SolTypeValue::<Timestamp<T>>::stv_to_tokens(self).0
+    }
+
+    #[inline]
+    fn stv_abi_encode_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(SolTypeValue::<Timestamp<T>>::stv_to_tokens(self).as_slice());
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+impl<T: UnixTimestamp> SolType for Timestamp<T> {
+    type RustType = T;
+    type TokenType<'a> = WordToken;
+
+    #[inline]
+    fn sol_type_name() -> Cow<'static, str> {
+        "uint256".into()
+    }
+
+    #[inline]
+    fn valid_token(token: &Self::TokenType<'_>) -> bool {
+        IntBitCount::<256>::detokenize_uint(*token) <= U256::from(T::MAX_SECONDS)
+    }
+
+    #[inline]
+    fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
+        let secs = IntBitCount::<256>::detokenize_uint(token);
+        T::from_unix_seconds(secs.saturating_to::<u64>().min(T::MAX_SECONDS))
+    }
+}
+
+/// A Rust time type that can be encoded as a [`Timestamp`].
+///
+/// This trait is *sealed*: it is implemented for `chrono::DateTime<Utc>` with
+/// the `chrono` feature, and for `time::OffsetDateTime` with the `time`
+/// feature.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub trait UnixTimestamp: Sealed + Sized + 'static {
+    /// The greatest number of seconds since the Unix epoch that this type can
+    /// represent.
+    const MAX_SECONDS: u64;
+
+    /// Returns the number of whole seconds since the Unix epoch, or `0` if this
+    /// time is before it.
+    fn unix_seconds(&self) -> u64;
+
+    /// Creates a time from a number of seconds since the Unix epoch, which is
+    /// at most [`MAX_SECONDS`](Self::MAX_SECONDS).
+    fn from_unix_seconds(secs: u64) -> Self;
+}
+
+#[cfg(feature = "chrono")]
+impl Sealed for chrono::DateTime<chrono::Utc> {}
+
+#[cfg(feature = "chrono")]
+impl UnixTimestamp for chrono::DateTime<chrono::Utc> {
+    // +262142-12-31T23:59:59Z
+    const MAX_SECONDS: u64 = 8_210_266_876_799;
+
+    #[inline]
+    fn unix_seconds(&self) -> u64 {
+        self.timestamp().try_into().unwrap_or(0)
+    }
+
+    #[inline]
+    fn from_unix_seconds(secs: u64) -> Self {
+        Self::from_timestamp(secs as i64, 0).expect("timestamp in range")
+    }
+}
+
+#[cfg(feature = "time")]
+impl Sealed for time::OffsetDateTime {}
+
+#[cfg(feature = "time")]
+impl UnixTimestamp for time::OffsetDateTime {
+    // 9999-12-31T23:59:59Z, without the `large-dates` feature of `time`
+    const MAX_SECONDS: u64 = 253_402_300_799;
+
+    #[inline]
+    fn unix_seconds(&self) -> u64 {
+        self.unix_timestamp().try_into().unwrap_or(0)
+    }
+
+    #[inline]
+    fn from_unix_seconds(secs: u64) -> Self {
+        Self::from_unix_timestamp(secs as i64).expect("timestamp in range")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1161,8 +1576,10 @@ mod tests {
         let token = WordToken::new(word);
         macro_rules! test {
             ($($n:literal => $x:expr),+ $(,)?) => {$(
-                assert_eq!(<Uint<$n>>::detokenize(token), $x);
-                assert_eq!(<Int<$n>>::detokenize(token), $x);
+                let expected: <Uint<$n> as SolType>::RustType = $x;
+                assert_eq!(<Uint<$n>>::detokenize(token), expected);
+                let expected: <Int<$n> as SolType>::RustType = $x;
+                assert_eq!(<Int<$n>>::detokenize(token), expected);
             )+};
         }
         #[rustfmt::skip]
@@ -1287,4 +1704,95 @@ mod tests {
         assert_eq!(<Int<248>>::detokenize(token), "0xff82038405860788098a0b8c0d8e0f901192139415961798199a1b9c1d9e1fa0".as_u256_as_i256());
         assert_eq!(<Int<256>>::detokenize(token), "0x0182038405860788098a0b8c0d8e0f901192139415961798199a1b9c1d9e1fa0".as_u256_as_i256());
     }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn chrono_timestamp() {
+        use crate::SolValue;
+        use chrono::{DateTime, Utc};
+
+        type Ts = Timestamp<DateTime<Utc>>;
+
+        let time = DateTime::<Utc>::from_timestamp(1_700_000_000, 999).unwrap();
+        let encoded = time.abi_encode();
+        assert_eq!(encoded, U256::from(1_700_000_000u64).abi_encode());
+        assert_eq!(
+            DateTime::<Utc>::abi_decode(&encoded, true).unwrap(),
+            DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap()
+        );
+        assert_eq!(
+            (time, true).abi_encode(),
+            (U256::from(1_700_000_000u64), true).abi_encode()
+        );
+
+        let before_epoch = DateTime::<Utc>::from_timestamp(-1, 0).unwrap();
+        assert_eq!(before_epoch.abi_encode(), U256::ZERO.abi_encode());
+
+        assert_eq!(
+            <DateTime<Utc> as UnixTimestamp>::MAX_SECONDS,
+            DateTime::<Utc>::MAX_UTC.timestamp() as u64
+        );
+        let max = U256::from(<DateTime<Utc> as UnixTimestamp>::MAX_SECONDS);
+        assert!(Ts::abi_decode(&max.abi_encode(), true).is_ok());
+        let too_large = (max + U256::from(1)).abi_encode();
+        assert!(Ts::abi_decode(&too_large, true).is_err());
+        assert_eq!(
+            Ts::abi_decode(&too_large, false).unwrap().timestamp() as u64,
+            <DateTime<Utc> as UnixTimestamp>::MAX_SECONDS
+        );
+        assert_eq!(
+            Ts::abi_decode(&U256::MAX.abi_encode(), false)
+                .unwrap()
+                .timestamp() as u64,
+            <DateTime<Utc> as UnixTimestamp>::MAX_SECONDS
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "time")]
+    fn time_timestamp() {
+        use crate::SolValue;
+        use time::{OffsetDateTime, PrimitiveDateTime};
+
+        type Ts = Timestamp<OffsetDateTime>;
+
+        let time = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+        let encoded = time.abi_encode();
+        assert_eq!(encoded, U256::from(1_700_000_000u64).abi_encode());
+        assert_eq!(OffsetDateTime::abi_decode(&encoded, true).unwrap(), time);
+
+        let before_epoch = OffsetDateTime::from_unix_timestamp(-1).unwrap();
+        assert_eq!(before_epoch.abi_encode(), U256::ZERO.abi_encode());
+
+        assert_eq!(
+            <OffsetDateTime as UnixTimestamp>::MAX_SECONDS,
+            PrimitiveDateTime::MAX.assume_utc().unix_timestamp() as u64
+        );
+        let max = U256::from(<OffsetDateTime as UnixTimestamp>::MAX_SECONDS);
+        assert!(Ts::abi_decode(&max.abi_encode(), true).is_ok());
+        let too_large = (max + U256::from(1)).abi_encode();
+        assert!(Ts::abi_decode(&too_large, true).is_err());
+        assert_eq!(
+            Ts::abi_decode(&too_large, false).unwrap().unix_timestamp() as u64,
+            <OffsetDateTime as UnixTimestamp>::MAX_SECONDS
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn uuid() {
+        use crate::SolValue;
+
+        let id = uuid::Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8);
+        let encoded = id.abi_encode();
+        assert_eq!(encoded, RustFixedBytes(*id.as_bytes()).abi_encode());
+        assert_eq!(uuid::Uuid::abi_decode(&encoded, true).unwrap(), id);
+        assert_eq!(id.abi_encode_packed(), id.as_bytes());
+        assert_eq!(id.sol_type_name(), "bytes16");
+
+        let mut dirty = encoded;
+        dirty[31] = 1;
+        assert!(uuid::Uuid::abi_decode(&dirty, true).is_err());
+        assert_eq!(uuid::Uuid::abi_decode(&dirty, false).unwrap(), id);
+    }
 }
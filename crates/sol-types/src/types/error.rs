@@ -61,22 +61,24 @@ pub trait SolError: Sized {
     fn abi_decode(data: &[u8], validate: bool) -> Result<Self> {
         let data = data
             .strip_prefix(&Self::SELECTOR)
-            .ok_or_else(|| crate::Error::type_check_fail_sig(data, Self::SIGNATURE))?;
+            .ok_or_else(|| crate::Error::selector_mismatch(Self::SELECTOR, data))?;
         Self::abi_decode_raw(data, validate)
     }
 
     /// ABI encode the error to the given buffer **without** its selector.
     #[inline]
     fn abi_encode_raw(&self, out: &mut Vec<u8>) {
-        out.reserve(self.abi_encoded_size());
-        out.extend(crate::abi::encode_sequence(&self.tokenize()));
+        // tokenize only once, as it allocates for arrays
+        let tokens = self.tokenize();
+        out.reserve(tokens.total_words() * Word::len_bytes());
+        out.extend_from_slice(&crate::abi::encode_sequence(&tokens));
     }
 
     /// ABI encode the error to the given buffer **with** its selector.
     #[inline]
     fn abi_encode(&self) -> Vec<u8> {
         let mut out = Vec::with_capacity(4 + self.abi_encoded_size());
-        out.extend(&Self::SELECTOR);
+        out.extend_from_slice(&Self::SELECTOR);
         self.abi_encode_raw(&mut out);
         out
     }
@@ -164,6 +166,12 @@ impl SolError for Revert {
     fn abi_encoded_size(&self) -> usize {
         64 + crate::utils::next_multiple_of_32(self.reason.len())
     }
+
+    #[inline]
+    fn abi_encode_raw(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(crate::utils::pad_usize(32).as_slice());
+        crate::private::encode_packed_seq_to(self.reason.as_bytes(), out);
+    }
 }
 
 impl Revert {
@@ -294,6 +302,11 @@ impl SolError for Panic {
     fn abi_encoded_size(&self) -> usize {
         32
     }
+
+    #[inline]
+    fn abi_encode_raw(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.code.to_be_bytes::<32>());
+    }
 }
 
 impl Panic {
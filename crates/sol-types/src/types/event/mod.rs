@@ -1,6 +1,6 @@
 use crate::{
     abi::token::{TokenSeq, TokenType, WordToken},
-    Result, SolType, Word,
+    Error, Result, SolType, Word,
 };
 use alloc::vec::Vec;
 use alloy_primitives::{FixedBytes, Log, B256};
@@ -78,14 +78,16 @@ pub trait SolEvent: Sized {
     /// ABI-encode the dynamic data of this event into the given buffer.
     #[inline]
     fn encode_data_to(&self, out: &mut Vec<u8>) {
-        out.reserve(self.abi_encoded_size());
-        out.extend(crate::abi::encode_sequence(&self.tokenize_body()));
+        // tokenize only once, as it allocates for arrays
+        let tokens = self.tokenize_body();
+        out.reserve(tokens.total_words() * Word::len_bytes());
+        out.extend_from_slice(&crate::abi::encode_sequence(&tokens));
     }
 
     /// ABI-encode the dynamic data of this event.
     #[inline]
     fn encode_data(&self) -> Vec<u8> {
-        let mut out = Vec::new();
+        let mut out = Vec::with_capacity(self.abi_encoded_size());
         self.encode_data_to(&mut out);
         out
     }
@@ -156,4 +158,35 @@ pub trait SolEvent: Sized {
     fn decode_log_object(log: &Log, validate: bool) -> Result<Self> {
         Self::decode_log(log.topics().iter().copied(), &log.data, validate)
     }
+
+    /// Decode the event from the given log topics and data, checking that
+    /// they belong to this event.
+    ///
+    /// Unlike [`decode_log`](Self::decode_log), this checks that the number of
+    /// topics matches the event's, and that the first topic is the event's
+    /// signature hash if it is not anonymous.
+    fn decode_raw_log(topics: &[B256], data: &[u8], validate: bool) -> Result<Self> {
+        Self::check_topics(topics)?;
+        Self::decode_log(topics.iter().copied(), data, validate)
+    }
+
+    /// Checks that the given log topics belong to this event: that their
+    /// number matches the event's, and that the first topic is the event's
+    /// signature hash if it is not anonymous.
+    #[inline]
+    fn check_topics(topics: &[B256]) -> Result<()> {
+        if topics.len() != Self::TopicList::COUNT {
+            return Err(Error::TopicLengthMismatch {
+                expected: Self::TopicList::COUNT,
+                actual: topics.len(),
+            })
+        }
+        if !Self::ANONYMOUS && topics[0] != Self::SIGNATURE_HASH {
+            return Err(Error::EventSignatureMismatch {
+                expected: Self::SIGNATURE_HASH,
+                actual: topics[0],
+            })
+        }
+        Ok(())
+    }
 }
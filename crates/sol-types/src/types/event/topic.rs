@@ -206,10 +206,36 @@ impl EventTopic for () {
 all_the_tuples!(tuple_impls);
 
 fn encode_topic_bytes(sl: &[u8], out: &mut Vec<u8>) {
-    let padding = 32 - sl.len() % 32;
+    let padding = crate::utils::next_multiple_of_32(sl.len()) - sl.len();
     out.reserve(sl.len() + padding);
 
     static PAD: [u8; 32] = [0; 32];
     out.extend_from_slice(sl);
     out.extend_from_slice(&PAD[..padding]);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String as RustString, ToString};
+
+    #[test]
+    fn bytes_preimage_padding() {
+        for len in [0, 1, 31, 32, 33, 64] {
+            let s: RustString = "a".repeat(len);
+            let mut out = Vec::new();
+            String::encode_topic_preimage(&s, &mut out);
+            assert_eq!(out.len(), String::topic_preimage_length(&s), "{len}");
+            assert_eq!(out.len(), crate::utils::next_multiple_of_32(len), "{len}");
+            assert_eq!(out[..len], *s.as_bytes());
+        }
+
+        let ss = ["a".repeat(32), RustString::new(), "b".to_string()];
+        let mut expected = ss[0].as_bytes().to_vec();
+        expected.extend([b'b'].iter().chain(&[0; 31]));
+        assert_eq!(
+            FixedArray::<String, 3>::encode_topic(&ss).0,
+            keccak256(expected)
+        );
+    }
+}
@@ -1,5 +1,4 @@
 use crate::{abi::token::WordToken, Error, Result, SolType};
-use alloc::borrow::Cow;
 
 mod sealed {
     pub trait Sealed {}
@@ -46,10 +45,17 @@ macro_rules! impl_topic_list_tuples {
                 I: IntoIterator<Item = D>,
                 D: Into<WordToken>
             {
-                let err = || Error::Other(Cow::Borrowed("topic list length mismatch"));
                 let mut iter = topics.into_iter();
+                let mut actual = 0;
+                let mut next = || {
+                    let topic = iter
+                        .next()
+                        .ok_or(Error::TopicLengthMismatch { expected: $c, actual })?;
+                    actual += 1;
+                    Ok::<_, Error>(topic.into())
+                };
                 Ok(($(
-                    <$t>::detokenize(iter.next().ok_or_else(err)?.into()),
+                    <$t>::detokenize(next()?),
                 )*))
             }
         }
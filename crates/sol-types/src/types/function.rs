@@ -4,6 +4,7 @@ use crate::{
     Result, SolType, Word,
 };
 use alloc::vec::Vec;
+use alloy_primitives::gas::CalldataCosts;
 
 /// A Solidity function call.
 ///
@@ -67,26 +68,92 @@ pub trait SolCall: Sized {
     fn abi_decode(data: &[u8], validate: bool) -> Result<Self> {
         let data = data
             .strip_prefix(&Self::SELECTOR)
-            .ok_or_else(|| crate::Error::type_check_fail_sig(data, Self::SIGNATURE))?;
+            .ok_or_else(|| crate::Error::selector_mismatch(Self::SELECTOR, data))?;
         Self::abi_decode_raw(data, validate)
     }
 
+    /// ABI decode this call's arguments from the given slice, **without** its
+    /// selector, erroring if the slice has trailing bytes.
+    ///
+    /// See [`abi::decode_sequence_exact`](crate::abi::decode_sequence_exact)
+    /// for more information.
+    #[inline]
+    fn abi_decode_raw_exact(data: &[u8], validate: bool) -> Result<Self> {
+        <Self::Arguments<'_> as SolType>::abi_decode_sequence_exact(data, validate).map(Self::new)
+    }
+
+    /// ABI decode this call's arguments from the given slice, **with** the
+    /// selector, erroring if the slice has trailing bytes.
+    ///
+    /// See [`abi::decode_sequence_exact`](crate::abi::decode_sequence_exact)
+    /// for more information.
+    #[inline]
+    fn abi_decode_exact(data: &[u8], validate: bool) -> Result<Self> {
+        let data = data
+            .strip_prefix(&Self::SELECTOR)
+            .ok_or_else(|| crate::Error::selector_mismatch(Self::SELECTOR, data))?;
+        Self::abi_decode_raw_exact(data, validate)
+    }
+
+    /// ABI decode this call's arguments from the given slice, **without** its
+    /// selector, returning the bytes trailing them.
+    ///
+    /// See [`abi::decode_sequence_with_trailing`](crate::abi::decode_sequence_with_trailing)
+    /// for more information.
+    #[inline]
+    fn abi_decode_raw_with_trailing(data: &[u8], validate: bool) -> Result<(Self, &[u8])> {
+        <Self::Arguments<'_> as SolType>::abi_decode_sequence_with_trailing(data, validate)
+            .map(|(args, trailing)| (Self::new(args), trailing))
+    }
+
+    /// ABI decode this call's arguments from the given slice, **with** the
+    /// selector, returning the bytes trailing them.
+    ///
+    /// This can be used to decode calls relayed by an [ERC-2771] forwarder,
+    /// which appends the address of the original sender to the calldata.
+    ///
+    /// See [`abi::decode_sequence_with_trailing`](crate::abi::decode_sequence_with_trailing)
+    /// for more information.
+    ///
+    /// [ERC-2771]: https://eips.ethereum.org/EIPS/eip-2771
+    #[inline]
+    fn abi_decode_with_trailing(data: &[u8], validate: bool) -> Result<(Self, &[u8])> {
+        let data = data
+            .strip_prefix(&Self::SELECTOR)
+            .ok_or_else(|| crate::Error::selector_mismatch(Self::SELECTOR, data))?;
+        Self::abi_decode_raw_with_trailing(data, validate)
+    }
+
     /// ABI encode the call to the given buffer **without** its selector.
     #[inline]
     fn abi_encode_raw(&self, out: &mut Vec<u8>) {
-        out.reserve(self.abi_encoded_size());
-        out.extend(crate::abi::encode_sequence(&self.tokenize()));
+        // tokenize only once, as it allocates for arrays
+        let tokens = self.tokenize();
+        out.reserve(tokens.total_words() * Word::len_bytes());
+        out.extend_from_slice(&crate::abi::encode_sequence(&tokens));
     }
 
     /// ABI encode the call to the given buffer **with** its selector.
     #[inline]
     fn abi_encode(&self) -> Vec<u8> {
         let mut out = Vec::with_capacity(4 + self.abi_encoded_size());
-        out.extend(&Self::SELECTOR);
+        out.extend_from_slice(&Self::SELECTOR);
         self.abi_encode_raw(&mut out);
         out
     }
 
+    /// Returns the calldata gas cost of the ABI-encoded call, **with** its
+    /// selector, under the given per-fork costs.
+    ///
+    /// This is the greater of the standard and [EIP-7623] floor costs; see
+    /// [`CalldataCosts::min_gas`] for details.
+    ///
+    /// [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+    #[inline]
+    fn abi_encoded_calldata_gas(&self, costs: &CalldataCosts) -> u64 {
+        costs.min_gas(&self.abi_encode())
+    }
+
     /// ABI decode this call's return values from the given slice.
     fn abi_decode_returns(data: &[u8], validate: bool) -> Result<Self::Return>;
 
@@ -96,6 +163,8 @@ pub trait SolCall: Sized {
     where
         E: SolTypeValue<Self::ReturnTuple<'a>>,
     {
-        crate::abi::encode_sequence(&e.stv_to_tokens())
+        let mut out = Vec::with_capacity(e.stv_abi_encoded_size());
+        e.stv_abi_encode_to(&mut out);
+        out
     }
 }
@@ -1,5 +1,8 @@
 use crate::{Error, Panic, Result, Revert, SolError};
-use alloc::vec::Vec;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
 use core::{convert::Infallible, fmt, iter::FusedIterator, marker::PhantomData};
 
 #[cfg(feature = "std")]
@@ -442,6 +445,96 @@ impl<T: SolInterface> ExactSizeIterator for Selectors<T> {
 
 impl<T: SolInterface> FusedIterator for Selectors<T> {}
 
+/// Detects selectors shared by several [`SolInterface`] types.
+///
+/// Proxies and diamonds route calls to an implementation based on their
+/// selector only, so a selector declared by several interfaces is silently
+/// routed to just one of them. Similarly, errors sharing a selector cannot be
+/// told apart when decoding revert data.
+///
+/// Interfaces only expose their selectors, so this reports the names of the
+/// colliding interfaces rather than the colliding items. Use
+/// `JsonAbi::function_collisions` and related methods from `alloy-json-abi` to
+/// also get the items' signatures, and event topic collisions.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_sol_types::{sol, SelectorCollisions, SolCall};
+///
+/// sol! {
+///     interface Proxy {
+///         function upgradeTo(address implementation);
+///     }
+/// }
+///
+/// sol! {
+///     interface Implementation {
+///         function upgradeTo(address implementation);
+///         function transfer(address to, uint256 amount);
+///     }
+/// }
+///
+/// let collisions = SelectorCollisions::new()
+///     .with::<Proxy::ProxyCalls>()
+///     .with::<Implementation::ImplementationCalls>();
+/// assert_eq!(
+///     collisions.collisions().collect::<Vec<_>>(),
+///     [(
+///         Proxy::upgradeToCall::SELECTOR,
+///         &["ProxyCalls", "ImplementationCalls"][..]
+///     )]
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SelectorCollisions {
+    selectors: BTreeMap<[u8; 4], Vec<&'static str>>,
+    /// The type names of the added interfaces.
+    added: BTreeSet<&'static str>,
+}
+
+impl SelectorCollisions {
+    /// Creates a new, empty collision detector.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the selectors of `T`, identified by its [name](SolInterface::NAME).
+    ///
+    /// Adding the same type more than once has no effect.
+    pub fn add<T: SolInterface>(&mut self) -> &mut Self {
+        if self.added.insert(core::any::type_name::<T>()) {
+            for selector in T::selectors() {
+                self.selectors.entry(selector).or_default().push(T::NAME);
+            }
+        }
+        self
+    }
+
+    /// Adds the selectors of `T`, returning `self`.
+    #[inline]
+    pub fn with<T: SolInterface>(mut self) -> Self {
+        self.add::<T>();
+        self
+    }
+
+    /// Returns an iterator over the colliding selectors, with the names of the
+    /// interfaces declaring them, in order of selector and then of addition.
+    pub fn collisions(&self) -> impl Iterator<Item = ([u8; 4], &[&'static str])> + '_ {
+        self.selectors
+            .iter()
+            .filter(|(_, names)| names.len() > 1)
+            .map(|(selector, names)| (*selector, names.as_slice()))
+    }
+
+    /// Returns `true` if any selector is declared by more than one interface.
+    #[inline]
+    pub fn has_collisions(&self) -> bool {
+        self.collisions().next().is_some()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -479,6 +572,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn selector_collisions() {
+        crate::sol! {
+            contract A {
+                function f();
+                function g(uint256);
+                error E();
+            }
+        }
+
+        crate::sol! {
+            contract B {
+                function g(uint256);
+                function h();
+                error E();
+            }
+        }
+
+        let mut collisions = SelectorCollisions::new();
+        assert!(!collisions.has_collisions());
+        collisions.add::<A::ACalls>().add::<A::ACalls>();
+        assert!(!collisions.has_collisions());
+
+        collisions.add::<B::BCalls>();
+        assert_eq!(
+            collisions.collisions().collect::<Vec<_>>(),
+            [(sel("g(uint256)"), &["ACalls", "BCalls"][..])]
+        );
+
+        let errors = SelectorCollisions::new()
+            .with::<A::AErrors>()
+            .with::<B::BErrors>()
+            .with::<GenericContractError>();
+        assert_eq!(
+            errors.collisions().collect::<Vec<_>>(),
+            [(sel("E()"), &["AErrors", "BErrors"][..])]
+        );
+
+        let errors = errors.with::<ContractError<A::AErrors>>();
+        assert_eq!(errors.collisions().count(), 3);
+    }
+
     #[test]
     fn contract_error_enum_2() {
         crate::sol! {
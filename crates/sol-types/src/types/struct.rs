@@ -2,9 +2,9 @@
 //! Solidity structs logic, particularly for EIP-712 encoding/decoding.
 
 use super::SolType;
-use crate::Eip712Domain;
+use crate::{Eip712Domain, Eip712DomainRegistry};
 use alloc::{borrow::Cow, string::String, vec::Vec};
-use alloy_primitives::{keccak256, B256};
+use alloy_primitives::{keccak256, Address, B256};
 
 /// A Solidity struct.
 ///
@@ -108,4 +108,21 @@ pub trait SolStruct: SolType<RustType = Self> {
         digest_input[34..66].copy_from_slice(&self.eip712_hash_struct()[..]);
         keccak256(digest_input)
     }
+
+    /// Computes the [`eip712_signing_hash`](SolStruct::eip712_signing_hash)
+    /// with the domain of the given verifying contract on the given chain,
+    /// looked up in `registry`.
+    ///
+    /// Returns `None` if the domain is not registered.
+    #[inline]
+    fn eip712_signing_hash_from(
+        &self,
+        registry: &Eip712DomainRegistry,
+        chain_id: u64,
+        verifying_contract: Address,
+    ) -> Option<B256> {
+        registry
+            .get(chain_id, verifying_contract)
+            .map(|domain| self.eip712_signing_hash(domain))
+    }
 }
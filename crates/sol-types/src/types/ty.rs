@@ -148,6 +148,27 @@ pub trait SolType: Sized {
     /// See the [`abi::token`] module for more information.
     fn detokenize(token: Self::TokenType<'_>) -> Self::RustType;
 
+    /// Decodes this type's value directly from the decoder, without
+    /// type-checking it.
+    ///
+    /// Defaults to decoding and detokenizing this type's token. Arrays, tuples
+    /// and [`sol!`](crate::sol!) structs decode their elements directly
+    /// instead.
+    #[inline]
+    fn abi_decode_from(dec: &mut abi::Decoder<'_>) -> Result<Self::RustType> {
+        <Self::TokenType<'_> as TokenType<'_>>::decode_from(dec).map(Self::detokenize)
+    }
+
+    /// Decodes this type's value directly from the decoder as a sequence,
+    /// without type-checking it.
+    ///
+    /// This only differs from [`abi_decode_from`](Self::abi_decode_from) for
+    /// tuples, whose elements are decoded in place.
+    #[inline]
+    fn abi_decode_sequence_from(dec: &mut abi::Decoder<'_>) -> Result<Self::RustType> {
+        Self::abi_decode_from(dec)
+    }
+
     /// Tokenizes the given value into this type's token.
     ///
     /// See the [`abi::token`] module for more information.
@@ -192,16 +213,21 @@ pub trait SolType: Sized {
         out
     }
 
-    /// Tokenizes and ABI-encodes the given value by wrapping it in a
+    /// ABI-encodes the given value by wrapping it in a
     /// single-element sequence.
     ///
     /// See the [`abi`] module for more information.
     #[inline]
     fn abi_encode<E: ?Sized + SolTypeValue<Self>>(rust: &E) -> Vec<u8> {
-        abi::encode(&rust.stv_to_tokens())
+        let mut out = Vec::with_capacity(Self::DYNAMIC as usize * 32 + rust.stv_abi_encoded_size());
+        if Self::DYNAMIC {
+            out.extend_from_slice(crate::utils::pad_usize(32).as_slice());
+        }
+        rust.stv_abi_encode_to(&mut out);
+        out
     }
 
-    /// Tokenizes and ABI-encodes the given value as function parameters.
+    /// ABI-encodes the given value as function parameters.
     ///
     /// See the [`abi`] module for more information.
     #[inline]
@@ -209,10 +235,14 @@ pub trait SolType: Sized {
     where
         for<'a> Self::TokenType<'a>: TokenSeq<'a>,
     {
-        abi::encode_params(&rust.stv_to_tokens())
+        if <Self::TokenType<'_> as TokenSeq<'_>>::IS_TUPLE {
+            Self::abi_encode_sequence(rust)
+        } else {
+            Self::abi_encode(rust)
+        }
     }
 
-    /// Tokenizes and ABI-encodes the given value as a sequence.
+    /// ABI-encodes the given value as a sequence.
     ///
     /// See the [`abi`] module for more information.
     #[inline]
@@ -220,7 +250,15 @@ pub trait SolType: Sized {
     where
         for<'a> Self::TokenType<'a>: TokenSeq<'a>,
     {
-        abi::encode_sequence(&rust.stv_to_tokens())
+        // the encoding of a tuple is that of its elements as a sequence, but
+        // the encoding of an array also contains its length
+        if <Self::TokenType<'_> as TokenSeq<'_>>::IS_TUPLE {
+            let mut out = Vec::with_capacity(rust.stv_abi_encoded_size());
+            rust.stv_abi_encode_to(&mut out);
+            out
+        } else {
+            abi::encode_sequence(&rust.stv_to_tokens())
+        }
     }
 
     /// Decodes this type's value from an ABI blob by interpreting it as a
@@ -229,7 +267,12 @@ pub trait SolType: Sized {
     /// See the [`abi`] module for more information.
     #[inline]
     fn abi_decode(data: &[u8], validate: bool) -> Result<Self::RustType> {
-        abi::decode::<Self::TokenType<'_>>(data, validate).and_then(check_decode::<Self>(validate))
+        if validate {
+            abi::decode::<Self::TokenType<'_>>(data, validate)
+                .and_then(check_decode::<Self>(validate))
+        } else {
+            Self::abi_decode_from(&mut abi::Decoder::new(data, false))
+        }
     }
 
     /// Decodes this type's value from an ABI blob by interpreting it as
@@ -241,8 +284,11 @@ pub trait SolType: Sized {
     where
         Self::TokenType<'de>: TokenSeq<'de>,
     {
-        abi::decode_params::<Self::TokenType<'_>>(data, validate)
-            .and_then(check_decode::<Self>(validate))
+        if <Self::TokenType<'de> as TokenSeq<'de>>::IS_TUPLE {
+            Self::abi_decode_sequence(data, validate)
+        } else {
+            Self::abi_decode(data, validate)
+        }
     }
 
     /// Decodes this type's value from an ABI blob by interpreting it as a
@@ -254,9 +300,94 @@ pub trait SolType: Sized {
     where
         Self::TokenType<'de>: TokenSeq<'de>,
     {
-        abi::decode_sequence::<Self::TokenType<'_>>(data, validate)
+        if validate || !<Self::TokenType<'de> as TokenSeq<'de>>::IS_TUPLE {
+            abi::decode_sequence::<Self::TokenType<'_>>(data, validate)
+                .and_then(check_decode::<Self>(validate))
+        } else {
+            Self::abi_decode_sequence_from(&mut abi::Decoder::new(data, false))
+        }
+    }
+
+    /// Decodes this type's value from an ABI blob by interpreting it as a
+    /// single-element sequence, erroring if the blob has trailing bytes.
+    ///
+    /// See [`abi::decode_sequence_exact`] for more information.
+    #[inline]
+    fn abi_decode_exact(data: &[u8], validate: bool) -> Result<Self::RustType> {
+        abi::decode_exact::<Self::TokenType<'_>>(data, validate)
             .and_then(check_decode::<Self>(validate))
     }
+
+    /// Decodes this type's value from an ABI blob by interpreting it as
+    /// function parameters, erroring if the blob has trailing bytes.
+    ///
+    /// See [`abi::decode_sequence_exact`] for more information.
+    #[inline]
+    fn abi_decode_params_exact<'de>(data: &'de [u8], validate: bool) -> Result<Self::RustType>
+    where
+        Self::TokenType<'de>: TokenSeq<'de>,
+    {
+        abi::decode_params_exact::<Self::TokenType<'_>>(data, validate)
+            .and_then(check_decode::<Self>(validate))
+    }
+
+    /// Decodes this type's value from an ABI blob by interpreting it as a
+    /// sequence, erroring if the blob has trailing bytes.
+    ///
+    /// See [`abi::decode_sequence_exact`] for more information.
+    #[inline]
+    fn abi_decode_sequence_exact<'de>(data: &'de [u8], validate: bool) -> Result<Self::RustType>
+    where
+        Self::TokenType<'de>: TokenSeq<'de>,
+    {
+        abi::decode_sequence_exact::<Self::TokenType<'_>>(data, validate).and_then(check_decode::<
+            Self,
+        >(
+            validate
+        ))
+    }
+
+    /// Decodes this type's value from an ABI blob by interpreting it as a
+    /// single-element sequence, returning the bytes trailing its encoding.
+    ///
+    /// See [`abi::decode_sequence_with_trailing`] for more information.
+    #[inline]
+    fn abi_decode_with_trailing(data: &[u8], validate: bool) -> Result<(Self::RustType, &[u8])> {
+        abi::decode_with_trailing::<Self::TokenType<'_>>(data, validate)
+            .and_then(|(token, trailing)| Ok((check_decode::<Self>(validate)(token)?, trailing)))
+    }
+
+    /// Decodes this type's value from an ABI blob by interpreting it as
+    /// function parameters, returning the bytes trailing their encoding.
+    ///
+    /// See [`abi::decode_sequence_with_trailing`] for more information.
+    #[inline]
+    fn abi_decode_params_with_trailing<'de>(
+        data: &'de [u8],
+        validate: bool,
+    ) -> Result<(Self::RustType, &'de [u8])>
+    where
+        Self::TokenType<'de>: TokenSeq<'de>,
+    {
+        abi::decode_params_with_trailing::<Self::TokenType<'_>>(data, validate)
+            .and_then(|(token, trailing)| Ok((check_decode::<Self>(validate)(token)?, trailing)))
+    }
+
+    /// Decodes this type's value from an ABI blob by interpreting it as a
+    /// sequence, returning the bytes trailing its encoding.
+    ///
+    /// See [`abi::decode_sequence_with_trailing`] for more information.
+    #[inline]
+    fn abi_decode_sequence_with_trailing<'de>(
+        data: &'de [u8],
+        validate: bool,
+    ) -> Result<(Self::RustType, &'de [u8])>
+    where
+        Self::TokenType<'de>: TokenSeq<'de>,
+    {
+        abi::decode_sequence_with_trailing::<Self::TokenType<'_>>(data, validate)
+            .and_then(|(token, trailing)| Ok((check_decode::<Self>(validate)(token)?, trailing)))
+    }
 }
 
 #[inline]
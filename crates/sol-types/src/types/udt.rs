@@ -34,6 +34,11 @@ macro_rules! define_udt {
             fn stv_abi_encode_packed_to(&self, out: &mut $crate::private::Vec<u8>) {
                 <$underlying as $crate::SolType>::abi_encode_packed_to(self, out)
             }
+
+            #[inline]
+            fn stv_abi_encode_to(&self, out: &mut $crate::private::Vec<u8>) {
+                $crate::private::SolTypeValue::<$underlying>::stv_abi_encode_to(self, out)
+            }
         }
 
         impl $name {
@@ -161,6 +161,85 @@ pub trait SolValue: SolTypeValue<Self::SolType> {
     {
         Self::SolType::abi_decode_sequence(data, validate).map(Self::from)
     }
+
+    /// ABI-decode this type from the given data, erroring on trailing bytes.
+    ///
+    /// See [`SolType::abi_decode_exact`] for more information.
+    #[inline]
+    fn abi_decode_exact(data: &[u8], validate: bool) -> Result<Self>
+    where
+        Self: From<<Self::SolType as SolType>::RustType>,
+    {
+        Self::SolType::abi_decode_exact(data, validate).map(Self::from)
+    }
+
+    /// ABI-decode this type from the given data, erroring on trailing bytes.
+    ///
+    /// See [`SolType::abi_decode_params_exact`] for more information.
+    #[inline]
+    fn abi_decode_params_exact<'de>(data: &'de [u8], validate: bool) -> Result<Self>
+    where
+        Self: From<<Self::SolType as SolType>::RustType>,
+        <Self::SolType as SolType>::TokenType<'de>: TokenSeq<'de>,
+    {
+        Self::SolType::abi_decode_params_exact(data, validate).map(Self::from)
+    }
+
+    /// ABI-decode this type from the given data, erroring on trailing bytes.
+    ///
+    /// See [`SolType::abi_decode_sequence_exact`] for more information.
+    #[inline]
+    fn abi_decode_sequence_exact<'de>(data: &'de [u8], validate: bool) -> Result<Self>
+    where
+        Self: From<<Self::SolType as SolType>::RustType>,
+        <Self::SolType as SolType>::TokenType<'de>: TokenSeq<'de>,
+    {
+        Self::SolType::abi_decode_sequence_exact(data, validate).map(Self::from)
+    }
+
+    /// ABI-decode this type from the given data, returning the trailing bytes.
+    ///
+    /// See [`SolType::abi_decode_with_trailing`] for more information.
+    #[inline]
+    fn abi_decode_with_trailing(data: &[u8], validate: bool) -> Result<(Self, &[u8])>
+    where
+        Self: From<<Self::SolType as SolType>::RustType>,
+    {
+        Self::SolType::abi_decode_with_trailing(data, validate)
+            .map(|(value, trailing)| (Self::from(value), trailing))
+    }
+
+    /// ABI-decode this type from the given data, returning the trailing bytes.
+    ///
+    /// See [`SolType::abi_decode_params_with_trailing`] for more information.
+    #[inline]
+    fn abi_decode_params_with_trailing<'de>(
+        data: &'de [u8],
+        validate: bool,
+    ) -> Result<(Self, &'de [u8])>
+    where
+        Self: From<<Self::SolType as SolType>::RustType>,
+        <Self::SolType as SolType>::TokenType<'de>: TokenSeq<'de>,
+    {
+        Self::SolType::abi_decode_params_with_trailing(data, validate)
+            .map(|(value, trailing)| (Self::from(value), trailing))
+    }
+
+    /// ABI-decode this type from the given data, returning the trailing bytes.
+    ///
+    /// See [`SolType::abi_decode_sequence_with_trailing`] for more information.
+    #[inline]
+    fn abi_decode_sequence_with_trailing<'de>(
+        data: &'de [u8],
+        validate: bool,
+    ) -> Result<(Self, &'de [u8])>
+    where
+        Self: From<<Self::SolType as SolType>::RustType>,
+        <Self::SolType as SolType>::TokenType<'de>: TokenSeq<'de>,
+    {
+        Self::SolType::abi_decode_sequence_with_trailing(data, validate)
+            .map(|(value, trailing)| (Self::from(value), trailing))
+    }
 }
 
 macro_rules! impl_sol_value {
@@ -201,14 +280,30 @@ impl_sol_value! {
 
     [] Vec<u8> => sol_data::Bytes [];
     [] [u8] => sol_data::Bytes [];
+    ['a] Cow<'a, str> => sol_data::String [];
+    ['a] Cow<'a, [u8]> => sol_data::Bytes [];
 
     // Generic
     [T: SolValue] Vec<T> => sol_data::Array<T::SolType> [];
     [T: SolValue] [T] => sol_data::Array<T::SolType> [];
+    ['a, T: Clone + SolValue] Cow<'a, [T]> => sol_data::Array<T::SolType> [];
     [T: SolValue, const N: usize] [T; N] => sol_data::FixedArray<T::SolType, N> [];
+    #[cfg(feature = "smallvec")]
+    [A: smallvec::Array] smallvec::SmallVec<A> => sol_data::Array<<A::Item as SolValue>::SolType> [where A::Item: SolValue];
+    #[cfg(feature = "arrayvec")]
+    [T: SolValue, const CAP: usize] arrayvec::ArrayVec<T, CAP> => sol_data::Array<T::SolType> [];
 
     ['a, T: ?Sized + SolValue] &'a T => T::SolType [where &'a T: SolTypeValue<T::SolType>];
     ['a, T: ?Sized + SolValue] &'a mut T => T::SolType [where &'a mut T: SolTypeValue<T::SolType>];
+
+    // Timestamps
+    #[cfg(feature = "chrono")]
+    [] chrono::DateTime<chrono::Utc> => sol_data::Timestamp<Self> [];
+    #[cfg(feature = "time")]
+    [] time::OffsetDateTime => sol_data::Timestamp<Self> [];
+
+    #[cfg(feature = "uuid")]
+    [] uuid::Uuid => sol_data::Uuid [];
 }
 
 macro_rules! tuple_impls {
@@ -386,6 +481,37 @@ mod tests {
         assert_eq!(tuple.sol_type_name(), "(uint16,string,bytes1,address[])");
     }
 
+    #[test]
+    fn cow() {
+        let s = Cow::Borrowed("hello");
+        assert_eq!(s.sol_type_name(), "string");
+        assert_eq!(s.abi_encode(), "hello".abi_encode());
+        assert_eq!(Cow::<str>::abi_decode(&s.abi_encode(), true).unwrap(), s);
+
+        let b = Cow::Borrowed(&b"\x01\x02"[..]);
+        assert_eq!(b.sol_type_name(), "bytes");
+        assert_eq!(b.abi_encode(), Bytes::from_static(b"\x01\x02").abi_encode());
+        assert_eq!(Cow::<[u8]>::abi_decode(&b.abi_encode(), true).unwrap(), b);
+
+        let addresses = [Address::ZERO, Address::repeat_byte(1)];
+        let a = Cow::Borrowed(&addresses[..]);
+        assert_eq!(a.sol_type_name(), "address[]");
+        assert_eq!(a.abi_encode(), addresses.to_vec().abi_encode());
+        assert_eq!(a.eip712_data_word(), addresses[..].eip712_data_word());
+        assert_eq!(
+            Cow::<[Address]>::abi_decode(&a.abi_encode(), true).unwrap(),
+            a
+        );
+
+        let strings: Vec<Cow<'_, str>> = vec!["a".into(), String::from("b").into()];
+        assert_eq!(strings.sol_type_name(), "string[]");
+        assert_eq!(strings.abi_encode(), vec!["a", "b"].abi_encode());
+        assert_eq!(
+            (s, b, a).abi_encode_params(),
+            ("hello", b"\x01\x02".to_vec(), addresses.to_vec()).abi_encode_params()
+        );
+    }
+
     #[test]
     fn decode() {
         let _: Result<String> = String::abi_decode(b"", false);
@@ -396,4 +522,43 @@ mod tests {
         let _: Result<(i64, Vec<(u32, String, Vec<FixedBytes<4>>)>, U256)> =
             <(i64, Vec<(u32, String, Vec<FixedBytes<4>>)>, U256)>::abi_decode(b"", false);
     }
+
+    #[test]
+    #[cfg(feature = "smallvec")]
+    fn smallvec() {
+        let v: smallvec::SmallVec<[U256; 4]> = [U256::from(1), U256::from(2)].into_iter().collect();
+        let vec = v.to_vec();
+        assert_eq!(v.sol_type_name(), "uint256[]");
+        assert_eq!(v.abi_encode(), vec.abi_encode());
+        assert_eq!(v.abi_encode_packed(), vec.abi_encode_packed());
+        assert_eq!(v.abi_encoded_size(), vec.abi_encoded_size());
+        assert_eq!(v.eip712_data_word(), vec.eip712_data_word());
+        assert_eq!(
+            smallvec::SmallVec::<[U256; 4]>::abi_decode(&v.abi_encode(), true).unwrap(),
+            v
+        );
+
+        let spilled: smallvec::SmallVec<[String; 1]> =
+            ["a".into(), "b".into()].into_iter().collect();
+        assert!(spilled.spilled());
+        assert_eq!(spilled.abi_encode(), spilled.to_vec().abi_encode());
+    }
+
+    #[test]
+    #[cfg(feature = "arrayvec")]
+    fn arrayvec() {
+        let mut v = arrayvec::ArrayVec::<(Address, bool), 4>::new();
+        v.push((Address::ZERO, true));
+        v.push((Address::repeat_byte(1), false));
+        let vec = v.to_vec();
+        assert_eq!(v.sol_type_name(), "(address,bool)[]");
+        assert_eq!(v.abi_encode(), vec.abi_encode());
+        assert_eq!(v.abi_encode_packed(), vec.abi_encode_packed());
+        assert_eq!(v.abi_encoded_size(), vec.abi_encoded_size());
+        assert_eq!(v.eip712_data_word(), vec.eip712_data_word());
+        assert_eq!(
+            (v.clone(), 1u64).abi_encode_params(),
+            (vec, 1u64).abi_encode_params()
+        );
+    }
 }
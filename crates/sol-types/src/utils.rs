@@ -8,11 +8,52 @@
 // except according to those terms.
 
 //! Utilities used by different modules.
+//!
+//! The word-level helpers are used internally by the ABI decoder, and are
+//! exported so that external decoders and fuzzers can use the same
+//! overflow-safe arithmetic.
 
 use crate::{Error, Result, Word};
 
 const USIZE_BYTES: usize = usize::BITS as usize / 8;
 
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::Word {}
+}
+use sealed::Sealed;
+
+/// Extension methods for [`Word`].
+///
+/// This trait is sealed and implemented only on [`Word`].
+pub trait WordExt: Sealed {
+    /// Converts the word, interpreted as a big-endian integer, to a `usize`.
+    ///
+    /// Returns `None` if the value does not fit in a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_sol_types::{utils::WordExt, Word};
+    ///
+    /// assert_eq!(Word::with_last_byte(0x20).try_as_usize(), Some(0x20));
+    /// assert_eq!(Word::repeat_byte(0xff).try_as_usize(), None);
+    /// ```
+    fn try_as_usize(&self) -> Option<usize>;
+}
+
+impl WordExt for Word {
+    #[inline]
+    fn try_as_usize(&self) -> Option<usize> {
+        let (before, data) = self.split_at(32 - USIZE_BYTES);
+        if check_zeroes(before) {
+            Some(usize::from_be_bytes(data.try_into().unwrap()))
+        } else {
+            None
+        }
+    }
+}
+
 /// Calculates the padded length of a slice by rounding its length to the next
 /// word.
 #[inline(always)]
@@ -42,14 +83,67 @@ pub const fn next_multiple_of_32(n: usize) -> usize {
     }
 }
 
+/// See [`usize::checked_next_multiple_of`].
+#[inline(always)]
+pub const fn checked_next_multiple_of_32(n: usize) -> Option<usize> {
+    match n % 32 {
+        0 => Some(n),
+        r => n.checked_add(32 - r),
+    }
+}
+
+/// Returns the end of the range of `len` bytes starting at `offset`, if it
+/// does not overflow and is within a buffer of length `buf_len`.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_sol_types::utils::checked_end;
+///
+/// assert_eq!(checked_end(32, 32, 64), Some(64));
+/// assert_eq!(checked_end(32, 33, 64), None);
+/// assert_eq!(checked_end(usize::MAX, 1, usize::MAX), None);
+/// ```
+#[inline]
+pub const fn checked_end(offset: usize, len: usize, buf_len: usize) -> Option<usize> {
+    match offset.checked_add(len) {
+        Some(end) if end <= buf_len => Some(end),
+        _ => None,
+    }
+}
+
 /// Left-pads a `usize` to 32 bytes.
 #[inline]
-pub(crate) fn pad_usize(value: usize) -> Word {
+pub fn pad_usize(value: usize) -> Word {
     let mut padded = Word::ZERO;
     padded[32 - USIZE_BYTES..32].copy_from_slice(&value.to_be_bytes());
     padded
 }
 
+/// Left-pads `data` with zeroes to 32 bytes, like a value type.
+///
+/// Returns `None` if `data` is longer than 32 bytes.
+#[inline]
+pub fn left_pad(data: &[u8]) -> Option<Word> {
+    let start = 32usize.checked_sub(data.len())?;
+    let mut padded = Word::ZERO;
+    padded[start..].copy_from_slice(data);
+    Some(padded)
+}
+
+/// Right-pads `data` with zeroes to 32 bytes, like a fixed-size byte array.
+///
+/// Returns `None` if `data` is longer than 32 bytes.
+#[inline]
+pub fn right_pad(data: &[u8]) -> Option<Word> {
+    if data.len() > 32 {
+        return None
+    }
+    let mut padded = Word::ZERO;
+    padded[..data.len()].copy_from_slice(data);
+    Some(padded)
+}
+
 /// Returns `Ok(())`. Exists for the [`define_udt!`](crate::define_udt!)'s
 /// typecheck.
 #[doc(hidden)]
@@ -65,12 +159,13 @@ pub(crate) fn check_zeroes(data: &[u8]) -> bool {
 
 #[inline]
 pub(crate) fn as_offset(word: &Word, validate: bool) -> Result<usize> {
-    let (before, data) = word.split_at(32 - USIZE_BYTES);
-    if validate && !check_zeroes(before) {
-        return Err(Error::type_check_fail(&word[..], "offset (usize)"))
+    if validate {
+        return word
+            .try_as_usize()
+            .ok_or_else(|| Error::type_check_fail(&word[..], "offset (usize)"))
     }
     Ok(usize::from_be_bytes(
-        <[u8; USIZE_BYTES]>::try_from(data).unwrap(),
+        <[u8; USIZE_BYTES]>::try_from(&word[32 - USIZE_BYTES..]).unwrap(),
     ))
 }
 
@@ -87,6 +182,40 @@ mod tests {
         assert_eq!(words_for(&[0; 33]), 2);
     }
 
+    #[test]
+    fn test_try_as_usize() {
+        assert_eq!(Word::ZERO.try_as_usize(), Some(0));
+        assert_eq!(pad_usize(usize::MAX).try_as_usize(), Some(usize::MAX));
+        let mut word = pad_usize(1);
+        word[32 - USIZE_BYTES - 1] = 1;
+        assert_eq!(word.try_as_usize(), None);
+        assert!(as_offset(&word, true).is_err());
+        assert_eq!(as_offset(&word, false).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_checked_math() {
+        assert_eq!(checked_next_multiple_of_32(0), Some(0));
+        assert_eq!(checked_next_multiple_of_32(33), Some(64));
+        assert_eq!(checked_next_multiple_of_32(usize::MAX), None);
+        assert_eq!(checked_end(0, 0, 0), Some(0));
+        assert_eq!(checked_end(1, 0, 0), None);
+        assert_eq!(checked_end(usize::MAX, 0, usize::MAX), Some(usize::MAX));
+        assert_eq!(checked_end(usize::MAX, 1, usize::MAX), None);
+    }
+
+    #[test]
+    fn test_pad() {
+        assert_eq!(left_pad(&[]), Some(Word::ZERO));
+        assert_eq!(left_pad(&[1, 2]).unwrap()[30..], [1, 2]);
+        assert_eq!(right_pad(&[1, 2]).unwrap()[..2], [1, 2]);
+        assert_eq!(left_pad(&[1; 32]), Some(Word::repeat_byte(1)));
+        assert_eq!(right_pad(&[1; 32]), Some(Word::repeat_byte(1)));
+        assert_eq!(left_pad(&[1; 33]), None);
+        assert_eq!(right_pad(&[1; 33]), None);
+        assert_eq!(left_pad(&[0x20]), Some(pad_usize(0x20)));
+    }
+
     #[test]
     fn test_pad_u32() {
         // this will fail if endianness is not supported
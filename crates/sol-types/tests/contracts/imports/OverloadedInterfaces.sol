@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+interface A {
+    function f(uint256) external;
+    function f(bool) external;
+}
+
+interface B {
+    function f(bool) external;
+    function f(address) external;
+}
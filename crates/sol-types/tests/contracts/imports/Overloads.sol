@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import "./OverloadedInterfaces.sol";
+
+contract C is A, B {
+    function f(uint256) external {}
+    function f(address) external {}
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import "./IERC20.sol";
+import {Fraction, Math} from "math/Math.sol";
+
+contract Token is IERC20 {
+    mapping(address => uint256) public balanceOf;
+
+    function transfer(address to, uint256 amount) external returns (bool) {
+        balanceOf[msg.sender] -= amount;
+        balanceOf[to] += amount;
+        emit Transfer(msg.sender, to, amount);
+        return true;
+    }
+}
+
+contract Vault {
+    struct Deposit {
+        address owner;
+        uint256 amount;
+    }
+
+    event Deposited(address indexed owner, uint256 amount);
+
+    function deposit(uint256 amount) external returns (Deposit memory) {}
+}
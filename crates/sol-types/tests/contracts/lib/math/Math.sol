@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+struct Fraction {
+    uint256 numerator;
+    uint256 denominator;
+}
+
+library Math {
+    function mulDiv(uint256 x, uint256 y, uint256 denominator) internal pure returns (uint256) {
+        return x * y / denominator;
+    }
+}
@@ -1,5 +1,7 @@
 use alloy_primitives::{hex, keccak256, Address, B256, I256, U256};
-use alloy_sol_types::{eip712_domain, sol, SolCall, SolError, SolStruct, SolType};
+use alloy_sol_types::{
+    abi, eip712_domain, sol, sol_data, SolCall, SolError, SolEvent, SolStruct, SolType,
+};
 use serde::Serialize;
 use serde_json::Value;
 
@@ -213,6 +215,265 @@ fn empty_call() {
     let depositCall {} = depositCall::abi_decode_raw(&[], true).unwrap();
 }
 
+#[test]
+fn calldata_gas() {
+    use alloy_primitives::gas::CalldataCosts;
+
+    sol! {
+        function transfer(address to, uint256 amount) external returns (bool);
+    }
+
+    let call = transferCall {
+        to: Address::with_last_byte(1),
+        amount: U256::from(0x1234),
+    };
+    // selector + 2 words, with 4 + 1 + 2 non-zero bytes
+    let data = call.abi_encode();
+    assert_eq!(data.len(), 68);
+    assert_eq!(
+        call.abi_encoded_calldata_gas(&CalldataCosts::ISTANBUL),
+        61 * 4 + 7 * 16
+    );
+    assert_eq!(
+        call.abi_encoded_calldata_gas(&CalldataCosts::PRAGUE),
+        (61 + 7 * 4) * 10
+    );
+}
+
+#[test]
+fn encode_presized() {
+    sol! {
+        function multisend(address[] targets, string[] memos) external;
+        event Sent(address[] targets, string[] memos);
+        error Failed(string[] memos);
+    }
+
+    let targets = vec![Address::repeat_byte(0x11); 1000];
+    let memos = vec!["memo".to_string(); 1000];
+    let call = multisendCall {
+        targets: targets.clone(),
+        memos: memos.clone(),
+    };
+    let data = call.abi_encode();
+    assert_eq!(data.len(), 4 + call.abi_encoded_size());
+    assert_eq!(data.capacity(), data.len());
+
+    let event = Sent {
+        targets,
+        memos: memos.clone(),
+    };
+    let data = event.encode_data();
+    assert_eq!(data.len(), event.abi_encoded_size());
+    assert_eq!(data.capacity(), data.len());
+
+    let error = Failed { memos };
+    let data = error.abi_encode();
+    assert_eq!(data.len(), 4 + error.abi_encoded_size());
+    assert_eq!(data.capacity(), data.len());
+}
+
+#[test]
+fn struct_encoded_size() {
+    sol! {
+        #[derive(Debug, PartialEq)]
+        struct Point {
+            uint256 x;
+            uint256 y;
+        }
+
+        struct Named {
+            Point point;
+            string name;
+        }
+    }
+
+    assert_eq!(Point::ENCODED_SIZE, Some(64));
+    assert_eq!(Named::ENCODED_SIZE, None);
+
+    type Points = alloy_sol_types::sol_data::FixedArray<Point, 2>;
+    assert_eq!(Points::ENCODED_SIZE, Some(128));
+
+    let points = [
+        Point {
+            x: U256::from(1),
+            y: U256::from(2),
+        },
+        Point {
+            x: U256::from(3),
+            y: U256::from(4),
+        },
+    ];
+    let encoded = Points::abi_encode(&points);
+    assert_eq!(encoded.len(), 128);
+    assert_eq!(Points::abi_encoded_size(&points), 128);
+    assert_eq!(Points::abi_decode(&encoded, true).unwrap(), points);
+}
+
+#[test]
+fn encode_without_tokens() {
+    sol! {
+        struct Inner {
+            bytes data;
+            uint8[2] pair;
+        }
+
+        struct Outer {
+            address owner;
+            Inner[] inners;
+            string[3] names;
+            Inner[2] fixed;
+        }
+
+        function submit(Outer outer, bytes32 id, Inner[][] nested) returns (Outer, string);
+        event Submitted(address indexed owner, Outer outer, uint256 id, string indexed tag);
+        error Rejected(Inner inner, string reason);
+    }
+
+    let inner = |n: u8| Inner {
+        data: vec![n; n as usize * 13],
+        pair: [n, n.wrapping_mul(3)],
+    };
+    let outer = Outer {
+        owner: Address::repeat_byte(0x11),
+        inners: vec![inner(1), inner(0), inner(5)],
+        names: ["a".into(), String::new(), "x".repeat(70)],
+        fixed: [inner(2), inner(3)],
+    };
+
+    let encoded = Outer::abi_encode(&outer);
+    assert_eq!(encoded, abi::encode(&Outer::tokenize(&outer)));
+    assert_eq!(encoded.len(), 32 + Outer::abi_encoded_size(&outer));
+    assert_eq!(
+        Outer::abi_decode(&encoded, true).unwrap().names,
+        outer.names
+    );
+    assert_eq!(
+        Outer::abi_encode_params(&outer),
+        abi::encode_params(&Outer::tokenize(&outer))
+    );
+    assert_eq!(
+        Outer::abi_encode_sequence(&outer),
+        abi::encode_sequence(&Outer::tokenize(&outer))
+    );
+
+    let call = submitCall {
+        outer: outer.clone(),
+        id: B256::repeat_byte(0x22),
+        nested: vec![vec![], vec![inner(4), inner(7)]],
+    };
+    let data = call.abi_encode();
+    assert_eq!(data[4..], abi::encode_sequence(&call.tokenize()));
+    assert_eq!(data.len(), 4 + call.abi_encoded_size());
+    assert_eq!(
+        submitCall::abi_encode_returns(&(outer.clone(), "ok".to_string())),
+        abi::encode_sequence(&(
+            Outer::tokenize(&outer),
+            abi::token::PackedSeqToken::from("ok".as_bytes())
+        ))
+    );
+
+    let event = Submitted {
+        owner: outer.owner,
+        outer: outer.clone(),
+        id: U256::from(3),
+        tag: B256::ZERO,
+    };
+    let data = event.encode_data();
+    assert_eq!(data, abi::encode_sequence(&event.tokenize_body()));
+    assert_eq!(data.len(), event.abi_encoded_size());
+
+    let error = Rejected {
+        inner: inner(9),
+        reason: "no".into(),
+    };
+    let data = error.abi_encode();
+    assert_eq!(data[4..], abi::encode_sequence(&error.tokenize()));
+    assert_eq!(data.len(), 4 + error.abi_encoded_size());
+}
+
+#[test]
+fn decode_without_tokens() {
+    sol! {
+        struct Item {
+            bytes data;
+            uint16[2] pair;
+            string[] tags;
+        }
+
+        function process(Item[] items, Item[2] fixed, uint256 n) returns (Item, bool);
+    }
+
+    let item = |n: u16| Item {
+        data: vec![n as u8; n as usize * 11],
+        pair: [n, n * 2],
+        tags: vec!["t".repeat(n as usize); n as usize],
+    };
+    let call = processCall {
+        items: vec![item(0), item(3), item(1)],
+        fixed: [item(2), item(4)],
+        n: U256::from(7),
+    };
+    let data = call.abi_encode();
+    for validate in [false, true] {
+        let decoded = processCall::abi_decode(&data, validate).unwrap();
+        assert_eq!(decoded.abi_encode(), data);
+        assert_eq!(decoded.items[1].tags, call.items[1].tags);
+        assert_eq!(decoded.fixed[1].pair, [4, 8]);
+    }
+
+    let encoded = Item::abi_encode(&item(5));
+    let decoded = Item::abi_decode(&encoded, false).unwrap();
+    assert_eq!(Item::abi_encode(&decoded), encoded);
+    let decoded = Item::abi_decode_sequence(&Item::abi_encode_sequence(&item(5)), false).unwrap();
+    assert_eq!(Item::abi_encode(&decoded), encoded);
+
+    let returns = processCall::abi_encode_returns(&(item(2), true));
+    let decoded = processCall::abi_decode_returns(&returns, false).unwrap();
+    assert_eq!(decoded._0.data, item(2).data);
+    assert!(decoded._1);
+
+    // a huge length must not be trusted to preallocate
+    let data = hex!(
+        "0000000000000000000000000000000000000000000000000000000000000020"
+        "00000000000000000000000000000000000000000000000000000000ffffffff"
+    );
+    assert!(<sol_data::Array<sol_data::Uint<256>>>::abi_decode(&data, false).is_err());
+}
+
+#[test]
+fn decode_selector_mismatch() {
+    sol! {
+        function transfer(address to, uint256 amount) external;
+        error Unauthorized(address caller);
+    }
+
+    let mut data = transferCall {
+        to: Address::ZERO,
+        amount: U256::from(1),
+    }
+    .abi_encode();
+    assert!(transferCall::abi_decode(&data, true).is_ok());
+
+    data[0] ^= 0xff;
+    let mut actual = transferCall::SELECTOR;
+    actual[0] ^= 0xff;
+    assert_eq!(
+        transferCall::abi_decode(&data, true).err(),
+        Some(alloy_sol_types::Error::SelectorMismatch {
+            expected: transferCall::SELECTOR.into(),
+            actual: actual.into(),
+        })
+    );
+    assert_eq!(
+        transferCall::abi_decode(&data[..3], true).err(),
+        Some(alloy_sol_types::Error::Overrun { offset: 0 })
+    );
+    assert!(matches!(
+        Unauthorized::abi_decode(&data, true),
+        Err(alloy_sol_types::Error::SelectorMismatch { .. })
+    ));
+}
+
 #[test]
 fn function_names() {
     sol! {
@@ -380,6 +641,69 @@ fn abigen_sol_multicall() {
     );
 }
 
+#[test]
+fn abigen_sol_imports() {
+    sol!(
+        #![sol(include_paths = ["tests/contracts/lib"])]
+        "tests/contracts/imports/Token.sol"
+    );
+
+    // one module per contract, with the imported items in scope
+    assert_eq!(Token::transferCall::SIGNATURE, "transfer(address,uint256)");
+    assert_eq!(Token::balanceOfCall::SIGNATURE, "balanceOf(address)");
+    assert_eq!(
+        IERC20::Transfer::SIGNATURE,
+        "Transfer(address,address,uint256)"
+    );
+    assert_eq!(Vault::depositCall::SIGNATURE, "deposit(uint256)");
+    assert_eq!(<Vault::Deposit as SolType>::sol_type_name(), "Deposit");
+    assert_eq!(
+        Vault::Deposit::eip712_encode_type(),
+        "Deposit(address owner,uint256 amount)"
+    );
+    assert_eq!(
+        Math::mulDivCall::SIGNATURE,
+        "mulDiv(uint256,uint256,uint256)"
+    );
+    let _ = Fraction {
+        numerator: U256::from(1),
+        denominator: U256::from(2),
+    };
+}
+
+#[test]
+fn overloads_across_contracts() {
+    sol! {
+        interface A {
+            function f(uint256);
+        }
+
+        interface B {
+            function f(address);
+        }
+    }
+
+    // inline items are overloaded across contracts
+    assert_eq!(A::f_0Call::SIGNATURE, "f(uint256)");
+    assert_eq!(B::f_1Call::SIGNATURE, "f(address)");
+}
+
+#[test]
+fn overloads_per_imported_contract() {
+    mod imported {
+        alloy_sol_types::sol!("tests/contracts/imports/Overloads.sol");
+    }
+    use imported::*;
+
+    // items of imported contracts are only overloaded within each contract
+    assert_eq!(A::f_0Call::SIGNATURE, "f(uint256)");
+    assert_eq!(A::f_1Call::SIGNATURE, "f(bool)");
+    assert_eq!(B::f_0Call::SIGNATURE, "f(bool)");
+    assert_eq!(B::f_1Call::SIGNATURE, "f(address)");
+    assert_eq!(C::f_0Call::SIGNATURE, "f(uint256)");
+    assert_eq!(C::f_1Call::SIGNATURE, "f(address)");
+}
+
 #[test]
 fn struct_field_attrs() {
     sol! {
@@ -403,6 +727,72 @@ fn struct_field_attrs() {
     );
 }
 
+#[test]
+fn struct_field_cfg() {
+    sol! {
+        struct CfgFields {
+            uint256 a;
+            #[cfg(all())]
+            bool b;
+            #[cfg(any())]
+            address c;
+            #[cfg(any())]
+            #[cfg(all())]
+            bytes d;
+        }
+    }
+
+    let value = CfgFields {
+        a: U256::from(1),
+        b: true,
+    };
+    assert_eq!(CfgFields::eip712_root_type(), "CfgFields(uint256 a,bool b)");
+    assert_eq!(CfgFields::abi_encoded_size(&value), 64);
+    let encoded = CfgFields::abi_encode(&value);
+    assert_eq!(encoded.len(), 64);
+    let decoded = CfgFields::abi_decode(&encoded, true).unwrap();
+    assert_eq!((decoded.a, decoded.b), (value.a, value.b));
+}
+
+#[test]
+fn param_attrs() {
+    sol! {
+        #[derive(Serialize)]
+        function transfer(
+            #[serde(rename = "recipient")] address to,
+            #[cfg_attr(all(), serde(skip))] uint256 amount
+        ) returns (#[serde(rename = "ok")] bool);
+
+        #[derive(Serialize)]
+        event Transfer(#[serde(rename = "sender")] address indexed from, uint256 amount);
+
+        #[derive(Serialize)]
+        error Insufficient(#[serde(rename = "have")] uint256 balance);
+    }
+
+    fn json<T: Serialize>(value: T) -> Value {
+        serde_json::to_value(value).unwrap()
+    }
+
+    let call = json(transferCall {
+        to: Address::ZERO,
+        amount: U256::from(1),
+    });
+    assert!(call.get("recipient").is_some());
+    assert!(call.get("amount").is_none());
+    assert_eq!(json(transferReturn { _0: true })["ok"], Value::Bool(true));
+
+    let event = json(Transfer {
+        from: Address::ZERO,
+        amount: U256::ZERO,
+    });
+    assert!(event.get("sender").is_some());
+    let error = json(Insufficient {
+        balance: U256::ZERO,
+    });
+    assert!(error.get("have").is_some());
+}
+
 #[test]
 fn enum_variant_attrs() {
     sol! {
@@ -789,3 +1179,406 @@ fn eip712_encode_data_nesting() {
             .unwrap()
     )
 }
+
+#[test]
+fn eip712_signing_hash_from_registry() {
+    sol! {
+        struct Permit {
+            address spender;
+            uint256 value;
+        }
+    }
+
+    let contract = Address::repeat_byte(0x11);
+    let domain = eip712_domain! {
+        name: "Token",
+        chain_id: 1,
+        verifying_contract: contract,
+    };
+    let mut registry = alloy_sol_types::Eip712DomainRegistry::new();
+    registry.insert(1, contract, domain.clone());
+
+    let permit = Permit {
+        spender: Address::repeat_byte(0x22),
+        value: U256::from(1),
+    };
+    assert_eq!(
+        permit.eip712_signing_hash_from(&registry, 1, contract),
+        Some(permit.eip712_signing_hash(&domain))
+    );
+    assert_eq!(
+        permit.eip712_signing_hash_from(&registry, 10, contract),
+        None
+    );
+}
+
+#[test]
+fn event_try_from_log() {
+    sol! {
+        contract Token {
+            event Transfer(address indexed from, address indexed to, uint256 value);
+            event Approval(address indexed owner, address indexed spender, uint256 value);
+            event Note(bytes32 indexed tag) anonymous;
+        }
+    }
+    use alloy_primitives::Log;
+    use alloy_sol_types::Error;
+    use Token::{Approval, Note, TokenEvents, Transfer};
+
+    let transfer = Transfer {
+        from: Address::repeat_byte(0x11),
+        to: Address::repeat_byte(0x22),
+        value: U256::from(100),
+    };
+    let topics: Vec<B256> = transfer.encode_topics().into_iter().map(|t| t.0).collect();
+    let data = transfer.encode_data();
+    let log = Log::new(topics.clone(), data.clone().into()).unwrap();
+
+    let decoded = Transfer::try_from((&topics[..], &data[..])).unwrap();
+    assert_eq!(decoded.value, transfer.value);
+    assert_eq!(Transfer::try_from(&log).unwrap().to, transfer.to);
+    assert_eq!(
+        Approval::try_from(&log).err(),
+        Some(Error::EventSignatureMismatch {
+            expected: Approval::SIGNATURE_HASH,
+            actual: Transfer::SIGNATURE_HASH,
+        })
+    );
+    assert_eq!(
+        Transfer::try_from((&topics[..2], &data[..])).err(),
+        Some(Error::TopicLengthMismatch {
+            expected: 3,
+            actual: 2,
+        })
+    );
+    assert_eq!(
+        Transfer::decode_log(topics[..2].iter().copied(), &data, true).err(),
+        Some(Error::TopicLengthMismatch {
+            expected: 3,
+            actual: 2,
+        })
+    );
+
+    let value = |event| match event {
+        TokenEvents::Transfer(transfer) => transfer.value,
+        TokenEvents::Approval(approval) => approval.value,
+        TokenEvents::Note(note) => note.tag.into(),
+    };
+    assert_eq!(TokenEvents::try_from(&log).map(value), Ok(U256::from(100)));
+
+    // anonymous events are never matched
+    let note = Note {
+        tag: B256::repeat_byte(0x33),
+    };
+    let topics: Vec<B256> = note.encode_topics().into_iter().map(|t| t.0).collect();
+    assert_eq!(
+        Note::try_from((&topics[..], &[][..])).unwrap().tag,
+        note.tag
+    );
+    assert_eq!(
+        TokenEvents::try_from((&topics[..], &[][..])).map(value),
+        Err(Error::UnknownEventSignature {
+            name: "TokenEvents",
+            signature_hash: note.tag,
+        })
+    );
+    let unknown = B256::repeat_byte(0xff);
+    assert_eq!(
+        TokenEvents::try_from((&[unknown][..], &data[..])).err(),
+        Some(Error::UnknownEventSignature {
+            name: "TokenEvents",
+            signature_hash: unknown,
+        })
+    );
+    assert_eq!(
+        TokenEvents::try_from((&[][..], &data[..])).err(),
+        Some(Error::TopicLengthMismatch {
+            expected: 1,
+            actual: 0,
+        })
+    );
+}
+
+#[test]
+fn borrowed_events() {
+    sol! {
+        #[sol(borrowed_events)]
+        #[derive(Debug, PartialEq)]
+        event Message(
+            address indexed from,
+            string indexed topic,
+            string text,
+            uint64 nonce,
+            bytes payload,
+        );
+    }
+    use alloy_primitives::Log;
+    use alloy_sol_types::Error;
+    use std::borrow::Cow;
+
+    let message = Message {
+        from: Address::repeat_byte(0x11),
+        topic: keccak256("greetings"),
+        text: "hello".into(),
+        nonce: 7,
+        payload: vec![1, 2, 3],
+    };
+    let topics: Vec<B256> = message.encode_topics().into_iter().map(|t| t.0).collect();
+    let data = message.encode_data();
+
+    let borrowed = MessageRef::decode_raw_log(&topics, &data, true).unwrap();
+    assert_eq!(borrowed.from, message.from);
+    assert_eq!(borrowed.topic, message.topic);
+    assert!(matches!(borrowed.text, Cow::Borrowed("hello")));
+    assert_eq!(borrowed.nonce, 7);
+    assert_eq!(borrowed.payload, [1, 2, 3]);
+    assert!(data.as_ptr_range().contains(&borrowed.payload.as_ptr()));
+    assert_eq!(borrowed.into_owned(), message);
+
+    let log = Log::new(topics.clone(), data.into()).unwrap();
+    assert_eq!(
+        MessageRef::decode_log(&log, true).unwrap().into_owned(),
+        message
+    );
+    assert!(matches!(
+        MessageRef::decode_raw_log(&topics[1..], &log.data, true),
+        Err(Error::TopicLengthMismatch {
+            expected: 3,
+            actual: 2
+        })
+    ));
+    assert!(MessageRef::decode_raw_log(&[B256::ZERO; 3], &log.data, true).is_err());
+}
+
+#[test]
+#[cfg(feature = "arbitrary")]
+fn arbitrary() {
+    use arbitrary::{Arbitrary, Unstructured};
+    use proptest::{prelude::any, strategy::Strategy, test_runner::TestRunner};
+
+    sol! {
+        #![sol(arbitrary)]
+
+        enum Kind { A, B, C }
+
+        struct Order {
+            address maker;
+            uint24 amount;
+            Kind kind;
+            bytes32[2] tags;
+            string memo;
+        }
+
+        function place(Order order, int16 delta) returns (bool);
+        function ping();
+        error Rejected(Kind kind);
+    }
+
+    let data = [0xff; 1024];
+    let mut u = Unstructured::new(&data);
+    let call = placeCall::arbitrary(&mut u).unwrap();
+    assert!(matches!(call.order.kind, Kind::A | Kind::B | Kind::C));
+    assert_eq!(
+        placeCall::abi_decode(&call.abi_encode(), true)
+            .unwrap()
+            .abi_encode(),
+        call.abi_encode()
+    );
+    assert!(matches!(
+        Kind::arbitrary(&mut u),
+        Ok(Kind::A | Kind::B | Kind::C)
+    ));
+    let _ = (
+        pingCall::arbitrary(&mut u).unwrap(),
+        pingReturn::arbitrary(&mut u).unwrap(),
+    );
+
+    let mut runner = TestRunner::deterministic();
+    runner
+        .run(&(any::<placeCall>(), any::<Rejected>()), |(call, error)| {
+            let encoded = call.abi_encode();
+            assert_eq!(
+                placeCall::abi_decode(&encoded, true).unwrap().abi_encode(),
+                encoded
+            );
+            let encoded = error.abi_encode();
+            assert_eq!(
+                Rejected::abi_decode(&encoded, true).unwrap().abi_encode(),
+                encoded
+            );
+            Ok(())
+        })
+        .unwrap();
+    let _ = any::<placeReturn>().boxed();
+}
+
+#[test]
+fn struct_conversions() {
+    use alloy_sol_types::SolValue;
+
+    sol! {
+        #![sol(struct_conversions)]
+
+        struct Details {
+            address token;
+            uint160 amount;
+            uint48 expiration;
+            uint48 nonce;
+        }
+
+        contract Permit2 {
+            struct PermitDetails {
+                address token;
+                uint160 amount;
+                uint48 expiration;
+                uint48 nonce;
+            }
+
+            struct PermitSingle {
+                PermitDetails details;
+                address spender;
+                bytes32[] sigs;
+            }
+        }
+
+        contract Router {
+            struct PermitDetails {
+                address token;
+                uint160 amount;
+                uint48 expiration;
+                uint48 nonce;
+            }
+
+            struct PermitSingle {
+                PermitDetails details;
+                address spender;
+                bytes32[] sigs;
+            }
+
+            struct Other {
+                address token;
+                uint160 value;
+                uint48 expiration;
+                uint48 nonce;
+            }
+        }
+    }
+
+    let details = Permit2::PermitDetails {
+        token: Address::repeat_byte(0x11),
+        amount: U256::from(1),
+        expiration: 2,
+        nonce: 3,
+    };
+    let single = Permit2::PermitSingle {
+        details: details.clone(),
+        spender: Address::repeat_byte(0x22),
+        sigs: vec![B256::repeat_byte(0x33)],
+    };
+
+    let converted = Router::PermitSingle::from(single.clone());
+    assert_eq!(converted.abi_encode(), single.abi_encode());
+    assert_eq!(converted.eip712_hash_struct(), single.eip712_hash_struct());
+    let back: Permit2::PermitSingle = converted.into();
+    assert_eq!(back.abi_encode(), single.abi_encode());
+
+    let converted: Router::PermitDetails = details.clone().into();
+    assert_eq!(converted.nonce, 3);
+    assert_eq!(Details::from(details).abi_encode(), converted.abi_encode());
+}
+
+#[test]
+fn call_with_trailing() {
+    sol! {
+        function transfer(address to, uint256 amount);
+    }
+
+    let call = transferCall {
+        to: Address::repeat_byte(0x11),
+        amount: U256::from(100),
+    };
+    let sender = Address::repeat_byte(0x22);
+    let data = [call.abi_encode(), sender.to_vec()].concat();
+
+    assert!(transferCall::abi_decode(&data, true).is_err());
+    assert!(transferCall::abi_decode_exact(&data, false).is_err());
+    let (decoded, trailing) = transferCall::abi_decode_with_trailing(&data, true).unwrap();
+    assert_eq!((decoded.to, decoded.amount), (call.to, call.amount));
+    assert_eq!(Address::from_slice(trailing), sender);
+
+    let encoded = call.abi_encode();
+    let (_, trailing) = transferCall::abi_decode_with_trailing(&encoded, true).unwrap();
+    assert!(trailing.is_empty());
+    assert!(transferCall::abi_decode_raw_with_trailing(&data, true).is_err());
+}
+
+#[test]
+fn selectors_only() {
+    mod full {
+        alloy_sol_types::sol! {
+            struct Payment {
+                address to;
+                uint256 amount;
+            }
+
+            interface IRouter {
+                event Routed(address indexed from, Payment payment);
+                error Unroutable(bytes4 selector);
+                function route(Payment calldata payment) external returns (bool);
+                function route(Payment[] calldata payments) external returns (bool);
+            }
+        }
+    }
+
+    mod selectors {
+        alloy_sol_types::sol! {
+            struct Payment {
+                address to;
+                uint256 amount;
+            }
+
+            #[sol(selectors_only)]
+            interface IRouter {
+                event Routed(address indexed from, Payment payment);
+                error Unroutable(bytes4 selector);
+                function route(Payment calldata payment) external returns (bool);
+                function route(Payment[] calldata payments) external returns (bool);
+            }
+        }
+    }
+
+    use full::IRouter as F;
+    use selectors::IRouter as S;
+
+    assert_eq!(S::route_0Call::SIGNATURE, "route((address,uint256))");
+    assert_eq!(S::route_0Call::SIGNATURE, F::route_0Call::SIGNATURE);
+    assert_eq!(S::route_0Call::SELECTOR, F::route_0Call::SELECTOR);
+    assert_eq!(S::route_1Call::SIGNATURE, "route((address,uint256)[])");
+    assert_eq!(S::route_1Call::SELECTOR, F::route_1Call::SELECTOR);
+    assert_eq!(S::Unroutable::SIGNATURE, F::Unroutable::SIGNATURE);
+    assert_eq!(S::Unroutable::SELECTOR, F::Unroutable::SELECTOR);
+    assert_eq!(S::Routed::SIGNATURE, F::Routed::SIGNATURE);
+    assert_eq!(S::Routed::SIGNATURE_HASH, F::Routed::SIGNATURE_HASH);
+
+    // the constants can be used as patterns
+    let route = |selector: [u8; 4]| match selector {
+        S::route_0Call::SELECTOR => Some(0),
+        S::route_1Call::SELECTOR => Some(1),
+        _ => None,
+    };
+    assert_eq!(route(F::route_1Call::SELECTOR), Some(1));
+    assert_eq!(route([0; 4]), None);
+
+    alloy_sol_types::sol! {
+        #![sol(selectors_only)]
+
+        function transfer(address to, uint256 amount) external returns (bool);
+        event Transfer(address indexed from, address indexed to, uint256 amount);
+    }
+    assert_eq!(transferCall::SIGNATURE, "transfer(address,uint256)");
+    assert_eq!(transferCall::SELECTOR, [0xa9, 0x05, 0x9c, 0xbb]);
+    assert_eq!(
+        Transfer::SIGNATURE_HASH,
+        keccak256("Transfer(address,address,uint256)")
+    );
+}
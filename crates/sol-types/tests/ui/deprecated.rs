@@ -0,0 +1,41 @@
+#![deny(deprecated)]
+
+use alloy_sol_types::sol;
+
+sol! {
+    #![sol(borrowed_events)]
+
+    contract Vault {
+        /// @notice Withdraws everything.
+        /// @custom:deprecated Use `withdraw(uint256)` instead.
+        function withdraw() external;
+
+        function withdraw(uint256 amount) external;
+
+        /**
+         * @custom:deprecated
+         */
+        event Withdrawn(address indexed owner, bytes data);
+    }
+}
+
+sol! {
+    #![sol(deprecation_tag = "custom:retired")]
+
+    /// @custom:retired no longer supported
+    /// since version 2
+    /// @param x ignored
+    function retired(uint256 x);
+
+    /// @custom:deprecated ignored with a custom tag
+    function notRetired();
+}
+
+fn main() {
+    let _ = Vault::withdraw_0Call {};
+    let _ = Vault::withdraw_1Call { amount: Default::default() };
+    let _ = Vault::Withdrawn { owner: Default::default(), data: Default::default() };
+    let _ = Vault::WithdrawnRef { owner: Default::default(), data: &[] };
+    let _ = retiredCall { x: Default::default() };
+    let _ = notRetiredCall {};
+}
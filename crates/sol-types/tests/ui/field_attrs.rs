@@ -0,0 +1,44 @@
+use alloy_sol_types::sol;
+
+sol! {
+    struct CfgField {
+        uint256 a;
+        #[cfg(any())]
+        bool b;
+    }
+
+    function cfgStructParam(CfgField a);
+}
+
+sol! {
+    struct AllCfgFields {
+        #[cfg(any())]
+        uint256 a;
+    }
+}
+
+sol! {
+    struct TooManyCfgs {
+        uint256 a;
+        #[cfg(feature = "a")]
+        uint256 b;
+        #[cfg(feature = "b")]
+        uint256 c;
+        #[cfg(feature = "c")]
+        uint256 d;
+        #[cfg(feature = "d")]
+        uint256 e;
+        #[cfg(feature = "e")]
+        uint256 f;
+    }
+}
+
+sol! {
+    function cfgParam(#[cfg(any())] uint256 a) returns (#[cfg(all())] bool);
+}
+
+sol! {
+    event CfgEventParam(#[cfg(any())] uint256 indexed a);
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+use alloy_sol_types::sol;
+
+sol! {
+    interface FunctionCollision {
+        function burn(uint256);
+        function collate_propagate_storage(bytes16);
+    }
+}
+
+sol! {
+    interface ErrorCollision {
+        error burn(uint256);
+        error collate_propagate_storage(bytes16);
+    }
+}
+
+sol! {
+    // functions and errors do not collide with each other
+    interface NoCollision {
+        function burn(uint256);
+        error collate_propagate_storage(bytes16);
+    }
+}
+
+sol! {
+    // unresolved types are reported by the item expansion
+    interface Unresolved {
+        function burn(Missing);
+        error burn(Missing);
+    }
+}
+
+fn main() {}